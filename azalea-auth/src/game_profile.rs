@@ -4,10 +4,46 @@ use std::{
 };
 
 use azalea_buf::{AzBuf, AzBufLimited, AzBufVar, BufReadError};
+use base64::Engine;
 use indexmap::IndexMap;
+use rsa::{
+    RsaPublicKey,
+    pkcs1v15::{Signature, VerifyingKey},
+    pkcs8::DecodePublicKey,
+    signature::Verifier,
+};
 use serde::{Deserialize, Serialize, Serializer};
+use sha1::Sha1;
 use uuid::Uuid;
 
+/// The public key that Mojang's session server uses to sign profile
+/// properties, as a base64-encoded DER `SubjectPublicKeyInfo`.
+///
+/// This is the same key that the vanilla client ships as
+/// `yggdrasil_session_pubkey.der` in authlib. Use
+/// [`mojang_profile_public_key`] to get it as an [`RsaPublicKey`].
+pub const MOJANG_PROFILE_PUBLIC_KEY: &str = "\
+MIICIjANBgkqhkiG9w0BAQEFAAOCAg8AMIICCgKCAgEAylB4B6m5lz7jwrcFz6Fd/fnfUhcvlxsT\
+Sn5kIK/2aGG1C3kMy4VjhwlxF6BFUSnfxhNswPjh3ZitkBxEAFY25uzkJFRwHwVA9mdwjashXILt\
+R6OqdLXXFVyUPIURLOSWqGNBtb08EN5fMnG8iFLgEJIBMxs9BvF3s3/FhuHyPKiVTZmXY0WY4ZyY\
+qvoKR+XjaTRPPvBsDa4WI2u1zxXMeHlodT3lnCzVvyOYBLXL6CJgByuOxccJ8hnXfF9yY4F0aeL0\
+80Jz/3+EBNG8RO4ByhtBf4Ny8NQ6stWsjfeUIvH7bU/4zCYcYOq4WrInXHqS8qruDmIl7P5XXGca\
+buzQstPf/h2CRAUpP/PlHXcMlvewjmGU6MfDK+lifScNYwjPxRo4nKTGFZf/0aqHCh/EAsQyLKrO\
+IYRE0lDG3bzBh8ogIMLAugsAfBb6M3mqCqKaTMAf/VAjh5FFJnjS+7bE+bZEV0qwax1CEoPPJL1f\
+IQjOS8zj086gjpGRCtSy9+bTPTfTR/SJ+VUB5G2IeCItkNHpJX2ygojFZ9n5Fnj7R9ZnOM+L8nyI\
+jPu3aePvtcrXlyLhH/hvOfIOjPxOlqW+O5QwSFP4OEcyLAUgDdUgyW36Z5mB285uKW/ighzZsOTe\
+vVUG2QwDItObIV6i8RCxFbN2oDHyPaO5j1tTaBNyVt8CAwEAAQ==";
+
+/// Parse [`MOJANG_PROFILE_PUBLIC_KEY`] so it can be passed to
+/// [`GameProfile::verify_property`].
+pub fn mojang_profile_public_key() -> RsaPublicKey {
+    let der = base64::engine::general_purpose::STANDARD
+        .decode(MOJANG_PROFILE_PUBLIC_KEY)
+        .expect("MOJANG_PROFILE_PUBLIC_KEY should be valid base64");
+    RsaPublicKey::from_public_key_der(&der)
+        .expect("MOJANG_PROFILE_PUBLIC_KEY should be a valid RSA public key")
+}
+
 /// Information about the player that's usually stored on Mojang's servers.
 #[derive(AzBuf, Clone, Debug, Default, Eq, PartialEq)]
 pub struct GameProfile {
@@ -34,6 +70,98 @@ impl GameProfile {
             properties: Arc::new(GameProfileProperties::default()),
         }
     }
+
+    /// Decode the `textures` property of this profile into the URLs of the
+    /// player's skin and cape.
+    ///
+    /// Returns `None` if the profile has no `textures` property or if it
+    /// couldn't be decoded. Note that this doesn't verify the signature of the
+    /// property.
+    pub fn textures(&self) -> Option<ProfileTextures> {
+        let property = self.properties.map.get("textures")?;
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(&property.value)
+            .ok()?;
+        let payload: TexturesPayload = serde_json::from_slice(&decoded).ok()?;
+
+        let skin = payload.textures.skin;
+        let skin_model = match skin.as_ref().and_then(|s| s.metadata.as_ref()) {
+            Some(metadata) if metadata.model.as_deref() == Some("slim") => SkinModel::Slim,
+            _ => SkinModel::Classic,
+        };
+        Some(ProfileTextures {
+            skin_url: skin.map(|s| s.url),
+            cape_url: payload.textures.cape.map(|c| c.url),
+            skin_model,
+        })
+    }
+
+    /// Check whether the property with the given name has a valid signature
+    /// from Mojang.
+    ///
+    /// Returns `false` if the property doesn't exist, isn't signed, or the
+    /// signature doesn't match. The key that Mojang's session server signs
+    /// profile properties with is available from
+    /// [`mojang_profile_public_key`].
+    pub fn verify_property(&self, name: &str, mojang_pubkey: &RsaPublicKey) -> bool {
+        let Some(property) = self.properties.map.get(name) else {
+            return false;
+        };
+        let Some(signature) = &property.signature else {
+            return false;
+        };
+        let Ok(signature) = base64::engine::general_purpose::STANDARD.decode(signature) else {
+            return false;
+        };
+        let Ok(signature) = Signature::try_from(signature.as_slice()) else {
+            return false;
+        };
+
+        VerifyingKey::<Sha1>::new(mojang_pubkey.clone())
+            .verify(property.value.as_bytes(), &signature)
+            .is_ok()
+    }
+}
+
+/// The decoded `textures` property of a [`GameProfile`].
+///
+/// The URLs are `None` if the player doesn't have a custom skin or a cape.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProfileTextures {
+    pub skin_url: Option<String>,
+    pub cape_url: Option<String>,
+    pub skin_model: SkinModel,
+}
+
+/// The arm width of a player's skin.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SkinModel {
+    /// The wide-armed "Steve" model.
+    #[default]
+    Classic,
+    /// The slim-armed "Alex" model.
+    Slim,
+}
+
+#[derive(Deserialize)]
+struct TexturesPayload {
+    textures: TexturesMap,
+}
+#[derive(Deserialize)]
+struct TexturesMap {
+    #[serde(rename = "SKIN")]
+    skin: Option<TextureEntry>,
+    #[serde(rename = "CAPE")]
+    cape: Option<TextureEntry>,
+}
+#[derive(Deserialize)]
+struct TextureEntry {
+    url: String,
+    metadata: Option<TextureMetadata>,
+}
+#[derive(Deserialize)]
+struct TextureMetadata {
+    model: Option<String>,
 }
 
 impl From<SerializableGameProfile> for GameProfile {
@@ -211,4 +339,139 @@ mod tests {
             }
         );
     }
+
+    fn profile_with_textures(value: &str) -> GameProfile {
+        let mut map = IndexMap::new();
+        map.insert(
+            "textures".to_owned(),
+            ProfilePropertyValue {
+                value: value.to_owned(),
+                signature: None,
+            },
+        );
+        GameProfile {
+            properties: GameProfileProperties { map }.into(),
+            ..GameProfile::new(Uuid::nil(), "Notch".to_owned())
+        }
+    }
+
+    #[test]
+    fn test_textures() {
+        let profile = profile_with_textures(concat!(
+            "ewogICJ0aW1lc3RhbXAiIDogMTcwMDAwMDAwMDAwMCwKICAicHJvZmlsZUlkIiA6",
+            "ICIwNjlhNzlmNDQ0ZTk0NzI2YTViZWZjYTkwZTM4YWFmNSIsCiAgInByb2ZpbGVO",
+            "YW1lIiA6ICJOb3RjaCIsCiAgInRleHR1cmVzIiA6IHsKICAgICJTS0lOIiA6IHsK",
+            "ICAgICAgInVybCIgOiAiaHR0cDovL3RleHR1cmVzLm1pbmVjcmFmdC5uZXQvdGV4",
+            "dHVyZS8yOTIwMDlhNDkyNWI1OGYwMmM3N2RhZGMzZWNlZjA3ZWE0Yzc0NzJmNjRl",
+            "MGZkYzMyY2U1NTIyNDg5MzYyNjgwIiwKICAgICAgIm1ldGFkYXRhIiA6IHsKICAg",
+            "ICAgICAibW9kZWwiIDogInNsaW0iCiAgICAgIH0KICAgIH0sCiAgICAiQ0FQRSIg",
+            "OiB7CiAgICAgICJ1cmwiIDogImh0dHA6Ly90ZXh0dXJlcy5taW5lY3JhZnQubmV0",
+            "L3RleHR1cmUvOTUzY2FjOGI3NzlmZTQxMzgzZTY3NWVlMmI4NjA3MWE3MTY1OGYy",
+            "MTgwZjU2ZmJjZThhYTMxNWVhNzBlMmVkNiIKICAgIH0KICB9Cn0=",
+        ));
+        assert_eq!(
+            profile.textures(),
+            Some(ProfileTextures {
+                skin_url: Some("http://textures.minecraft.net/texture/292009a4925b58f02c77dadc3ecef07ea4c7472f64e0fdc32ce5522489362680".to_owned()),
+                cape_url: Some("http://textures.minecraft.net/texture/953cac8b779fe41383e675ee2b86071a71658f2180f56fbce8aa315ea70e2ed6".to_owned()),
+                skin_model: SkinModel::Slim,
+            })
+        );
+    }
+
+    #[test]
+    fn test_textures_without_skin() {
+        let profile = profile_with_textures(
+            "eyJ0aW1lc3RhbXAiOjE3MDAwMDAwMDAwMDAsInByb2ZpbGVJZCI6IjA2OWE3OWY0NDRlOTQ3MjZhNWJlZmNhOTBlMzhhYWY1IiwicHJvZmlsZU5hbWUiOiJOb3RjaCIsInRleHR1cmVzIjp7fX0=",
+        );
+        assert_eq!(profile.textures(), Some(ProfileTextures::default()));
+    }
+
+    #[test]
+    fn test_textures_missing_or_malformed() {
+        assert_eq!(
+            GameProfile::new(Uuid::nil(), "Notch".to_owned()).textures(),
+            None
+        );
+        assert_eq!(profile_with_textures("not base64!").textures(), None);
+        // valid base64, but not json
+        assert_eq!(profile_with_textures("aGVsbG8=").textures(), None);
+    }
+
+    /// A 1024-bit RSA key in PKCS#8 DER, only used for this test.
+    const PRIVATE_KEY_BASE64: &str = "\
+        MIICdwIBADANBgkqhkiG9w0BAQEFAASCAmEwggJdAgEAAoGBAL4aSRmlJTKG6gtp5tBQOYHEKyJB5FrgdB9lgWcAMh\
+        MSiiRHIofYy6ZrZ/tOaoqMaD7T0LLRuAJdPJmFbs2VVSMVzM2sawYPvV+gXsDX1iVZ58SdOV7JuzPlRbAePG0jDO4T\
+        07FbjY9s5ifw41nF9xmctqvdv2VJ4hE2vzVJomPRAgMBAAECgYBxjC/Hkp3SPUWEhb/pA1ImEW8WKjdklJanvjB5vm\
+        MqgzNiEd8tNzAE+jiEocT/dng5trj8Zkm7l8c/h7S0NRNBNxIWJ28RFWgwrY1MZdbeXFxXiHrd5N9psoQE2k/ivyFk\
+        9cRSJvDx1aXvPsNtrv5wy9GKfqontWi0Zb81tnSR1QJBAN74+u4dVlgWe/7DEimM164b+aeae5SuIAEZXotrG5PSBl\
+        EJSodgd3MLq8zl32quDjSUKki73KD2XKsd+/TeDYsCQQDaQuS8ypRzLbTT5Ikxn4d5iixbkRllykUhPKprwCu/D6nk\
+        uUfyO307893Ve2zARZzPi9cqvO2GnejBkpC2H3eTAkEArBvtvT0B+A6q+XRjoZToqqlHZNr2x0Y8x/xoIR+08r4sSA\
+        Lu91479Z6tpwRNFxBxQJ6ZoPj5jXl9AEytvk5kjwJBANkHcUmlKs4I4eldM2qrlduuIIOAmb+UzP7Kwy9XrGhU2/v6\
+        nObqbpIB4VDZZP8feVkLVY5CxPF57asl4U2npo8CQEYxNmSOmxELmvUa03hqW8TW4JMU5itgq3XCnYyaQCx/Yd5Vte\
+        TVV0ZZ8R6rzruDPQowszW/cD8geb+MSH2RniI=";
+
+    fn signed_profile(value: &str, signed_value: &str) -> (GameProfile, RsaPublicKey) {
+        use rsa::{
+            RsaPrivateKey,
+            pkcs1v15::SigningKey,
+            pkcs8::DecodePrivateKey,
+            signature::{SignatureEncoding, Signer},
+        };
+
+        let private_key_der = base64::engine::general_purpose::STANDARD
+            .decode(PRIVATE_KEY_BASE64)
+            .unwrap();
+        let private_key = RsaPrivateKey::from_pkcs8_der(&private_key_der).unwrap();
+        let public_key = private_key.to_public_key();
+
+        let signature = SigningKey::<Sha1>::new(private_key)
+            .sign(signed_value.as_bytes())
+            .to_bytes();
+        let mut map = IndexMap::new();
+        map.insert(
+            "textures".to_owned(),
+            ProfilePropertyValue {
+                value: value.to_owned(),
+                signature: Some(base64::engine::general_purpose::STANDARD.encode(signature)),
+            },
+        );
+        let profile = GameProfile {
+            properties: GameProfileProperties { map }.into(),
+            ..GameProfile::new(Uuid::nil(), "Notch".to_owned())
+        };
+        (profile, public_key)
+    }
+
+    #[test]
+    fn test_verify_property() {
+        let value = "eyJ0aW1lc3RhbXAiOjE3MDAwMDAwMDAwMDAsInRleHR1cmVzIjp7fX0=";
+        let (profile, public_key) = signed_profile(value, value);
+        assert!(profile.verify_property("textures", &public_key));
+        assert!(!profile.verify_property("nonexistent", &public_key));
+    }
+
+    #[test]
+    fn test_verify_tampered_property() {
+        let (profile, public_key) = signed_profile(
+            "eyJ0aW1lc3RhbXAiOjE3MDAwMDAwMDAwMDEsInRleHR1cmVzIjp7fX0=",
+            "eyJ0aW1lc3RhbXAiOjE3MDAwMDAwMDAwMDAsInRleHR1cmVzIjp7fX0=",
+        );
+        assert!(!profile.verify_property("textures", &public_key));
+    }
+
+    #[test]
+    fn test_mojang_profile_public_key() {
+        use rsa::traits::PublicKeyParts;
+
+        assert_eq!(mojang_profile_public_key().size(), 4096 / 8);
+    }
+
+    #[test]
+    fn test_verify_property_rejects_other_keys() {
+        // a property signed by some other key must not pass as signed by mojang
+        let value = "eyJ0aW1lc3RhbXAiOjE3MDAwMDAwMDAwMDAsInRleHR1cmVzIjp7fX0=";
+        let (profile, _) = signed_profile(value, value);
+        assert!(!profile.verify_property("textures", &mojang_profile_public_key()));
+    }
 }
@@ -33,6 +33,23 @@ pub enum ClientSessionServerError {
     UnexpectedResponse { status_code: u16, body: String },
 }
 
+impl ClientSessionServerError {
+    /// Returns whether this error is likely to be temporary, like when
+    /// Mojang's servers are having an outage or we got rate limited.
+    ///
+    /// Requests that failed with these errors may succeed if they're tried
+    /// again after a short delay, unlike errors such as
+    /// [`Self::InvalidSession`] or [`Self::Banned`].
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::HttpError(err) => err.is_timeout() || err.is_connect(),
+            Self::AuthServersUnreachable | Self::RateLimited => true,
+            Self::UnexpectedResponse { status_code, .. } => *status_code >= 500,
+            _ => false,
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum ServerSessionServerError {
     #[error("Error sending HTTP request to sessionserver: {0}")]
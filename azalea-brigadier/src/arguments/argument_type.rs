@@ -1,5 +1,6 @@
 use std::{any::Any, sync::Arc};
 
+use super::string_argument_type::StringArgument;
 use crate::{
     errors::CommandSyntaxError,
     string_reader::StringReader,
@@ -16,4 +17,23 @@ pub trait ArgumentType {
     fn examples(&self) -> Vec<String> {
         vec![]
     }
+
+    /// The parser that this argument is sent as in the vanilla Commands
+    /// packet, or `None` if it doesn't correspond to one of Brigadier's
+    /// built-in argument types.
+    fn parser(&self) -> Option<ArgumentParser> {
+        None
+    }
+}
+
+/// One of Brigadier's built-in argument types, along with the properties that
+/// get sent over the network.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArgumentParser {
+    Bool,
+    Float { min: Option<f32>, max: Option<f32> },
+    Double { min: Option<f64>, max: Option<f64> },
+    Integer { min: Option<i32>, max: Option<i32> },
+    Long { min: Option<i64>, max: Option<i64> },
+    String(StringArgument),
 }
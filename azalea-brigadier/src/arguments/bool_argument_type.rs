@@ -1,6 +1,6 @@
 use std::{any::Any, sync::Arc};
 
-use super::ArgumentType;
+use super::{ArgumentParser, ArgumentType};
 use crate::{
     context::CommandContext,
     errors::CommandSyntaxError,
@@ -26,6 +26,10 @@ impl ArgumentType for Boolean {
         builder.build()
     }
 
+    fn parser(&self) -> Option<ArgumentParser> {
+        Some(ArgumentParser::Bool)
+    }
+
     fn examples(&self) -> Vec<String> {
         vec!["true".to_owned(), "false".to_owned()]
     }
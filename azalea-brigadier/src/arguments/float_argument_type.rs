@@ -1,6 +1,6 @@
 use std::{any::Any, sync::Arc};
 
-use super::ArgumentType;
+use super::{ArgumentParser, ArgumentType};
 use crate::{
     context::CommandContext,
     errors::{BuiltInError, CommandSyntaxError},
@@ -40,6 +40,13 @@ impl ArgumentType for Float {
         Ok(Arc::new(result))
     }
 
+    fn parser(&self) -> Option<ArgumentParser> {
+        Some(ArgumentParser::Float {
+            min: self.minimum,
+            max: self.maximum,
+        })
+    }
+
     fn examples(&self) -> Vec<String> {
         vec!["0", "1.2", ".5", "-1", "-.5", "-1234.56"]
             .into_iter()
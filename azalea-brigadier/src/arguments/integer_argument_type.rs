@@ -1,6 +1,6 @@
 use std::{any::Any, sync::Arc};
 
-use super::ArgumentType;
+use super::{ArgumentParser, ArgumentType};
 use crate::{
     context::CommandContext,
     errors::{BuiltInError, CommandSyntaxError},
@@ -40,6 +40,13 @@ impl ArgumentType for Integer {
         Ok(Arc::new(result))
     }
 
+    fn parser(&self) -> Option<ArgumentParser> {
+        Some(ArgumentParser::Integer {
+            min: self.minimum,
+            max: self.maximum,
+        })
+    }
+
     fn examples(&self) -> Vec<String> {
         vec!["0", "123", "-123"]
             .into_iter()
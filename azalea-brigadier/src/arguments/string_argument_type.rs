@@ -1,8 +1,9 @@
 use std::{any::Any, sync::Arc};
 
-use super::ArgumentType;
+use super::{ArgumentParser, ArgumentType};
 use crate::{context::CommandContext, errors::CommandSyntaxError, string_reader::StringReader};
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum StringArgument {
     /// Match up until the next space.
     SingleWord,
@@ -27,6 +28,10 @@ impl ArgumentType for StringArgument {
         Ok(Arc::new(result))
     }
 
+    fn parser(&self) -> Option<ArgumentParser> {
+        Some(ArgumentParser::String(*self))
+    }
+
     fn examples(&self) -> Vec<String> {
         match self {
             StringArgument::SingleWord => vec!["word", "words_with_underscores"],
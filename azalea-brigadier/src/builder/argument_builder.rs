@@ -1,5 +1,6 @@
 use std::{
     fmt::{self, Debug},
+    pin::Pin,
     sync::Arc,
 };
 
@@ -8,9 +9,9 @@ use parking_lot::RwLock;
 use super::{literal_argument_builder::Literal, required_argument_builder::Argument};
 use crate::{
     context::CommandContext,
-    errors::CommandSyntaxError,
+    errors::{BuiltInError, CommandSyntaxError},
     modifier::RedirectModifier,
-    tree::{Command, CommandNode},
+    tree::{AsyncCommand, Command, CommandNode},
 };
 
 #[derive(Debug)]
@@ -34,6 +35,7 @@ pub struct ArgumentBuilder<S> {
     arguments: CommandNode<S>,
 
     command: Command<S>,
+    async_command: AsyncCommand<S>,
     requirement: Arc<dyn Fn(&S) -> bool + Send + Sync>,
     target: Option<Arc<RwLock<CommandNode<S>>>>,
 
@@ -50,6 +52,7 @@ impl<S> ArgumentBuilder<S> {
                 ..Default::default()
             },
             command: None,
+            async_command: None,
             requirement: Arc::new(|_| true),
             forks: false,
             modifier: None,
@@ -106,6 +109,37 @@ impl<S> ArgumentBuilder<S> {
         self
     }
 
+    /// Same as [`Self::executes`] but the command is async, which is useful
+    /// for commands that need to do IO.
+    ///
+    /// Async commands must be run with [`CommandDispatcher::execute_async`].
+    /// Running them with [`CommandDispatcher::execute`] will return an error.
+    ///
+    /// ```
+    /// # use azalea_brigadier::prelude::*;
+    /// # let mut subject = CommandDispatcher::<()>::new();
+    /// # subject.register(
+    /// literal("foo").executes_async(|ctx: &CommandContext<()>| Box::pin(async { 42 }))
+    /// # );
+    /// ```
+    ///
+    /// [`CommandDispatcher::execute_async`]: crate::command_dispatcher::CommandDispatcher::execute_async
+    /// [`CommandDispatcher::execute`]: crate::command_dispatcher::CommandDispatcher::execute
+    pub fn executes_async<F>(mut self, f: F) -> Self
+    where
+        F: for<'a> Fn(&'a CommandContext<S>) -> Pin<Box<dyn Future<Output = i32> + 'a>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        // the node still needs a sync command so it's considered executable
+        self.command = Some(Arc::new(|_| {
+            Err(BuiltInError::DispatcherCommandIsAsync.create())
+        }));
+        self.async_command = Some(Arc::new(f));
+        self
+    }
+
     /// Set the requirement for this node to be considered.
     ///
     /// If this is not present on a node, it is considered to always pass.
@@ -168,6 +202,7 @@ impl<S> ArgumentBuilder<S> {
         let mut result = CommandNode {
             value: self.arguments.value,
             command: self.command,
+            async_command: self.async_command,
             requirement: self.requirement,
             redirect: self.target,
             modifier: self.modifier,
@@ -202,6 +237,7 @@ impl<S> Clone for ArgumentBuilder<S> {
         Self {
             arguments: self.arguments.clone(),
             command: self.command.clone(),
+            async_command: self.async_command.clone(),
             requirement: self.requirement.clone(),
             target: self.target.clone(),
             forks: self.forks,
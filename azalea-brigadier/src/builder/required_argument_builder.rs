@@ -6,7 +6,7 @@ use std::{
 
 use super::argument_builder::{ArgumentBuilder, ArgumentBuilderType};
 use crate::{
-    arguments::ArgumentType,
+    arguments::{ArgumentParser, ArgumentType},
     context::CommandContext,
     errors::CommandSyntaxError,
     string_reader::StringReader,
@@ -53,6 +53,16 @@ impl<S> Argument<S> {
     pub fn examples(&self) -> Vec<String> {
         self.parser.examples()
     }
+
+    pub fn parser(&self) -> Option<ArgumentParser> {
+        self.parser.parser()
+    }
+
+    /// Whether this argument has a custom [`SuggestionProvider`], rather than
+    /// only using the suggestions from its argument type.
+    pub fn has_custom_suggestions(&self) -> bool {
+        self.custom_suggestions.is_some()
+    }
 }
 
 impl<S> From<Argument<S>> for ArgumentBuilderType<S> {
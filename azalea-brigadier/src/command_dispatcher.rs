@@ -9,14 +9,14 @@ use std::{
 use parking_lot::RwLock;
 
 use crate::{
-    builder::argument_builder::ArgumentBuilder,
-    context::{CommandContextBuilder, ContextChain},
+    builder::argument_builder::{ArgumentBuilder, ArgumentBuilderType},
+    context::{CommandContextBuilder, ContextChain, StringRange},
     errors::{BuiltInError, CommandSyntaxError},
     parse_results::ParseResults,
     result_consumer::{DefaultResultConsumer, ResultConsumer},
     string_reader::StringReader,
     suggestion::{Suggestions, SuggestionsBuilder},
-    tree::CommandNode,
+    tree::{CommandNode, PacketNode, PacketNodeType},
 };
 
 /// The root of the command tree. You need to make this to register commands.
@@ -32,6 +32,7 @@ where
 {
     pub root: Arc<RwLock<CommandNode<S>>>,
     consumer: Box<dyn ResultConsumer<S> + Send + Sync>,
+    literal_case_insensitive: bool,
 }
 
 impl<S> CommandDispatcher<S> {
@@ -39,9 +40,25 @@ impl<S> CommandDispatcher<S> {
         Self {
             root: Arc::new(RwLock::new(CommandNode::default())),
             consumer: Box::new(DefaultResultConsumer),
+            literal_case_insensitive: false,
         }
     }
 
+    /// Set whether literals should be matched while ignoring ASCII case when
+    /// parsing, so `/Home` would match a `home` literal.
+    ///
+    /// The nodes in the parsed context will still have the names that they
+    /// were registered with. This is disabled by default.
+    pub fn set_literal_case_insensitive(&mut self, case_insensitive: bool) {
+        self.literal_case_insensitive = case_insensitive;
+    }
+
+    /// Whether literals are matched case-insensitively, see
+    /// [`Self::set_literal_case_insensitive`].
+    pub fn is_literal_case_insensitive(&self) -> bool {
+        self.literal_case_insensitive
+    }
+
     /// Add a new node to the root.
     ///
     /// ```
@@ -74,7 +91,10 @@ impl<S> CommandDispatcher<S> {
         let mut potentials: Vec<ParseResults<S>> = vec![];
         let cursor = original_reader.cursor();
 
-        for child in node.read().get_relevant_nodes(&mut original_reader.clone()) {
+        for child in node
+            .read()
+            .relevant_nodes(&mut original_reader.clone(), self.literal_case_insensitive)
+        {
             if !child.read().can_use(&source) {
                 continue;
             }
@@ -190,6 +210,21 @@ impl<S> CommandDispatcher<S> {
         self.execute_parsed(parse)
     }
 
+    /// Same as [`Self::execute`], but commands that were registered with
+    /// [`ArgumentBuilder::executes_async`] are awaited.
+    ///
+    /// Synchronous commands are also supported and are run like normal.
+    pub async fn execute_async(
+        &self,
+        input: impl Into<StringReader>,
+        source: S,
+    ) -> Result<i32, CommandSyntaxError> {
+        let input = input.into();
+
+        let parse = self.parse(input, source);
+        self.execute_parsed_async(parse).await
+    }
+
     pub fn add_paths(
         node: Arc<RwLock<CommandNode<S>>>,
         result: &mut Vec<Vec<Arc<RwLock<CommandNode<S>>>>>,
@@ -238,8 +273,90 @@ impl<S> CommandDispatcher<S> {
         Some(node)
     }
 
+    /// Flatten the command tree into the list of nodes that's sent in the
+    /// vanilla Commands packet.
+    ///
+    /// Nodes are indexed in breadth-first order, so the root is always at
+    /// index 0 and the indices are stable as long as the tree doesn't change.
+    /// Requirements aren't checked, so every node in the tree is included.
+    pub fn to_packet_nodes(&self) -> Vec<PacketNode> {
+        fn index_of<S>(
+            node: &Arc<RwLock<CommandNode<S>>>,
+            indices: &mut HashMap<*const RwLock<CommandNode<S>>, u32>,
+            ordered: &mut Vec<Arc<RwLock<CommandNode<S>>>>,
+        ) -> u32 {
+            *indices.entry(Arc::as_ptr(node)).or_insert_with(|| {
+                ordered.push(node.clone());
+                ordered.len() as u32 - 1
+            })
+        }
+
+        let mut indices = HashMap::new();
+        let mut ordered = Vec::new();
+        index_of(&self.root, &mut indices, &mut ordered);
+
+        let mut nodes = Vec::new();
+        // `ordered` grows as we discover new nodes, so this visits them in
+        // breadth-first order
+        while let Some(node) = ordered.get(nodes.len()).cloned() {
+            let node = node.read();
+
+            let children = node
+                .children
+                .values()
+                .map(|child| index_of(child, &mut indices, &mut ordered))
+                .collect();
+            let redirect_node = node
+                .redirect
+                .as_ref()
+                .map(|target| index_of(target, &mut indices, &mut ordered));
+
+            let node_type = if nodes.is_empty() {
+                PacketNodeType::Root
+            } else {
+                match &node.value {
+                    ArgumentBuilderType::Literal(literal) => PacketNodeType::Literal {
+                        name: literal.value.clone(),
+                    },
+                    ArgumentBuilderType::Argument(argument) => PacketNodeType::Argument {
+                        name: argument.name.clone(),
+                        parser: argument.parser(),
+                        has_custom_suggestions: argument.has_custom_suggestions(),
+                    },
+                }
+            };
+
+            nodes.push(PacketNode {
+                is_executable: node.command.is_some(),
+                children,
+                redirect_node,
+                node_type,
+            });
+        }
+        nodes
+    }
+
     /// Executes a given pre-parsed command.
     pub fn execute_parsed(&self, parse: ParseResults<S>) -> Result<i32, CommandSyntaxError> {
+        let (flat_context, source) = self.flatten_parsed(parse)?;
+        flat_context.execute_all(source, self.consumer.as_ref())
+    }
+
+    /// Executes a given pre-parsed command, awaiting it if it's async.
+    pub async fn execute_parsed_async(
+        &self,
+        parse: ParseResults<'_, S>,
+    ) -> Result<i32, CommandSyntaxError> {
+        let (flat_context, source) = self.flatten_parsed(parse)?;
+        flat_context
+            .execute_all_async(source, self.consumer.as_ref())
+            .await
+    }
+
+    fn flatten_parsed(
+        &self,
+        parse: ParseResults<S>,
+    ) -> Result<(ContextChain<S>, Arc<S>), CommandSyntaxError> {
         if parse.reader.can_read() {
             return Err(if parse.exceptions.len() == 1 {
                 parse.exceptions.values().next().unwrap().clone()
@@ -258,7 +375,7 @@ impl<S> CommandDispatcher<S> {
             return Err(BuiltInError::DispatcherUnknownCommand.create_with_context(&parse.reader));
         };
 
-        flat_context.execute_all(original.source.clone(), self.consumer.as_ref())
+        Ok((flat_context, original.source.clone()))
     }
 
     pub fn get_all_usage(
@@ -440,6 +557,13 @@ impl<S> CommandDispatcher<S> {
         Self::get_completion_suggestions_with_cursor(parse, cursor)
     }
 
+    /// Get the suggestions for the input at the given cursor position.
+    ///
+    /// Only the input before the cursor is considered, and the range of the
+    /// suggestions ends at the cursor. This matches vanilla Brigadier, but it
+    /// means that applying a suggestion while the cursor is in the middle of a
+    /// word will leave the rest of the word after it. If that's not what you
+    /// want, use [`Self::get_completion_suggestions_replacing_token`].
     pub fn get_completion_suggestions_with_cursor(
         parse: ParseResults<S>,
         cursor: usize,
@@ -469,6 +593,39 @@ impl<S> CommandDispatcher<S> {
 
         Suggestions::merge(full_input, &all_suggestions)
     }
+
+    /// Like [`Self::get_completion_suggestions_with_cursor`], but the
+    /// suggestions replace the whole word that the cursor is in instead of
+    /// only the part before the cursor.
+    ///
+    /// This is useful for text editors where the cursor can be moved into the
+    /// middle of the input.
+    pub fn get_completion_suggestions_replacing_token(
+        parse: ParseResults<S>,
+        cursor: usize,
+    ) -> Suggestions {
+        let full_input = parse.reader.string().to_owned();
+        let suggestions = Self::get_completion_suggestions_with_cursor(parse, cursor);
+        if suggestions.is_empty() {
+            return suggestions;
+        }
+
+        let token_end = full_input[cursor..]
+            .find(' ')
+            .map_or(full_input.len(), |i| cursor + i);
+        let range = suggestions.range();
+        let range = StringRange::between(range.start(), usize::max(range.end(), token_end));
+        let list = suggestions
+            .list()
+            .iter()
+            .map(|suggestion| {
+                let mut suggestion = suggestion.clone();
+                suggestion.range = range;
+                suggestion
+            })
+            .collect();
+        Suggestions::new(range, list)
+    }
 }
 
 impl<S> Default for CommandDispatcher<S> {
@@ -95,6 +95,81 @@ impl<S> ContextChain<S> {
             return self.run_executable(self.executable.clone(), source, result_consumer, false);
         }
 
+        let (current_sources, forked_mode) = self.run_modifiers(source, result_consumer)?;
+
+        let mut result = 0;
+        for execution_source in current_sources {
+            result += self.run_executable(
+                self.executable.clone(),
+                execution_source,
+                result_consumer,
+                forked_mode,
+            )?;
+        }
+
+        Ok(result)
+    }
+
+    /// Same as [`Self::run_executable`], but if the command is async then it's
+    /// awaited.
+    pub async fn run_executable_async(
+        &self,
+        executable: Rc<CommandContext<S>>,
+        source: Arc<S>,
+        result_consumer: &dyn ResultConsumer<S>,
+        forked_mode: bool,
+    ) -> Result<i32, CommandSyntaxError> {
+        // the last node is the one that the command came from
+        let async_command = executable
+            .nodes()
+            .last()
+            .and_then(|node| node.node.read().async_command.clone());
+        let Some(async_command) = async_command else {
+            return self.run_executable(executable, source, result_consumer, forked_mode);
+        };
+
+        let context_to_use = Rc::new(executable.copy_for(source));
+        let result = (async_command)(&context_to_use).await;
+        result_consumer.on_command_complete(context_to_use, true, result);
+        if forked_mode { Ok(1) } else { Ok(result) }
+    }
+
+    /// Same as [`Self::execute_all`], but async commands are awaited.
+    pub async fn execute_all_async(
+        &self,
+        source: Arc<S>,
+        result_consumer: &dyn ResultConsumer<S>,
+    ) -> Result<i32, CommandSyntaxError> {
+        if self.modifiers.is_empty() {
+            return self
+                .run_executable_async(self.executable.clone(), source, result_consumer, false)
+                .await;
+        }
+
+        let (current_sources, forked_mode) = self.run_modifiers(source, result_consumer)?;
+
+        let mut result = 0;
+        for execution_source in current_sources {
+            result += self
+                .run_executable_async(
+                    self.executable.clone(),
+                    execution_source,
+                    result_consumer,
+                    forked_mode,
+                )
+                .await?;
+        }
+
+        Ok(result)
+    }
+
+    /// Run all of the modifiers in this chain, returning the sources that the
+    /// executable should be run with and whether we're in forked mode.
+    fn run_modifiers(
+        &self,
+        source: Arc<S>,
+        result_consumer: &dyn ResultConsumer<S>,
+    ) -> Result<(Vec<Arc<S>>, bool), CommandSyntaxError> {
         let mut forked_mode = false;
         let mut current_sources = vec![source];
 
@@ -111,22 +186,12 @@ impl<S> ContextChain<S> {
                 )?);
             }
             if next_sources.is_empty() {
-                return Ok(0);
+                return Ok((vec![], forked_mode));
             }
             current_sources = next_sources;
         }
 
-        let mut result = 0;
-        for execution_source in current_sources {
-            result += self.run_executable(
-                self.executable.clone(),
-                execution_source,
-                result_consumer,
-                forked_mode,
-            )?;
-        }
-
-        Ok(result)
+        Ok((current_sources, forked_mode))
     }
 
     pub fn stage(&self) -> Stage {
@@ -38,6 +38,7 @@ pub enum BuiltInError {
     DispatcherUnknownArgument,
     DispatcherExpectedArgumentSeparator,
     DispatcherParseException { message: String },
+    DispatcherCommandIsAsync,
 }
 
 impl fmt::Debug for BuiltInError {
@@ -136,6 +137,9 @@ impl fmt::Debug for BuiltInError {
             BuiltInError::DispatcherParseException { message } => {
                 write!(f, "Could not parse command: {message}")
             }
+            BuiltInError::DispatcherCommandIsAsync => {
+                write!(f, "Command must be executed asynchronously")
+            }
         }
     }
 }
@@ -10,6 +10,7 @@ pub mod parse_results;
 pub mod result_consumer;
 pub mod string_reader;
 pub mod suggestion;
+pub mod testing;
 pub mod tree;
 
 pub mod prelude {
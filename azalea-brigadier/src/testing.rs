@@ -0,0 +1,116 @@
+//! Helpers for unit testing command trees.
+//!
+//! ```
+//! use azalea_brigadier::{
+//!     errors::BuiltInError,
+//!     prelude::*,
+//!     testing::{TestSource, assert_execute_error, assert_executes},
+//! };
+//!
+//! let mut dispatcher = CommandDispatcher::new();
+//! dispatcher.register(
+//!     literal("add")
+//!         .then(argument("n", integer()).executes(|ctx| get_integer(ctx, "n").unwrap() + 1)),
+//! );
+//! dispatcher.register(
+//!     literal("admin")
+//!         .requires(TestSource::has_permission)
+//!         .executes(|_| 1),
+//! );
+//!
+//! assert_executes(&dispatcher, "add 2", TestSource::permitted(), 3);
+//! assert_executes(&dispatcher, "admin", TestSource::permitted(), 1);
+//! assert_execute_error(
+//!     &dispatcher,
+//!     "admin",
+//!     TestSource::forbidden(),
+//!     BuiltInError::DispatcherUnknownCommand,
+//! );
+//! ```
+
+use crate::{command_dispatcher::CommandDispatcher, errors::BuiltInError};
+
+/// A command source that's either allowed to run every command or isn't
+/// allowed to run any of them.
+///
+/// Use [`TestSource::has_permission`] as the requirement for commands that
+/// should only be available to permitted sources.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TestSource {
+    pub permitted: bool,
+}
+
+impl TestSource {
+    pub fn permitted() -> Self {
+        Self { permitted: true }
+    }
+    pub fn forbidden() -> Self {
+        Self { permitted: false }
+    }
+
+    /// Returns whether this source is permitted, for use with
+    /// [`ArgumentBuilder::requires`].
+    ///
+    /// [`ArgumentBuilder::requires`]: crate::builder::argument_builder::ArgumentBuilder::requires
+    pub fn has_permission(&self) -> bool {
+        self.permitted
+    }
+}
+
+/// Execute a command and assert that it succeeded with the given result.
+///
+/// # Panics
+///
+/// Panics if the command failed or returned a different result.
+#[track_caller]
+pub fn assert_executes<S>(
+    dispatcher: &CommandDispatcher<S>,
+    input: &str,
+    source: S,
+    expected: i32,
+) {
+    match dispatcher.execute(input, source) {
+        Ok(result) => assert_eq!(
+            result, expected,
+            "command {input:?} returned {result} instead of {expected}"
+        ),
+        Err(err) => panic!("command {input:?} failed: {err:?}"),
+    }
+}
+
+/// Execute a command and assert that it failed with the given kind of error.
+///
+/// # Panics
+///
+/// Panics if the command succeeded or failed with a different error.
+#[track_caller]
+pub fn assert_execute_error<S>(
+    dispatcher: &CommandDispatcher<S>,
+    input: &str,
+    source: S,
+    expected: BuiltInError,
+) {
+    match dispatcher.execute(input, source) {
+        Ok(result) => panic!("command {input:?} succeeded with {result} but it should've failed"),
+        Err(err) => assert_eq!(
+            err.kind(),
+            &expected,
+            "command {input:?} failed with the wrong error"
+        ),
+    }
+}
+
+/// Parse a command and assert that the whole input was parsed without
+/// errors, without executing it.
+///
+/// # Panics
+///
+/// Panics if the command couldn't be parsed.
+#[track_caller]
+pub fn assert_parses<S>(dispatcher: &CommandDispatcher<S>, input: &str, source: S) {
+    let parse = dispatcher.parse(input.into(), source);
+    assert!(
+        !parse.reader.can_read() && parse.exceptions.is_empty(),
+        "command {input:?} couldn't be parsed: {parse:?}"
+    );
+}
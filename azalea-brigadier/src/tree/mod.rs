@@ -1,11 +1,15 @@
+mod packet_node;
+
 use std::{
     collections::{BTreeMap, HashMap},
     fmt::{self, Debug},
     hash::{Hash, Hasher},
+    pin::Pin,
     ptr,
     sync::Arc,
 };
 
+pub use packet_node::{PacketNode, PacketNodeType};
 use parking_lot::RwLock;
 
 use crate::{
@@ -22,6 +26,13 @@ use crate::{
 
 pub type Command<S> =
     Option<Arc<dyn Fn(&CommandContext<S>) -> Result<i32, CommandSyntaxError> + Send + Sync>>;
+pub type AsyncCommand<S> = Option<
+    Arc<
+        dyn for<'a> Fn(&'a CommandContext<S>) -> Pin<Box<dyn Future<Output = i32> + 'a>>
+            + Send
+            + Sync,
+    >,
+>;
 
 /// An ArgumentBuilder that has been built.
 #[non_exhaustive]
@@ -34,6 +45,12 @@ pub struct CommandNode<S> {
     pub arguments: HashMap<String, Arc<RwLock<CommandNode<S>>>>,
 
     pub command: Command<S>,
+    /// The command set with [`ArgumentBuilder::executes_async`], which is run
+    /// instead of `command` by [`CommandDispatcher::execute_async`].
+    ///
+    /// [`ArgumentBuilder::executes_async`]: crate::builder::argument_builder::ArgumentBuilder::executes_async
+    /// [`CommandDispatcher::execute_async`]: crate::command_dispatcher::CommandDispatcher::execute_async
+    pub async_command: AsyncCommand<S>,
     pub requirement: Arc<dyn Fn(&S) -> bool + Send + Sync>,
     pub redirect: Option<Arc<RwLock<CommandNode<S>>>>,
     pub forks: bool,
@@ -48,6 +65,7 @@ impl<S> Clone for CommandNode<S> {
             literals: self.literals.clone(),
             arguments: self.arguments.clone(),
             command: self.command.clone(),
+            async_command: self.async_command.clone(),
             requirement: self.requirement.clone(),
             redirect: self.redirect.clone(),
             forks: self.forks,
@@ -85,6 +103,16 @@ impl<S> CommandNode<S> {
     }
 
     pub fn get_relevant_nodes(&self, input: &mut StringReader) -> Vec<Arc<RwLock<CommandNode<S>>>> {
+        self.relevant_nodes(input, false)
+    }
+
+    /// Same as [`Self::get_relevant_nodes`], but literals can optionally be
+    /// matched ignoring ASCII case.
+    pub(crate) fn relevant_nodes(
+        &self,
+        input: &mut StringReader,
+        literal_case_insensitive: bool,
+    ) -> Vec<Arc<RwLock<CommandNode<S>>>> {
         let literals = &self.literals;
 
         if literals.is_empty() {
@@ -103,10 +131,23 @@ impl<S> CommandNode<S> {
             input.cursor = cursor;
             let literal = literals.get(&text);
             if let Some(literal) = literal {
-                vec![literal.clone()]
-            } else {
-                self.arguments.values().cloned().collect()
+                return vec![literal.clone()];
+            }
+            if literal_case_insensitive {
+                // iterate over the children instead of the literals so the order is stable
+                let matching = self
+                    .children
+                    .iter()
+                    .filter(|(name, _)| {
+                        name.eq_ignore_ascii_case(&text) && literals.contains_key(*name)
+                    })
+                    .map(|(_, node)| node.clone())
+                    .collect::<Vec<_>>();
+                if !matching.is_empty() {
+                    return matching;
+                }
             }
+            self.arguments.values().cloned().collect()
         }
     }
 
@@ -120,6 +161,10 @@ impl<S> CommandNode<S> {
             // We've found something to merge onto
             if let Some(command) = &node.read().command {
                 child.write().command = Some(command.clone());
+                child
+                    .write()
+                    .async_command
+                    .clone_from(&node.read().async_command);
             }
             for grandchild in node.read().children.values() {
                 child.write().add_child(grandchild);
@@ -177,7 +222,10 @@ impl<S> CommandNode<S> {
             }
             ArgumentBuilderType::Literal(ref literal) => {
                 let start = reader.cursor();
-                let end = self.parse(reader);
+                let end = self.parse(
+                    reader,
+                    context_builder.dispatcher.is_literal_case_insensitive(),
+                );
 
                 if let Some(end) = end {
                     context_builder.with_node(
@@ -195,7 +243,7 @@ impl<S> CommandNode<S> {
         }
     }
 
-    fn parse(&self, reader: &mut StringReader) -> Option<usize> {
+    fn parse(&self, reader: &mut StringReader, case_insensitive: bool) -> Option<usize> {
         match self.value {
             ArgumentBuilderType::Argument(_) => {
                 panic!("Can't parse argument.")
@@ -204,12 +252,16 @@ impl<S> CommandNode<S> {
                 let start = reader.cursor();
                 if reader.can_read_length(literal.value.len()) {
                     let end = start + literal.value.len();
-                    if reader
+                    let text = reader
                         .string()
                         .get(start..end)
-                        .expect("Couldn't slice reader correctly?")
-                        == literal.value
-                    {
+                        .expect("Couldn't slice reader correctly?");
+                    let matches = if case_insensitive {
+                        text.eq_ignore_ascii_case(&literal.value)
+                    } else {
+                        text == literal.value
+                    };
+                    if matches {
                         reader.cursor = end;
                         if !reader.can_read() || reader.peek() == ' ' {
                             return Some(end);
@@ -269,6 +321,7 @@ impl<S> Default for CommandNode<S> {
             arguments: HashMap::new(),
 
             command: None,
+            async_command: None,
             requirement: Arc::new(|_| true),
             redirect: None,
             forks: false,
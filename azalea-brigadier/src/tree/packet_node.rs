@@ -0,0 +1,63 @@
+use crate::arguments::ArgumentParser;
+
+/// A flattened [`CommandNode`](super::CommandNode), in the same shape as the
+/// nodes in the vanilla Commands packet.
+///
+/// These are created with [`CommandDispatcher::to_packet_nodes`].
+///
+/// [`CommandDispatcher::to_packet_nodes`]: crate::command_dispatcher::CommandDispatcher::to_packet_nodes
+#[derive(Clone, Debug, PartialEq)]
+pub struct PacketNode {
+    pub is_executable: bool,
+    /// The indices of this node's children.
+    pub children: Vec<u32>,
+    /// The index of the node that this node redirects to, if any.
+    pub redirect_node: Option<u32>,
+    pub node_type: PacketNodeType,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PacketNodeType {
+    Root,
+    Literal {
+        name: String,
+    },
+    Argument {
+        name: String,
+        /// The built-in argument type that this node uses, or `None` if it's a
+        /// custom [`ArgumentType`](crate::arguments::ArgumentType).
+        parser: Option<ArgumentParser>,
+        /// Whether the argument has a custom suggestion provider, in which case
+        /// the server is expected to send the suggestions.
+        has_custom_suggestions: bool,
+    },
+}
+
+impl PacketNode {
+    /// The flags byte for this node, as it's written in the Commands packet.
+    ///
+    /// The lowest two bits are the node type (0 for root, 1 for literal, 2 for
+    /// argument), followed by whether it's executable, whether it has a
+    /// redirect, and whether it has custom suggestions.
+    pub fn flags(&self) -> u8 {
+        let mut flags = match self.node_type {
+            PacketNodeType::Root => 0,
+            PacketNodeType::Literal { .. } => 1,
+            PacketNodeType::Argument { .. } => 2,
+        };
+        if self.is_executable {
+            flags |= 0x04;
+        }
+        if self.redirect_node.is_some() {
+            flags |= 0x08;
+        }
+        if let PacketNodeType::Argument {
+            has_custom_suggestions: true,
+            ..
+        } = self.node_type
+        {
+            flags |= 0x10;
+        }
+        flags
+    }
+}
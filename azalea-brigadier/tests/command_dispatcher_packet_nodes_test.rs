@@ -0,0 +1,76 @@
+use azalea_brigadier::{
+    arguments::ArgumentParser,
+    prelude::*,
+    tree::{PacketNode, PacketNodeType},
+};
+
+#[test]
+fn packet_nodes_with_redirects() {
+    let mut subject = CommandDispatcher::<()>::new();
+    let foo = subject.register(literal("foo").then(argument("bar", integer()).executes(|_| 42)));
+    subject.register(literal("redirect").redirect(foo));
+    subject.register(literal("execute").then(literal("run").redirect(subject.root.clone())));
+
+    let nodes = subject.to_packet_nodes();
+
+    // children are sorted by name and visited breadth-first
+    assert_eq!(
+        nodes,
+        vec![
+            PacketNode {
+                is_executable: false,
+                children: vec![1, 2, 3],
+                redirect_node: None,
+                node_type: PacketNodeType::Root,
+            },
+            PacketNode {
+                is_executable: false,
+                children: vec![4],
+                redirect_node: None,
+                node_type: PacketNodeType::Literal {
+                    name: "execute".to_owned()
+                },
+            },
+            PacketNode {
+                is_executable: false,
+                children: vec![5],
+                redirect_node: None,
+                node_type: PacketNodeType::Literal {
+                    name: "foo".to_owned()
+                },
+            },
+            PacketNode {
+                is_executable: false,
+                children: vec![],
+                redirect_node: Some(2),
+                node_type: PacketNodeType::Literal {
+                    name: "redirect".to_owned()
+                },
+            },
+            PacketNode {
+                is_executable: false,
+                children: vec![],
+                redirect_node: Some(0),
+                node_type: PacketNodeType::Literal {
+                    name: "run".to_owned()
+                },
+            },
+            PacketNode {
+                is_executable: true,
+                children: vec![],
+                redirect_node: None,
+                node_type: PacketNodeType::Argument {
+                    name: "bar".to_owned(),
+                    parser: Some(ArgumentParser::Integer {
+                        min: None,
+                        max: None
+                    }),
+                    has_custom_suggestions: false,
+                },
+            },
+        ]
+    );
+
+    let flags = nodes.iter().map(|n| n.flags()).collect::<Vec<_>>();
+    assert_eq!(flags, vec![0x00, 0x01, 0x01, 0x09, 0x09, 0x06]);
+}
@@ -1,7 +1,7 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use azalea_brigadier::{
-    arguments::integer_argument_type::integer,
+    arguments::integer_argument_type::{get_integer, integer},
     builder::{literal_argument_builder::literal, required_argument_builder::argument},
     command_dispatcher::CommandDispatcher,
     context::CommandContext,
@@ -41,6 +41,75 @@ fn create_and_merge_commands() {
     assert_eq!(subject.execute("base bar", &CommandSource {}).unwrap(), 42);
 }
 
+#[tokio::test]
+async fn execute_async_command() {
+    let mut subject = CommandDispatcher::new();
+    subject.register(
+        literal("foo").then(argument("bar", integer()).executes_async(|ctx| {
+            Box::pin(async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                get_integer(ctx, "bar").unwrap()
+            })
+        })),
+    );
+    subject.register(literal("baz").executes(|_| 42));
+
+    assert_eq!(
+        subject
+            .execute_async("foo 123", &CommandSource {})
+            .await
+            .unwrap(),
+        123
+    );
+    // sync commands still work when executed asynchronously
+    assert_eq!(
+        subject
+            .execute_async("baz", &CommandSource {})
+            .await
+            .unwrap(),
+        42
+    );
+
+    let err = subject.execute("foo 123", &CommandSource {}).unwrap_err();
+    assert_eq!(err.kind(), &BuiltInError::DispatcherCommandIsAsync);
+}
+
+#[test]
+fn literals_are_case_sensitive_by_default() {
+    let mut subject = CommandDispatcher::new();
+    subject.register(literal("home").executes(|_| 42));
+
+    assert_eq!(subject.execute("home", &CommandSource {}).unwrap(), 42);
+    let err = subject.execute("Home", &CommandSource {}).unwrap_err();
+    assert_eq!(err.kind(), &BuiltInError::DispatcherUnknownCommand);
+}
+
+#[test]
+fn execute_case_insensitive_literals() {
+    let mut subject = CommandDispatcher::new();
+    subject.set_literal_case_insensitive(true);
+    subject.register(literal("home").then(literal("set").executes(|_| 42)));
+    subject.register(literal("tp").then(argument("x", integer()).executes(|_| 100)));
+
+    assert_eq!(subject.execute("Home SET", &CommandSource {}).unwrap(), 42);
+    assert_eq!(subject.execute("TP 5", &CommandSource {}).unwrap(), 100);
+
+    // the parsed nodes keep the casing they were registered with
+    let parse = subject.parse("HOME Set".into(), &CommandSource {});
+    let names = parse
+        .context
+        .nodes
+        .iter()
+        .map(|n| n.node.read().name().to_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["home".to_owned(), "set".to_owned()]);
+    let set = parse.context.nodes[1].node.read().clone();
+    assert_eq!(
+        subject.get_path(set),
+        vec!["home".to_owned(), "set".to_owned()]
+    );
+}
+
 #[test]
 fn execute_unknown_command() {
     let mut subject = CommandDispatcher::new();
@@ -200,6 +200,46 @@ fn get_completion_suggestions_moving_cursor_sub_commands() {
     test_suggestions(&subject, "parent_one faz ", 15, StringRange::at(0), vec![]);
 }
 
+#[test]
+fn get_completion_suggestions_replacing_token_mid_argument() {
+    let mut subject = CommandDispatcher::<()>::new();
+    subject.register(
+        literal("give")
+            .then(literal("diamond").then(argument("count", integer())))
+            .then(literal("dirt").then(argument("count", integer()))),
+    );
+
+    // the cursor is after "di" in "diamond"
+    let result = CommandDispatcher::get_completion_suggestions_replacing_token(
+        subject.parse("give diamond 5".into(), ()),
+        7,
+    );
+    let range = StringRange::between(5, 12);
+    assert_eq!(result.range(), range);
+    assert_eq!(
+        result.list(),
+        [
+            Suggestion::new(range, "diamond"),
+            Suggestion::new(range, "dirt")
+        ]
+    );
+    assert_eq!(result.list()[1].apply("give diamond 5"), "give dirt 5");
+
+    // at the end of the input, it's the same as the normal suggestions
+    test_suggestions(
+        &subject,
+        "give di",
+        7,
+        StringRange::between(5, 7),
+        vec!["diamond", "dirt"],
+    );
+    let result = CommandDispatcher::get_completion_suggestions_replacing_token(
+        subject.parse("give di".into(), ()),
+        7,
+    );
+    assert_eq!(result.range(), StringRange::between(5, 7));
+}
+
 #[test]
 fn get_completion_suggestions_sub_commands_partial() {
     let mut subject = CommandDispatcher::<()>::new();
@@ -3,9 +3,11 @@
 #[cfg(feature = "online-mode")]
 pub mod microsoft;
 pub mod offline;
+pub mod profile;
 
 use std::{fmt::Debug, ops::Deref, pin::Pin, sync::Arc};
 
+use azalea_auth::game_profile::GameProfile;
 #[cfg(feature = "online-mode")]
 use azalea_auth::sessionserver::ClientSessionServerError;
 use bevy_ecs::component::Component;
@@ -66,7 +68,8 @@ pub trait AccountTrait: Send + Sync + Debug {
     /// Returns the unique identifier for this player.
     ///
     /// For offline-mode accounts, this UUID is generated by calling
-    /// [`azalea_crypto::offline::generate_uuid`].
+    /// [`Account::offline_uuid`], unless it was overridden with
+    /// [`Account::offline_with_uuid`].
     fn uuid(&self) -> Uuid;
 
     /// The access token for authentication.
@@ -74,6 +77,17 @@ pub trait AccountTrait: Send + Sync + Debug {
     /// You can obtain one of these manually from `azalea-auth`.
     fn access_token(&self) -> Option<String>;
 
+    /// The full [`GameProfile`] for this account, if it's already known.
+    ///
+    /// If this returns `Some`, the profile is inserted as our
+    /// [`GameProfileComponent`] when we start joining a server, instead of
+    /// only after the server sends it to us.
+    ///
+    /// [`GameProfileComponent`]: crate::player::GameProfileComponent
+    fn profile(&self) -> Option<GameProfile> {
+        None
+    }
+
     /// Refreshes the access token for this account.
     #[cfg(feature = "online-mode")]
     fn refresh(&self) -> BoxFuture<'_, Result<(), azalea_auth::AuthError>> {
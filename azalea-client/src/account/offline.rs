@@ -10,13 +10,14 @@ use crate::account::{Account, AccountTrait};
 #[derive(Debug)]
 pub struct OfflineAccount {
     username: String,
+    uuid: Uuid,
 }
 impl AccountTrait for OfflineAccount {
     fn username(&self) -> &str {
         &self.username
     }
     fn uuid(&self) -> Uuid {
-        azalea_crypto::offline::generate_uuid(&self.username)
+        self.uuid
     }
     fn access_token(&self) -> Option<String> {
         None
@@ -29,9 +30,47 @@ impl Account {
     ///
     /// This is useful for testing in LAN worlds.
     pub fn offline(username: &str) -> Self {
+        Self::offline_with_uuid(username, Self::offline_uuid(username))
+    }
+
+    /// Get the UUID that offline-mode servers give to the player with the given
+    /// username.
+    ///
+    /// This is a UUIDv3 of `OfflinePlayer:<username>`, which is what vanilla
+    /// uses. Note that the username is case-sensitive.
+    pub fn offline_uuid(username: &str) -> Uuid {
+        azalea_crypto::offline::generate_uuid(username)
+    }
+
+    /// Create an offline account that uses the given UUID instead of the one
+    /// that would normally be derived from the username.
+    ///
+    /// This is only useful in controlled environments (like test worlds)
+    /// where you need the bot to present itself as a specific player. The UUID
+    /// is sent to the server when logging in, and it's also used to look up our
+    /// client's entity.
+    pub fn offline_with_uuid(username: &str, uuid: Uuid) -> Self {
         OfflineAccount {
             username: username.to_owned(),
+            uuid,
         }
         .into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offline_uuid() {
+        assert_eq!(
+            Account::offline_uuid("Notch"),
+            Uuid::parse_str("b50ad385-829d-3141-a216-7e7d7539ba7f").unwrap()
+        );
+        assert_eq!(
+            Account::offline("Notch").uuid(),
+            Account::offline_uuid("Notch")
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use azalea_auth::game_profile::GameProfile;
+use uuid::Uuid;
+
+use crate::account::{Account, AccountTrait};
+
+/// A type of account that uses a [`GameProfile`] that we already have, instead
+/// of getting one from Mojang.
+///
+/// Like [`OfflineAccount`](super::offline::OfflineAccount), this never
+/// authenticates with Mojang's servers, so it can only join offline-mode
+/// servers.
+///
+/// This type is not intended to be used directly by the user. To actually make
+/// one of these accounts, see [`Account::from_profile`].
+#[derive(Debug)]
+pub struct ProfileAccount {
+    profile: GameProfile,
+}
+impl AccountTrait for ProfileAccount {
+    fn username(&self) -> &str {
+        &self.profile.name
+    }
+    fn uuid(&self) -> Uuid {
+        self.profile.uuid
+    }
+    fn access_token(&self) -> Option<String> {
+        None
+    }
+    fn profile(&self) -> Option<GameProfile> {
+        Some(self.profile.clone())
+    }
+}
+
+impl Account {
+    /// Create an account from a [`GameProfile`] that was resolved ahead of
+    /// time, like one that was cached from a previous session.
+    ///
+    /// The profile's name and UUID are sent to the server when logging in, and
+    /// the profile is inserted as our [`GameProfileComponent`] as soon as we
+    /// start joining, so its properties (like our skin) are available before
+    /// the server sends them. Mojang's servers are never contacted, so this is
+    /// mostly useful for offline-mode servers and test harnesses.
+    ///
+    /// [`GameProfileComponent`]: crate::player::GameProfileComponent
+    pub fn from_profile(profile: GameProfile) -> Self {
+        ProfileAccount { profile }.into()
+    }
+}
@@ -28,11 +28,17 @@ use crate::{
     chunks::ChunkBatchInfo,
     connection::RawConnection,
     cookies::ServerCookies,
+    cooldowns::ItemCooldowns,
     interact::BlockStatePredictionHandler,
-    local_player::{Experience, Hunger, PermissionLevel, TabList, WorldHolder},
+    keep_alive::PendingKeepAlives,
+    local_player::{
+        Experience, Hunger, PermissionLevel, TabList, TabListHeaderFooter, WorldHolder,
+    },
     mining,
     movement::LastSentLookDirection,
     player::retroactively_add_game_profile_component,
+    tick_rate::WorldTickRate,
+    world_border::WorldBorder,
 };
 /// A bundle of components that's inserted right when we switch to the `login`
 /// state and stay present on our clients until we disconnect.
@@ -59,6 +65,7 @@ pub struct JoinedClientBundle {
     pub physics_state: PhysicsState,
     pub inventory: Inventory,
     pub tab_list: TabList,
+    pub tab_list_header_footer: TabListHeaderFooter,
     pub block_state_prediction_handler: BlockStatePredictionHandler,
     pub queued_server_block_updates: QueuedServerBlockUpdates,
     pub last_sent_direction: LastSentLookDirection,
@@ -68,6 +75,9 @@ pub struct JoinedClientBundle {
     pub hunger: Hunger,
     pub experience: Experience,
     pub cookies: ServerCookies,
+    pub item_cooldowns: ItemCooldowns,
+    pub pending_keep_alives: PendingKeepAlives,
+    pub world_border: WorldBorder,
 
     pub entity_id_index: EntityIdIndex,
 
@@ -157,7 +167,7 @@ pub fn start_ecs_runner(
 }
 
 /// Runs the `Update` schedule 60 times per second and the `GameTick` schedule
-/// 20 times per second.
+/// 20 times per second (or at the rate in [`WorldTickRate`]).
 ///
 /// Exits when we receive an `AppExit` event.
 async fn run_schedule_loop(
@@ -171,8 +181,6 @@ async fn run_schedule_loop(
     // framerate. unlike vanilla though, we also only handle packets during Updates
     // due to everything running in ecs systems.
     const UPDATE_DURATION_TARGET: Duration = Duration::from_micros(1_000_000 / 60);
-    // minecraft runs at 20 tps
-    const GAME_TICK_DURATION_TARGET: Duration = Duration::from_micros(1_000_000 / 20);
 
     loop {
         // sleep until the next update if necessary
@@ -187,18 +195,25 @@ async fn run_schedule_loop(
 
         let mut ecs = ecs.write();
 
-        // if last tick is None or more than 50ms ago, run the GameTick schedule
         ecs.run_schedule(outer_schedule_label);
+        // minecraft normally runs at 20 tps, but servers can change it
+        let game_tick_duration_target = ecs
+            .get_resource::<WorldTickRate>()
+            .copied()
+            .unwrap_or_default()
+            .tick_duration();
+        // if last tick is None or more than one tick duration ago, run the GameTick
+        // schedule
         if last_tick
-            .map(|last_tick| last_tick.elapsed() > GAME_TICK_DURATION_TARGET)
+            .map(|last_tick| last_tick.elapsed() > game_tick_duration_target)
             .unwrap_or(true)
         {
             if let Some(last_tick) = &mut last_tick {
-                *last_tick += GAME_TICK_DURATION_TARGET;
+                *last_tick += game_tick_duration_target;
 
                 // if we're more than 10 ticks behind, set last_tick to now.
                 // vanilla doesn't do it in exactly the same way but it shouldn't really matter
-                if (now - *last_tick) > GAME_TICK_DURATION_TARGET * 10 {
+                if (now - *last_tick) > game_tick_duration_target * 10 {
                     warn!(
                         "GameTick is more than 10 ticks behind, skipping ticks so we don't have to burst too much"
                     );
@@ -243,20 +258,93 @@ fn should_exit(ecs: &mut World) -> Option<AppExit> {
     None
 }
 
-pub struct AmbiguityLoggerPlugin;
+/// A plugin that makes Bevy report systems in the `Update` and [`GameTick`]
+/// schedules that have ambiguous ordering.
+///
+/// This is included in [`DefaultPlugins`] and warns by default. You can make
+/// it stricter (for example, to fail your tests when a new ambiguity is
+/// introduced) or turn it off by replacing it:
+///
+/// ```
+/// # use azalea_client::{AmbiguityLoggerPlugin, DefaultPlugins};
+/// # use bevy_app::PluginGroup;
+/// # use bevy_ecs::schedule::LogLevel;
+/// let plugins = DefaultPlugins.set(AmbiguityLoggerPlugin {
+///     level: LogLevel::Error,
+/// });
+/// ```
+///
+/// [`DefaultPlugins`]: crate::DefaultPlugins
+#[derive(Clone, Debug)]
+pub struct AmbiguityLoggerPlugin {
+    /// What to do when an ambiguity is detected.
+    ///
+    /// If this is [`LogLevel::Ignore`], then the schedules' build settings
+    /// won't be touched at all.
+    pub level: LogLevel,
+}
+impl Default for AmbiguityLoggerPlugin {
+    fn default() -> Self {
+        Self {
+            level: LogLevel::Warn,
+        }
+    }
+}
 impl Plugin for AmbiguityLoggerPlugin {
     fn build(&self, app: &mut App) {
+        if matches!(self.level, LogLevel::Ignore) {
+            return;
+        }
+        let level = self.level.clone();
         app.edit_schedule(Update, |schedule| {
             schedule.set_build_settings(ScheduleBuildSettings {
-                ambiguity_detection: LogLevel::Warn,
+                ambiguity_detection: level.clone(),
                 ..Default::default()
             });
         });
         app.edit_schedule(GameTick, |schedule| {
             schedule.set_build_settings(ScheduleBuildSettings {
-                ambiguity_detection: LogLevel::Warn,
+                ambiguity_detection: level,
                 ..Default::default()
             });
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::schedule::ScheduleLabel;
+
+    use super::*;
+
+    fn ambiguity_detection(app: &App, label: impl ScheduleLabel) -> Option<LogLevel> {
+        app.get_schedule(label)
+            .map(|schedule| schedule.get_build_settings().ambiguity_detection)
+    }
+
+    #[test]
+    fn test_ambiguity_logger_level() {
+        let mut app = App::new();
+        app.add_plugins(AmbiguityLoggerPlugin {
+            level: LogLevel::Error,
+        });
+        assert!(matches!(
+            ambiguity_detection(&app, Update),
+            Some(LogLevel::Error)
+        ));
+        assert!(matches!(
+            ambiguity_detection(&app, GameTick),
+            Some(LogLevel::Error)
+        ));
+
+        let mut app = App::new();
+        app.add_plugins(AmbiguityLoggerPlugin {
+            level: LogLevel::Ignore,
+        });
+        assert!(matches!(
+            ambiguity_detection(&app, Update),
+            None | Some(LogLevel::Ignore)
+        ));
+        assert!(ambiguity_detection(&app, GameTick).is_none());
+    }
+}
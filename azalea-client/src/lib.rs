@@ -22,7 +22,9 @@ pub use azalea_protocol::common::client_information::ClientInformation;
 // version.
 pub use bevy_tasks;
 pub use client::{
-    InConfigState, InGameState, JoinedClientBundle, LocalPlayerBundle, start_ecs_runner,
+    AmbiguityLoggerPlugin, InConfigState, InGameState, JoinedClientBundle, LocalPlayerBundle,
+    start_ecs_runner,
 };
+pub use client_information::ClientInformationBuilder;
 pub use movement::{StartSprintEvent, StartWalkEvent};
 pub use plugins::*;
@@ -1,10 +1,13 @@
 use std::{collections::HashMap, sync::Arc};
 
-use azalea_core::game_type::GameMode;
+use azalea_chat::FormattedText;
+use azalea_core::{game_type::GameMode, position::GlobalPos};
+use azalea_registry::data::DimensionKind;
 use azalea_world::{PartialWorld, World};
 use bevy_ecs::{component::Component, prelude::*};
 use derive_more::{Deref, DerefMut};
 use parking_lot::RwLock;
+use rand::{SeedableRng, rngs::StdRng};
 use uuid::Uuid;
 
 use crate::{ClientInformation, player::PlayerInfo};
@@ -49,6 +52,41 @@ impl From<GameMode> for LocalGameMode {
     }
 }
 
+/// The dimension type of the world that a local player is in.
+///
+/// This is set when we receive a login or respawn packet. The dimension type
+/// is a data-driven registry, so to get its name or properties (like the
+/// height of the world) you'll have to resolve it with the world's registries.
+///
+/// Note that this is different from the [`WorldName`], since multiple worlds
+/// can share the same dimension type.
+///
+/// [`WorldName`]: azalea_world::WorldName
+#[derive(Clone, Component, Copy, Debug, Deref, DerefMut, PartialEq)]
+pub struct LocalDimensionKind(pub DimensionKind);
+
+/// A random number generator that's seeded separately for every client.
+///
+/// Plugins should use this instead of `rand::rng()` when making random
+/// decisions, so that giving two clients the same seed (with
+/// [`ConnectOpts::rng_seed`]) makes them behave identically.
+///
+/// The generator is re-created from the seed every time the client joins a
+/// server, including when it reconnects. If no seed was set, a new one is
+/// picked from entropy each time.
+///
+/// [`ConnectOpts::rng_seed`]: crate::join::ConnectOpts::rng_seed
+#[derive(Clone, Component, Debug, Deref, DerefMut)]
+pub struct ClientRng(pub StdRng);
+impl ClientRng {
+    pub fn new(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+    pub fn from_entropy() -> Self {
+        Self::new(rand::random())
+    }
+}
+
 /// Level must be 0..=4
 #[derive(Clone, Component, Default, Deref, DerefMut)]
 pub struct PermissionLevel(pub u8);
@@ -73,6 +111,36 @@ pub struct PermissionLevel(pub u8);
 /// all of your clients will have the same tab list.
 #[derive(Clone, Component, Debug, Default, Deref, DerefMut, Resource)]
 pub struct TabList(HashMap<Uuid, PlayerInfo>);
+impl TabList {
+    /// Returns the players that are shown in the tab list, in the order that
+    /// the vanilla client displays them.
+    ///
+    /// Players with a higher [`PlayerInfo::list_order`] come first, then
+    /// spectators are moved to the end, and ties are broken by username
+    /// (case-insensitively). Vanilla also groups players by their team, but
+    /// teams aren't tracked by Azalea so that step is skipped.
+    pub fn display_order(&self) -> Vec<&PlayerInfo> {
+        let mut players = self.values().filter(|info| info.listed).collect::<Vec<_>>();
+        players.sort_by_cached_key(|info| {
+            (
+                -info.list_order,
+                info.gamemode == GameMode::Spectator,
+                info.profile.name.to_lowercase(),
+            )
+        });
+        players
+    }
+}
+
+/// The header and footer text that the server set to be displayed above and
+/// below the tab list.
+///
+/// These are empty if the server never sent them.
+#[derive(Clone, Component, Debug, Default, PartialEq)]
+pub struct TabListHeaderFooter {
+    pub header: FormattedText,
+    pub footer: FormattedText,
+}
 
 #[derive(Clone, Component, Debug)]
 pub struct Hunger {
@@ -125,6 +193,28 @@ impl Default for Experience {
     }
 }
 
+/// The world spawn point that the server sent us, which is where compasses
+/// point to.
+///
+/// This component is only present after the server sends it, which vanilla
+/// servers do when we join and whenever the spawn point is changed.
+#[derive(Clone, Component, Debug, PartialEq)]
+pub struct WorldSpawn {
+    /// The position of the spawn point, and the dimension that it's in.
+    pub global_pos: GlobalPos,
+    /// The direction that players face when spawning here.
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+/// The place where the client last died, which is what recovery compasses
+/// point to.
+///
+/// The server sends this when we join and respawn, and this component is only
+/// present if we've died before.
+#[derive(Clone, Component, Debug, Deref, PartialEq)]
+pub struct LastDeathLocation(pub GlobalPos);
+
 impl WorldHolder {
     /// Create a new `WorldHolder` for the given entity.
     ///
@@ -31,6 +31,21 @@ pub struct PlayerInfo {
     ///
     /// Use [`GameProfile::name`] to get the player's actual username.
     pub display_name: Option<Box<FormattedText>>,
+    /// Whether the player is shown in the tab list.
+    ///
+    /// Players that aren't listed are still in the [`TabList`] map, but
+    /// vanilla clients won't display them.
+    ///
+    /// [`TabList`]: crate::local_player::TabList
+    pub listed: bool,
+    /// The position of the player in the tab list, where players with higher
+    /// values are displayed first.
+    ///
+    /// See [`TabList::display_order`] to get the players in the order that
+    /// they're displayed.
+    ///
+    /// [`TabList::display_order`]: crate::local_player::TabList::display_order
+    pub list_order: i32,
 }
 
 /// A component only present in players that contains the [`GameProfile`] (which
@@ -0,0 +1,74 @@
+//! Keep track of the advancements that the server has sent us, and our progress
+//! towards completing them.
+
+use std::collections::HashMap;
+
+use azalea_protocol::packets::game::c_update_advancements::{
+    Advancement, AdvancementProgress, ClientboundUpdateAdvancements,
+};
+use azalea_registry::identifier::Identifier;
+use bevy_ecs::prelude::*;
+
+/// The advancements that the server sent us, along with our progress on each
+/// of them.
+///
+/// This component is only present after the server first sends us an
+/// advancement update, which vanilla servers do right after we join.
+#[derive(Clone, Component, Debug, Default, PartialEq)]
+pub struct Advancements {
+    pub advancements: HashMap<Identifier, Advancement>,
+    /// The criteria that we've completed (or not) for every advancement,
+    /// keyed by the advancement's id.
+    pub progress: HashMap<Identifier, AdvancementProgress>,
+}
+
+impl Advancements {
+    /// Apply a [`ClientboundUpdateAdvancements`] packet.
+    pub fn update(&mut self, p: &ClientboundUpdateAdvancements) {
+        if p.reset {
+            self.advancements.clear();
+            self.progress.clear();
+        }
+        for id in &p.removed {
+            self.advancements.remove(id);
+            self.progress.remove(id);
+        }
+        for holder in &p.added {
+            self.advancements
+                .insert(holder.id.clone(), holder.value.clone());
+        }
+        // the server always sends every criterion for the advancements that changed,
+        // so we can replace our progress for them
+        for (id, progress) in &p.progress {
+            self.progress.insert(id.clone(), progress.clone());
+        }
+    }
+
+    /// Returns whether we've completed the advancement with the given id.
+    ///
+    /// An advancement is done when at least one criterion from each of its
+    /// requirements has been completed. Unknown advancements are never done.
+    pub fn is_done(&self, id: &Identifier) -> bool {
+        let (Some(advancement), Some(progress)) =
+            (self.advancements.get(id), self.progress.get(id))
+        else {
+            return false;
+        };
+        if advancement.requirements.is_empty() {
+            return false;
+        }
+        advancement.requirements.iter().all(|requirement| {
+            requirement.iter().any(|criterion| {
+                progress
+                    .get(criterion)
+                    .is_some_and(|criterion| criterion.date.is_some())
+            })
+        })
+    }
+
+    /// Returns an iterator over the ids of the advancements that we've
+    /// completed.
+    pub fn done(&self) -> impl Iterator<Item = &Identifier> {
+        self.advancements.keys().filter(|id| self.is_done(id))
+    }
+}
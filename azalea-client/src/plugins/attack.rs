@@ -1,14 +1,14 @@
 use azalea_core::{game_type::GameMode, tick::GameTick};
 use azalea_entity::{
-    Attributes, Crouching, Physics, indexing::EntityIdIndex, metadata::Sprinting,
+    Attributes, Crouching, Dead, Physics, indexing::EntityIdIndex, metadata::Sprinting,
     update_bounding_box,
 };
 use azalea_physics::PhysicsSystems;
-use azalea_protocol::packets::game::s_interact::{self, ServerboundInteract};
+use azalea_protocol::packets::game::s_interact::{self, InteractionHand, ServerboundInteract};
 use bevy_app::{App, Plugin, Update};
 use bevy_ecs::prelude::*;
 use derive_more::{Deref, DerefMut};
-use tracing::warn;
+use tracing::{debug, warn};
 
 use super::packet::game::SendGamePacketEvent;
 use crate::{
@@ -52,16 +52,19 @@ pub struct AttackQueued {
 #[allow(clippy::type_complexity)]
 pub fn handle_attack_queued(
     mut commands: Commands,
-    mut query: Query<(
-        Entity,
-        &mut TicksSinceLastAttack,
-        &mut Physics,
-        &mut Sprinting,
-        &AttackQueued,
-        &LocalGameMode,
-        &Crouching,
-        &EntityIdIndex,
-    )>,
+    mut query: Query<
+        (
+            Entity,
+            &mut TicksSinceLastAttack,
+            &mut Physics,
+            &mut Sprinting,
+            &AttackQueued,
+            &LocalGameMode,
+            &Crouching,
+            &EntityIdIndex,
+        ),
+        Without<Dead>,
+    >,
 ) {
     for (
         client_entity,
@@ -92,6 +95,7 @@ pub fn handle_attack_queued(
         ));
         commands.trigger(SwingArmEvent {
             entity: client_entity,
+            hand: InteractionHand::MainHand,
         });
 
         // we can't attack if we're in spectator mode but it still sends the attack
@@ -116,8 +120,16 @@ pub struct AttackEvent {
     /// The entity that will be attacked.
     pub target: Entity,
 }
-pub fn handle_attack_event(mut events: MessageReader<AttackEvent>, mut commands: Commands) {
+pub fn handle_attack_event(
+    mut events: MessageReader<AttackEvent>,
+    mut commands: Commands,
+    dead_query: Query<(), With<Dead>>,
+) {
     for event in events.read() {
+        if dead_query.contains(event.entity) {
+            debug!("Ignoring AttackEvent since we're dead");
+            continue;
+        }
         commands.entity(event.entity).insert(AttackQueued {
             target: event.target,
         });
@@ -1,5 +1,6 @@
 use azalea_block::BlockState;
 use azalea_core::position::BlockPos;
+use azalea_world::BlockEntity;
 use bevy_app::{App, Plugin, Update};
 use bevy_ecs::prelude::*;
 
@@ -29,6 +30,9 @@ impl Plugin for BlockUpdatePlugin {
 #[derive(Clone, Component, Debug, Default)]
 pub struct QueuedServerBlockUpdates {
     pub list: Vec<(BlockPos, BlockState)>,
+    /// Block entity data that the server sent us, which is applied after the
+    /// block states in [`Self::list`].
+    pub block_entities: Vec<(BlockPos, BlockEntity)>,
 }
 
 pub fn handle_block_update_event(
@@ -45,5 +49,10 @@ pub fn handle_block_update_event(
                 world.chunks.set_block_state(pos, block_state);
             }
         }
+        // block entities usually come right after the block update that created them,
+        // so they have to be set after the block states
+        for (pos, block_entity) in queued.block_entities.drain(..) {
+            world.chunks.set_block_entity(pos, block_entity);
+        }
     }
 }
@@ -5,8 +5,9 @@ use azalea_protocol::packets::{
     game::{ServerboundChat, ServerboundChatCommand, s_chat::LastSeenMessagesUpdate},
 };
 use bevy_ecs::prelude::*;
+use tracing::warn;
 
-use super::ChatKind;
+use super::{ChatKind, LongChatMessageBehavior, MAX_CHAT_LENGTH};
 use crate::packet::game::SendGamePacketEvent;
 #[cfg(feature = "online-mode")]
 use crate::{account::Account, chat_signing::ChatSigningSession};
@@ -32,61 +33,110 @@ pub struct SendChatKindEvent {
 pub fn handle_send_chat_kind_event(
     mut events: MessageReader<SendChatKindEvent>,
     mut commands: Commands,
+    behavior_query: Query<&LongChatMessageBehavior>,
     #[cfg(feature = "online-mode")] mut query: Query<(&Account, &mut ChatSigningSession)>,
 ) {
     for event in events.read() {
-        let content = event
-            .content
-            .chars()
-            .filter(|c| !matches!(c, '\x00'..='\x1F' | '\x7F' | '§'))
-            .take(256)
-            .collect::<String>();
+        let content = sanitize_chat_message(&event.content);
 
-        let timestamp = SystemTime::now();
-
-        let packet = match event.kind {
-            ChatKind::Message => {
-                let salt = azalea_crypto::signing::make_salt();
-
-                #[cfg(feature = "online-mode")]
-                let signature = if let Ok((account, mut chat_session)) = query.get_mut(event.entity)
+        let parts = match event.kind {
+            ChatKind::Message if content.chars().count() > MAX_CHAT_LENGTH => {
+                match behavior_query
+                    .get(event.entity)
+                    .copied()
+                    .unwrap_or_default()
                 {
-                    Some(create_signature(
-                        account,
-                        &mut chat_session,
-                        salt,
-                        timestamp,
-                        &content,
-                    ))
-                } else {
-                    None
-                };
-                #[cfg(not(feature = "online-mode"))]
-                let signature = None;
-
-                ServerboundChat {
-                    message: content,
-                    timestamp: timestamp
-                        .duration_since(UNIX_EPOCH)
-                        .expect("Time shouldn't be before epoch")
-                        .as_millis()
-                        .try_into()
-                        .expect("Instant should fit into a u64"),
-                    salt,
-                    signature,
-                    // TODO: implement last_seen_messages
-                    last_seen_messages: LastSeenMessagesUpdate::default(),
+                    LongChatMessageBehavior::Split => split_chat_message(&content, MAX_CHAT_LENGTH),
+                    LongChatMessageBehavior::Reject => {
+                        warn!(
+                            "Not sending chat message since it's longer than {MAX_CHAT_LENGTH} characters"
+                        );
+                        continue;
+                    }
                 }
             }
-            .into_variant(),
-            ChatKind::Command => {
-                // TODO: commands that require chat signing
-                ServerboundChatCommand { command: content }.into_variant()
-            }
+            ChatKind::Message => vec![content],
+            // vanilla doesn't limit the length of commands, and truncating them would
+            // make us run a different command than the one we were asked to
+            ChatKind::Command => vec![content],
         };
 
-        commands.trigger(SendGamePacketEvent::new(event.entity, packet));
+        for content in parts {
+            let timestamp = SystemTime::now();
+
+            let packet = match event.kind {
+                ChatKind::Message => {
+                    let salt = azalea_crypto::signing::make_salt();
+
+                    #[cfg(feature = "online-mode")]
+                    let signature =
+                        if let Ok((account, mut chat_session)) = query.get_mut(event.entity) {
+                            Some(create_signature(
+                                account,
+                                &mut chat_session,
+                                salt,
+                                timestamp,
+                                &content,
+                            ))
+                        } else {
+                            None
+                        };
+                    #[cfg(not(feature = "online-mode"))]
+                    let signature = None;
+
+                    ServerboundChat {
+                        message: content,
+                        timestamp: timestamp
+                            .duration_since(UNIX_EPOCH)
+                            .expect("Time shouldn't be before epoch")
+                            .as_millis()
+                            .try_into()
+                            .expect("Instant should fit into a u64"),
+                        salt,
+                        signature,
+                        // TODO: implement last_seen_messages
+                        last_seen_messages: LastSeenMessagesUpdate::default(),
+                    }
+                }
+                .into_variant(),
+                ChatKind::Command => {
+                    // TODO: commands that require chat signing
+                    ServerboundChatCommand { command: content }.into_variant()
+                }
+            };
+
+            commands.trigger(SendGamePacketEvent::new(event.entity, packet));
+        }
+    }
+}
+
+/// Remove the characters that vanilla servers will kick us for sending in
+/// chat, which are `§` and control characters.
+pub fn sanitize_chat_message(message: &str) -> String {
+    message
+        .chars()
+        .filter(|c| !matches!(c, '\x00'..='\x1F' | '\x7F' | '§'))
+        .collect()
+}
+
+/// Split a chat message into parts that are at most `max_length` characters
+/// long, preferring to split on spaces.
+pub fn split_chat_message(message: &str, max_length: usize) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut rest = message.trim();
+    while let Some((limit, _)) = rest.char_indices().nth(max_length) {
+        let split_at = if rest[limit..].starts_with(' ') {
+            limit
+        } else {
+            rest[..limit].rfind(' ').filter(|&i| i > 0).unwrap_or(limit)
+        };
+        parts.push(rest[..split_at].trim_end().to_owned());
+        rest = rest[split_at..].trim_start();
+    }
+    if !rest.is_empty() {
+        parts.push(rest.to_owned());
     }
+    parts
 }
 
 #[cfg(feature = "online-mode")]
@@ -12,8 +12,15 @@ use azalea_protocol::packets::game::{
 use bevy_app::{App, Plugin, Update};
 use bevy_ecs::prelude::*;
 use handler::{SendChatKindEvent, handle_send_chat_kind_event};
+use thiserror::Error;
 use uuid::Uuid;
 
+/// The maximum number of characters that vanilla servers allow in a chat
+/// message. Sending a longer message will get us kicked.
+///
+/// This doesn't apply to commands.
+pub const MAX_CHAT_LENGTH: usize = 256;
+
 pub struct ChatPlugin;
 impl Plugin for ChatPlugin {
     fn build(&self, app: &mut App) {
@@ -204,27 +211,53 @@ pub fn handle_send_chat_event(
     mut send_chat_kind_events: MessageWriter<SendChatKindEvent>,
 ) {
     for event in events.read() {
-        if event.content.starts_with('/') {
-            send_chat_kind_events.write(SendChatKindEvent {
-                entity: event.entity,
-                content: event.content[1..].to_string(),
-                kind: ChatKind::Command,
-            });
-        } else {
-            send_chat_kind_events.write(SendChatKindEvent {
-                entity: event.entity,
-                content: event.content.clone(),
-                kind: ChatKind::Message,
-            });
-        }
+        let (kind, content) = ChatKind::from_content(&event.content);
+        send_chat_kind_events.write(SendChatKindEvent {
+            entity: event.entity,
+            content: content.to_owned(),
+            kind,
+        });
     }
 }
 
 /// A kind of chat packet, either a chat message or a command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ChatKind {
     Message,
     Command,
 }
+impl ChatKind {
+    /// Decide whether the content of a [`SendChatEvent`] is a command (if it
+    /// starts with a slash) or a chat message.
+    ///
+    /// The content is returned without the slash if it's a command.
+    pub fn from_content(content: &str) -> (ChatKind, &str) {
+        match content.strip_prefix('/') {
+            Some(command) => (ChatKind::Command, command),
+            None => (ChatKind::Message, content),
+        }
+    }
+}
+
+/// A component that decides what happens when we try to send a chat message
+/// that's longer than [`MAX_CHAT_LENGTH`].
+///
+/// If this component isn't present, long messages are split.
+#[derive(Clone, Component, Copy, Debug, Default, Eq, PartialEq)]
+pub enum LongChatMessageBehavior {
+    /// Send the message as multiple chat packets, splitting on spaces when
+    /// possible.
+    #[default]
+    Split,
+    /// Don't send the message at all.
+    Reject,
+}
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum ChatError {
+    #[error("Chat message is {length} characters long, but the limit is {MAX_CHAT_LENGTH}")]
+    TooLong { length: usize },
+}
 
 // TODO
 // MessageSigner, ChatMessageContent, LastSeenMessages
@@ -84,6 +84,15 @@ pub fn poll_request_certs_task(
     }
 }
 
+/// Returns whether the given certificates should be replaced with new ones.
+///
+/// Mojang tells us when we should start refreshing them, which is a while
+/// before they actually expire.
+pub fn should_refresh_certs(certs: &Certificates) -> bool {
+    let now = Utc::now();
+    now >= certs.refresh_after || now >= certs.expires_at
+}
+
 #[allow(clippy::type_complexity)]
 pub fn request_certs_if_needed(
     mut commands: Commands,
@@ -96,6 +105,7 @@ pub fn request_certs_if_needed(
         ),
         (
             Without<RequestCertsTask>,
+            Without<QueuedCertsToSend>,
             With<InGameState>,
             With<IsAuthenticated>,
         ),
@@ -108,22 +118,18 @@ pub fn request_certs_if_needed(
             continue;
         }
 
-        let certs = account.certs();
-        let should_refresh = if let Some(certs) = certs {
-            // certs were already requested and we're waiting for them to refresh
-
-            // but maybe they weren't sent yet, in which case we still want to send the
-            // certs
+        if let Some(certs) = account.certs()
+            && !should_refresh_certs(&certs)
+        {
+            // our certs are still good, but maybe they weren't sent yet (like if we just
+            // reconnected), in which case we can send them without requesting new ones
             if chat_signing_session.is_none() {
-                true
-            } else {
-                Utc::now() > certs.expires_at
+                commands.entity(entity).insert(QueuedCertsToSend { certs });
             }
-        } else {
-            true
-        };
+            continue;
+        }
 
-        if should_refresh && let Some(access_token) = account.access_token() {
+        if let Some(access_token) = account.access_token() {
             let task_pool = IoTaskPool::get();
 
             debug!("Started task to fetch certs");
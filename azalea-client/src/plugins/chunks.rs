@@ -7,13 +7,15 @@ use std::{
     time::{Duration, Instant},
 };
 
-use azalea_core::position::ChunkPos;
+use azalea_core::position::{ChunkBlockPos, ChunkPos};
 use azalea_protocol::packets::game::{
     c_level_chunk_with_light::ClientboundLevelChunkWithLight,
     s_chunk_batch_received::ServerboundChunkBatchReceived,
 };
+use azalea_world::BlockEntity;
 use bevy_app::{App, Plugin, Update};
 use bevy_ecs::prelude::*;
+use simdnbt::owned::{Nbt, NbtCompound};
 use tracing::{error, trace};
 
 use crate::{
@@ -24,20 +26,22 @@ use crate::{
 pub struct ChunksPlugin;
 impl Plugin for ChunksPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                handle_chunk_batch_start_event,
-                handle_receive_chunk_event,
-                handle_chunk_batch_finished_event,
+        app.init_resource::<ChunkBatchSettings>()
+            .add_systems(
+                Update,
+                (
+                    handle_chunk_batch_start_event,
+                    handle_receive_chunk_event,
+                    handle_chunk_batch_finished_event,
+                )
+                    .chain()
+                    .before(InventorySystems)
+                    .before(perform_respawn),
             )
-                .chain()
-                .before(InventorySystems)
-                .before(perform_respawn),
-        )
-        .add_message::<ReceiveChunkEvent>()
-        .add_message::<ChunkBatchStartEvent>()
-        .add_message::<ChunkBatchFinishedEvent>();
+            .add_message::<ReceiveChunkEvent>()
+            .add_message::<ChunkBatchStartEvent>()
+            .add_message::<ChunkBatchFinishedEvent>()
+            .add_message::<ChunkBatchRateChangedEvent>();
     }
 }
 
@@ -52,6 +56,65 @@ pub struct ChunkBatchInfo {
     pub start_time: Instant,
     pub aggregated_duration_per_chunk: Duration,
     pub old_samples_weight: u32,
+    /// The `desired_chunks_per_tick` from the last time that a
+    /// [`ChunkBatchRateChangedEvent`] was sent.
+    pub last_reported_chunks_per_tick: f32,
+}
+
+/// How much the `desired_chunks_per_tick` has to change (as a fraction of the
+/// last reported value) before a [`ChunkBatchRateChangedEvent`] is sent.
+pub const CHUNK_BATCH_RATE_CHANGE_THRESHOLD: f32 = 0.1;
+
+/// A resource *and* component that configures how we calculate the
+/// `desired_chunks_per_tick` that we send to the server after receiving a batch
+/// of chunks.
+///
+/// The value that we send is calculated from how long it took to receive the
+/// previous batches (see [`ChunkBatchInfo`]), but some servers behave badly
+/// when it's too high, so you may want to clamp it. The defaults for the other
+/// fields match vanilla, but you may want to change them if your connection is
+/// very bursty (like when going through some proxies).
+///
+/// The resource is used for every client, but you can also insert it as a
+/// component to override it for a single client. If you'd like to see the
+/// value that was sent, you can observe the [`SendGamePacketEvent`] for the
+/// [`ServerboundChunkBatchReceived`] packet.
+#[derive(Clone, Component, Debug, PartialEq, Resource)]
+pub struct ChunkBatchSettings {
+    /// The lowest `desired_chunks_per_tick` that we'll send to the server.
+    pub min_desired: f32,
+    /// The highest `desired_chunks_per_tick` that we'll send to the server.
+    pub max_desired: f32,
+    /// How far a batch's duration per chunk can be from the average before
+    /// it's clamped, as a multiple of the average.
+    ///
+    /// For example, the default of 3 means that a batch that took more than 3x
+    /// (or less than 1/3) as long as the average will be treated as if it took
+    /// exactly 3x (or 1/3) as long.
+    pub duration_clamp_ratio: u32,
+    /// The maximum weight that the previous average has compared to a new
+    /// batch. Higher values make the average change more slowly.
+    pub max_old_samples_weight: u32,
+}
+impl ChunkBatchSettings {
+    /// Clamp the given `desired_chunks_per_tick` to be within the range in
+    /// these settings.
+    pub fn clamp(&self, desired_chunks_per_tick: f32) -> f32 {
+        // not using f32::clamp since that panics if min > max
+        desired_chunks_per_tick
+            .max(self.min_desired)
+            .min(self.max_desired)
+    }
+}
+impl Default for ChunkBatchSettings {
+    fn default() -> Self {
+        Self {
+            min_desired: 0.,
+            max_desired: f32::MAX,
+            duration_clamp_ratio: 3,
+            max_old_samples_weight: 49,
+        }
+    }
 }
 
 #[derive(Message)]
@@ -64,6 +127,19 @@ pub struct ChunkBatchFinishedEvent {
     pub batch_size: u32,
 }
 
+/// Sent when our estimate of how many chunks we can receive per tick changes
+/// by more than [`CHUNK_BATCH_RATE_CHANGE_THRESHOLD`] since the last time this
+/// event was sent.
+///
+/// The rates are from [`ChunkBatchInfo::desired_chunks_per_tick`], so they
+/// aren't clamped by [`ChunkBatchSettings`].
+#[derive(Clone, Debug, Message)]
+pub struct ChunkBatchRateChangedEvent {
+    pub entity: Entity,
+    pub old_chunks_per_tick: f32,
+    pub new_chunks_per_tick: f32,
+}
+
 pub fn handle_receive_chunk_event(
     mut events: MessageReader<ReceiveChunkEvent>,
     mut query: Query<&WorldHolder>,
@@ -101,28 +177,79 @@ pub fn handle_receive_chunk_event(
                 "Couldn't set chunk data: {e}. World height: {}",
                 world.chunks.height
             );
+            continue;
         }
+
+        if let Some(chunk) = partial_world.chunks.limited_get(&pos) {
+            let mut chunk = chunk.write();
+            chunk.block_entities = event
+                .packet
+                .chunk_data
+                .block_entities
+                .iter()
+                .map(|block_entity| {
+                    let pos = ChunkBlockPos::new(
+                        block_entity.packed_xz >> 4,
+                        // this is a short in vanilla
+                        block_entity.y as i16 as i32,
+                        block_entity.packed_xz & 15,
+                    );
+                    (
+                        pos,
+                        BlockEntity {
+                            kind: block_entity.kind,
+                            data: nbt_to_compound(&block_entity.data),
+                        },
+                    )
+                })
+                .collect();
+        }
+    }
+}
+
+/// Convert the NBT sent in a block entity packet into a compound, or an empty
+/// one if the server didn't send any data.
+pub(crate) fn nbt_to_compound(nbt: &Nbt) -> NbtCompound {
+    match nbt {
+        Nbt::Some(base) => NbtCompound::clone(base),
+        Nbt::None => NbtCompound::new(),
     }
 }
 
 impl ChunkBatchInfo {
+    /// Update the average duration per chunk with a batch that just finished,
+    /// using the default (vanilla) [`ChunkBatchSettings`].
     pub fn batch_finished(&mut self, batch_size: u32) {
+        self.batch_finished_with_settings(batch_size, &ChunkBatchSettings::default());
+    }
+
+    /// Update the average duration per chunk with a batch that just finished.
+    pub fn batch_finished_with_settings(&mut self, batch_size: u32, settings: &ChunkBatchSettings) {
         if batch_size == 0 {
             return;
         }
         let batch_duration = self.start_time.elapsed();
         let duration_per_chunk = batch_duration / batch_size;
+        // a ratio of 0 would make us divide by zero
+        let clamp_ratio = settings.duration_clamp_ratio.max(1);
         let clamped_duration = Duration::clamp(
             duration_per_chunk,
-            self.aggregated_duration_per_chunk / 3,
-            self.aggregated_duration_per_chunk * 3,
+            self.aggregated_duration_per_chunk / clamp_ratio,
+            self.aggregated_duration_per_chunk * clamp_ratio,
         );
         self.aggregated_duration_per_chunk =
             ((self.aggregated_duration_per_chunk * self.old_samples_weight) + clamped_duration)
                 / (self.old_samples_weight + 1);
-        self.old_samples_weight = u32::min(49, self.old_samples_weight + 1);
+        self.old_samples_weight =
+            u32::min(settings.max_old_samples_weight, self.old_samples_weight + 1);
     }
 
+    /// The number of chunks that we think we can receive per tick, which is
+    /// what we ask the server to send us.
+    ///
+    /// Like in vanilla, this is derived from the average number of nanoseconds
+    /// that it took to receive each chunk in the previous batches, and it's
+    /// how many chunks we could receive in 7 milliseconds at that speed.
     pub fn desired_chunks_per_tick(&self) -> f32 {
         (7000000. / self.aggregated_duration_per_chunk.as_nanos() as f64) as f32
     }
@@ -140,14 +267,33 @@ pub fn handle_chunk_batch_start_event(
 }
 
 pub fn handle_chunk_batch_finished_event(
-    mut query: Query<&mut ChunkBatchInfo>,
+    mut query: Query<(&mut ChunkBatchInfo, Option<&ChunkBatchSettings>)>,
     mut events: MessageReader<ChunkBatchFinishedEvent>,
+    mut rate_changed_events: MessageWriter<ChunkBatchRateChangedEvent>,
+    default_settings: Res<ChunkBatchSettings>,
     mut commands: Commands,
 ) {
     for event in events.read() {
-        if let Ok(mut chunk_batch_info) = query.get_mut(event.entity) {
-            chunk_batch_info.batch_finished(event.batch_size);
-            let desired_chunks_per_tick = chunk_batch_info.desired_chunks_per_tick();
+        if let Ok((mut chunk_batch_info, settings)) = query.get_mut(event.entity) {
+            let settings = settings.unwrap_or(&default_settings);
+            chunk_batch_info.batch_finished_with_settings(event.batch_size, settings);
+
+            let new_chunks_per_tick = chunk_batch_info.desired_chunks_per_tick();
+            let old_chunks_per_tick = chunk_batch_info.last_reported_chunks_per_tick;
+            if (new_chunks_per_tick - old_chunks_per_tick).abs()
+                > old_chunks_per_tick * CHUNK_BATCH_RATE_CHANGE_THRESHOLD
+            {
+                chunk_batch_info.last_reported_chunks_per_tick = new_chunks_per_tick;
+                rate_changed_events.write(ChunkBatchRateChangedEvent {
+                    entity: event.entity,
+                    old_chunks_per_tick,
+                    new_chunks_per_tick,
+                });
+            }
+
+            let desired_chunks_per_tick =
+                settings.clamp(chunk_batch_info.desired_chunks_per_tick());
+            trace!("Sending desired_chunks_per_tick: {desired_chunks_per_tick}");
             commands.trigger(SendGamePacketEvent::new(
                 event.entity,
                 ServerboundChunkBatchReceived {
@@ -202,10 +348,56 @@ impl ChunkReceiveSpeedAccumulator {
 
 impl Default for ChunkBatchInfo {
     fn default() -> Self {
-        Self {
+        let mut info = Self {
             start_time: Instant::now(),
             aggregated_duration_per_chunk: Duration::from_millis(2),
             old_samples_weight: 1,
-        }
+            last_reported_chunks_per_tick: 0.,
+        };
+        info.last_reported_chunks_per_tick = info.desired_chunks_per_tick();
+        info
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finish_batch(
+        info: &mut ChunkBatchInfo,
+        settings: &ChunkBatchSettings,
+        batch_duration: Duration,
+    ) {
+        info.start_time = Instant::now() - batch_duration;
+        info.batch_finished_with_settings(1, settings);
+    }
+
+    #[test]
+    fn test_default_clamp_ratio() {
+        let mut info = ChunkBatchInfo::default();
+        finish_batch(
+            &mut info,
+            &ChunkBatchSettings::default(),
+            Duration::from_millis(20),
+        );
+        // 20ms gets clamped to 3 * 2ms, so the average is (2ms + 6ms) / 2
+        assert_eq!(info.aggregated_duration_per_chunk, Duration::from_millis(4));
+        assert_eq!(info.old_samples_weight, 2);
+    }
+
+    #[test]
+    fn test_custom_clamp_ratio() {
+        let settings = ChunkBatchSettings {
+            duration_clamp_ratio: 20,
+            max_old_samples_weight: 2,
+            ..Default::default()
+        };
+        let mut info = ChunkBatchInfo::default();
+        finish_batch(&mut info, &settings, Duration::from_millis(20));
+        // the range is now 0.1ms..40ms, so the 20ms batch isn't clamped
+        assert!(info.aggregated_duration_per_chunk >= Duration::from_millis(11));
+
+        finish_batch(&mut info, &settings, Duration::from_millis(20));
+        assert_eq!(info.old_samples_weight, 2);
     }
 }
@@ -1,9 +1,13 @@
+use azalea_entity::HumanoidArm;
 use azalea_protocol::{
-    common::client_information::ClientInformation,
+    common::client_information::{
+        ChatVisibility, ClientInformation, ModelCustomization, ParticleStatus,
+    },
     packets::config::s_client_information::ServerboundClientInformation,
 };
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
+use thiserror::Error;
 use tracing::{debug, warn};
 
 use super::packet::config::SendConfigPacketEvent;
@@ -42,3 +46,138 @@ pub fn send_client_information(
         ));
     }
 }
+
+/// The highest view distance that the vanilla client lets you pick, in chunks.
+pub const MAX_VIEW_DISTANCE: u8 = 32;
+/// The longest language code that vanilla servers accept.
+pub const MAX_LANGUAGE_LENGTH: usize = 16;
+
+/// A builder for [`ClientInformation`] that checks that the values are ones
+/// that servers will accept.
+///
+/// ```
+/// # use azalea_client::ClientInformationBuilder;
+/// # use azalea_entity::HumanoidArm;
+/// # fn example() -> Result<(), azalea_client::client_information::ClientInformationError> {
+/// let client_information = ClientInformationBuilder::new()
+///     .view_distance(12)?
+///     .main_hand(HumanoidArm::Left)
+///     .build();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ClientInformationBuilder {
+    client_information: ClientInformation,
+}
+
+impl ClientInformationBuilder {
+    /// Start building from [`ClientInformation::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the language, formatted like "en_us".
+    ///
+    /// Returns an error if it's empty or longer than [`MAX_LANGUAGE_LENGTH`].
+    pub fn language(mut self, language: &str) -> Result<Self, ClientInformationError> {
+        if language.is_empty() || language.len() > MAX_LANGUAGE_LENGTH {
+            return Err(ClientInformationError::InvalidLanguage(language.to_owned()));
+        }
+        self.client_information.language = language.to_owned();
+        Ok(self)
+    }
+    /// Set the view distance in chunks.
+    ///
+    /// View distances above [`MAX_VIEW_DISTANCE`] are clamped, and a view
+    /// distance of 0 is an error.
+    pub fn view_distance(mut self, view_distance: u8) -> Result<Self, ClientInformationError> {
+        if view_distance == 0 {
+            return Err(ClientInformationError::ZeroViewDistance);
+        }
+        self.client_information.view_distance = view_distance.min(MAX_VIEW_DISTANCE);
+        Ok(self)
+    }
+    pub fn chat_visibility(mut self, chat_visibility: ChatVisibility) -> Self {
+        self.client_information.chat_visibility = chat_visibility;
+        self
+    }
+    pub fn chat_colors(mut self, chat_colors: bool) -> Self {
+        self.client_information.chat_colors = chat_colors;
+        self
+    }
+    pub fn model_customization(mut self, model_customization: ModelCustomization) -> Self {
+        self.client_information.model_customization = model_customization;
+        self
+    }
+    pub fn main_hand(mut self, main_hand: HumanoidArm) -> Self {
+        self.client_information.main_hand = main_hand;
+        self
+    }
+    pub fn text_filtering_enabled(mut self, text_filtering_enabled: bool) -> Self {
+        self.client_information.text_filtering_enabled = text_filtering_enabled;
+        self
+    }
+    /// Set whether we should show up in the server list's player sample.
+    pub fn allows_listing(mut self, allows_listing: bool) -> Self {
+        self.client_information.allows_listing = allows_listing;
+        self
+    }
+    pub fn particle_status(mut self, particle_status: ParticleStatus) -> Self {
+        self.client_information.particle_status = particle_status;
+        self
+    }
+
+    pub fn build(self) -> ClientInformation {
+        self.client_information
+    }
+}
+
+impl From<ClientInformationBuilder> for ClientInformation {
+    fn from(builder: ClientInformationBuilder) -> Self {
+        builder.build()
+    }
+}
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum ClientInformationError {
+    #[error("View distance must be at least 1")]
+    ZeroViewDistance,
+    #[error("Language {0:?} must be between 1 and {MAX_LANGUAGE_LENGTH} characters long")]
+    InvalidLanguage(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_view_distance() {
+        assert_eq!(
+            ClientInformationBuilder::new()
+                .view_distance(0)
+                .unwrap_err(),
+            ClientInformationError::ZeroViewDistance
+        );
+        let client_information = ClientInformationBuilder::new()
+            .view_distance(64)
+            .unwrap()
+            .build();
+        assert_eq!(client_information.view_distance, MAX_VIEW_DISTANCE);
+    }
+
+    #[test]
+    fn test_language() {
+        assert!(ClientInformationBuilder::new().language("").is_err());
+        assert!(
+            ClientInformationBuilder::new()
+                .language("this_is_not_a_language")
+                .is_err()
+        );
+        let client_information = ClientInformationBuilder::new()
+            .language("fr_fr")
+            .unwrap()
+            .build();
+        assert_eq!(client_information.language, "fr_fr");
+    }
+}
@@ -1,43 +1,410 @@
 use std::{
+    collections::VecDeque,
     fmt::Debug,
     io::Cursor,
-    mem,
+    iter, mem,
+    panic::{self, AssertUnwindSafe},
     sync::{
         Arc,
         atomic::{self, AtomicBool},
     },
+    time::Instant,
 };
 
+use azalea_chat::FormattedText;
 use azalea_crypto::Aes128CfbEnc;
 use azalea_protocol::{
     connect::{RawReadConnection, RawWriteConnection},
     packets::{
-        ConnectionProtocol, Packet, ProtocolPacket, config::ClientboundConfigPacket,
-        game::ClientboundGamePacket, login::ClientboundLoginPacket,
+        ConnectionProtocol, Packet, ProtocolPacket,
+        config::ClientboundConfigPacket,
+        game::{ClientboundGamePacket, ServerboundGamePacket},
+        login::ClientboundLoginPacket,
     },
     read::{ReadPacketError, deserialize_packet},
     write::serialize_packet,
 };
 use bevy_app::prelude::*;
-use bevy_ecs::prelude::*;
+use bevy_ecs::{message::Messages, prelude::*};
 use bevy_tasks::{IoTaskPool, futures_lite::future};
+use parking_lot::Mutex;
 use thiserror::Error;
 use tokio::{
     io::AsyncWriteExt,
     net::tcp::OwnedWriteHalf,
-    sync::mpsc::{self},
+    sync::{
+        mpsc::{self},
+        oneshot,
+    },
 };
-use tracing::{debug, error, info, trace};
+use tracing::{debug, error, info, trace, warn};
 
 use super::packet::{
-    config::ReceiveConfigPacketEvent, game::ReceiveGamePacketEvent, login::ReceiveLoginPacketEvent,
+    config::ReceiveConfigPacketEvent,
+    game::ReceiveGamePacketEvent,
+    login::{AwaitingFirstConfigPacket, ReceiveLoginPacketEvent},
+};
+use crate::{
+    disconnect::DisconnectEvent,
+    packet::{config, game, login},
 };
-use crate::packet::{config, game, login};
 
 pub struct ConnectionPlugin;
 impl Plugin for ConnectionPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(PreUpdate, (read_packets, poll_all_writer_tasks).chain());
+        app.init_resource::<PacketParseSettings>()
+            .add_message::<FlushPacketsEvent>()
+            .add_systems(
+                PreUpdate,
+                (update_rate_limiters, read_packets, poll_all_writer_tasks).chain(),
+            )
+            // this is in PostUpdate so the packets that were sent in Update are included
+            .add_systems(PostUpdate, handle_flush_packets_event);
+    }
+}
+
+/// A resource *and* component that decides what happens when the server sends
+/// us a packet that we can't parse, like one with an ID that we don't know
+/// about, or one that makes our packet handlers panic.
+///
+/// The resource is used for every client, but you can also insert it as a
+/// component to override it for a single client.
+#[derive(Clone, Component, Debug, Default, Resource)]
+pub struct PacketParseSettings {
+    /// Whether we should disconnect from the server when we receive a packet
+    /// that we can't parse.
+    ///
+    /// If this is false (the default), the packet will be logged and skipped.
+    /// This makes us more resilient against servers that send extra packets,
+    /// for example modded servers.
+    pub strict_packets: bool,
+    /// Whether a panic while handling a packet should only disconnect the
+    /// client that received it, instead of crashing every client in the ECS.
+    ///
+    /// This is disabled by default since a panic usually means there's a bug
+    /// that should be fixed, but it's recommended to enable it for swarms
+    /// where one bad client shouldn't take down the rest.
+    ///
+    /// Note that the handler that panicked may have only partially updated
+    /// the client's state, which is why the client is disconnected.
+    pub catch_panics: bool,
+    /// A function that's called whenever we receive a packet that we can't
+    /// parse, and decides what we do about it.
+    ///
+    /// This is useful for debugging issues with servers that send unusual
+    /// packets, since it gets the raw bytes of the packet.
+    pub on_decode_error: Option<DecodeErrorHandler>,
+}
+
+/// A callback for [`PacketParseSettings::on_decode_error`].
+///
+/// It's called with the client's entity, the raw (decompressed and decrypted)
+/// bytes of the packet that couldn't be parsed, and the error.
+#[derive(Clone)]
+pub struct DecodeErrorHandler(
+    pub Arc<dyn Fn(Entity, &[u8], &ReadPacketError) -> DecodeErrorAction + Send + Sync>,
+);
+impl DecodeErrorHandler {
+    pub fn new(
+        f: impl Fn(Entity, &[u8], &ReadPacketError) -> DecodeErrorAction + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(f))
+    }
+}
+impl Debug for DecodeErrorHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DecodeErrorHandler").finish_non_exhaustive()
+    }
+}
+
+/// What to do after a [`DecodeErrorHandler`] is called.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DecodeErrorAction {
+    /// Do what we would've done if there was no handler, based on the rest of
+    /// the [`PacketParseSettings`].
+    #[default]
+    Default,
+    /// Skip the packet and keep reading from the connection, even if
+    /// [`PacketParseSettings::strict_packets`] is enabled.
+    Continue,
+    /// Disconnect from the server.
+    Disconnect,
+}
+
+/// Get notified once every packet that the client has sent so far has been
+/// written to the network.
+///
+/// The sender is dropped without sending anything if the connection closes
+/// before that happens. Packets sent in the same update as this event are
+/// included, so this can be used to wait for a chat message to actually be
+/// sent.
+#[derive(Message)]
+pub struct FlushPacketsEvent {
+    pub entity: Entity,
+    pub sender: oneshot::Sender<()>,
+}
+
+pub fn handle_flush_packets_event(
+    mut events: ResMut<Messages<FlushPacketsEvent>>,
+    mut query: Query<&mut RawConnection>,
+) {
+    // the events are drained instead of read since we need to take ownership of the
+    // senders
+    for event in events.drain() {
+        if let Ok(mut conn) = query.get_mut(event.entity) {
+            conn.notify_when_flushed(event.sender);
+        }
+    }
+}
+
+/// A component that limits how fast a client can read and write data, to
+/// simulate a slow connection to the server.
+///
+/// This is mostly useful for testing how your bot behaves on a poor
+/// connection. Packets that go over the limit are delayed until there's
+/// enough bandwidth for them, so nothing is dropped. Since this makes chunks
+/// arrive more slowly, the server will also be asked to send fewer chunks per
+/// tick (see [`ChunkBatchInfo`]).
+///
+/// Reading is limited based on the size of the packets after they're
+/// decompressed, and writing is limited based on the size of the packets that
+/// are sent over the network.
+///
+/// [`ChunkBatchInfo`]: crate::chunks::ChunkBatchInfo
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq)]
+pub struct BandwidthLimit {
+    /// The maximum number of bytes that we'll read per second, or `None` for no
+    /// limit.
+    pub read_bps: Option<u32>,
+    /// The maximum number of bytes that we'll write per second, or `None` for
+    /// no limit.
+    pub write_bps: Option<u32>,
+}
+
+/// A token bucket that's used to implement [`BandwidthLimit`].
+#[derive(Debug)]
+struct RateLimiter {
+    bytes_per_second: u32,
+    /// The number of bytes that we can use right now.
+    ///
+    /// This can go negative if we let through a packet that was larger than
+    /// the bandwidth we had left.
+    available: f64,
+    last_refill: Instant,
+}
+impl RateLimiter {
+    fn new(bytes_per_second: u32) -> Self {
+        Self {
+            bytes_per_second,
+            available: Self::max_available(bytes_per_second),
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// We only let up to a tick's worth of bandwidth build up, so packets are
+    /// spread out instead of all being let through at once.
+    fn max_available(bytes_per_second: u32) -> f64 {
+        bytes_per_second as f64 / 20.
+    }
+
+    fn has_capacity(&mut self) -> bool {
+        let now = Instant::now();
+        let refilled = (now - self.last_refill).as_secs_f64() * self.bytes_per_second as f64;
+        self.available = f64::min(
+            self.available + refilled,
+            Self::max_available(self.bytes_per_second),
+        );
+        self.last_refill = now;
+
+        self.available > 0.
+    }
+
+    fn consume(&mut self, bytes: usize) {
+        self.available -= bytes as f64;
+    }
+
+    fn try_consume(&mut self, bytes: usize) -> bool {
+        if !self.has_capacity() {
+            return false;
+        }
+        self.consume(bytes);
+        true
+    }
+}
+
+/// A resource that limits how many packets every client can send per second.
+///
+/// Sending a lot of packets in a short amount of time (for example, by
+/// interacting with many blocks in a single tick) can make anti-spam plugins
+/// kick the bot. Packets that go over the limit are delayed or dropped based
+/// on the [`PacketRateLimitPolicy`].
+///
+/// There's no limit if this resource isn't present. Only game packets sent
+/// with [`SendGamePacketEvent`] are limited, and packets that the server needs
+/// for the connection to keep working (see
+/// [`is_exempt_from_packet_rate_limit`]) are never delayed or dropped by it.
+/// Exempt packets are sent ahead of the packets that are waiting because of
+/// the limit, so a full buffer can't make us time out.
+///
+/// [`SendGamePacketEvent`]: crate::packet::game::SendGamePacketEvent
+#[derive(Clone, Copy, Debug, PartialEq, Resource)]
+pub struct PacketRateLimit {
+    /// The maximum number of packets that we'll send per second, on average.
+    pub packets_per_second: u32,
+    /// The number of packets that can be sent at once before the limit starts
+    /// applying.
+    ///
+    /// This is always treated as being at least 1.
+    pub burst: u32,
+    /// The maximum number of packets that can be waiting to be sent with the
+    /// [`PacketRateLimitPolicy::Buffer`] policy.
+    ///
+    /// Packets that would go over this are dropped instead of buffered.
+    pub max_buffered: u32,
+    pub policy: PacketRateLimitPolicy,
+}
+impl PacketRateLimit {
+    /// Create a new limit that buffers packets, with a burst of a quarter of a
+    /// second's worth of packets and room for five seconds' worth of buffered
+    /// packets.
+    pub fn new(packets_per_second: u32) -> Self {
+        Self {
+            packets_per_second,
+            burst: packets_per_second / 4,
+            max_buffered: packets_per_second.saturating_mul(5),
+            policy: PacketRateLimitPolicy::default(),
+        }
+    }
+
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst;
+        self
+    }
+
+    pub fn max_buffered(mut self, max_buffered: u32) -> Self {
+        self.max_buffered = max_buffered;
+        self
+    }
+
+    pub fn policy(mut self, policy: PacketRateLimitPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+}
+
+/// What happens to packets that would go over the [`PacketRateLimit`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PacketRateLimitPolicy {
+    /// Delay the packets until they can be sent without going over the limit.
+    ///
+    /// Packets may reach the server later than expected if we keep sending too
+    /// many, and they're dropped if more than
+    /// [`PacketRateLimit::max_buffered`] are waiting.
+    #[default]
+    Buffer,
+    /// Never send the packets that go over the limit.
+    Drop,
+}
+
+/// Returns whether the packet is one that the server needs in order to keep
+/// the connection working, so it's never delayed or dropped by the
+/// [`PacketRateLimit`].
+///
+/// These packets skip ahead of the packets that are buffered because of the
+/// limit, but they're still delayed by the [`BandwidthLimit`] like any other
+/// packet.
+///
+/// This includes keep-alives, pongs, teleport confirmations, and chat packets
+/// (since dropping a signed message breaks the chain of messages that the
+/// server validates).
+pub fn is_exempt_from_packet_rate_limit(packet: &ServerboundGamePacket) -> bool {
+    matches!(
+        packet,
+        ServerboundGamePacket::KeepAlive(_)
+            | ServerboundGamePacket::Pong(_)
+            | ServerboundGamePacket::AcceptTeleportation(_)
+            | ServerboundGamePacket::Chat(_)
+            | ServerboundGamePacket::ChatAck(_)
+            | ServerboundGamePacket::ChatCommand(_)
+            | ServerboundGamePacket::ChatCommandSigned(_)
+            | ServerboundGamePacket::ChatSessionUpdate(_)
+            | ServerboundGamePacket::ConfigurationAcknowledged(_)
+    )
+}
+
+/// A token bucket that's used to implement [`PacketRateLimit`].
+#[derive(Debug)]
+struct PacketRateLimiter {
+    limit: PacketRateLimit,
+    /// The number of packets that we can send right now.
+    available: f64,
+    last_refill: Instant,
+}
+impl PacketRateLimiter {
+    fn new(limit: PacketRateLimit) -> Self {
+        Self {
+            limit,
+            available: Self::max_available(&limit),
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn max_available(limit: &PacketRateLimit) -> f64 {
+        limit.burst.max(1) as f64
+    }
+
+    fn has_capacity(&mut self) -> bool {
+        let now = Instant::now();
+        let refilled =
+            (now - self.last_refill).as_secs_f64() * self.limit.packets_per_second as f64;
+        self.available = f64::min(self.available + refilled, Self::max_available(&self.limit));
+        self.last_refill = now;
+
+        self.available >= 1.
+    }
+
+    fn consume(&mut self) {
+        self.available -= 1.;
+    }
+
+    fn try_consume(&mut self) -> bool {
+        if !self.has_capacity() {
+            return false;
+        }
+        self.consume();
+        true
+    }
+}
+
+/// Make the rate limiters in [`RawConnection`] match the client's
+/// [`BandwidthLimit`] and the [`PacketRateLimit`].
+fn update_rate_limiters(
+    mut query: Query<(&mut RawConnection, Option<&BandwidthLimit>)>,
+    packet_rate_limit: Option<Res<PacketRateLimit>>,
+) {
+    let packet_rate_limit = packet_rate_limit.as_deref().copied();
+
+    for (mut conn, limit) in query.iter_mut() {
+        let limit = limit.copied().unwrap_or_default();
+
+        let current_read_bps = conn.read_limiter.as_ref().map(|l| l.bytes_per_second);
+        if current_read_bps != limit.read_bps {
+            conn.read_limiter = limit.read_bps.map(RateLimiter::new);
+        }
+
+        let Some(network) = &mut conn.network else {
+            continue;
+        };
+
+        let current_write_bps = network.write_limiter.as_ref().map(|l| l.bytes_per_second);
+        if current_write_bps != limit.write_bps {
+            network.write_limiter = limit.write_bps.map(RateLimiter::new);
+        }
+
+        let current_packet_rate_limit = network.packet_limiter.as_ref().map(|l| l.limit);
+        if current_packet_rate_limit != packet_rate_limit {
+            network.packet_limiter = packet_rate_limit.map(PacketRateLimiter::new);
+        }
     }
 }
 
@@ -68,15 +435,29 @@ pub fn read_packets(ecs: &mut World) {
     // handle injected packets, see the comment on
     // RawConnection::injected_clientbound_packets for more info
     for (entity, raw_packets) in entities_with_injected_packets {
-        for raw_packet in raw_packets {
-            let conn = conn_query.get(ecs, entity).unwrap();
+        let mut raw_packets = raw_packets.into_iter();
+        while let Some(raw_packet) = raw_packets.next() {
+            let mut conn = conn_query.get_mut(ecs, entity).unwrap();
+            if let Some(read_limiter) = &mut conn.read_limiter
+                && !read_limiter.try_consume(raw_packet.len())
+            {
+                // we're over the bandwidth limit, so put the packets back to be read in a
+                // later update
+                conn.injected_clientbound_packets
+                    .splice(0..0, iter::once(raw_packet).chain(raw_packets));
+                break;
+            }
             let state = conn.state;
 
             trace!("Received injected packet with bytes: {raw_packet:?}");
-            if let Err(e) =
-                handle_raw_packet(ecs, &raw_packet, entity, state, &mut queued_packet_events)
-            {
-                error!("Error reading injected packet: {e}");
+            if handle_raw_packet_or_disconnect(
+                ecs,
+                &raw_packet,
+                entity,
+                state,
+                &mut queued_packet_events,
+            ) {
+                break;
             }
         }
     }
@@ -84,20 +465,29 @@ pub fn read_packets(ecs: &mut World) {
     for entity in entities_handling_packets {
         loop {
             let mut conn = conn_query.get_mut(ecs, entity).unwrap();
+            if let Some(read_limiter) = &mut conn.read_limiter
+                && !read_limiter.has_capacity()
+            {
+                // the rest of the packets will be read in a later update
+                break;
+            }
             let net_conn = conn.net_conn().unwrap();
             let read_res = net_conn.reader.try_read();
             let state = conn.state;
             match read_res {
                 Ok(Some(raw_packet)) => {
+                    if let Some(read_limiter) = &mut conn.read_limiter {
+                        read_limiter.consume(raw_packet.len());
+                    }
                     let raw_packet = Arc::<[u8]>::from(raw_packet);
-                    if let Err(e) = handle_raw_packet(
+                    if handle_raw_packet_or_disconnect(
                         ecs,
                         &raw_packet,
                         entity,
                         state,
                         &mut queued_packet_events,
                     ) {
-                        error!("Error reading packet: {e}");
+                        break;
                     }
                 }
                 Ok(None) => {
@@ -130,6 +520,8 @@ pub fn read_packets(ecs: &mut World) {
 fn poll_all_writer_tasks(mut conn_query: Query<&mut RawConnection>) {
     for mut conn in conn_query.iter_mut() {
         if let Some(net_conn) = &mut conn.network {
+            net_conn.flush_throttled_writes();
+
             // this needs to be done at some point every update to make sure packets are
             // actually sent to the network
 
@@ -156,6 +548,128 @@ impl QueuedPacketEvents {
     }
 }
 
+fn packet_parse_settings(ecs: &World, entity: Entity) -> PacketParseSettings {
+    ecs.get::<PacketParseSettings>(entity)
+        .or_else(|| ecs.get_resource::<PacketParseSettings>())
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Call [`handle_raw_packet`], and handle any errors or panics based on the
+/// client's [`PacketParseSettings`].
+///
+/// Returns whether we disconnected.
+fn handle_raw_packet_or_disconnect(
+    ecs: &mut World,
+    raw_packet: &[u8],
+    entity: Entity,
+    state: ConnectionProtocol,
+    queued_packet_events: &mut QueuedPacketEvents,
+) -> bool {
+    let res = if packet_parse_settings(ecs, entity).catch_panics {
+        // the ECS is behind a parking_lot lock, which doesn't get poisoned, so as long
+        // as the panic doesn't escape from here the other clients can keep
+        // running
+        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+            handle_raw_packet(ecs, raw_packet, entity, state, queued_packet_events)
+        }));
+        match res {
+            Ok(res) => res,
+            Err(payload) => {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .copied()
+                    .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                    .unwrap_or("Box<dyn Any>");
+                error!(
+                    "Disconnecting since we panicked while handling a {state:?} packet: {message}"
+                );
+                disconnect_broken_connection(ecs, entity);
+                return true;
+            }
+        }
+    } else {
+        handle_raw_packet(ecs, raw_packet, entity, state, queued_packet_events)
+    };
+
+    match res {
+        Ok(()) => false,
+        Err(e) => {
+            let on_decode_error = packet_parse_settings(ecs, entity).on_decode_error;
+            match on_decode_error.map_or(DecodeErrorAction::Default, |handler| {
+                (handler.0)(entity, raw_packet, &e)
+            }) {
+                DecodeErrorAction::Default => {}
+                DecodeErrorAction::Continue => {
+                    debug!("Skipping packet that couldn't be parsed: {e}");
+                    return false;
+                }
+                DecodeErrorAction::Disconnect => {
+                    warn!("Disconnecting since we received a packet that couldn't be parsed: {e}");
+                    disconnect_broken_connection(ecs, entity);
+                    return true;
+                }
+            }
+
+            if state == ConnectionProtocol::Configuration
+                && ecs.get::<AwaitingFirstConfigPacket>(entity).is_some()
+            {
+                disconnect_unsupported_server(ecs, entity, &e);
+                return true;
+            }
+            handle_packet_parse_error(ecs, entity, &e)
+        }
+    }
+}
+
+/// Disconnect from a server that didn't send a configuration packet after
+/// login, which most likely means that it's older than 1.20.2.
+///
+/// Azalea only supports one version of Minecraft, so the best we can do here is
+/// to give a clearer error than the one for a packet that couldn't be parsed.
+fn disconnect_unsupported_server(ecs: &mut World, entity: Entity, error: &ReadPacketError) {
+    error!(
+        "Disconnecting since the server skipped the configuration state, so it's probably older than 1.20.2 (the first packet after login couldn't be parsed: {error})"
+    );
+    if let Some(mut conn) = ecs.get_mut::<RawConnection>(entity) {
+        conn.network = None;
+    }
+    let reason = FormattedText::from(format!(
+        "The server doesn't support the configuration state, so it's probably older than 1.20.2. Azalea only supports Minecraft {}.",
+        azalea_protocol::packets::VERSION_NAME
+    ));
+    ecs.write_message(DisconnectEvent::new(entity, Some(reason)));
+}
+
+fn disconnect_broken_connection(ecs: &mut World, entity: Entity) {
+    if let Some(mut conn) = ecs.get_mut::<RawConnection>(entity) {
+        conn.network = None;
+        // setting this will make us send a DisconnectEvent
+        conn.is_alive = false;
+    }
+}
+
+/// Log an error that happened while parsing a packet, and disconnect if
+/// [`PacketParseSettings::strict_packets`] is enabled.
+///
+/// Returns whether we disconnected.
+fn handle_packet_parse_error(ecs: &mut World, entity: Entity, error: &ReadPacketError) -> bool {
+    if packet_parse_settings(ecs, entity).strict_packets {
+        warn!("Disconnecting since we received a packet that couldn't be parsed: {error}");
+        disconnect_broken_connection(ecs, entity);
+        return true;
+    }
+
+    if let ReadPacketError::UnknownPacketId { .. } = error {
+        // this is expected from servers that have extra packets, so it's not worth
+        // logging as an error
+        warn!("Skipping packet: {error}");
+    } else {
+        error!("Error reading packet: {error}");
+    }
+    false
+}
+
 fn log_for_error(error: &ReadPacketError) {
     if !matches!(*error, ReadPacketError::ConnectionClosed) {
         error!("Error reading packet from Client: {error:?}");
@@ -180,6 +694,9 @@ pub struct RawConnection {
     pub(crate) network: Option<NetworkConnection>,
     pub state: ConnectionProtocol,
     pub(crate) is_alive: bool,
+    /// Limits how fast we read packets, based on the client's
+    /// [`BandwidthLimit`].
+    read_limiter: Option<RateLimiter>,
 
     /// This exists for internal testing purposes and probably shouldn't be used
     /// for normal bots.
@@ -198,16 +715,27 @@ impl RawConnection {
 
         let (network_packet_writer_tx, network_packet_writer_rx) =
             mpsc::unbounded_channel::<Box<[u8]>>();
+        let flush_state = Arc::new(Mutex::new(FlushState::default()));
 
-        let writer_task =
-            task_pool.spawn(write_task(network_packet_writer_rx, writer.write_stream));
+        let writer_task = task_pool.spawn(write_task(
+            network_packet_writer_rx,
+            writer.write_stream,
+            flush_state.clone(),
+        ));
 
         let mut conn = Self::new_networkless(state);
         conn.network = Some(NetworkConnection {
             reader,
             enc_cipher: writer.enc_cipher,
             network_packet_writer_tx,
-            writer_task,
+            writer_task: Some(writer_task),
+            write_limiter: None,
+            packet_limiter: None,
+            throttled_writes: VecDeque::new(),
+            throttled_writes_popped: 0,
+            flushes_waiting_for_throttled_writes: VecDeque::new(),
+            packets_dispatched: 0,
+            flush_state,
         });
 
         conn
@@ -218,6 +746,7 @@ impl RawConnection {
             network: None,
             state,
             is_alive: true,
+            read_limiter: None,
             injected_clientbound_packets: Vec::new(),
         }
     }
@@ -251,9 +780,46 @@ impl RawConnection {
         Ok(())
     }
 
+    /// Write a game packet to the server, applying the [`PacketRateLimit`] if
+    /// the packet isn't [exempt](is_exempt_from_packet_rate_limit) from it.
+    ///
+    /// This is called by the handler for [`SendGamePacketEvent`].
+    ///
+    /// [`SendGamePacketEvent`]: crate::packet::game::SendGamePacketEvent
+    pub fn write_game_packet(
+        &mut self,
+        packet: ServerboundGamePacket,
+    ) -> Result<(), WritePacketError> {
+        if let Some(network) = &mut self.network {
+            let rate_limit = if is_exempt_from_packet_rate_limit(&packet) {
+                PacketRateLimitKind::Exempt
+            } else {
+                PacketRateLimitKind::Limited
+            };
+            let raw_packet = serialize_packet(&packet)?;
+            network.write_raw_with_rate_limit(&raw_packet, rate_limit)?;
+            Ok(())
+        } else {
+            self.write(packet)
+        }
+    }
+
     pub fn net_conn(&mut self) -> Option<&mut NetworkConnection> {
         self.network.as_mut()
     }
+
+    /// Send to the given channel once all of the packets that were written so
+    /// far have been sent over the network.
+    ///
+    /// If there's no network connection (like in tests), this is sent
+    /// immediately.
+    pub fn notify_when_flushed(&mut self, sender: oneshot::Sender<()>) {
+        if let Some(network) = &mut self.network {
+            network.notify_when_flushed(sender);
+        } else {
+            let _ = sender.send(());
+        }
+    }
 }
 
 pub fn handle_raw_packet(
@@ -290,6 +856,9 @@ pub fn handle_raw_packet(
         ConnectionProtocol::Configuration => {
             let packet = Arc::new(deserialize_packet::<ClientboundConfigPacket>(stream)?);
             trace!("Packet: {packet:?}");
+            if ecs.get::<AwaitingFirstConfigPacket>(entity).is_some() {
+                ecs.entity_mut(entity).remove::<AwaitingFirstConfigPacket>();
+            }
             config::process_packet(ecs, entity, &packet);
             queued_packet_events
                 .config
@@ -305,12 +874,79 @@ pub struct NetworkConnection {
     // compression threshold is in the RawReadConnection
     pub enc_cipher: Option<Aes128CfbEnc>,
 
-    pub writer_task: bevy_tasks::Task<()>,
+    /// The task that writes our packets to the network.
+    ///
+    /// This is only `None` while the `NetworkConnection` is being dropped, at
+    /// which point the task is detached so it can finish sending the packets
+    /// that were queued before the connection closed.
+    pub writer_task: Option<bevy_tasks::Task<()>>,
     /// A queue of raw TCP packets to send.
     ///
     /// These will not be modified further, they should already be serialized
     /// and compressed and encrypted before being added here.
     network_packet_writer_tx: mpsc::UnboundedSender<Box<[u8]>>,
+    /// Limits how fast we write packets, based on the client's
+    /// [`BandwidthLimit`].
+    write_limiter: Option<RateLimiter>,
+    /// Limits how many packets we write, based on the [`PacketRateLimit`].
+    packet_limiter: Option<PacketRateLimiter>,
+    /// Packets that are waiting because we're over the [`BandwidthLimit`] or
+    /// [`PacketRateLimit`].
+    ///
+    /// These aren't encrypted yet, since packets that are exempt from the
+    /// [`PacketRateLimit`] can be sent before them and the cipher has to see
+    /// the packets in the order that they're sent in.
+    throttled_writes: VecDeque<ThrottledWrite>,
+    /// The number of packets that were ever taken out of `throttled_writes`.
+    throttled_writes_popped: u64,
+    /// Senders from [`Self::notify_when_flushed`] that are waiting until
+    /// `throttled_writes_popped` reaches the given number, which is when the
+    /// packets that were throttled when they asked have been sent to the writer
+    /// task.
+    ///
+    /// This is always sorted, since the number of throttled packets that were
+    /// ever queued only goes up.
+    flushes_waiting_for_throttled_writes: VecDeque<(u64, oneshot::Sender<()>)>,
+    /// The number of packets that we've sent to the writer task.
+    packets_dispatched: u64,
+    /// Shared with the writer task so it can tell us when our packets were
+    /// sent.
+    flush_state: Arc<Mutex<FlushState>>,
+}
+
+/// A packet that's waiting to be sent, which was compressed and framed but not
+/// encrypted.
+struct ThrottledWrite {
+    packet: Vec<u8>,
+    /// Whether the packet counts towards the [`PacketRateLimit`].
+    is_rate_limited: bool,
+}
+
+/// How a packet is affected by the [`PacketRateLimit`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum PacketRateLimitKind {
+    /// The packet counts towards the limit.
+    Limited,
+    /// The packet doesn't count towards the limit, but it's still sent in order
+    /// with the other packets.
+    Unlimited,
+    /// The packet doesn't count towards the limit and it skips ahead of the
+    /// packets that are waiting because of it.
+    Exempt,
+}
+
+#[derive(Default)]
+struct FlushState {
+    /// The number of packets that the writer task has sent over the network.
+    packets_written: u64,
+    /// The number of packets that were never sent because they went over the
+    /// [`PacketRateLimit`].
+    packets_dropped: u64,
+    /// Senders that are waiting for the given number of packets to be written.
+    ///
+    /// This is always sorted, since the number of dispatched packets only goes
+    /// up.
+    waiting: VecDeque<(u64, oneshot::Sender<()>)>,
 }
 impl NetworkConnection {
     pub fn write<P: ProtocolPacket + Debug>(
@@ -324,21 +960,199 @@ impl NetworkConnection {
         Ok(())
     }
 
+    /// Write a packet that was already serialized.
+    ///
+    /// This doesn't count towards the [`PacketRateLimit`], but it's still
+    /// limited by the [`BandwidthLimit`].
     pub fn write_raw(&mut self, raw_packet: &[u8]) -> Result<(), WritePacketError> {
-        let network_packet = azalea_protocol::write::encode_to_network_packet(
+        self.write_raw_with_rate_limit(raw_packet, PacketRateLimitKind::Unlimited)
+    }
+
+    fn write_raw_with_rate_limit(
+        &mut self,
+        raw_packet: &[u8],
+        rate_limit: PacketRateLimitKind,
+    ) -> Result<(), WritePacketError> {
+        let is_rate_limited = rate_limit == PacketRateLimitKind::Limited;
+        if is_rate_limited && self.should_drop_rate_limited_packet() {
+            trace!("Dropping packet since we're over the packet rate limit");
+            self.flush_state.lock().packets_dropped += 1;
+            return Ok(());
+        }
+
+        // the packet is encrypted when it's sent to the writer task
+        let packet = azalea_protocol::write::encode_to_network_packet(
             raw_packet,
             self.reader.compression_threshold,
-            &mut self.enc_cipher,
+            &mut None,
         );
+
+        if rate_limit == PacketRateLimitKind::Exempt {
+            // this still counts towards the bandwidth limit, but it's allowed to go
+            // over it so it isn't stuck behind the throttled packets
+            if let Some(write_limiter) = &mut self.write_limiter {
+                write_limiter.consume(packet.len());
+            }
+            return self.dispatch(packet);
+        }
+
+        if !self.throttled_writes.is_empty()
+            || !self.try_consume_limits(packet.len(), is_rate_limited)
+        {
+            // we're over a limit, so this will be sent in a later update
+            self.throttled_writes.push_back(ThrottledWrite {
+                packet,
+                is_rate_limited,
+            });
+            return Ok(());
+        }
+        self.dispatch(packet)
+    }
+
+    /// Encrypt a packet and send it to the writer task.
+    fn dispatch(&mut self, mut packet: Vec<u8>) -> Result<(), WritePacketError> {
+        if let Some(cipher) = &mut self.enc_cipher {
+            azalea_crypto::encrypt_packet(cipher, &mut packet);
+        }
         self.network_packet_writer_tx
-            .send(network_packet.into_boxed_slice())?;
+            .send(packet.into_boxed_slice())?;
+        self.packets_dispatched += 1;
         Ok(())
     }
 
+    /// Returns whether a packet that counts towards the [`PacketRateLimit`]
+    /// should be dropped instead of sent or buffered.
+    fn should_drop_rate_limited_packet(&mut self) -> bool {
+        let Some(packet_limiter) = &mut self.packet_limiter else {
+            return false;
+        };
+        match packet_limiter.limit.policy {
+            PacketRateLimitPolicy::Drop => !packet_limiter.try_consume(),
+            PacketRateLimitPolicy::Buffer => {
+                let would_be_buffered =
+                    !self.throttled_writes.is_empty() || !packet_limiter.has_capacity();
+                would_be_buffered
+                    && self.throttled_writes.len() >= packet_limiter.limit.max_buffered as usize
+            }
+        }
+    }
+
+    /// The number of packets that were never sent because they went over the
+    /// [`PacketRateLimit`].
+    pub fn packets_dropped(&self) -> u64 {
+        self.flush_state.lock().packets_dropped
+    }
+
+    /// Returns whether a network packet with the given length can be sent
+    /// without going over the [`BandwidthLimit`] or [`PacketRateLimit`], and
+    /// uses up the capacity for it if it can.
+    fn try_consume_limits(&mut self, network_packet_len: usize, is_rate_limited: bool) -> bool {
+        // packets over the limit with the drop policy were already skipped in
+        // write_raw_with_rate_limit
+        let mut packet_limiter = self
+            .packet_limiter
+            .as_mut()
+            .filter(|l| is_rate_limited && l.limit.policy == PacketRateLimitPolicy::Buffer);
+        if let Some(packet_limiter) = &mut packet_limiter
+            && !packet_limiter.has_capacity()
+        {
+            return false;
+        }
+        if let Some(write_limiter) = &mut self.write_limiter
+            && !write_limiter.try_consume(network_packet_len)
+        {
+            return false;
+        }
+        if let Some(packet_limiter) = packet_limiter {
+            packet_limiter.consume();
+        }
+        true
+    }
+
+    /// Send the packets that were delayed by the [`BandwidthLimit`] or
+    /// [`PacketRateLimit`], as long as we're under the limits now.
+    fn flush_throttled_writes(&mut self) {
+        while let Some((packet_len, is_rate_limited)) = self
+            .throttled_writes
+            .front()
+            .map(|w| (w.packet.len(), w.is_rate_limited))
+        {
+            if !self.try_consume_limits(packet_len, is_rate_limited) {
+                break;
+            }
+            if !self.dispatch_next_throttled_write() {
+                // the writer task ended, this is handled in poll_all_writer_tasks
+                break;
+            }
+        }
+    }
+
+    /// Send every throttled packet to the writer task, without checking the
+    /// limits.
+    fn dispatch_all_throttled_writes(&mut self) {
+        while !self.throttled_writes.is_empty() {
+            if !self.dispatch_next_throttled_write() {
+                break;
+            }
+        }
+    }
+
+    /// Send the first throttled packet to the writer task, and return whether
+    /// it was sent successfully.
+    fn dispatch_next_throttled_write(&mut self) -> bool {
+        let Some(throttled_write) = self.throttled_writes.pop_front() else {
+            return false;
+        };
+        self.throttled_writes_popped += 1;
+        let sent = self.dispatch(throttled_write.packet).is_ok();
+
+        while let Some((popped_needed, _)) = self.flushes_waiting_for_throttled_writes.front()
+            && *popped_needed <= self.throttled_writes_popped
+        {
+            let (_, sender) = self
+                .flushes_waiting_for_throttled_writes
+                .pop_front()
+                .unwrap();
+            self.wait_for_dispatched_packets(sender);
+        }
+
+        sent
+    }
+
+    /// Send to the given channel once all of the packets that were written so
+    /// far have been sent over the network.
+    ///
+    /// Packets that were dropped because of the [`PacketRateLimit`] are
+    /// ignored, since they'll never be sent. The sender is dropped if the
+    /// connection closes first.
+    pub fn notify_when_flushed(&mut self, sender: oneshot::Sender<()>) {
+        if self.throttled_writes.is_empty() {
+            self.wait_for_dispatched_packets(sender);
+        } else {
+            let popped_needed = self.throttled_writes_popped + self.throttled_writes.len() as u64;
+            self.flushes_waiting_for_throttled_writes
+                .push_back((popped_needed, sender));
+        }
+    }
+
+    /// Send to the given channel once all of the packets that were sent to the
+    /// writer task so far have been sent over the network.
+    fn wait_for_dispatched_packets(&mut self, sender: oneshot::Sender<()>) {
+        let mut flush_state = self.flush_state.lock();
+        if flush_state.packets_written >= self.packets_dispatched {
+            let _ = sender.send(());
+        } else {
+            flush_state
+                .waiting
+                .push_back((self.packets_dispatched, sender));
+        }
+    }
+
     /// Makes sure packets get sent and returns Some(()) if the connection has
     /// closed.
     pub fn poll_writer(&mut self) -> Option<()> {
-        let poll_once_res = future::poll_once(&mut self.writer_task);
+        let writer_task = self.writer_task.as_mut()?;
+        let poll_once_res = future::poll_once(writer_task);
         future::block_on(poll_once_res)
     }
 
@@ -346,26 +1160,75 @@ impl NetworkConnection {
         trace!("Set compression threshold to {threshold:?}");
         self.reader.compression_threshold = threshold;
     }
+    /// Set the largest size that a compressed packet is allowed to declare for
+    /// its decompressed data.
+    ///
+    /// See [`RawReadConnection::max_decompressed_size`] for more details.
+    pub fn set_max_decompressed_size(&mut self, max_decompressed_size: u32) {
+        self.reader.max_decompressed_size = max_decompressed_size;
+    }
     /// Set the encryption key that is used to encrypt and decrypt packets.
     ///
     /// The same key is used for both reading and writing.
     pub fn set_encryption_key(&mut self, key: [u8; 16]) {
         trace!("Enabled protocol encryption");
+        // packets that were written before encryption was enabled shouldn't be
+        // encrypted
+        self.dispatch_all_throttled_writes();
         let (enc_cipher, dec_cipher) = azalea_crypto::create_cipher(&key);
         self.reader.dec_cipher = Some(dec_cipher);
         self.enc_cipher = Some(enc_cipher);
     }
 }
 
+impl Drop for NetworkConnection {
+    /// Make sure that the packets we queued get sent before the connection is
+    /// closed.
+    ///
+    /// The protocol doesn't have a packet for cleanly leaving the server (the
+    /// vanilla client also just closes the connection), so the best we can do
+    /// is to make sure that nothing we sent gets lost and that the TCP
+    /// connection is shut down gracefully instead of being reset.
+    fn drop(&mut self) {
+        // we're disconnecting anyways, so there's no point in respecting the
+        // rate limits anymore
+        self.dispatch_all_throttled_writes();
+        // the writer task will end after it sends everything that's left in the
+        // channel, since the sender is about to be dropped
+        if let Some(writer_task) = self.writer_task.take() {
+            writer_task.detach();
+        }
+    }
+}
+
 async fn write_task(
     mut network_packet_writer_rx: mpsc::UnboundedReceiver<Box<[u8]>>,
     mut write_half: OwnedWriteHalf,
+    flush_state: Arc<Mutex<FlushState>>,
 ) {
     while let Some(network_packet) = network_packet_writer_rx.recv().await {
         if let Err(e) = write_half.write_all(&network_packet).await {
             debug!("Error writing packet to server: {e}");
             break;
         };
+
+        let mut state = flush_state.lock();
+        state.packets_written += 1;
+        while let Some((packets_needed, _)) = state.waiting.front()
+            && *packets_needed <= state.packets_written
+        {
+            let (_, sender) = state.waiting.pop_front().unwrap();
+            let _ = sender.send(());
+        }
+    }
+    // drop the senders so anything that's still waiting knows that its packets
+    // won't be sent
+    flush_state.lock().waiting.clear();
+
+    // let the server know that we're done writing, instead of waiting for the
+    // socket to be dropped
+    if let Err(e) = write_half.shutdown().await {
+        debug!("Error shutting down connection to server: {e}");
     }
 
     trace!("write task is done");
@@ -386,4 +1249,6 @@ pub enum WritePacketError {
         #[backtrace]
         source: mpsc::error::SendError<Box<[u8]>>,
     },
+    #[error("The connection was closed before the packet could be sent")]
+    ConnectionClosed,
 }
@@ -38,7 +38,15 @@ impl Plugin for CookiesPlugin {
 
 /// A component that holds arbitrary data sent by the server, that our client
 /// temporarily stores and persists across transfers.
-#[derive(Component, Default)]
+///
+/// The cookies are kept when we switch between the configuration and game
+/// states, and when the server transfers us to another server (see
+/// [`PendingTransfer`]). They're removed when we disconnect for any other
+/// reason, so cookies from one server are never sent to a server that we
+/// joined ourselves.
+///
+/// [`PendingTransfer`]: crate::join::PendingTransfer
+#[derive(Clone, Component, Debug, Default)]
 pub struct ServerCookies {
     pub map: HashMap<Identifier, Vec<u8>>,
 }
@@ -96,13 +104,16 @@ pub fn handle_request_cookie(
 }
 pub fn handle_store_cookie(
     store_cookie: On<StoreCookieEvent>,
+    mut commands: Commands,
     mut query: Query<&mut ServerCookies>,
 ) {
+    let key = store_cookie.key.clone();
+    let payload = store_cookie.payload.clone();
     if let Ok(mut server_cookies) = query.get_mut(store_cookie.entity) {
-        server_cookies
-            .map
-            .insert(store_cookie.key.clone(), store_cookie.payload.clone());
+        server_cookies.map.insert(key, payload);
     } else {
-        warn!("got StoreCookieEvent for a client without ServerCookies")
+        commands.entity(store_cookie.entity).insert(ServerCookies {
+            map: HashMap::from([(key, payload)]),
+        });
     }
 }
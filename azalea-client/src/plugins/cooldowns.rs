@@ -0,0 +1,92 @@
+//! Keep track of which items are on cooldown, like ender pearls right after
+//! throwing one.
+
+use std::collections::HashMap;
+
+use azalea_core::tick::GameTick;
+use azalea_inventory::{ItemStack, components::UseCooldown};
+use azalea_registry::{builtin::ItemKind, identifier::Identifier};
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+
+pub struct CooldownsPlugin;
+impl Plugin for CooldownsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(GameTick, tick_item_cooldowns);
+    }
+}
+
+/// The item cooldowns that the server told us about.
+///
+/// Cooldowns apply to a cooldown group instead of to a single item. Items
+/// with the `use_cooldown` component can pick their group, and every other
+/// item is in a group with the same name as the item (like
+/// `minecraft:ender_pearl`).
+#[derive(Clone, Component, Debug, Default)]
+pub struct ItemCooldowns {
+    pub cooldowns: HashMap<Identifier, ItemCooldown>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ItemCooldown {
+    /// The number of ticks until the cooldown is over.
+    pub remaining_ticks: u32,
+    /// The total length of the cooldown in ticks.
+    pub duration: u32,
+}
+
+impl ItemCooldowns {
+    /// Get the cooldown group that the given item is in.
+    pub fn cooldown_group(item: &ItemStack) -> Identifier {
+        item.get_component::<UseCooldown>()
+            .and_then(|use_cooldown| use_cooldown.cooldown_group.clone())
+            .unwrap_or_else(|| Self::default_cooldown_group(item.kind()))
+    }
+    /// Get the cooldown group for an item kind that doesn't have a custom one.
+    pub fn default_cooldown_group(kind: ItemKind) -> Identifier {
+        Identifier::new(kind.to_string())
+    }
+
+    /// Start a cooldown for a group, or remove it if `duration` is 0.
+    pub fn set(&mut self, group: Identifier, duration: u32) {
+        if duration == 0 {
+            self.cooldowns.remove(&group);
+        } else {
+            self.cooldowns.insert(
+                group,
+                ItemCooldown {
+                    remaining_ticks: duration,
+                    duration,
+                },
+            );
+        }
+    }
+
+    /// Get the cooldown for the given item, or `None` if it's not on cooldown.
+    pub fn get(&self, item: &ItemStack) -> Option<ItemCooldown> {
+        if item.is_empty() {
+            return None;
+        }
+        self.cooldowns.get(&Self::cooldown_group(item)).copied()
+    }
+    /// Returns whether the given item can't be used because it's on cooldown.
+    pub fn is_on_cooldown(&self, item: &ItemStack) -> bool {
+        self.get(item).is_some()
+    }
+
+    /// Make every cooldown a tick shorter, and remove the ones that are over.
+    pub fn tick(&mut self) {
+        self.cooldowns.retain(|_, cooldown| {
+            cooldown.remaining_ticks = cooldown.remaining_ticks.saturating_sub(1);
+            cooldown.remaining_ticks > 0
+        });
+    }
+}
+
+pub fn tick_item_cooldowns(mut query: Query<&mut ItemCooldowns>) {
+    for mut item_cooldowns in &mut query {
+        if !item_cooldowns.cooldowns.is_empty() {
+            item_cooldowns.tick();
+        }
+    }
+}
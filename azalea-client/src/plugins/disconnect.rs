@@ -3,7 +3,8 @@
 use azalea_chat::FormattedText;
 use azalea_core::entity_id::MinecraftEntityId;
 use azalea_entity::{
-    EntityBundle, HasClientLoaded, InLoadedChunk, LocalEntity, metadata::PlayerMetadataBundle,
+    EntityBundle, HasClientLoaded, InLoadedChunk, LocalEntity, Vehicle,
+    metadata::PlayerMetadataBundle,
 };
 use bevy_app::{App, Plugin, PostUpdate};
 use bevy_ecs::prelude::*;
@@ -14,7 +15,13 @@ use super::login::IsAuthenticated;
 #[cfg(feature = "online-mode")]
 use crate::chat_signing;
 use crate::{
-    client::JoinedClientBundle, connection::RawConnection, local_player::WorldHolder, mining,
+    advancements::Advancements,
+    client::JoinedClientBundle,
+    connection::RawConnection,
+    local_player::WorldHolder,
+    mining,
+    packet::game::{LastAttacker, PendingRespawn},
+    recipes::Recipes,
     tick_counter::TicksConnected,
 };
 
@@ -40,17 +47,127 @@ impl Plugin for DisconnectPlugin {
 /// An event sent when a client got disconnected from the server.
 ///
 /// If the client was kicked with a reason, that reason will be present in the
-/// [`reason`](DisconnectEvent::reason) field.
+/// [`reason`](DisconnectEvent::reason) field, and a rough classification of it
+/// is in the [`kind`](DisconnectEvent::kind) field.
 ///
 /// This event won't be sent if creating the initial connection to the server
 /// failed, for that see [`ConnectionFailedEvent`].
 ///
 /// [`ConnectionFailedEvent`]: crate::join::ConnectionFailedEvent
 
-#[derive(Message)]
+#[derive(Clone, Debug, Message)]
 pub struct DisconnectEvent {
     pub entity: Entity,
     pub reason: Option<FormattedText>,
+    /// The classified [`reason`](Self::reason).
+    ///
+    /// See [`DisconnectReason`] for more information.
+    pub kind: DisconnectReason,
+}
+
+impl DisconnectEvent {
+    /// Create a new event and classify its reason.
+    pub fn new(entity: Entity, reason: Option<FormattedText>) -> Self {
+        let kind = DisconnectReason::from_reason(reason.as_ref());
+        Self {
+            entity,
+            reason,
+            kind,
+        }
+    }
+}
+
+/// A rough classification of why a client was disconnected, made from the
+/// reason in a [`DisconnectEvent`].
+///
+/// This is done by checking the translation key of the reason, or the English
+/// text of vanilla kick messages for servers (like Spigot and Paper) that send
+/// them as plain text. Custom kick messages that were sent by plugins can't be
+/// classified, so those will be [`DisconnectReason::Other`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum DisconnectReason {
+    /// The connection was closed without the server giving a reason.
+    ConnectionLost,
+    /// The server (or us) stopped responding for too long.
+    Timeout,
+    /// The server is shutting down.
+    ServerShutdown,
+    /// The server is full.
+    ServerFull,
+    /// We were kicked by an operator.
+    Kicked,
+    /// Our account or IP is banned from the server.
+    Banned,
+    /// The server has a whitelist and we aren't on it.
+    NotWhitelisted,
+    /// Someone else logged in with the same account.
+    DuplicateLogin,
+    /// A reason that we couldn't classify.
+    Other(FormattedText),
+}
+impl DisconnectReason {
+    pub fn from_reason(reason: Option<&FormattedText>) -> Self {
+        let Some(reason) = reason else {
+            return Self::ConnectionLost;
+        };
+        let FormattedText::Translatable(translatable) = reason else {
+            return Self::from_message(&reason.to_string())
+                .unwrap_or_else(|| Self::Other(reason.clone()));
+        };
+
+        match translatable.key.as_str() {
+            "disconnect.timeout" => Self::Timeout,
+            "disconnect.lost" | "disconnect.closed" | "disconnect.endOfStream" => {
+                Self::ConnectionLost
+            }
+            "multiplayer.disconnect.server_shutdown" => Self::ServerShutdown,
+            "multiplayer.disconnect.server_full" => Self::ServerFull,
+            "multiplayer.disconnect.kicked" => Self::Kicked,
+            "multiplayer.disconnect.banned"
+            | "multiplayer.disconnect.banned.reason"
+            | "multiplayer.disconnect.banned_ip.reason" => Self::Banned,
+            "multiplayer.disconnect.not_whitelisted" => Self::NotWhitelisted,
+            "multiplayer.disconnect.duplicate_login" => Self::DuplicateLogin,
+            _ => Self::Other(reason.clone()),
+        }
+    }
+
+    /// Classify a plain-text kick message by matching it against the English
+    /// versions of the vanilla messages.
+    fn from_message(message: &str) -> Option<Self> {
+        let message = message.trim();
+        let reason = match message {
+            "Timed out" => Self::Timeout,
+            "Server closed" => Self::ServerShutdown,
+            "The server is full!" => Self::ServerFull,
+            "Kicked by an operator" | "Kicked by an operator." => Self::Kicked,
+            "You are not white-listed on this server!"
+            | "You are not whitelisted on this server!" => Self::NotWhitelisted,
+            "You logged in from another location" => Self::DuplicateLogin,
+            // ban messages usually have the reason and expiry appended
+            _ if message.starts_with("You are banned from this server")
+                || message.starts_with("Your IP address is banned from this server") =>
+            {
+                Self::Banned
+            }
+            _ => return None,
+        };
+        Some(reason)
+    }
+
+    /// Whether it makes sense to try reconnecting to the server after being
+    /// disconnected for this reason.
+    ///
+    /// This is false for bans, whitelists, and duplicate logins (since
+    /// reconnecting would kick the other client), and true for everything
+    /// else.
+    pub fn is_retryable(&self) -> bool {
+        !matches!(
+            self,
+            Self::Banned | Self::NotWhitelisted | Self::DuplicateLogin
+        )
+    }
 }
 
 /// A bundle of components that are removed when a client disconnects.
@@ -79,6 +196,13 @@ pub struct RemoveOnDisconnectBundle {
     pub has_client_loaded: HasClientLoaded,
     // TickCounter is reset on reconnect
     pub ticks_alive: TicksConnected,
+    // we're not riding anything anymore
+    pub vehicle: Vehicle,
+    pub pending_respawn: PendingRespawn,
+    pub last_attacker: LastAttacker,
+    // the server sends these again when we rejoin
+    pub advancements: Advancements,
+    pub recipes: Recipes,
 
     // the rest of the mining components are already removed, as JoinedClientBundle includes
     // MineBundle
@@ -92,7 +216,7 @@ pub fn remove_components_from_disconnected_players(
     mut events: MessageReader<DisconnectEvent>,
     mut loaded_by_query: Query<&mut azalea_entity::LoadedBy>,
 ) {
-    for DisconnectEvent { entity, reason } in events.read() {
+    for DisconnectEvent { entity, reason, .. } in events.read() {
         info!(
             "A client {entity:?} was disconnected{}",
             if let Some(reason) = reason {
@@ -136,10 +260,85 @@ fn disconnect_on_connection_dead(
 ) {
     for (entity, &is_connection_alive) in &query {
         if !*is_connection_alive {
-            disconnect_events.write(DisconnectEvent {
-                entity,
-                reason: None,
-            });
+            disconnect_events.write(DisconnectEvent::new(entity, None));
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use azalea_chat::translatable_component::TranslatableComponent;
+
+    use super::*;
+
+    fn classify_key(key: &str) -> DisconnectReason {
+        let reason: FormattedText = TranslatableComponent::new(key.to_owned(), vec![]).into();
+        DisconnectReason::from_reason(Some(&reason))
+    }
+
+    #[test]
+    fn test_classify_translation_keys() {
+        assert_eq!(
+            classify_key("multiplayer.disconnect.banned"),
+            DisconnectReason::Banned
+        );
+        assert_eq!(
+            classify_key("multiplayer.disconnect.banned_ip.reason"),
+            DisconnectReason::Banned
+        );
+        assert_eq!(
+            classify_key("multiplayer.disconnect.server_full"),
+            DisconnectReason::ServerFull
+        );
+        assert_eq!(
+            classify_key("multiplayer.disconnect.not_whitelisted"),
+            DisconnectReason::NotWhitelisted
+        );
+        assert_eq!(
+            classify_key("multiplayer.disconnect.duplicate_login"),
+            DisconnectReason::DuplicateLogin
+        );
+        assert_eq!(
+            classify_key("disconnect.timeout"),
+            DisconnectReason::Timeout
+        );
+        assert_eq!(
+            DisconnectReason::from_reason(None),
+            DisconnectReason::ConnectionLost
+        );
+    }
+
+    #[test]
+    fn test_classify_unknown_translation_key() {
+        let reason = FormattedText::from(TranslatableComponent::new(
+            "my.plugin.kick".to_owned(),
+            vec![],
+        ));
+        assert_eq!(
+            DisconnectReason::from_reason(Some(&reason)),
+            DisconnectReason::Other(reason)
+        );
+    }
+
+    #[test]
+    fn test_classify_plain_text() {
+        let classify =
+            |message: &str| DisconnectReason::from_reason(Some(&FormattedText::from(message)));
+        assert_eq!(
+            classify("You are not whitelisted on this server!"),
+            DisconnectReason::NotWhitelisted
+        );
+        assert_eq!(
+            classify("The server is full!"),
+            DisconnectReason::ServerFull
+        );
+        assert_eq!(
+            classify("You are banned from this server.\nReason: Banned by an operator."),
+            DisconnectReason::Banned
+        );
+        assert_eq!(
+            classify("Go away"),
+            DisconnectReason::Other("Go away".into())
+        );
+    }
+}
@@ -445,12 +445,13 @@ pub fn can_use_game_master_blocks(
 #[derive(Clone, Debug, EntityEvent)]
 pub struct SwingArmEvent {
     pub entity: Entity,
+    pub hand: InteractionHand,
 }
 pub fn handle_swing_arm_trigger(swing_arm: On<SwingArmEvent>, mut commands: Commands) {
     commands.trigger(SendGamePacketEvent::new(
         swing_arm.entity,
         ServerboundSwing {
-            hand: InteractionHand::MainHand,
+            hand: swing_arm.hand,
         },
     ));
 }
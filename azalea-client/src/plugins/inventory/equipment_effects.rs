@@ -2,7 +2,10 @@
 
 use std::collections::HashMap;
 
-use azalea_core::{data_registry::ResolvableDataRegistry, registry_holder::value::AttributeEffect};
+use azalea_core::{
+    data_registry::ResolvableDataRegistry,
+    registry_holder::{RegistryHolder, value::AttributeEffect},
+};
 use azalea_entity::{Attributes, inventory::Inventory};
 use azalea_inventory::{
     ItemStack,
@@ -111,9 +114,12 @@ pub fn handle_equipment_changes(
         if change.old.is_present() {
             // stopLocationBasedEffects
 
-            for (attribute, modifier) in
-                collect_attribute_modifiers_from_item(slot, &change.old, world_holder)
-            {
+            let modifiers = collect_attribute_modifiers_from_item(
+                slot,
+                &change.old,
+                &world_holder.shared.read().registries,
+            );
+            for (attribute, modifier) in modifiers {
                 if let Some(attribute) = attributes.get_mut(attribute) {
                     attribute.remove(&modifier.id);
                 }
@@ -125,9 +131,12 @@ pub fn handle_equipment_changes(
         if change.new.is_present() {
             // see ItemStack.forEachModifier in vanilla
 
-            for (attribute, modifier) in
-                collect_attribute_modifiers_from_item(slot, &change.new, world_holder)
-            {
+            let modifiers = collect_attribute_modifiers_from_item(
+                slot,
+                &change.new,
+                &world_holder.shared.read().registries,
+            );
+            for (attribute, modifier) in modifiers {
                 if let Some(attribute) = attributes.get_mut(attribute) {
                     attribute.remove(&modifier.id);
                     attribute.insert(modifier);
@@ -141,10 +150,15 @@ pub fn handle_equipment_changes(
     }
 }
 
-fn collect_attribute_modifiers_from_item(
+/// Get the attribute modifiers that the given item applies while it's in the
+/// given equipment slot, including the ones from its enchantments.
+///
+/// The registries are needed to look up the effects of enchantments, since
+/// they're sent by the server.
+pub fn collect_attribute_modifiers_from_item(
     slot: EquipmentSlot,
     item: &ItemStack,
-    world_holder: &WorldHolder,
+    registries: &RegistryHolder,
 ) -> Vec<(azalea_registry::builtin::Attribute, AttributeModifier)> {
     let mut modifiers = Vec::new();
 
@@ -161,9 +175,8 @@ fn collect_attribute_modifiers_from_item(
         .get_component::<components::Enchantments>()
         .unwrap_or_default();
     if !enchants.levels.is_empty() {
-        let registry_holder = &world_holder.shared.read().registries;
         for (enchant, &level) in &enchants.levels {
-            let Some((_enchant_id, enchant_definition)) = enchant.resolve(registry_holder) else {
+            let Some((_enchant_id, enchant_definition)) = enchant.resolve(registries) else {
                 warn!(
                     "Got equipment with an enchantment that wasn't in the registry, so it couldn't be resolved to an ID"
                 );
@@ -1,13 +1,15 @@
 pub mod equipment_effects;
 
 use azalea_chat::FormattedText;
-use azalea_core::tick::GameTick;
+use azalea_core::{direction::Direction, game_type::GameMode, position::BlockPos, tick::GameTick};
 use azalea_entity::{PlayerAbilities, inventory::Inventory as Inv};
 use azalea_inventory::operations::ClickOperation;
 pub use azalea_inventory::*;
 use azalea_protocol::packets::game::{
     s_container_click::{HashedStack, ServerboundContainerClick},
     s_container_close::ServerboundContainerClose,
+    s_interact::InteractionHand,
+    s_player_action::{self, ServerboundPlayerAction},
     s_set_carried_item::ServerboundSetCarriedItem,
 };
 use azalea_registry::builtin::MenuKind;
@@ -18,7 +20,9 @@ use indexmap::IndexMap;
 use tracing::{error, warn};
 
 use crate::{
+    interact::SwingArmEvent,
     inventory::equipment_effects::{collect_equipment_changes, handle_equipment_changes},
+    local_player::LocalGameMode,
     packet::game::SendGamePacketEvent,
 };
 
@@ -48,6 +52,7 @@ impl Plugin for InventoryPlugin {
         // number keys are checked on tick but scrolling can happen outside of ticks, therefore
         // this is fine
         .add_observer(handle_set_selected_hotbar_slot_event)
+        .add_observer(handle_drop_held_item_event)
         .add_observer(handle_equipment_changes);
     }
 }
@@ -73,6 +78,7 @@ fn handle_menu_opened_trigger(event: On<MenuOpenedEvent>, mut query: Query<&mut
     inventory.id = event.window_id;
     inventory.container_menu = Some(Menu::from_kind(event.menu_type));
     inventory.container_menu_title = Some(event.title.clone());
+    inventory.container_data.clear();
 }
 
 /// Tell the server that we want to close a container.
@@ -156,6 +162,7 @@ pub fn handle_client_side_close_container_trigger(
 
     inventory.id = 0;
     inventory.container_menu_title = None;
+    inventory.container_data.clear();
 }
 
 #[derive(Debug, EntityEvent)]
@@ -272,6 +279,61 @@ pub fn handle_set_selected_hotbar_slot_event(
     inventory.selected_hotbar_slot = set_selected_hotbar_slot.slot;
 }
 
+/// How many items should be dropped at once.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DropCount {
+    /// Drop a single item, like pressing the drop key (Q).
+    #[default]
+    One,
+    /// Drop the whole stack, like pressing control and the drop key.
+    Stack,
+}
+
+/// An ECS event to drop the item that we're holding in our main hand.
+///
+/// To drop items from other slots, use a [`ContainerClickEvent`] with a
+/// [`ThrowClick`](azalea_inventory::operations::ThrowClick).
+#[derive(EntityEvent)]
+pub struct DropHeldItemEvent {
+    pub entity: Entity,
+    pub count: DropCount,
+}
+pub fn handle_drop_held_item_event(
+    drop_held_item: On<DropHeldItemEvent>,
+    mut commands: Commands,
+    mut query: Query<(&mut Inv, &LocalGameMode)>,
+) {
+    let Ok((mut inventory, game_mode)) = query.get_mut(drop_held_item.entity) else {
+        return;
+    };
+    if game_mode.current == GameMode::Spectator {
+        return;
+    }
+
+    let full_stack = drop_held_item.count == DropCount::Stack;
+    let dropped = inventory.remove_from_selected(full_stack);
+
+    commands.trigger(SendGamePacketEvent::new(
+        drop_held_item.entity,
+        ServerboundPlayerAction {
+            action: if full_stack {
+                s_player_action::Action::DropAllItems
+            } else {
+                s_player_action::Action::DropItem
+            },
+            pos: BlockPos::ZERO,
+            direction: Direction::Down,
+            seq: 0,
+        },
+    ));
+    if dropped.is_present() {
+        commands.trigger(SwingArmEvent {
+            entity: drop_held_item.entity,
+            hand: InteractionHand::MainHand,
+        });
+    }
+}
+
 /// The item slot that the server thinks we have selected.
 ///
 /// See [`ensure_has_sent_carried_item`].
@@ -1,8 +1,8 @@
-use std::sync::Arc;
+use std::{io, sync::Arc};
 
 use azalea_entity::{LocalEntity, indexing::EntityUuidIndex};
 use azalea_protocol::{
-    address::ResolvedAddr,
+    address::{ResolvedAddr, ServerAddr},
     common::client_information::ClientInformation,
     connect::{Connection, ConnectionError, Proxy},
     packets::{
@@ -23,8 +23,11 @@ use crate::{
     LocalPlayerBundle,
     account::Account,
     connection::RawConnection,
-    local_player::WorldHolder,
+    cookies::ServerCookies,
+    disconnect::DisconnectEvent,
+    local_player::{ClientRng, WorldHolder},
     packet::login::{InLoginState, SendLoginPacketEvent},
+    player::GameProfileComponent,
 };
 
 /// A plugin that allows bots to join servers.
@@ -33,10 +36,12 @@ impl Plugin for JoinPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<StartJoinServerEvent>()
             .add_message::<ConnectionFailedEvent>()
+            .add_observer(handle_transfer_event)
             .add_systems(
                 Update,
                 (
                     handle_start_join_server_event.before(super::login::poll_auth_task),
+                    handle_pending_transfers,
                     poll_create_connection_task,
                 )
                     .chain(),
@@ -75,6 +80,14 @@ pub struct ConnectOpts {
     /// This is useful to set if a server has `prevent-proxy-connections`
     /// enabled.
     pub sessionserver_proxy: Option<Proxy>,
+    /// The seed for the client's [`ClientRng`].
+    ///
+    /// If this is `None`, a random seed will be used.
+    pub rng_seed: Option<u64>,
+    /// The [`ClientInformation`] that the client will start with.
+    ///
+    /// If this is `None`, [`ClientInformation::default`] will be used.
+    pub client_information: Option<ClientInformation>,
 }
 
 /// An event that's sent when creating the TCP connection and sending the first
@@ -133,6 +146,10 @@ pub fn handle_start_join_server_event(
 
         let mut entity_mut = commands.entity(entity);
 
+        // cookies are only kept when the server transfers us, so a different server
+        // can't see them
+        entity_mut.remove::<(ServerCookies, PendingTransfer)>();
+
         entity_mut.insert((
             // add the Account to the entity now so plugins can access it earlier
             event.account.to_owned(),
@@ -140,26 +157,112 @@ pub fn handle_start_join_server_event(
             // in
             LocalEntity,
             // this is inserted early so the user can always access and modify it
-            ClientInformation::default(),
+            event
+                .connect_opts
+                .client_information
+                .clone()
+                .unwrap_or_default(),
             // ConnectOpts is inserted as a component here
             event.connect_opts.clone(),
+            // re-seeded on every join so reconnecting with the same seed is also deterministic
+            match event.connect_opts.rng_seed {
+                Some(seed) => ClientRng::new(seed),
+                None => ClientRng::from_entropy(),
+            },
             // we don't insert InLoginState until we actually create the connection. note that
             // there's no InHandshakeState component since we switch off of the handshake state
             // immediately when the connection is created
         ));
+        if let Some(profile) = event.account.profile() {
+            // this gets replaced by the profile that the server sends us when we finish
+            // logging in
+            entity_mut.insert(GameProfileComponent(profile));
+        }
 
         let task_pool = IoTaskPool::get();
         let connect_opts = event.connect_opts.clone();
         let task = task_pool.spawn(async_compat::Compat::new(
-            create_conn_and_send_intention_packet(connect_opts),
+            create_conn_and_send_intention_packet(connect_opts, ClientIntention::Login),
         ));
 
         entity_mut.insert(CreateConnectionTask(task));
     }
 }
 
+/// Make a client leave its current server and join another one, like when the
+/// server sends a `ClientboundTransfer` packet.
+///
+/// The client's [`ServerCookies`] are kept so the new server can request them.
+#[derive(EntityEvent)]
+pub struct TransferEvent {
+    pub entity: Entity,
+    pub address: ServerAddr,
+}
+
+/// A component that's present on clients that are disconnecting so they can be
+/// transferred to another server.
+///
+/// It's removed once the connection to the new server is created (or fails to
+/// be created).
+#[derive(Clone, Component, Debug)]
+pub struct PendingTransfer {
+    pub address: ServerAddr,
+    /// The cookies that the previous server stored on our client.
+    pub cookies: Option<ServerCookies>,
+}
+
+pub fn handle_transfer_event(
+    transfer: On<TransferEvent>,
+    mut commands: Commands,
+    query: Query<Option<&ServerCookies>>,
+    mut disconnect_events: MessageWriter<DisconnectEvent>,
+) {
+    let Ok(cookies) = query.get(transfer.entity) else {
+        return;
+    };
+    debug!("Transferring {:?} to {}", transfer.entity, transfer.address);
+
+    commands.entity(transfer.entity).insert(PendingTransfer {
+        address: transfer.address.clone(),
+        cookies: cookies.cloned(),
+    });
+    // the new connection is created in handle_pending_transfers after this one is
+    // closed
+    disconnect_events.write(DisconnectEvent::new(transfer.entity, None));
+}
+
+#[allow(clippy::type_complexity)]
+pub fn handle_pending_transfers(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &PendingTransfer, &ConnectOpts),
+        (Without<RawConnection>, Without<CreateConnectionTask>),
+    >,
+) {
+    for (entity, transfer, connect_opts) in &query {
+        let address = transfer.address.clone();
+        let connect_opts = connect_opts.clone();
+
+        let task_pool = IoTaskPool::get();
+        let task = task_pool.spawn(async_compat::Compat::new(async move {
+            let address = ResolvedAddr::new(address).await.map_err(io::Error::other)?;
+            create_conn_and_send_intention_packet(
+                ConnectOpts {
+                    address,
+                    ..connect_opts
+                },
+                ClientIntention::Transfer,
+            )
+            .await
+        }));
+
+        commands.entity(entity).insert(CreateConnectionTask(task));
+    }
+}
+
 async fn create_conn_and_send_intention_packet(
     opts: ConnectOpts,
+    intention: ClientIntention,
 ) -> Result<LoginConn, ConnectionError> {
     let mut conn = if let Some(proxy) = opts.server_proxy {
         Connection::new_with_proxy(&opts.address.socket, proxy).await?
@@ -171,7 +274,7 @@ async fn create_conn_and_send_intention_packet(
         protocol_version: PROTOCOL_VERSION,
         hostname: opts.address.server.host.clone(),
         port: opts.address.server.port,
-        intention: ClientIntention::Login,
+        intention,
     })
     .await?;
 
@@ -185,15 +288,21 @@ type LoginConn = Connection<ClientboundLoginPacket, ServerboundLoginPacket>;
 #[derive(Component)]
 pub struct CreateConnectionTask(pub Task<Result<LoginConn, ConnectionError>>);
 
+#[allow(clippy::type_complexity)]
 pub fn poll_create_connection_task(
     mut commands: Commands,
-    mut query: Query<(Entity, &mut CreateConnectionTask, &Account)>,
+    mut query: Query<(
+        Entity,
+        &mut CreateConnectionTask,
+        &Account,
+        Option<&PendingTransfer>,
+    )>,
     mut connection_failed_events: MessageWriter<ConnectionFailedEvent>,
 ) {
-    for (entity, mut task, account) in query.iter_mut() {
+    for (entity, mut task, account, pending_transfer) in query.iter_mut() {
         if let Some(poll_res) = future::block_on(future::poll_once(&mut task.0)) {
             let mut entity_mut = commands.entity(entity);
-            entity_mut.remove::<CreateConnectionTask>();
+            entity_mut.remove::<(CreateConnectionTask, PendingTransfer)>();
             let conn = match poll_res {
                 Ok(conn) => conn,
                 Err(error) => {
@@ -230,6 +339,9 @@ pub fn poll_create_connection_task(
                 },
                 InLoginState,
             ));
+            if let Some(cookies) = pending_transfer.and_then(|t| t.cookies.clone()) {
+                entity_mut.insert(cookies);
+            }
 
             commands.trigger(SendLoginPacketEvent::new(
                 entity,
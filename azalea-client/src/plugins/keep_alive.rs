@@ -0,0 +1,124 @@
+//! Optionally delay our replies to keepalive packets, so they look less
+//! robotic.
+
+use std::{
+    collections::VecDeque,
+    ops::RangeInclusive,
+    time::{Duration, Instant},
+};
+
+use azalea_core::tick::GameTick;
+use azalea_protocol::packets::game::ServerboundKeepAlive;
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::prelude::*;
+use rand::prelude::*;
+
+use crate::{local_player::ClientRng, packet::game::SendGamePacketEvent};
+
+/// The most that we'll ever delay a keepalive reply by.
+///
+/// Vanilla servers kick clients that take more than 15 seconds to reply, so
+/// this is a third of that. This is a [`Duration`] instead of a number of
+/// ticks since the server can slow down ticking with `/tick rate`.
+pub const MAX_KEEP_ALIVE_DELAY: Duration = Duration::from_secs(5);
+
+/// Sends the keepalive replies that were delayed by a [`KeepAliveDelay`].
+pub struct KeepAlivePlugin;
+impl Plugin for KeepAlivePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            GameTick,
+            (tick_delayed_keep_alives, send_delayed_keep_alives).chain(),
+        )
+        .add_systems(Update, send_delayed_keep_alives);
+    }
+}
+
+/// A component that makes the client wait a random number of ticks before
+/// replying to keepalive packets, instead of replying immediately.
+///
+/// Some anticheats look for clients that always reply to keepalives in exactly
+/// the same amount of time. The delay is picked with the client's
+/// [`ClientRng`], and the reply is always sent within [`MAX_KEEP_ALIVE_DELAY`]
+/// so we don't get kicked for timing out, even if the ticks are slow.
+#[derive(Clone, Component, Debug, PartialEq)]
+pub struct KeepAliveDelay {
+    pub min_ticks: u32,
+    pub max_ticks: u32,
+}
+impl KeepAliveDelay {
+    pub fn new(ticks: RangeInclusive<u32>) -> Self {
+        Self {
+            min_ticks: *ticks.start(),
+            max_ticks: *ticks.end(),
+        }
+    }
+
+    /// Pick how many ticks to wait before replying.
+    pub fn pick_delay(&self, rng: Option<&mut ClientRng>) -> u32 {
+        let max_ticks = self.max_ticks;
+        let min_ticks = self.min_ticks.min(max_ticks);
+        match rng {
+            Some(rng) => rng.random_range(min_ticks..=max_ticks),
+            None => rand::random_range(min_ticks..=max_ticks),
+        }
+    }
+}
+impl Default for KeepAliveDelay {
+    /// Wait between 0 and 10 ticks (half a second).
+    fn default() -> Self {
+        Self::new(0..=10)
+    }
+}
+
+/// The keepalive replies that we're waiting to send because of a
+/// [`KeepAliveDelay`].
+#[derive(Clone, Component, Debug, Default)]
+pub struct PendingKeepAlives(pub VecDeque<PendingKeepAlive>);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PendingKeepAlive {
+    pub id: u64,
+    pub ticks_left: u32,
+    /// The reply is sent at this time even if there are still ticks left.
+    pub deadline: Instant,
+}
+impl PendingKeepAlive {
+    pub fn new(id: u64, ticks_left: u32) -> Self {
+        Self {
+            id,
+            ticks_left,
+            deadline: Instant::now() + MAX_KEEP_ALIVE_DELAY,
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.ticks_left == 0 || Instant::now() >= self.deadline
+    }
+}
+
+pub fn tick_delayed_keep_alives(mut query: Query<&mut PendingKeepAlives>) {
+    for mut pending in &mut query {
+        for keep_alive in &mut pending.0 {
+            keep_alive.ticks_left = keep_alive.ticks_left.saturating_sub(1);
+        }
+    }
+}
+
+pub fn send_delayed_keep_alives(
+    mut query: Query<(Entity, &mut PendingKeepAlives)>,
+    mut commands: Commands,
+) {
+    for (entity, mut pending) in &mut query {
+        // replies are always sent in the same order that the keepalives were received
+        while let Some(keep_alive) = pending.0.front()
+            && keep_alive.is_ready()
+        {
+            commands.trigger(SendGamePacketEvent::new(
+                entity,
+                ServerboundKeepAlive { id: keep_alive.id },
+            ));
+            pending.0.pop_front();
+        }
+    }
+}
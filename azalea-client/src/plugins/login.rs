@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 #[cfg(feature = "online-mode")]
 use azalea_auth::sessionserver::ClientSessionServerError;
 use azalea_protocol::{
@@ -20,15 +22,30 @@ use crate::{account::Account, join::ConnectOpts};
 pub struct LoginPlugin;
 impl Plugin for LoginPlugin {
     fn build(&self, app: &mut App) {
-        app.add_observer(handle_receive_hello_event)
+        app.init_resource::<AuthRetryDelay>()
+            .add_observer(handle_receive_hello_event)
             .add_systems(Update, (poll_auth_task, reply_to_custom_queries));
     }
 }
 
+/// How long to wait before trying to authenticate with Mojang's sessionserver
+/// again after it failed with a transient error, like a 503.
+///
+/// This is one second by default, and it can be changed by overwriting the
+/// resource.
+#[derive(Clone, Copy, Debug, Resource)]
+pub struct AuthRetryDelay(pub Duration);
+impl Default for AuthRetryDelay {
+    fn default() -> Self {
+        Self(Duration::from_secs(1))
+    }
+}
+
 fn handle_receive_hello_event(
     receive_hello: On<ReceiveHelloEvent>,
     mut commands: Commands,
     query: Query<&ConnectOpts>,
+    retry_delay: Res<AuthRetryDelay>,
 ) {
     let task_pool = IoTaskPool::get();
 
@@ -46,7 +63,9 @@ fn handle_receive_hello_event(
         None
     };
 
-    let task = task_pool.spawn(async { auth_with_account(account, packet, connect_opts).await });
+    let retry_delay = retry_delay.0;
+    let task = task_pool
+        .spawn(async move { auth_with_account(account, packet, connect_opts, retry_delay).await });
     commands.entity(client).insert(AuthTask(task));
 }
 
@@ -108,6 +127,7 @@ pub async fn auth_with_account(
     account: Account,
     packet: ClientboundHello,
     proxy: Option<Proxy>,
+    retry_delay: Duration,
 ) -> Result<(ServerboundKey, PrivateKey), AuthWithAccountError> {
     let Ok(encrypt_res) = azalea_crypto::encrypt(&packet.public_key, &packet.challenge) else {
         return Err(AuthWithAccountError::Encryption(packet));
@@ -119,7 +139,7 @@ pub async fn auth_with_account(
     let private_key = encrypt_res.secret_key;
 
     #[cfg(not(feature = "online-mode"))]
-    let _ = (account, proxy);
+    let _ = (account, proxy, retry_delay);
 
     #[cfg(feature = "online-mode")]
     if packet.should_authenticate {
@@ -128,46 +148,80 @@ pub async fn auth_with_account(
             return Ok((key_packet, private_key));
         };
 
-        // keep track of the number of times we tried authenticating so we can give up
-        // after too many
-        let mut attempts: usize = 1;
-
         let proxy = proxy.map(Proxy::into);
+        join_with_retries(
+            &account,
+            &packet.public_key,
+            &private_key,
+            &packet.server_id,
+            proxy,
+            retry_delay,
+        )
+        .await?;
+    }
 
-        while let Err(err) = {
-            let proxy = proxy.clone();
+    Ok((key_packet, private_key))
+}
 
-            // this is necessary since reqwest usually depends on tokio and we're using
-            // `futures` here
-            async_compat::Compat::new(async {
-                account
-                    .join(&packet.public_key, &private_key, &packet.server_id, proxy)
-                    .await
-            })
-            .await
-        } {
-            if attempts >= 2 {
-                // if this is the second attempt and we failed
-                // both times, give up
-                return Err(err.into());
-            }
-            if matches!(
-                err,
-                ClientSessionServerError::InvalidSession
-                    | ClientSessionServerError::ForbiddenOperation
-            ) {
-                // uh oh, we got an invalid session and have
-                // to reauthenticate now
-
-                async_compat::Compat::new(account.refresh()).await?;
-            } else {
-                return Err(err.into());
-            }
-            attempts += 1;
+/// Tell the sessionserver that we're joining a server, trying again if the
+/// first attempt fails in a way that might be fixed by retrying.
+///
+/// If our session expired, the account's access token is refreshed before
+/// trying again. If the error looks transient (like the sessionserver being
+/// down), we wait for `retry_delay` first. Other errors, like being banned,
+/// are returned immediately.
+#[cfg(feature = "online-mode")]
+async fn join_with_retries(
+    account: &Account,
+    public_key: &[u8],
+    private_key: &PrivateKey,
+    server_id: &str,
+    proxy: Option<reqwest::Proxy>,
+    retry_delay: Duration,
+) -> Result<(), AuthWithAccountError> {
+    // keep track of the number of times we tried authenticating so we can give up
+    // after too many
+    let mut attempts: usize = 1;
+
+    while let Err(err) = {
+        let proxy = proxy.clone();
+
+        // this is necessary since reqwest usually depends on tokio and we're using
+        // `futures` here
+        async_compat::Compat::new(async {
+            account
+                .join(public_key, private_key, server_id, proxy)
+                .await
+        })
+        .await
+    } {
+        if attempts >= 2 {
+            // if this is the second attempt and we failed
+            // both times, give up
+            return Err(err.into());
         }
+        if matches!(
+            err,
+            ClientSessionServerError::InvalidSession | ClientSessionServerError::ForbiddenOperation
+        ) {
+            // uh oh, we got an invalid session and have
+            // to reauthenticate now
+
+            async_compat::Compat::new(account.refresh()).await?;
+        } else if err.is_transient() {
+            // the sessionserver is probably having a bad time, so give it a moment before
+            // we try again
+            warn!(
+                "Error authenticating with the sessionserver, retrying in {retry_delay:?}: {err}"
+            );
+            async_compat::Compat::new(tokio::time::sleep(retry_delay)).await;
+        } else {
+            return Err(err.into());
+        }
+        attempts += 1;
     }
 
-    Ok((key_packet, private_key))
+    Ok(())
 }
 
 pub fn reply_to_custom_queries(
@@ -189,3 +243,108 @@ pub fn reply_to_custom_queries(
         ));
     }
 }
+
+#[cfg(all(test, feature = "online-mode"))]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::account::{AccountTrait, BoxFuture};
+
+    /// An account that pretends to talk to a sessionserver that responds with
+    /// the given status codes in order.
+    #[derive(Debug)]
+    struct MockSessionServerAccount {
+        status_codes: Vec<u16>,
+        attempts: Arc<AtomicUsize>,
+    }
+    impl AccountTrait for MockSessionServerAccount {
+        fn username(&self) -> &str {
+            "azalea"
+        }
+        fn uuid(&self) -> Uuid {
+            Uuid::nil()
+        }
+        fn access_token(&self) -> Option<String> {
+            Some("token".to_owned())
+        }
+        fn join<'a>(
+            &'a self,
+            _public_key: &'a [u8],
+            _private_key: &'a [u8; 16],
+            _server_id: &'a str,
+            _proxy: Option<reqwest::Proxy>,
+        ) -> BoxFuture<'a, Result<(), ClientSessionServerError>> {
+            let attempt = self.attempts.fetch_add(1, Ordering::SeqCst);
+            let status_code = self.status_codes[attempt];
+            Box::pin(async move {
+                match status_code {
+                    204 => Ok(()),
+                    403 => Err(ClientSessionServerError::Banned),
+                    status_code => Err(ClientSessionServerError::UnexpectedResponse {
+                        status_code,
+                        body: String::new(),
+                    }),
+                }
+            })
+        }
+    }
+
+    /// Returns the result of joining and the number of requests that were made
+    /// to the sessionserver.
+    fn join_with_mock(status_codes: &[u16]) -> (Result<(), AuthWithAccountError>, usize) {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let account = Account::from(MockSessionServerAccount {
+            status_codes: status_codes.to_vec(),
+            attempts: attempts.clone(),
+        });
+        let res = future::block_on(join_with_retries(
+            &account,
+            &[],
+            &[0; 16],
+            "",
+            None,
+            Duration::from_millis(10),
+        ));
+        (res, attempts.load(Ordering::SeqCst))
+    }
+
+    #[test]
+    fn test_retry_after_service_unavailable() {
+        let (res, attempts) = join_with_mock(&[503, 204]);
+        assert!(res.is_ok());
+        assert_eq!(attempts, 2);
+    }
+
+    #[test]
+    fn test_give_up_after_two_attempts() {
+        let (res, attempts) = join_with_mock(&[503, 503, 204]);
+        assert!(matches!(
+            res,
+            Err(AuthWithAccountError::SessionServer(
+                ClientSessionServerError::UnexpectedResponse {
+                    status_code: 503,
+                    ..
+                }
+            ))
+        ));
+        assert_eq!(attempts, 2);
+    }
+
+    #[test]
+    fn test_banned_fails_fast() {
+        let (res, attempts) = join_with_mock(&[403, 204]);
+        assert!(matches!(
+            res,
+            Err(AuthWithAccountError::SessionServer(
+                ClientSessionServerError::Banned
+            ))
+        ));
+        assert_eq!(attempts, 1);
+    }
+}
@@ -1,12 +1,15 @@
 use azalea_block::{BlockState, BlockTrait, fluid_state::FluidState};
 use azalea_core::{direction::Direction, game_type::GameMode, position::BlockPos, tick::GameTick};
 use azalea_entity::{
-    ActiveEffects, Attributes, FluidOnEyes, Physics, PlayerAbilities, Position,
+    ActiveEffects, Attributes, Dead, FluidOnEyes, Physics, PlayerAbilities, Position,
     inventory::Inventory, mining::get_mine_progress,
 };
 use azalea_inventory::ItemStack;
 use azalea_physics::{PhysicsSystems, collision::BlockWithShape};
-use azalea_protocol::packets::game::s_player_action::{self, ServerboundPlayerAction};
+use azalea_protocol::packets::game::{
+    s_interact::InteractionHand,
+    s_player_action::{self, ServerboundPlayerAction},
+};
 use azalea_registry::builtin::{BlockKind, ItemKind};
 use azalea_world::{WorldName, Worlds};
 use bevy_app::{App, Plugin, Update};
@@ -157,11 +160,15 @@ pub struct StartMiningBlockEvent {
 fn handle_start_mining_block_event(
     mut commands: Commands,
     mut events: MessageReader<StartMiningBlockEvent>,
-    mut query: Query<&HitResultComponent>,
+    mut query: Query<(&HitResultComponent, Has<Dead>)>,
 ) {
     for event in events.read() {
         trace!("{event:?}");
-        let hit_result = query.get_mut(event.entity).unwrap();
+        let (hit_result, dead) = query.get_mut(event.entity).unwrap();
+        if dead {
+            debug!("Ignoring StartMiningBlockEvent since we're dead");
+            continue;
+        }
         if event.force {
             let direction = if let Some(block_hit_result) =
                 hit_result.as_block_hit_result_if_not_miss()
@@ -297,7 +304,10 @@ pub fn handle_mining_queued(
                 position: mining_queued.position,
             });
             **mine_delay = 5;
-            commands.trigger(SwingArmEvent { entity });
+            commands.trigger(SwingArmEvent {
+                entity,
+                hand: InteractionHand::MainHand,
+            });
         } else if mining.is_none()
             || !is_same_mining_target(
                 mining_queued.position,
@@ -382,7 +392,10 @@ pub fn handle_mining_queued(
                     seq: sequence_number.start_predicting(),
                 },
             ));
-            commands.trigger(SwingArmEvent { entity });
+            commands.trigger(SwingArmEvent {
+                entity,
+                hand: InteractionHand::MainHand,
+            });
             // another swing packet gets sent in the same tick in
             // continue_mining_block, vanilla does this too
         }
@@ -579,23 +592,26 @@ pub fn decrement_mine_delay(mut query: Query<&mut MineDelay>) {
 
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn continue_mining_block(
-    mut query: Query<(
-        Entity,
-        &WorldName,
-        &LocalGameMode,
-        &Inventory,
-        &MineBlockPos,
-        &MineItem,
-        &ActiveEffects,
-        &FluidOnEyes,
-        &Physics,
-        &Attributes,
-        &Mining,
-        &mut MineDelay,
-        &mut MineProgress,
-        &mut MineTicks,
-        &mut BlockStatePredictionHandler,
-    )>,
+    mut query: Query<
+        (
+            Entity,
+            &WorldName,
+            &LocalGameMode,
+            &Inventory,
+            &MineBlockPos,
+            &MineItem,
+            &ActiveEffects,
+            &FluidOnEyes,
+            &Physics,
+            &Attributes,
+            &Mining,
+            &mut MineDelay,
+            &mut MineProgress,
+            &mut MineTicks,
+            &mut BlockStatePredictionHandler,
+        ),
+        Without<Dead>,
+    >,
     mut commands: Commands,
     mut mine_block_progress_events: MessageWriter<MineBlockProgressEvent>,
     worlds: Res<Worlds>,
@@ -634,7 +650,10 @@ pub fn continue_mining_block(
                 entity,
                 position: mining.pos,
             });
-            commands.trigger(SwingArmEvent { entity });
+            commands.trigger(SwingArmEvent {
+                entity,
+                hand: InteractionHand::MainHand,
+            });
         } else if mining.force
             || is_same_mining_target(
                 mining.pos,
@@ -697,7 +716,10 @@ pub fn continue_mining_block(
                 position: mining.pos,
                 destroy_stage: mine_progress.destroy_stage(),
             });
-            commands.trigger(SwingArmEvent { entity });
+            commands.trigger(SwingArmEvent {
+                entity,
+                hand: InteractionHand::MainHand,
+            });
         } else {
             trace!("switching mining target to {:?}", mining.pos);
             commands.entity(entity).insert(MiningQueued {
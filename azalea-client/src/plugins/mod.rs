@@ -1,5 +1,6 @@
 use bevy_app::{PluginGroup, PluginGroupBuilder};
 
+pub mod advancements;
 pub mod attack;
 pub mod block_update;
 pub mod brand;
@@ -10,30 +11,54 @@ pub mod chunks;
 pub mod client_information;
 pub mod connection;
 pub mod cookies;
+pub mod cooldowns;
 pub mod disconnect;
 pub mod interact;
 pub mod inventory;
 pub mod join;
+pub mod keep_alive;
 pub mod loading;
 pub mod login;
 pub mod mining;
 pub mod movement;
 pub mod packet;
 pub mod pong;
+pub mod recipes;
 pub mod respawn;
+pub mod stats;
 pub mod task_pool;
 pub mod tick_counter;
 pub mod tick_end;
+pub mod tick_rate;
+pub mod world_border;
 
 /// This plugin group will add all the default plugins necessary for Azalea to
 /// work.
+///
+/// # System ordering
+///
+/// Packets from the server are handled in `PreUpdate`. If your plugin needs to
+/// run at a specific point relative to Azalea's own systems, it can be ordered
+/// against these sets, which always run in this order during every
+/// [`GameTick`]:
+///
+/// 1. [`MiningSystems`]
+/// 2. [`PhysicsSystems`], which also includes updating our controls and pose.
+/// 3. [`SendMovementSystems`], which sends our position to the server.
+/// 4. [`TickEndSystems`], which sends the tick end packet.
+///
+/// [`GameTick`]: azalea_core::tick::GameTick
+/// [`MiningSystems`]: mining::MiningSystems
+/// [`PhysicsSystems`]: azalea_physics::PhysicsSystems
+/// [`SendMovementSystems`]: movement::SendMovementSystems
+/// [`TickEndSystems`]: tick_end::TickEndSystems
 pub struct DefaultPlugins;
 
 impl PluginGroup for DefaultPlugins {
     fn build(self) -> PluginGroupBuilder {
         #[allow(unused_mut)]
         let mut group = PluginGroupBuilder::start::<Self>()
-            .add(crate::client::AmbiguityLoggerPlugin)
+            .add(crate::client::AmbiguityLoggerPlugin::default())
             .add(bevy_time::TimePlugin)
             .add(packet::PacketPlugin)
             .add(crate::client::AzaleaPlugin)
@@ -46,6 +71,7 @@ impl PluginGroup for DefaultPlugins {
             .add(movement::MovementPlugin)
             .add(interact::InteractPlugin)
             .add(respawn::RespawnPlugin)
+            .add(stats::StatsPlugin)
             .add(mining::MiningPlugin)
             .add(attack::AttackPlugin)
             .add(chunks::ChunksPlugin)
@@ -55,11 +81,15 @@ impl PluginGroup for DefaultPlugins {
             .add(brand::BrandPlugin)
             .add(client_information::ClientInformationPlugin)
             .add(tick_counter::TickCounterPlugin)
+            .add(tick_rate::TickRatePlugin)
+            .add(world_border::WorldBorderPlugin)
             .add(pong::PongPlugin)
+            .add(keep_alive::KeepAlivePlugin)
             .add(connection::ConnectionPlugin)
             .add(login::LoginPlugin)
             .add(join::JoinPlugin)
-            .add(cookies::CookiesPlugin);
+            .add(cookies::CookiesPlugin)
+            .add(cooldowns::CooldownsPlugin);
         #[cfg(feature = "online-mode")]
         {
             group = group.add(chat_signing::ChatSigningPlugin);
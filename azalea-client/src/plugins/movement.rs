@@ -1,3 +1,8 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
 use azalea_core::{
     entity_id::MinecraftEntityId,
     game_type::GameMode,
@@ -5,8 +10,8 @@ use azalea_core::{
     tick::GameTick,
 };
 use azalea_entity::{
-    Attributes, Crouching, HasClientLoaded, Jumping, LastSentPosition, LocalEntity, LookDirection,
-    Physics, PlayerAbilities, Pose, Position,
+    Attributes, Crouching, Dead, HasClientLoaded, Jumping, LastSentPosition, LocalEntity,
+    LookDirection, Physics, PlayerAbilities, Pose, Position,
     dimensions::calculate_dimensions,
     metadata::{self, Sprinting},
     update_bounding_box,
@@ -62,21 +67,43 @@ impl Plugin for MovementPlugin {
                         .in_set(PhysicsSystems)
                         .before(ai_step)
                         .before(azalea_physics::fluids::update_in_water_state_and_do_fluid_pushing),
-                    send_player_input_packet,
-                    send_sprinting_if_needed
-                        .after(azalea_entity::update_in_loaded_chunk)
-                        .after(travel),
-                    send_position.after(PhysicsSystems),
+                    (
+                        send_player_input_packet,
+                        send_sprinting_if_needed
+                            .after(azalea_entity::update_in_loaded_chunk)
+                            .after(travel),
+                        send_position.after(PhysicsSystems),
+                    )
+                        .chain()
+                        .in_set(SendMovementSystems),
                 )
                     .chain(),
             )
-            .add_observer(handle_knockback);
+            .add_observer(handle_knockback)
+            .add_observer(handle_send_position_event);
     }
 }
 
+/// The [`SystemSet`] for the [`Update`] systems that handle
+/// [`StartWalkEvent`] and [`StartSprintEvent`].
+///
+/// If you're sending these events from a system, run it
+/// `.before(MoveEventsSystems)` so they're handled in the same update.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, SystemSet)]
 pub struct MoveEventsSystems;
 
+/// The [`SystemSet`] for the [`GameTick`] systems that send our inputs,
+/// sprinting state, and position to the server.
+///
+/// These always run after [`PhysicsSystems`], so systems that are ordered
+/// `.after(SendMovementSystems)` will see the same position that was sent to
+/// the server this tick. Everything in this set runs before
+/// [`TickEndSystems`].
+///
+/// [`TickEndSystems`]: crate::tick_end::TickEndSystems
+#[derive(Clone, Debug, Eq, Hash, PartialEq, SystemSet)]
+pub struct SendMovementSystems;
+
 /// A component that contains the look direction that was last sent over the
 /// network.
 #[derive(Clone, Component, Debug, Default)]
@@ -97,7 +124,7 @@ pub fn send_position(
             &mut Physics,
             &mut LastSentLookDirection,
         ),
-        With<HasClientLoaded>,
+        (With<HasClientLoaded>, Without<Dead>),
     >,
     mut commands: Commands,
 ) {
@@ -193,10 +220,67 @@ pub fn send_position(
     }
 }
 
+/// An event that makes the client immediately send its current position and
+/// look direction to the server in a [`ServerboundMovePlayerPosRot`], instead
+/// of waiting for [`send_position`] to decide whether it's necessary.
+///
+/// This also updates [`LastSentPosition`] and [`LastSentLookDirection`], so
+/// the position won't be sent again next tick unless it changes.
+#[derive(EntityEvent)]
+pub struct SendPositionEvent {
+    pub entity: Entity,
+}
+pub fn handle_send_position_event(
+    send_position: On<SendPositionEvent>,
+    mut query: Query<
+        (
+            &Position,
+            &LookDirection,
+            &mut PhysicsState,
+            &mut LastSentPosition,
+            &mut Physics,
+            &mut LastSentLookDirection,
+        ),
+        Without<Dead>,
+    >,
+    mut commands: Commands,
+) {
+    let entity = send_position.entity;
+    let Ok((
+        position,
+        direction,
+        mut physics_state,
+        mut last_sent_position,
+        mut physics,
+        mut last_direction,
+    )) = query.get_mut(entity)
+    else {
+        return;
+    };
+
+    let packet = ServerboundMovePlayerPosRot {
+        pos: **position,
+        look_direction: *direction,
+        flags: MoveFlags {
+            on_ground: physics.on_ground(),
+            horizontal_collision: physics.horizontal_collision,
+        },
+    };
+
+    **last_sent_position = **position;
+    physics_state.position_remainder = 0;
+    last_direction.y_rot = direction.y_rot();
+    last_direction.x_rot = direction.x_rot();
+    let on_ground = physics.on_ground();
+    physics.set_last_on_ground(on_ground);
+
+    commands.trigger(SendGamePacketEvent::new(entity, packet));
+}
+
 #[derive(Clone, Component, Debug, Default, Eq, PartialEq)]
 pub struct LastSentInput(pub ServerboundPlayerInput);
 pub fn send_player_input_packet(
-    mut query: Query<(Entity, &PhysicsState, &Jumping, Option<&LastSentInput>)>,
+    mut query: Query<(Entity, &PhysicsState, &Jumping, Option<&LastSentInput>), Without<Dead>>,
     mut commands: Commands,
 ) {
     for (entity, physics_state, jumping, last_sent_input) in query.iter_mut() {
@@ -227,7 +311,7 @@ pub fn send_player_input_packet(
 }
 
 pub fn send_sprinting_if_needed(
-    mut query: Query<(Entity, &MinecraftEntityId, &Sprinting, &mut PhysicsState)>,
+    mut query: Query<(Entity, &MinecraftEntityId, &Sprinting, &mut PhysicsState), Without<Dead>>,
     mut commands: Commands,
 ) {
     for (entity, minecraft_entity_id, sprinting, mut physics_state) in query.iter_mut() {
@@ -580,6 +664,47 @@ pub fn handle_knockback(knockback: On<KnockbackEvent>, mut query: Query<&mut Phy
     }
 }
 
+/// A component that decides whether we accept the teleports that the server
+/// sends us.
+///
+/// If this component isn't present then every teleport is accepted, which is
+/// what vanilla does. When the function returns false, the teleport is ignored
+/// and our current position is sent to the server instead of accepting it.
+///
+/// Note that vanilla servers will ignore our movement until we accept their
+/// teleport, so rejecting teleports is mostly useful for servers that send
+/// them as a test. The first teleport after joining or respawning is always
+/// accepted, since we don't know where we are before then.
+#[derive(Clone, Component)]
+pub struct TeleportFilter(pub Arc<dyn Fn(&ProposedTeleport) -> bool + Send + Sync>);
+impl TeleportFilter {
+    pub fn new(f: impl Fn(&ProposedTeleport) -> bool + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    pub fn accepts(&self, teleport: &ProposedTeleport) -> bool {
+        (self.0)(teleport)
+    }
+}
+impl Debug for TeleportFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TeleportFilter").finish_non_exhaustive()
+    }
+}
+
+/// A teleport from the server that's passed to a [`TeleportFilter`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProposedTeleport {
+    /// The ID of the teleport, which is sent back to the server if we accept
+    /// it.
+    pub id: u32,
+    pub current_position: Vec3,
+    /// Where we'll be after the teleport, with relative movements already
+    /// applied.
+    pub new_position: Vec3,
+    pub new_look_direction: LookDirection,
+}
+
 pub fn update_pose(
     mut query: Query<(
         Entity,
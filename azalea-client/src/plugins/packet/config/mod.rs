@@ -4,6 +4,7 @@ use std::io::Cursor;
 
 use azalea_entity::LocalEntity;
 use azalea_protocol::{
+    address::ServerAddr,
     packets::{ConnectionProtocol, config::*},
     read::{ReadPacketError, deserialize_packet},
 };
@@ -15,8 +16,9 @@ use super::{as_system, declare_packet_handlers};
 use crate::{
     client::InConfigState,
     connection::RawConnection,
-    cookies::{RequestCookieEvent, StoreCookieEvent},
+    cookies::{RequestCookieEvent, ServerCookies, StoreCookieEvent},
     disconnect::DisconnectEvent,
+    join::TransferEvent,
     local_player::WorldHolder,
     packet::game::{KeepAliveEvent, ResourcePackEvent},
 };
@@ -87,39 +89,41 @@ impl ConfigPacketHandler<'_> {
     pub fn disconnect(&mut self, p: &ClientboundDisconnect) {
         warn!("Got disconnect packet {p:?}");
         as_system::<MessageWriter<_>>(self.ecs, |mut events| {
-            events.write(DisconnectEvent {
-                entity: self.player,
-                reason: Some(p.reason.clone()),
-            });
+            events.write(DisconnectEvent::new(self.player, Some(p.reason.clone())));
         });
     }
 
     pub fn finish_configuration(&mut self, _p: &ClientboundFinishConfiguration) {
         debug!("got FinishConfiguration packet");
 
-        as_system::<(Commands, Query<&mut RawConnection>)>(
-            self.ecs,
-            |(mut commands, mut query)| {
-                let mut raw_conn = query.get_mut(self.player).unwrap();
-                raw_conn.state = ConnectionProtocol::Game;
+        as_system::<(
+            Commands,
+            Query<(&mut RawConnection, Option<&ServerCookies>)>,
+        )>(self.ecs, |(mut commands, mut query)| {
+            let (mut raw_conn, cookies) = query.get_mut(self.player).unwrap();
+            raw_conn.state = ConnectionProtocol::Game;
 
-                commands.trigger(SendConfigPacketEvent::new(
-                    self.player,
-                    ServerboundFinishConfiguration,
-                ));
+            commands.trigger(SendConfigPacketEvent::new(
+                self.player,
+                ServerboundFinishConfiguration,
+            ));
 
-                // these components are added now that we're going to be in the Game state
-                commands
-                    .entity(self.player)
-                    .remove::<InConfigState>()
-                    .insert((
-                        crate::JoinedClientBundle::default(),
-                        // localentity should already be added, but in case the user forgot or
-                        // something we also add it here
-                        LocalEntity,
-                    ));
-            },
-        );
+            // these components are added now that we're going to be in the Game state
+            commands
+                .entity(self.player)
+                .remove::<InConfigState>()
+                .insert((
+                    crate::JoinedClientBundle::default(),
+                    // localentity should already be added, but in case the user forgot or
+                    // something we also add it here
+                    LocalEntity,
+                ));
+            // the bundle would've replaced the cookies that were stored while we were
+            // configuring
+            if let Some(cookies) = cookies {
+                commands.entity(self.player).insert(cookies.clone());
+            }
+        });
     }
 
     pub fn keep_alive(&mut self, p: &ClientboundKeepAlive) {
@@ -204,6 +208,20 @@ impl ConfigPacketHandler<'_> {
 
     pub fn transfer(&mut self, p: &ClientboundTransfer) {
         debug!("Got transfer packet {p:?}");
+
+        let Ok(port) = u16::try_from(p.port) else {
+            warn!("Got transfer packet with invalid port {}", p.port);
+            return;
+        };
+        as_system::<Commands>(self.ecs, |mut commands| {
+            commands.trigger(TransferEvent {
+                entity: self.player,
+                address: ServerAddr {
+                    host: p.host.clone(),
+                    port,
+                },
+            });
+        });
     }
 
     pub fn select_known_packs(&mut self, p: &ClientboundSelectKnownPacks) {
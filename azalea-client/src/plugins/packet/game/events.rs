@@ -1,12 +1,19 @@
 use std::sync::{Arc, Weak};
 
 use azalea_chat::FormattedText;
+use azalea_core::{entity_id::MinecraftEntityId, position::Vec3};
+use azalea_entity::LookDirection;
 use azalea_protocol::packets::{
     Packet,
-    game::{ClientboundGamePacket, ClientboundPlayerCombatKill, ServerboundGamePacket},
+    game::{
+        ClientboundGamePacket, ClientboundPlayerCombatKill, ServerboundGamePacket,
+        s_interact::InteractionHand,
+    },
 };
+use azalea_registry::{builtin::EntityKind, identifier::Identifier};
 use azalea_world::{World, WorldName};
 use bevy_ecs::prelude::*;
+use derive_more::Deref;
 use parking_lot::RwLock;
 use tracing::{error, trace};
 use uuid::Uuid;
@@ -68,7 +75,7 @@ pub fn handle_outgoing_packets_observer(
         }
 
         trace!("Sending game packet: {:?}", event.packet);
-        if let Err(e) = raw_connection.write(event.packet.clone()) {
+        if let Err(e) = raw_connection.write_game_packet(event.packet.clone()) {
             error!("Failed to send packet: {e}");
         }
     } else {
@@ -101,6 +108,73 @@ pub struct UpdatePlayerEvent {
     pub info: PlayerInfo,
 }
 
+/// A new entity was added to a local player's world.
+///
+/// This is only sent once per entity for each local player, even if the
+/// server sends the entity again. If the server sends the entity's metadata in
+/// the same bundle as the entity, it'll have already been applied by the time
+/// this event is read.
+#[derive(Clone, Debug, Message)]
+pub struct EntitySpawnedEvent {
+    /// The local player entity that received this event.
+    pub entity: Entity,
+    /// The ECS entity that was spawned.
+    pub spawned: Entity,
+    pub kind: EntityKind,
+    /// The ID that the server uses for the entity.
+    pub minecraft_id: MinecraftEntityId,
+}
+
+/// An entity was removed from a local player's world, usually because it went
+/// out of render distance or died.
+///
+/// By the time you receive this event, the entity will have already been
+/// removed from the local player's [`EntityIdIndex`], and the ECS entity may
+/// have been despawned if no other clients in the swarm have it loaded.
+///
+/// [`EntityIdIndex`]: azalea_entity::indexing::EntityIdIndex
+#[derive(Clone, Debug, Message)]
+pub struct EntityRemovedEvent {
+    /// The local player entity that received this event.
+    pub entity: Entity,
+    /// The ECS entity that was removed.
+    pub removed: Entity,
+    /// The ID that the server used for the entity that was removed.
+    pub minecraft_id: MinecraftEntityId,
+}
+
+/// An entity in a local player's world swung its arm, usually because it
+/// attacked something or broke a block.
+#[derive(Clone, Debug, Message)]
+pub struct EntitySwingEvent {
+    /// The local player entity that received this event.
+    pub entity: Entity,
+    /// The ECS entity that swung its arm.
+    pub swung: Entity,
+    pub hand: InteractionHand,
+}
+
+/// An explosion happened in a local player's world.
+///
+/// Since Minecraft 1.21.2, the packet for explosions doesn't include the
+/// positions of the blocks that were destroyed. The server sends those as
+/// normal block updates instead, which usually arrive right after this event.
+///
+/// If the explosion knocked us back, the knockback is applied to our velocity
+/// before this event is sent.
+#[derive(Clone, Debug, Message)]
+pub struct ExplosionEvent {
+    pub entity: Entity,
+    /// The position where the explosion happened.
+    pub center: Vec3,
+    pub radius: f32,
+    /// The number of blocks that the explosion destroyed.
+    pub block_count: u32,
+    /// The velocity that was added to our player because of the explosion, or
+    /// `None` if we weren't knocked back.
+    pub player_knockback: Option<Vec3>,
+}
+
 /// Event for when an entity dies.
 ///
 /// If it's a local player and there's a reason in the death screen, the
@@ -109,8 +183,21 @@ pub struct UpdatePlayerEvent {
 pub struct DeathEvent {
     pub entity: Entity,
     pub packet: Option<ClientboundPlayerCombatKill>,
+    /// The entity that caused the last damage we took before dying, if any.
+    ///
+    /// The server doesn't tell us who killed us, so this comes from the
+    /// [`LastAttacker`] component.
+    pub killer: Option<Entity>,
 }
 
+/// A component for local players that has the entity that most recently
+/// damaged us.
+///
+/// This is set from the damage events that the server sends, and it's removed
+/// when we respawn.
+#[derive(Clone, Component, Copy, Debug, Deref, PartialEq)]
+pub struct LastAttacker(pub Entity);
+
 /// A KeepAlive packet is sent from the server to verify that the client is
 /// still connected.
 #[derive(Clone, Debug, Message)]
@@ -137,6 +224,76 @@ pub struct ResourcePackEvent {
     pub prompt: Option<FormattedText>,
 }
 
+/// Sent the first time that the server tells a local player where it is after
+/// logging in or respawning.
+///
+/// The player's position is [`Vec3::ZERO`] until this happens, so this is the
+/// earliest point where [`Position`] can be trusted.
+///
+/// [`Position`]: azalea_entity::Position
+#[derive(Clone, Debug, Message)]
+pub struct InitialPositionEvent {
+    pub entity: Entity,
+    /// The position that the server put us at.
+    pub position: Vec3,
+}
+
+/// The server changed the direction that a local player is looking in, with a
+/// [`ClientboundPlayerRotation`] packet.
+///
+/// The new direction will have already been applied to the player's
+/// [`LookDirection`] by the time this event is read.
+///
+/// [`ClientboundPlayerRotation`]: azalea_protocol::packets::game::ClientboundPlayerRotation
+#[derive(Clone, Debug, Message)]
+pub struct RotatedEvent {
+    pub entity: Entity,
+    pub look_direction: LookDirection,
+}
+
+/// A local player respawned after dying.
+///
+/// This is sent once the server tells us where we respawned, so
+/// [`Position`] can be trusted. It isn't sent when we change dimensions
+/// without dying.
+///
+/// [`Position`]: azalea_entity::Position
+#[derive(Clone, Debug, Message)]
+pub struct RespawnEvent {
+    pub entity: Entity,
+    /// The dimension that we respawned in, like `minecraft:overworld`.
+    pub dimension: Identifier,
+    /// Where the server put us after respawning.
+    pub position: Vec3,
+    /// Whether we probably respawned at our own respawn point (like a bed or a
+    /// respawn anchor) instead of at the world spawn.
+    ///
+    /// The server doesn't tell us this directly, so it's guessed by checking
+    /// whether we respawned away from the world spawn. If the server told us
+    /// that our respawn point was missing or obstructed, this is always false.
+    pub is_probably_bed_spawn: bool,
+}
+
+/// A component for local players that respawned after dying but that haven't
+/// been told where they are yet.
+///
+/// It's removed when [`RespawnEvent`] is sent.
+#[derive(Clone, Component, Debug)]
+pub struct PendingRespawn {
+    pub dimension: Identifier,
+    /// Whether the server sent a `NoRespawnBlockAvailable` game event, which
+    /// means that our bed or respawn anchor was missing and we were sent to
+    /// the world spawn instead.
+    pub missing_respawn_block: bool,
+}
+
+/// A marker component for local players that haven't received their position
+/// from the server since logging in or respawning.
+///
+/// It's removed when [`InitialPositionEvent`] is sent.
+#[derive(Clone, Component, Debug)]
+pub struct WaitingForInitialPosition;
+
 /// A world instance (aka dimension) was loaded by a client.
 ///
 /// Since the world is given to you as a weak reference, it won't be able to be
@@ -8,37 +8,54 @@ use azalea_core::{
     position::{ChunkPos, Vec3},
 };
 use azalea_entity::{
-    ActiveEffects, Dead, EntityBundle, EntityKindComponent, HasClientLoaded, LoadedBy, LocalEntity,
-    LookDirection, Physics, PlayerAbilities, Position, RelativeEntityUpdate,
+    ActiveEffects, Attributes, Dead, EntityBundle, EntityKindComponent, HasClientLoaded, LoadedBy,
+    LocalEntity, LookDirection, Physics, PlayerAbilities, Position, RelativeEntityUpdate, Vehicle,
     indexing::{EntityIdIndex, EntityUuidIndex},
     inventory::Inventory,
     metadata::{Health, apply_metadata},
 };
 use azalea_protocol::{
+    address::ServerAddr,
     common::movements::MoveFlags,
-    packets::{ConnectionProtocol, game::*},
+    packets::{
+        ConnectionProtocol,
+        common::CommonPlayerSpawnInfo,
+        game::{c_animate::AnimationAction, s_interact::InteractionHand, *},
+    },
 };
 use azalea_registry::builtin::EntityKind;
-use azalea_world::{PartialWorld, WorldName, Worlds};
+use azalea_world::{BlockEntity, PartialWorld, WorldName, Worlds};
 use bevy_ecs::{prelude::*, system::SystemState};
 pub use events::*;
 use tracing::{debug, error, trace, warn};
 
 use crate::{
     ClientInformation,
+    advancements::Advancements,
+    attack::AttackQueued,
     block_update::QueuedServerBlockUpdates,
     chat::{ChatPacket, ChatReceivedEvent},
-    chunks,
+    chunks::{self, nbt_to_compound},
     connection::RawConnection,
-    cookies::{RequestCookieEvent, StoreCookieEvent},
+    cookies::{RequestCookieEvent, ServerCookies, StoreCookieEvent},
+    cooldowns::ItemCooldowns,
     disconnect::DisconnectEvent,
     interact::BlockStatePredictionHandler,
     inventory::{ClientsideCloseContainerEvent, MenuOpenedEvent, SetContainerContentEvent},
-    local_player::{Experience, Hunger, LocalGameMode, TabList, WorldHolder},
-    movement::{KnockbackData, KnockbackEvent},
+    join::TransferEvent,
+    keep_alive::{KeepAliveDelay, PendingKeepAlive, PendingKeepAlives},
+    local_player::{
+        ClientRng, Experience, Hunger, LastDeathLocation, LocalDimensionKind, LocalGameMode,
+        TabList, TabListHeaderFooter, WorldHolder, WorldSpawn,
+    },
+    movement::{KnockbackData, KnockbackEvent, ProposedTeleport, TeleportFilter},
     packet::{as_system, declare_packet_handlers},
     player::{GameProfileComponent, PlayerInfo},
+    recipes::Recipes,
+    stats::{Statistics, WaitingForStatistics},
     tick_counter::TicksConnected,
+    tick_rate::WorldTickRate,
+    world_border::WorldBorder,
 };
 
 pub fn process_packet(ecs: &mut World, player: Entity, packet: &ClientboundGamePacket) {
@@ -302,9 +319,12 @@ impl GamePacketHandler<'_> {
                         current: p.common.game_type,
                         previous: p.common.previous_game_type.into(),
                     },
+                    LocalDimensionKind(p.common.dimension_type),
                     entity_bundle,
                     TicksConnected(0),
+                    WaitingForInitialPosition,
                 ));
+                set_last_death_location(&mut commands, self.player, &p.common);
 
                 azalea_entity::indexing::add_entity_to_indexes(
                     entity_id,
@@ -393,10 +413,7 @@ impl GamePacketHandler<'_> {
         warn!("Got disconnect packet {p:?}");
 
         as_system::<MessageWriter<_>>(self.ecs, |mut events| {
-            events.write(DisconnectEvent {
-                entity: self.player,
-                reason: Some(p.reason.clone()),
-            });
+            events.write(DisconnectEvent::new(self.player, Some(p.reason.clone())));
         });
     }
 
@@ -412,18 +429,69 @@ impl GamePacketHandler<'_> {
         debug!("Got player position packet {p:?}");
 
         as_system::<(
-            Query<(&mut Physics, &mut LookDirection, &mut Position)>,
+            Query<(
+                &mut Physics,
+                &mut LookDirection,
+                &mut Position,
+                Has<WaitingForInitialPosition>,
+                Option<&TeleportFilter>,
+            )>,
             Commands,
-        )>(self.ecs, |(mut query, mut commands)| {
-            let Ok((mut physics, mut direction, mut position)) = query.get_mut(self.player) else {
+            MessageWriter<InitialPositionEvent>,
+        )>(self.ecs, |(mut query, mut commands, mut events)| {
+            let Ok((mut physics, mut direction, mut position, waiting_for_position, filter)) =
+                query.get_mut(self.player)
+            else {
                 return;
             };
 
+            if let Some(filter) = filter
+                && !waiting_for_position
+            {
+                let mut new_position = *position;
+                let mut new_direction = *direction;
+                let mut new_physics = physics.clone();
+                p.relative.apply(
+                    &p.change,
+                    &mut new_position,
+                    &mut new_direction,
+                    &mut new_physics,
+                );
+                let teleport = ProposedTeleport {
+                    id: p.id,
+                    current_position: **position,
+                    new_position: *new_position,
+                    new_look_direction: new_direction,
+                };
+                if !filter.accepts(&teleport) {
+                    debug!("Rejected teleport {teleport:?}");
+                    commands.trigger(SendGamePacketEvent::new(
+                        self.player,
+                        ServerboundMovePlayerPosRot {
+                            pos: **position,
+                            look_direction: *direction,
+                            flags: MoveFlags::default(),
+                        },
+                    ));
+                    return;
+                }
+            }
+
             p.relative
                 .apply(&p.change, &mut position, &mut direction, &mut physics);
             // old_pos is set to the current position when we're teleported
             physics.set_old_pos(*position);
 
+            if waiting_for_position {
+                commands
+                    .entity(self.player)
+                    .remove::<WaitingForInitialPosition>();
+                events.write(InitialPositionEvent {
+                    entity: self.player,
+                    position: **position,
+                });
+            }
+
             // send the relevant packets
             commands.trigger(SendGamePacketEvent::new(
                 self.player,
@@ -440,6 +508,46 @@ impl GamePacketHandler<'_> {
         });
     }
 
+    pub fn player_rotation(&mut self, p: &ClientboundPlayerRotation) {
+        debug!("Got player rotation packet {p:?}");
+
+        as_system::<(
+            Query<&mut LookDirection>,
+            Commands,
+            MessageWriter<RotatedEvent>,
+        )>(self.ecs, |(mut query, mut commands, mut events)| {
+            let Ok(mut direction) = query.get_mut(self.player) else {
+                return;
+            };
+
+            let y_rot = if p.relative_y {
+                direction.y_rot() + p.y_rot
+            } else {
+                p.y_rot
+            };
+            let x_rot = if p.relative_x {
+                direction.x_rot() + p.x_rot
+            } else {
+                p.x_rot
+            };
+            *direction = LookDirection::new(y_rot, x_rot);
+
+            events.write(RotatedEvent {
+                entity: self.player,
+                look_direction: *direction,
+            });
+
+            // let the server know that we've been rotated
+            commands.trigger(SendGamePacketEvent::new(
+                self.player,
+                ServerboundMovePlayerRot {
+                    look_direction: *direction,
+                    flags: MoveFlags::default(),
+                },
+            ));
+        });
+    }
+
     pub fn player_info_update(&mut self, p: &ClientboundPlayerInfoUpdate) {
         debug!("Got player info packet {p:?}");
 
@@ -467,6 +575,8 @@ impl GamePacketHandler<'_> {
                             gamemode: updated_info.game_mode,
                             latency: updated_info.latency,
                             display_name: updated_info.display_name.clone(),
+                            listed: updated_info.listed,
+                            list_order: updated_info.list_order,
                         };
                         tab_list.insert(updated_info.profile.uuid, info.clone());
                         add_player_events.write(AddPlayerEvent {
@@ -485,6 +595,12 @@ impl GamePacketHandler<'_> {
                         if p.actions.update_display_name {
                             info.display_name.clone_from(&updated_info.display_name);
                         }
+                        if p.actions.update_listed {
+                            info.listed = updated_info.listed;
+                        }
+                        if p.actions.update_list_order {
+                            info.list_order = updated_info.list_order;
+                        }
                         update_player_events.write(UpdatePlayerEvent {
                             entity: self.player,
                             info: info.clone(),
@@ -565,6 +681,7 @@ impl GamePacketHandler<'_> {
             Query<Entity>,
             Res<Worlds>,
             ResMut<EntityUuidIndex>,
+            MessageWriter<EntitySpawnedEvent>,
         )>(
             self.ecs,
             |(
@@ -574,6 +691,7 @@ impl GamePacketHandler<'_> {
                 entity_query,
                 worlds,
                 mut entity_uuid_index,
+                mut entity_spawned_events,
             )| {
                 let (mut entity_id_index, world_name, tab_list) =
                     query.get_mut(self.player).unwrap();
@@ -608,6 +726,16 @@ impl GamePacketHandler<'_> {
                     };
                     loaded_by.insert(self.player);
 
+                    // only send the event if this client didn't already know about the entity
+                    if entity_id_index.get_by_minecraft_entity(entity_id).is_none() {
+                        entity_spawned_events.write(EntitySpawnedEvent {
+                            entity: self.player,
+                            spawned: ecs_entity,
+                            kind: p.entity_type,
+                            minecraft_id: entity_id,
+                        });
+                    }
+
                     // per-client id index
                     entity_id_index.insert(entity_id, ecs_entity);
 
@@ -648,6 +776,13 @@ impl GamePacketHandler<'_> {
                 // the bundle doesn't include the default entity metadata so we add that
                 // separately
                 p.apply_metadata(&mut spawned);
+
+                entity_spawned_events.write(EntitySpawnedEvent {
+                    entity: self.player,
+                    spawned: ecs_entity,
+                    kind: p.entity_type,
+                    minecraft_id: entity_id,
+                });
             },
         );
     }
@@ -708,8 +843,45 @@ impl GamePacketHandler<'_> {
         });
     }
 
-    pub fn update_attributes(&mut self, _p: &ClientboundUpdateAttributes) {
+    pub fn update_attributes(&mut self, p: &ClientboundUpdateAttributes) {
         // debug!("Got update attributes packet {p:?}");
+
+        as_system::<(
+            Query<&EntityIdIndex>,
+            Query<(&mut Attributes, Option<&ActiveEffects>, Has<LocalEntity>)>,
+        )>(self.ecs, |(query, mut attributes_query)| {
+            let entity_id_index = query.get(self.player).unwrap();
+
+            let Some(entity) = entity_id_index.get_by_minecraft_entity(p.entity_id) else {
+                debug!(
+                    "Got update attributes packet for unknown entity id {}",
+                    p.entity_id
+                );
+                return;
+            };
+            let Ok((mut attributes, active_effects, is_local)) = attributes_query.get_mut(entity)
+            else {
+                return;
+            };
+
+            for snapshot in &p.values {
+                // attributes that we don't use are ignored
+                let Some(instance) = attributes.get_mut(snapshot.attribute) else {
+                    continue;
+                };
+                instance.base = snapshot.base;
+                instance.clear_modifiers();
+                for modifier in &snapshot.modifiers {
+                    instance.insert(modifier.clone());
+                }
+            }
+
+            // the server doesn't always include the modifiers for our effects, and
+            // update_effect_attribute_modifiers only runs when the effects change
+            if is_local && let Some(active_effects) = active_effects {
+                azalea_physics::apply_effect_attribute_modifiers(active_effects, &mut attributes);
+            }
+        });
     }
 
     pub fn set_entity_motion(&mut self, p: &ClientboundSetEntityMotion) {
@@ -748,12 +920,107 @@ impl GamePacketHandler<'_> {
         );
     }
 
+    pub fn set_passengers(&mut self, p: &ClientboundSetPassengers) {
+        debug!("Got set passengers packet {p:?}");
+
+        as_system::<(Commands, Query<&EntityIdIndex>, Query<(Entity, &Vehicle)>)>(
+            self.ecs,
+            |(mut commands, query, vehicle_query)| {
+                let entity_id_index = query.get(self.player).unwrap();
+
+                let Some(vehicle) = entity_id_index.get_by_minecraft_entity(p.vehicle) else {
+                    debug!(
+                        "Got set passengers packet for unknown vehicle entity id {}",
+                        p.vehicle
+                    );
+                    return;
+                };
+                let passengers = p
+                    .passengers
+                    .iter()
+                    .filter_map(|&id| entity_id_index.get_by_minecraft_entity(id))
+                    .collect::<Vec<_>>();
+
+                // the packet contains every passenger, so anything that isn't in it anymore
+                // must've dismounted
+                for (entity, current_vehicle) in &vehicle_query {
+                    if **current_vehicle == vehicle && !passengers.contains(&entity) {
+                        commands.entity(entity).remove::<Vehicle>();
+                    }
+                }
+                for passenger in passengers {
+                    commands.entity(passenger).insert(Vehicle(vehicle));
+                }
+            },
+        );
+    }
+
     pub fn set_entity_link(&mut self, p: &ClientboundSetEntityLink) {
         debug!("Got set entity link packet {p:?}");
     }
 
     pub fn initialize_border(&mut self, p: &ClientboundInitializeBorder) {
         debug!("Got initialize border packet {p:?}");
+
+        let mut world_border = WorldBorder {
+            center_x: p.new_center_x,
+            center_z: p.new_center_z,
+            absolute_max_size: p.new_absolute_max_size,
+            warning_blocks: p.warning_blocks,
+            warning_time: p.warning_time,
+            ..Default::default()
+        };
+        world_border.lerp_size_between(p.old_size, p.new_size, p.lerp_time);
+
+        as_system::<Commands>(self.ecs, |mut commands| {
+            commands.entity(self.player).insert(world_border);
+        });
+    }
+
+    pub fn set_border_center(&mut self, p: &ClientboundSetBorderCenter) {
+        debug!("Got set border center packet {p:?}");
+
+        as_system::<Query<&mut WorldBorder>>(self.ecs, |mut query| {
+            let mut world_border = query.get_mut(self.player).unwrap();
+            world_border.center_x = p.new_center_x;
+            world_border.center_z = p.new_center_z;
+        });
+    }
+
+    pub fn set_border_lerp_size(&mut self, p: &ClientboundSetBorderLerpSize) {
+        debug!("Got set border lerp size packet {p:?}");
+
+        as_system::<Query<&mut WorldBorder>>(self.ecs, |mut query| {
+            let mut world_border = query.get_mut(self.player).unwrap();
+            world_border.lerp_size_between(p.old_size, p.new_size, p.lerp_time);
+        });
+    }
+
+    pub fn set_border_size(&mut self, p: &ClientboundSetBorderSize) {
+        debug!("Got set border size packet {p:?}");
+
+        as_system::<Query<&mut WorldBorder>>(self.ecs, |mut query| {
+            let mut world_border = query.get_mut(self.player).unwrap();
+            world_border.set_size(p.size);
+        });
+    }
+
+    pub fn set_border_warning_delay(&mut self, p: &ClientboundSetBorderWarningDelay) {
+        debug!("Got set border warning delay packet {p:?}");
+
+        as_system::<Query<&mut WorldBorder>>(self.ecs, |mut query| {
+            let mut world_border = query.get_mut(self.player).unwrap();
+            world_border.warning_time = p.warning_delay;
+        });
+    }
+
+    pub fn set_border_warning_distance(&mut self, p: &ClientboundSetBorderWarningDistance) {
+        debug!("Got set border warning distance packet {p:?}");
+
+        as_system::<Query<&mut WorldBorder>>(self.ecs, |mut query| {
+            let mut world_border = query.get_mut(self.player).unwrap();
+            world_border.warning_blocks = p.warning_blocks;
+        });
     }
 
     pub fn set_time(&mut self, _p: &ClientboundSetTime) {
@@ -762,6 +1029,14 @@ impl GamePacketHandler<'_> {
 
     pub fn set_default_spawn_position(&mut self, p: &ClientboundSetDefaultSpawnPosition) {
         debug!("Got set default spawn position packet {p:?}");
+
+        as_system::<Commands>(self.ecs, |mut commands| {
+            commands.entity(self.player).insert(WorldSpawn {
+                global_pos: p.global_pos.clone(),
+                yaw: p.yaw,
+                pitch: p.pitch,
+            });
+        });
     }
 
     pub fn set_health(&mut self, p: &ClientboundSetHealth) {
@@ -770,12 +1045,14 @@ impl GamePacketHandler<'_> {
         as_system::<Query<(&mut Health, &mut Hunger)>>(self.ecs, |mut query| {
             let (mut health, mut hunger) = query.get_mut(self.player).unwrap();
 
-            **health = p.health;
+            // only mark the health as changed if it actually changed, so we don't send
+            // multiple death events when the server updates our food while we're dead
+            health.set_if_neq(Health(p.health));
             (hunger.food, hunger.saturation) = (p.food, p.saturation);
 
-            // the `Dead` component is added by the `update_dead` system
-            // in azalea-world and then the `dead_event` system fires
-            // the Death event.
+            // the `Dead` component is added by the `update_dead` system in
+            // azalea-entity, and `death_event_on_0_health` sends the
+            // `DeathEvent`.
         });
     }
 
@@ -833,6 +1110,22 @@ impl GamePacketHandler<'_> {
 
     pub fn update_advancements(&mut self, p: &ClientboundUpdateAdvancements) {
         debug!("Got update advancements packet {p:?}");
+
+        as_system::<(Commands, Query<Option<&mut Advancements>>)>(
+            self.ecs,
+            |(mut commands, mut query)| {
+                let Ok(advancements) = query.get_mut(self.player) else {
+                    return;
+                };
+                if let Some(mut advancements) = advancements {
+                    advancements.update(p);
+                } else {
+                    let mut advancements = Advancements::default();
+                    advancements.update(p);
+                    commands.entity(self.player).insert(advancements);
+                }
+            },
+        );
     }
 
     pub fn rotate_head(&mut self, _p: &ClientboundRotateHead) {}
@@ -856,9 +1149,19 @@ impl GamePacketHandler<'_> {
                 commands.entity(entity).queue(RelativeEntityUpdate::new(
                     world_holder.partial.clone(),
                     move |entity_mut| {
+                        let is_local_entity = entity_mut.contains::<LocalEntity>();
                         let mut physics = entity_mut.get_mut::<Physics>().unwrap();
                         let new_pos = physics.vec_delta_codec.decode(&new_delta);
                         physics.vec_delta_codec.set_base(new_pos);
+
+                        if is_local_entity {
+                            // our own position and on_ground are decided by our physics, and
+                            // the server would see an incorrect on_ground if we let it be
+                            // overwritten
+                            debug!("Ignoring move entity pos packet for local player");
+                            return;
+                        }
+
                         physics.set_on_ground(new_on_ground);
 
                         let mut position = entity_mut.get_mut::<Position>().unwrap();
@@ -906,9 +1209,16 @@ impl GamePacketHandler<'_> {
                 commands.entity(entity).queue(RelativeEntityUpdate::new(
                     world_holder.partial.clone(),
                     move |entity_mut| {
+                        let is_local_entity = entity_mut.contains::<LocalEntity>();
                         let mut physics = entity_mut.get_mut::<Physics>().unwrap();
                         let new_position = physics.vec_delta_codec.decode(&new_delta);
                         physics.vec_delta_codec.set_base(new_position);
+
+                        if is_local_entity {
+                            debug!("Ignoring move entity pos rot packet for local player");
+                            return;
+                        }
+
                         physics.set_on_ground(new_on_ground);
 
                         let mut position = entity_mut.get_mut::<Position>().unwrap();
@@ -943,6 +1253,11 @@ impl GamePacketHandler<'_> {
                     commands.entity(entity).queue(RelativeEntityUpdate::new(
                         world_holder.partial.clone(),
                         move |entity_mut| {
+                            if entity_mut.contains::<LocalEntity>() {
+                                debug!("Ignoring move entity rot packet for local player");
+                                return;
+                            }
+
                             let mut physics = entity_mut.get_mut::<Physics>().unwrap();
                             physics.set_on_ground(new_on_ground);
 
@@ -964,13 +1279,41 @@ impl GamePacketHandler<'_> {
     pub fn keep_alive(&mut self, p: &ClientboundKeepAlive) {
         debug!("Got keep alive packet {p:?} for {:?}", self.player);
 
-        as_system::<(MessageWriter<KeepAliveEvent>, Commands)>(
+        as_system::<(
+            Query<(
+                Option<&KeepAliveDelay>,
+                Option<&mut ClientRng>,
+                Option<&mut PendingKeepAlives>,
+            )>,
+            MessageWriter<KeepAliveEvent>,
+            Commands,
+        )>(
             self.ecs,
-            |(mut keepalive_events, mut commands)| {
+            |(mut query, mut keepalive_events, mut commands)| {
                 keepalive_events.write(KeepAliveEvent {
                     entity: self.player,
                     id: p.id,
                 });
+
+                if let Ok((Some(delay), rng, pending)) = query.get_mut(self.player) {
+                    let ticks_left = delay.pick_delay(rng.map(Mut::into_inner));
+                    let has_pending = pending
+                        .as_ref()
+                        .is_some_and(|pending| !pending.0.is_empty());
+                    if ticks_left > 0 || has_pending {
+                        let keep_alive = PendingKeepAlive::new(p.id, ticks_left);
+                        match pending {
+                            Some(mut pending) => pending.0.push_back(keep_alive),
+                            None => {
+                                commands
+                                    .entity(self.player)
+                                    .insert(PendingKeepAlives(vec![keep_alive].into()));
+                            }
+                        }
+                        return;
+                    }
+                }
+
                 commands.trigger(SendGamePacketEvent::new(
                     self.player,
                     ServerboundKeepAlive { id: p.id },
@@ -982,10 +1325,15 @@ impl GamePacketHandler<'_> {
     pub fn remove_entities(&mut self, p: &ClientboundRemoveEntities) {
         debug!("Got remove entities packet {p:?}");
 
-        as_system::<(Query<&mut EntityIdIndex>, Query<&mut LoadedBy>)>(
+        as_system::<(
+            Query<(&mut EntityIdIndex, Option<&AttackQueued>)>,
+            Query<&mut LoadedBy>,
+            MessageWriter<EntityRemovedEvent>,
+            Commands,
+        )>(
             self.ecs,
-            |(mut query, mut entity_query)| {
-                let Ok(mut entity_id_index) = query.get_mut(self.player) else {
+            |(mut query, mut entity_query, mut entity_removed_events, mut commands)| {
+                let Ok((mut entity_id_index, attack_queued)) = query.get_mut(self.player) else {
                     warn!("our local player doesn't have EntityIdIndex");
                     return;
                 };
@@ -997,6 +1345,17 @@ impl GamePacketHandler<'_> {
                         );
                         continue;
                     };
+
+                    entity_removed_events.write(EntityRemovedEvent {
+                        entity: self.player,
+                        removed: entity,
+                        minecraft_id: id,
+                    });
+                    // don't try to attack an entity that we can't see anymore
+                    if attack_queued.is_some_and(|a| a.target == entity) {
+                        commands.entity(self.player).remove::<AttackQueued>();
+                    }
+
                     let Ok(mut loaded_by) = entity_query.get_mut(entity) else {
                         warn!(
                             "tried to despawn entity {id} but it doesn't have a LoadedBy component",
@@ -1065,6 +1424,30 @@ impl GamePacketHandler<'_> {
 
     pub fn animate(&mut self, p: &ClientboundAnimate) {
         debug!("Got animate packet {p:?}");
+
+        let hand = match p.action {
+            AnimationAction::SwingMainHand => InteractionHand::MainHand,
+            AnimationAction::SwingOffHand => InteractionHand::OffHand,
+            _ => return,
+        };
+
+        as_system::<(Query<&EntityIdIndex>, MessageWriter<EntitySwingEvent>)>(
+            self.ecs,
+            |(query, mut events)| {
+                let Ok(entity_id_index) = query.get(self.player) else {
+                    return;
+                };
+                let Some(swung) = entity_id_index.get_by_minecraft_entity(p.id) else {
+                    debug!("Got animate packet for unknown entity id {}", p.id);
+                    return;
+                };
+                events.write(EntitySwingEvent {
+                    entity: self.player,
+                    swung,
+                    hand,
+                });
+            },
+        );
     }
 
     pub fn section_blocks_update(&mut self, p: &ClientboundSectionBlocksUpdate) {
@@ -1084,7 +1467,6 @@ impl GamePacketHandler<'_> {
 
         debug!("Got game event packet {p:?}");
 
-        #[allow(clippy::single_match)]
         match p.event {
             EventType::ChangeGameMode => {
                 as_system::<Query<&mut LocalGameMode>>(self.ecs, |mut query| {
@@ -1094,6 +1476,13 @@ impl GamePacketHandler<'_> {
                     }
                 });
             }
+            EventType::NoRespawnBlockAvailable => {
+                as_system::<Query<&mut PendingRespawn>>(self.ecs, |mut query| {
+                    if let Ok(mut pending_respawn) = query.get_mut(self.player) {
+                        pending_respawn.missing_respawn_block = true;
+                    }
+                });
+            }
             _ => {}
         }
     }
@@ -1147,7 +1536,28 @@ impl GamePacketHandler<'_> {
         );
     }
 
-    pub fn award_stats(&mut self, _p: &ClientboundAwardStats) {}
+    pub fn award_stats(&mut self, p: &ClientboundAwardStats) {
+        debug!("Got award stats packet {p:?}");
+
+        as_system::<(Commands, Query<Option<&mut Statistics>>)>(
+            self.ecs,
+            |(mut commands, mut query)| {
+                let Ok(statistics) = query.get_mut(self.player) else {
+                    return;
+                };
+                if let Some(mut statistics) = statistics {
+                    statistics.extend(&p.stats);
+                } else {
+                    commands
+                        .entity(self.player)
+                        .insert(Statistics(p.stats.clone()));
+                }
+                commands
+                    .entity(self.player)
+                    .remove::<WaitingForStatistics>();
+            },
+        );
+    }
 
     pub fn block_changed_ack(&mut self, p: &ClientboundBlockChangedAck) {
         as_system::<Query<(&WorldHolder, &mut BlockStatePredictionHandler)>>(
@@ -1162,7 +1572,20 @@ impl GamePacketHandler<'_> {
 
     pub fn block_destruction(&mut self, _p: &ClientboundBlockDestruction) {}
 
-    pub fn block_entity_data(&mut self, _p: &ClientboundBlockEntityData) {}
+    pub fn block_entity_data(&mut self, p: &ClientboundBlockEntityData) {
+        debug!("Got block entity data packet {p:?}");
+
+        as_system::<Query<&mut QueuedServerBlockUpdates>>(self.ecs, |mut query| {
+            let mut queued = query.get_mut(self.player).unwrap();
+            queued.block_entities.push((
+                p.pos,
+                BlockEntity {
+                    kind: p.block_entity_type,
+                    data: nbt_to_compound(&p.tag),
+                },
+            ));
+        });
+    }
 
     pub fn block_event(&mut self, p: &ClientboundBlockEvent) {
         debug!("Got block event packet {p:?}");
@@ -1199,14 +1622,20 @@ impl GamePacketHandler<'_> {
     pub fn container_set_data(&mut self, p: &ClientboundContainerSetData) {
         debug!("Got container set data packet {p:?}");
 
-        // TODO: handle ContainerSetData packet
-        // this is used for various things like the furnace progress
-        // bar
+        // this is used for various things like the furnace progress bar
         // see https://minecraft.wiki/w/Java_Edition_protocol/Packets#Set_Container_Property
 
-        // as_system::<Query<&mut Inventory>>(self.ecs, |mut query| {
-        //     let inventory = query.get_mut(self.player).unwrap();
-        // });
+        as_system::<Query<&mut Inventory>>(self.ecs, |mut query| {
+            let mut inventory = query.get_mut(self.player).unwrap();
+            if p.container_id != inventory.id {
+                debug!(
+                    "Ignoring container data for container {}, since the current container is {}",
+                    p.container_id, inventory.id
+                );
+                return;
+            }
+            inventory.container_data.insert(p.id, p.value);
+        });
     }
 
     pub fn container_set_slot(&mut self, p: &ClientboundContainerSetSlot) {
@@ -1258,7 +1687,16 @@ impl GamePacketHandler<'_> {
         });
     }
 
-    pub fn cooldown(&mut self, _p: &ClientboundCooldown) {}
+    pub fn cooldown(&mut self, p: &ClientboundCooldown) {
+        debug!("Got cooldown packet {p:?}");
+
+        as_system::<Query<&mut ItemCooldowns>>(self.ecs, |mut query| {
+            let Ok(mut item_cooldowns) = query.get_mut(self.player) else {
+                return;
+            };
+            item_cooldowns.set(p.cooldown_group.clone(), p.duration);
+        });
+    }
 
     pub fn custom_chat_completions(&mut self, _p: &ClientboundCustomChatCompletions) {}
 
@@ -1267,14 +1705,25 @@ impl GamePacketHandler<'_> {
     pub fn explode(&mut self, p: &ClientboundExplode) {
         debug!("Got explode packet {p:?}");
 
-        as_system::<Commands>(self.ecs, |mut knockback_events| {
-            if let Some(knockback) = p.player_knockback {
-                knockback_events.trigger(KnockbackEvent {
+        as_system::<(Commands, MessageWriter<ExplosionEvent>)>(
+            self.ecs,
+            |(mut commands, mut explosion_events)| {
+                if let Some(knockback) = p.player_knockback {
+                    commands.trigger(KnockbackEvent {
+                        entity: self.player,
+                        data: KnockbackData::Add(knockback),
+                    });
+                }
+                // the blocks that were destroyed are sent to us separately as block updates
+                explosion_events.write(ExplosionEvent {
                     entity: self.player,
-                    data: KnockbackData::Add(knockback),
+                    center: p.center,
+                    radius: p.radius,
+                    block_count: p.block_count.max(0) as u32,
+                    player_knockback: p.player_knockback,
                 });
-            }
-        });
+            },
+        );
     }
 
     pub fn forget_level_chunk(&mut self, p: &ClientboundForgetLevelChunk) {
@@ -1336,16 +1785,17 @@ impl GamePacketHandler<'_> {
 
         as_system::<(
             Commands,
-            Query<(&MinecraftEntityId, Option<&Dead>)>,
+            Query<(&MinecraftEntityId, Option<&Dead>, Option<&LastAttacker>)>,
             MessageWriter<_>,
         )>(self.ecs, |(mut commands, mut query, mut events)| {
-            let (entity_id, dead) = query.get_mut(self.player).unwrap();
+            let (entity_id, dead, last_attacker) = query.get_mut(self.player).unwrap();
 
             if *entity_id == p.player_id && dead.is_none() {
                 commands.entity(self.player).insert(Dead);
                 events.write(DeathEvent {
                     entity: self.player,
                     packet: Some(p.clone()),
+                    killer: last_attacker.map(|a| **a),
                 });
             }
         });
@@ -1412,6 +1862,7 @@ impl GamePacketHandler<'_> {
                     &GameProfileComponent,
                     &ClientInformation,
                     Option<&mut WorldName>,
+                    Has<Dead>,
                 ),
                 With<LocalEntity>,
             >,
@@ -1421,7 +1872,7 @@ impl GamePacketHandler<'_> {
         )>(
             self.ecs,
             |(mut commands, mut query, mut events, mut worlds, mut loaded_by_query)| {
-                let Ok((mut world_holder, game_profile, client_information, world_name)) =
+                let Ok((mut world_holder, game_profile, client_information, world_name, is_dead)) =
                     query.get_mut(self.player)
                 else {
                     warn!("Got respawn packet but player doesn't have the required components");
@@ -1488,12 +1939,23 @@ impl GamePacketHandler<'_> {
                         current: p.common.game_type,
                         previous: p.common.previous_game_type.into(),
                     },
+                    LocalDimensionKind(p.common.dimension_type),
                     entity_bundle,
+                    WaitingForInitialPosition,
                 ));
 
                 commands
                     .entity(self.player)
-                    .remove::<(Dead, HasClientLoaded)>();
+                    .remove::<(Dead, HasClientLoaded, Vehicle, LastAttacker)>();
+
+                set_last_death_location(&mut commands, self.player, &p.common);
+                if is_dead {
+                    // this is for sending RespawnEvent after we get our new position
+                    commands.entity(self.player).insert(PendingRespawn {
+                        dimension: p.common.dimension.clone(),
+                        missing_respawn_block: false,
+                    });
+                }
             },
         )
     }
@@ -1501,29 +1963,34 @@ impl GamePacketHandler<'_> {
     pub fn start_configuration(&mut self, _p: &ClientboundStartConfiguration) {
         debug!("Got start configuration packet");
 
-        as_system::<(Commands, Query<(&mut RawConnection, &mut WorldHolder)>)>(
-            self.ecs,
-            |(mut commands, mut query)| {
-                let Some((mut raw_conn, mut world_holder)) = query.get_mut(self.player).ok() else {
-                    warn!("Got start configuration packet but player doesn't have a RawConnection");
-                    return;
-                };
-                raw_conn.state = ConnectionProtocol::Configuration;
+        as_system::<(
+            Commands,
+            Query<(&mut RawConnection, &mut WorldHolder, Option<&ServerCookies>)>,
+        )>(self.ecs, |(mut commands, mut query)| {
+            let Some((mut raw_conn, mut world_holder, cookies)) = query.get_mut(self.player).ok()
+            else {
+                warn!("Got start configuration packet but player doesn't have a RawConnection");
+                return;
+            };
+            raw_conn.state = ConnectionProtocol::Configuration;
 
-                commands.trigger(SendGamePacketEvent::new(
-                    self.player,
-                    ServerboundConfigurationAcknowledged,
-                ));
+            commands.trigger(SendGamePacketEvent::new(
+                self.player,
+                ServerboundConfigurationAcknowledged,
+            ));
 
-                commands
-                    .entity(self.player)
-                    .insert(crate::client::InConfigState)
-                    .remove::<crate::JoinedClientBundle>()
-                    .remove::<EntityBundle>();
+            commands
+                .entity(self.player)
+                .insert(crate::client::InConfigState)
+                .remove::<crate::JoinedClientBundle>()
+                .remove::<EntityBundle>();
+            // the cookies are part of JoinedClientBundle, but they should be kept
+            if let Some(cookies) = cookies {
+                commands.entity(self.player).insert(cookies.clone());
+            }
 
-                world_holder.reset();
-            },
-        );
+            world_holder.reset();
+        });
     }
 
     pub fn entity_position_sync(&mut self, p: &ClientboundEntityPositionSync) {
@@ -1569,15 +2036,9 @@ impl GamePacketHandler<'_> {
 
     pub fn select_advancements_tab(&mut self, _p: &ClientboundSelectAdvancementsTab) {}
     pub fn set_action_bar_text(&mut self, _p: &ClientboundSetActionBarText) {}
-    pub fn set_border_center(&mut self, _p: &ClientboundSetBorderCenter) {}
-    pub fn set_border_lerp_size(&mut self, _p: &ClientboundSetBorderLerpSize) {}
-    pub fn set_border_size(&mut self, _p: &ClientboundSetBorderSize) {}
-    pub fn set_border_warning_delay(&mut self, _p: &ClientboundSetBorderWarningDelay) {}
-    pub fn set_border_warning_distance(&mut self, _p: &ClientboundSetBorderWarningDistance) {}
     pub fn set_camera(&mut self, _p: &ClientboundSetCamera) {}
     pub fn set_display_objective(&mut self, _p: &ClientboundSetDisplayObjective) {}
     pub fn set_objective(&mut self, _p: &ClientboundSetObjective) {}
-    pub fn set_passengers(&mut self, _p: &ClientboundSetPassengers) {}
     pub fn set_player_team(&mut self, p: &ClientboundSetPlayerTeam) {
         debug!("Got set player team packet {p:?}");
     }
@@ -1589,14 +2050,58 @@ impl GamePacketHandler<'_> {
     pub fn clear_titles(&mut self, _p: &ClientboundClearTitles) {}
     pub fn sound_entity(&mut self, _p: &ClientboundSoundEntity) {}
     pub fn stop_sound(&mut self, _p: &ClientboundStopSound) {}
-    pub fn tab_list(&mut self, _p: &ClientboundTabList) {}
+    pub fn tab_list(&mut self, p: &ClientboundTabList) {
+        debug!("Got tab list packet {p:?}");
+
+        as_system::<Query<&mut TabListHeaderFooter>>(self.ecs, |mut query| {
+            let Ok(mut header_footer) = query.get_mut(self.player) else {
+                return;
+            };
+            header_footer.header = p.header.clone();
+            header_footer.footer = p.footer.clone();
+        });
+    }
     pub fn tag_query(&mut self, _p: &ClientboundTagQuery) {}
     pub fn take_item_entity(&mut self, _p: &ClientboundTakeItemEntity) {}
     pub fn bundle_delimiter(&mut self, _p: &ClientboundBundleDelimiter) {}
-    pub fn damage_event(&mut self, _p: &ClientboundDamageEvent) {}
+    pub fn damage_event(&mut self, p: &ClientboundDamageEvent) {
+        debug!("Got damage event packet {p:?}");
+
+        as_system::<(Commands, Query<(&MinecraftEntityId, &EntityIdIndex)>)>(
+            self.ecs,
+            |(mut commands, query)| {
+                let (entity_id, entity_id_index) = query.get(self.player).unwrap();
+                if *entity_id != p.entity_id {
+                    return;
+                }
+
+                let Some(source_id) = p.source_cause_id.0 else {
+                    return;
+                };
+                let source_id = MinecraftEntityId(source_id as i32);
+                if let Some(source) = entity_id_index.get_by_minecraft_entity(source_id) {
+                    commands.entity(self.player).insert(LastAttacker(source));
+                }
+            },
+        );
+    }
     pub fn hurt_animation(&mut self, _p: &ClientboundHurtAnimation) {}
-    pub fn ticking_state(&mut self, _p: &ClientboundTickingState) {}
-    pub fn ticking_step(&mut self, _p: &ClientboundTickingStep) {}
+    pub fn ticking_state(&mut self, p: &ClientboundTickingState) {
+        debug!("Got ticking state packet {p:?}");
+
+        let mut world_tick_rate = self.ecs.get_resource_or_init::<WorldTickRate>();
+        world_tick_rate.tick_rate = p.tick_rate;
+        world_tick_rate.is_frozen = p.is_frozen;
+        if !p.is_frozen {
+            world_tick_rate.frozen_ticks_to_run = 0;
+        }
+    }
+    pub fn ticking_step(&mut self, p: &ClientboundTickingStep) {
+        debug!("Got ticking step packet {p:?}");
+
+        let mut world_tick_rate = self.ecs.get_resource_or_init::<WorldTickRate>();
+        world_tick_rate.frozen_ticks_to_run = p.tick_steps;
+    }
     pub fn reset_score(&mut self, _p: &ClientboundResetScore) {}
     pub fn cookie_request(&mut self, p: &ClientboundCookieRequest) {
         debug!("Got cookie request packet {p:?}");
@@ -1619,7 +2124,23 @@ impl GamePacketHandler<'_> {
     }
     pub fn debug_sample(&mut self, _p: &ClientboundDebugSample) {}
     pub fn pong_response(&mut self, _p: &ClientboundPongResponse) {}
-    pub fn transfer(&mut self, _p: &ClientboundTransfer) {}
+    pub fn transfer(&mut self, p: &ClientboundTransfer) {
+        debug!("Got transfer packet {p:?}");
+
+        let Ok(port) = u16::try_from(p.port) else {
+            warn!("Got transfer packet with invalid port {}", p.port);
+            return;
+        };
+        as_system::<Commands>(self.ecs, |mut commands| {
+            commands.trigger(TransferEvent {
+                entity: self.player,
+                address: ServerAddr {
+                    host: p.host.clone(),
+                    port,
+                },
+            });
+        });
+    }
     pub fn move_minecart_along_track(&mut self, _p: &ClientboundMoveMinecartAlongTrack) {}
     pub fn set_held_slot(&mut self, p: &ClientboundSetHeldSlot) {
         debug!("Got set held slot packet {p:?}");
@@ -1635,9 +2156,41 @@ impl GamePacketHandler<'_> {
     pub fn projectile_power(&mut self, _p: &ClientboundProjectilePower) {}
     pub fn custom_report_details(&mut self, _p: &ClientboundCustomReportDetails) {}
     pub fn server_links(&mut self, _p: &ClientboundServerLinks) {}
-    pub fn player_rotation(&mut self, _p: &ClientboundPlayerRotation) {}
-    pub fn recipe_book_add(&mut self, _p: &ClientboundRecipeBookAdd) {}
-    pub fn recipe_book_remove(&mut self, _p: &ClientboundRecipeBookRemove) {}
+    pub fn recipe_book_add(&mut self, p: &ClientboundRecipeBookAdd) {
+        debug!("Got recipe book add packet {p:?}");
+
+        as_system::<(Commands, Query<Option<&mut Recipes>>)>(
+            self.ecs,
+            |(mut commands, mut query)| {
+                let Ok(recipes) = query.get_mut(self.player) else {
+                    return;
+                };
+                let entries = p
+                    .entries
+                    .iter()
+                    .map(|entry| (entry.contents.id, entry.contents.clone()));
+                match recipes {
+                    Some(mut recipes) if !p.replace => recipes.extend(entries),
+                    _ => {
+                        commands
+                            .entity(self.player)
+                            .insert(Recipes(entries.collect()));
+                    }
+                }
+            },
+        );
+    }
+    pub fn recipe_book_remove(&mut self, p: &ClientboundRecipeBookRemove) {
+        debug!("Got recipe book remove packet {p:?}");
+
+        as_system::<Query<&mut Recipes>>(self.ecs, |mut query| {
+            if let Ok(mut recipes) = query.get_mut(self.player) {
+                for id in &p.recipes {
+                    recipes.remove(id);
+                }
+            }
+        });
+    }
     pub fn recipe_book_settings(&mut self, _p: &ClientboundRecipeBookSettings) {}
     pub fn test_instance_block_status(&mut self, _p: &ClientboundTestInstanceBlockStatus) {}
     pub fn waypoint(&mut self, _p: &ClientboundWaypoint) {}
@@ -1666,3 +2219,19 @@ impl GamePacketHandler<'_> {
         debug!("Got game test highlight pos packet {p:?}");
     }
 }
+
+/// Insert or remove the [`LastDeathLocation`] component based on what the
+/// server sent in a login or respawn packet.
+fn set_last_death_location(
+    commands: &mut Commands,
+    player: Entity,
+    spawn_info: &CommonPlayerSpawnInfo,
+) {
+    if let Some(last_death_location) = &spawn_info.last_death_location {
+        commands
+            .entity(player)
+            .insert(LastDeathLocation(last_death_location.clone()));
+    } else {
+        commands.entity(player).remove::<LastDeathLocation>();
+    }
+}
@@ -44,6 +44,16 @@ pub fn process_packet(ecs: &mut World, player: Entity, packet: &ClientboundLogin
 #[derive(Clone, Component, Debug)]
 pub struct InLoginState;
 
+/// A marker component for local players that finished logging in but haven't
+/// received any packets in the `configuration` state yet.
+///
+/// Servers older than 1.20.2 don't have a configuration state and start
+/// sending game packets right after login, so if the first packet after login
+/// can't be parsed as a configuration packet, we assume the server is too old
+/// and disconnect instead of waiting forever.
+#[derive(Clone, Component, Debug)]
+pub struct AwaitingFirstConfigPacket;
+
 pub struct LoginPacketHandler<'a> {
     pub ecs: &'a mut World,
     pub player: Entity,
@@ -70,10 +80,7 @@ impl LoginPacketHandler<'_> {
         debug!("Got disconnect {:?}", p);
 
         as_system::<MessageWriter<_>>(self.ecs, |mut events| {
-            events.write(DisconnectEvent {
-                entity: self.player,
-                reason: Some(p.reason.clone()),
-            });
+            events.write(DisconnectEvent::new(self.player, Some(p.reason.clone())));
         });
     }
     pub fn login_finished(&mut self, p: &ClientboundLoginFinished) {
@@ -93,7 +100,7 @@ impl LoginPacketHandler<'_> {
                 commands
                     .entity(self.player)
                     .remove::<InLoginState>()
-                    .insert(InConfigState)
+                    .insert((InConfigState, AwaitingFirstConfigPacket))
                     .insert(GameProfileComponent(p.game_profile.clone()));
 
                 let mut conn = query
@@ -1,3 +1,4 @@
+use azalea_core::position::BlockPos;
 use azalea_entity::metadata::Health;
 use bevy_app::{App, Plugin, Update};
 use bevy_ecs::{
@@ -5,8 +6,8 @@ use bevy_ecs::{
     system::{SystemParam, SystemState},
 };
 
-use self::game::DeathEvent;
-use crate::chat::ChatReceivedEvent;
+use self::game::{DeathEvent, InitialPositionEvent, LastAttacker, PendingRespawn, RespawnEvent};
+use crate::{chat::ChatReceivedEvent, local_player::WorldSpawn};
 
 pub mod config;
 pub mod game;
@@ -15,25 +16,60 @@ pub mod login;
 pub struct PacketPlugin;
 
 pub fn death_event_on_0_health(
-    query: Query<(Entity, &Health), Changed<Health>>,
+    query: Query<(Entity, &Health, Option<&LastAttacker>), Changed<Health>>,
     mut death_events: MessageWriter<DeathEvent>,
 ) {
-    for (entity, health) in query.iter() {
+    for (entity, health, last_attacker) in query.iter() {
         if **health == 0. {
             death_events.write(DeathEvent {
                 entity,
                 packet: None,
+                killer: last_attacker.map(|a| **a),
             });
         }
     }
 }
 
+/// How far away from the world spawn players can be put when they respawn
+/// there, with the default `spawn_radius` gamerule.
+pub const WORLD_SPAWN_RADIUS: i32 = 10;
+
+/// Send a [`RespawnEvent`] once a player that respawned knows where it is.
+pub fn send_respawn_event(
+    mut commands: Commands,
+    mut initial_position_events: MessageReader<InitialPositionEvent>,
+    query: Query<(&PendingRespawn, Option<&WorldSpawn>)>,
+    mut respawn_events: MessageWriter<RespawnEvent>,
+) {
+    for event in initial_position_events.read() {
+        let Ok((pending_respawn, world_spawn)) = query.get(event.entity) else {
+            continue;
+        };
+
+        let block_pos = BlockPos::from(event.position);
+        let is_at_world_spawn = world_spawn.is_some_and(|world_spawn| {
+            let spawn = &world_spawn.global_pos;
+            spawn.dimension == pending_respawn.dimension
+                && (block_pos.x - spawn.pos.x).abs() <= WORLD_SPAWN_RADIUS
+                && (block_pos.z - spawn.pos.z).abs() <= WORLD_SPAWN_RADIUS
+        });
+
+        respawn_events.write(RespawnEvent {
+            entity: event.entity,
+            dimension: pending_respawn.dimension.clone(),
+            position: event.position,
+            is_probably_bed_spawn: !pending_respawn.missing_respawn_block && !is_at_world_spawn,
+        });
+        commands.entity(event.entity).remove::<PendingRespawn>();
+    }
+}
+
 impl Plugin for PacketPlugin {
     fn build(&self, app: &mut App) {
         app.add_observer(game::handle_outgoing_packets_observer)
             .add_observer(config::handle_outgoing_packets_observer)
             .add_observer(login::handle_outgoing_packets_observer)
-            .add_systems(Update, death_event_on_0_health)
+            .add_systems(Update, (death_event_on_0_health, send_respawn_event))
             .add_message::<game::ReceiveGamePacketEvent>()
             .add_message::<config::ReceiveConfigPacketEvent>()
             .add_message::<login::ReceiveLoginPacketEvent>()
@@ -41,11 +77,18 @@ impl Plugin for PacketPlugin {
             .add_message::<game::AddPlayerEvent>()
             .add_message::<game::RemovePlayerEvent>()
             .add_message::<game::UpdatePlayerEvent>()
+            .add_message::<game::EntitySpawnedEvent>()
+            .add_message::<game::EntityRemovedEvent>()
+            .add_message::<game::EntitySwingEvent>()
             .add_message::<ChatReceivedEvent>()
             .add_message::<game::DeathEvent>()
             .add_message::<game::KeepAliveEvent>()
             .add_message::<game::ResourcePackEvent>()
             .add_message::<game::WorldLoadedEvent>()
+            .add_message::<game::InitialPositionEvent>()
+            .add_message::<game::RespawnEvent>()
+            .add_message::<game::ExplosionEvent>()
+            .add_message::<game::RotatedEvent>()
             .add_message::<login::ReceiveCustomQueryEvent>();
     }
 }
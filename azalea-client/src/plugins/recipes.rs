@@ -0,0 +1,17 @@
+//! Keep track of the recipes that we've unlocked in our recipe book.
+
+use std::collections::HashMap;
+
+use azalea_protocol::packets::game::c_recipe_book_add::RecipeDisplayEntry;
+use bevy_ecs::prelude::*;
+use derive_more::{Deref, DerefMut};
+
+/// The recipes that are unlocked in our recipe book, keyed by the id that the
+/// server gave them.
+///
+/// These ids are only meaningful to the server that sent them, and they're
+/// what the server expects in packets like `ServerboundPlaceRecipe`.
+///
+/// This component is only present after the server first sends us recipes.
+#[derive(Clone, Component, Debug, Default, Deref, DerefMut, PartialEq)]
+pub struct Recipes(pub HashMap<u32, RecipeDisplayEntry>);
@@ -0,0 +1,64 @@
+//! Requesting and storing the statistics that are shown in the statistics menu,
+//! like play time and the number of blocks mined.
+
+use std::collections::HashMap;
+
+use azalea_protocol::packets::game::{
+    c_award_stats::Stat,
+    s_client_command::{self, ServerboundClientCommand},
+};
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::prelude::*;
+use derive_more::{Deref, DerefMut};
+
+use crate::packet::game::SendGamePacketEvent;
+
+/// A plugin that makes [`RequestStatisticsEvent`] ask the server for our
+/// statistics.
+///
+/// The statistics that the server sends back are stored in the
+/// [`Statistics`] component.
+pub struct StatsPlugin;
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<RequestStatisticsEvent>()
+            .add_systems(Update, handle_request_statistics_event);
+    }
+}
+
+/// The statistics for our player that the server has sent us.
+///
+/// Servers only send statistics when we ask for them with
+/// [`RequestStatisticsEvent`], and values for statistics that the server
+/// didn't include are kept from previous responses.
+///
+/// This component is only present after the server first sends us our
+/// statistics.
+#[derive(Clone, Component, Debug, Default, Deref, DerefMut, PartialEq)]
+pub struct Statistics(pub HashMap<Stat, i32>);
+
+/// A marker component for clients that asked the server for their statistics
+/// and haven't gotten a response yet.
+#[derive(Clone, Component, Debug)]
+pub struct WaitingForStatistics;
+
+/// Ask the server to send us our [`Statistics`].
+#[derive(Clone, Debug, Message)]
+pub struct RequestStatisticsEvent {
+    pub entity: Entity,
+}
+
+pub fn handle_request_statistics_event(
+    mut events: MessageReader<RequestStatisticsEvent>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        commands.entity(event.entity).insert(WaitingForStatistics);
+        commands.trigger(SendGamePacketEvent::new(
+            event.entity,
+            ServerboundClientCommand {
+                action: s_client_command::Action::RequestStats,
+            },
+        ));
+    }
+}
@@ -8,7 +8,9 @@ use azalea_world::WorldName;
 use bevy_app::{App, Plugin};
 use bevy_ecs::prelude::*;
 
-use crate::{mining::MiningSystems, packet::game::SendGamePacketEvent};
+use crate::{
+    mining::MiningSystems, movement::SendMovementSystems, packet::game::SendGamePacketEvent,
+};
 
 /// A plugin that makes clients send a [`ServerboundClientTickEnd`] packet every
 /// tick.
@@ -19,13 +21,22 @@ impl Plugin for TickEndPlugin {
             GameTick,
             // this has to happen after every other event that might send packets
             game_tick_packet
+                .in_set(TickEndSystems)
                 .after(PhysicsSystems)
                 .after(MiningSystems)
-                .after(crate::movement::send_position),
+                .after(SendMovementSystems),
         );
     }
 }
 
+/// The [`SystemSet`] for the [`GameTick`] system that sends the
+/// [`ServerboundClientTickEnd`] packet.
+///
+/// Packets that you want the server to receive as part of the current tick
+/// should be sent by systems that run `.before(TickEndSystems)`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, SystemSet)]
+pub struct TickEndSystems;
+
 pub fn game_tick_packet(
     query: Query<Entity, (With<LocalEntity>, With<WorldName>)>,
     mut commands: Commands,
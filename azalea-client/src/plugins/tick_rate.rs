@@ -0,0 +1,80 @@
+//! Keep track of how fast the server is ticking, which can be changed with the
+//! `/tick rate`, `/tick freeze`, and `/tick step` commands.
+
+use std::time::Duration;
+
+use azalea_core::tick::GameTick;
+use azalea_physics::PhysicsSystems;
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+
+/// The normal number of ticks per second.
+pub const DEFAULT_TICK_RATE: f32 = 20.;
+
+/// Adds the [`WorldTickRate`] resource, which is updated when the server sends
+/// us a `ticking_state` or `ticking_step` packet.
+///
+/// Like in vanilla, freezing the server doesn't freeze our own player, so
+/// [`PhysicsSystems`] keep running while the server is frozen.
+pub struct TickRatePlugin;
+impl Plugin for TickRatePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WorldTickRate>()
+            .add_systems(GameTick, update_world_tick_rate.before(PhysicsSystems));
+    }
+}
+
+/// A resource for the number of game ticks that the server runs per second.
+///
+/// This is 20 unless the server changed it. Azalea runs the [`GameTick`]
+/// schedule at this rate, so our physics and movement packets keep up with
+/// the server.
+///
+/// Note that game ticks only run during an `Update`, which happens at most 60
+/// times per second, so tick rates higher than that will be capped.
+///
+/// Since this is a resource, every client in the ECS shares the same tick
+/// rate, and the last value that was received wins.
+///
+/// [`GameTick`]: azalea_core::tick::GameTick
+#[derive(Clone, Copy, Debug, PartialEq, Resource)]
+pub struct WorldTickRate {
+    pub tick_rate: f32,
+    /// Whether the server froze the game with `/tick freeze`.
+    pub is_frozen: bool,
+    /// The number of ticks that the server is stepping through while it's
+    /// frozen, from `/tick step`.
+    pub frozen_ticks_to_run: u32,
+    /// Whether entities other than players should be ticked during this tick.
+    ///
+    /// This is updated at the start of every [`GameTick`].
+    pub runs_normally: bool,
+}
+impl WorldTickRate {
+    /// The amount of time between each game tick.
+    pub fn tick_duration(&self) -> Duration {
+        // the server doesn't allow tick rates below 1, but we clamp it here anyways so
+        // a weird value can't break the schedule loop
+        Duration::from_secs_f64(1. / self.tick_rate.max(1.) as f64)
+    }
+}
+impl Default for WorldTickRate {
+    fn default() -> Self {
+        Self {
+            tick_rate: DEFAULT_TICK_RATE,
+            is_frozen: false,
+            frozen_ticks_to_run: 0,
+            runs_normally: true,
+        }
+    }
+}
+
+/// Decide whether this tick should run, and use up one of the stepped ticks if
+/// we're frozen.
+pub fn update_world_tick_rate(mut world_tick_rate: ResMut<WorldTickRate>) {
+    world_tick_rate.runs_normally =
+        !world_tick_rate.is_frozen || world_tick_rate.frozen_ticks_to_run > 0;
+    if world_tick_rate.frozen_ticks_to_run > 0 {
+        world_tick_rate.frozen_ticks_to_run -= 1;
+    }
+}
@@ -0,0 +1,156 @@
+//! Keep track of the world border, which is the edge of the world that players
+//! can't go past.
+
+use azalea_core::{position::Vec3, tick::GameTick};
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+
+/// Updates the size of the [`WorldBorder`] every tick while it's growing or
+/// shrinking.
+pub struct WorldBorderPlugin;
+impl Plugin for WorldBorderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(GameTick, tick_world_border);
+    }
+}
+
+/// The world border in the dimension that the client is in.
+///
+/// The border is a square around its center, and its size is the length of
+/// each side. It can change size gradually, which is often used by minigames
+/// to make the playable area smaller over time.
+#[derive(Clone, Component, Debug, PartialEq)]
+pub struct WorldBorder {
+    pub center_x: f64,
+    pub center_z: f64,
+    /// The current length of each side of the border.
+    pub size: f64,
+    /// The size that the border had when it started changing size.
+    pub lerp_from: f64,
+    /// The size that the border is changing to.
+    pub lerp_target: f64,
+    /// The total number of ticks that the current size change takes.
+    pub lerp_duration: u64,
+    /// The number of ticks until the border reaches [`Self::lerp_target`], or
+    /// 0 if it's not changing size.
+    pub lerp_remaining: u64,
+    /// The border can never go further than this from the origin, no matter
+    /// its size or center.
+    pub absolute_max_size: u32,
+    /// How close to the border a player has to be for their screen to turn red.
+    pub warning_blocks: u32,
+    /// How many seconds before a shrinking border reaches a player that their
+    /// screen starts to turn red.
+    pub warning_time: u32,
+}
+
+impl Default for WorldBorder {
+    /// The world border that vanilla uses by default.
+    fn default() -> Self {
+        let size = 59_999_968.;
+        Self {
+            center_x: 0.,
+            center_z: 0.,
+            size,
+            lerp_from: size,
+            lerp_target: size,
+            lerp_duration: 0,
+            lerp_remaining: 0,
+            absolute_max_size: 29_999_984,
+            warning_blocks: 5,
+            warning_time: 15,
+        }
+    }
+}
+
+impl WorldBorder {
+    /// Immediately change the size of the border.
+    pub fn set_size(&mut self, size: f64) {
+        self.size = size;
+        self.lerp_from = size;
+        self.lerp_target = size;
+        self.lerp_duration = 0;
+        self.lerp_remaining = 0;
+    }
+
+    /// Start gradually changing the size of the border, over the given number
+    /// of ticks.
+    pub fn lerp_size_between(&mut self, from: f64, to: f64, ticks: u64) {
+        if ticks == 0 || from == to {
+            self.set_size(to);
+            return;
+        }
+        self.size = from;
+        self.lerp_from = from;
+        self.lerp_target = to;
+        self.lerp_duration = ticks;
+        self.lerp_remaining = ticks;
+    }
+
+    /// Returns whether the border is currently changing size.
+    pub fn is_moving(&self) -> bool {
+        self.lerp_remaining > 0
+    }
+
+    /// Advance the border's size change by one tick.
+    pub fn tick(&mut self) {
+        if !self.is_moving() {
+            return;
+        }
+        self.lerp_remaining -= 1;
+        if self.lerp_remaining == 0 {
+            self.set_size(self.lerp_target);
+        } else {
+            let progress =
+                (self.lerp_duration - self.lerp_remaining) as f64 / self.lerp_duration as f64;
+            self.size = self.lerp_from + (self.lerp_target - self.lerp_from) * progress;
+        }
+    }
+
+    pub fn min_x(&self) -> f64 {
+        (self.center_x - self.size / 2.).max(-(self.absolute_max_size as f64))
+    }
+    pub fn max_x(&self) -> f64 {
+        (self.center_x + self.size / 2.).min(self.absolute_max_size as f64)
+    }
+    pub fn min_z(&self) -> f64 {
+        (self.center_z - self.size / 2.).max(-(self.absolute_max_size as f64))
+    }
+    pub fn max_z(&self) -> f64 {
+        (self.center_z + self.size / 2.).min(self.absolute_max_size as f64)
+    }
+
+    /// Returns whether the given position is inside of the border.
+    ///
+    /// Only the X and Z coordinates are checked, since the border goes from the
+    /// bottom to the top of the world.
+    pub fn is_within_bounds(&self, pos: Vec3) -> bool {
+        pos.x >= self.min_x()
+            && pos.x < self.max_x()
+            && pos.z >= self.min_z()
+            && pos.z < self.max_z()
+    }
+
+    /// Get the distance from the given position to the closest edge of the
+    /// border.
+    ///
+    /// This is negative if the position is outside of the border.
+    pub fn distance_to_border(&self, pos: Vec3) -> f64 {
+        let distance_to_min_x = pos.x - self.min_x();
+        let distance_to_max_x = self.max_x() - pos.x;
+        let distance_to_min_z = pos.z - self.min_z();
+        let distance_to_max_z = self.max_z() - pos.z;
+        distance_to_min_x
+            .min(distance_to_max_x)
+            .min(distance_to_min_z)
+            .min(distance_to_max_z)
+    }
+}
+
+pub fn tick_world_border(mut query: Query<&mut WorldBorder>) {
+    for mut world_border in &mut query {
+        if world_border.is_moving() {
+            world_border.tick();
+        }
+    }
+}
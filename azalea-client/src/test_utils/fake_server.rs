@@ -0,0 +1,115 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use azalea_protocol::{
+    address::{ResolvedAddr, ServerAddr},
+    connect::Connection,
+    packets::{
+        handshake::{ClientboundHandshakePacket, ServerboundHandshakePacket, ServerboundIntention},
+        login::{ClientboundLoginPacket, ServerboundHello, ServerboundLoginPacket},
+    },
+};
+use bevy_app::App;
+use tokio::net::TcpListener;
+
+use crate::{
+    account::Account,
+    join::{ConnectOpts, StartJoinServerEvent},
+};
+
+/// How long [`update_until`] waits before giving up.
+pub const UPDATE_UNTIL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The packets that a client sent to a fake server before it started logging
+/// in.
+#[derive(Clone, Debug)]
+pub struct LoginPackets {
+    pub intention: ServerboundIntention,
+    pub hello: ServerboundHello,
+}
+
+/// The address of a local [`TcpListener`], for use in [`ConnectOpts`].
+pub fn listener_addr(listener: &TcpListener) -> ResolvedAddr {
+    let socket = listener.local_addr().unwrap();
+    ResolvedAddr {
+        server: ServerAddr {
+            host: "localhost".to_owned(),
+            port: socket.port(),
+        },
+        socket,
+    }
+}
+
+/// Accept a connection on the listener and read the packets that the client
+/// sends up to and including the hello.
+///
+/// The connection is returned too, so the server can keep talking to the
+/// client (for example to kick it).
+pub async fn accept_login(
+    listener: &TcpListener,
+) -> (
+    LoginPackets,
+    Connection<ServerboundLoginPacket, ClientboundLoginPacket>,
+) {
+    let (stream, _) = listener.accept().await.unwrap();
+    let mut conn: Connection<ServerboundHandshakePacket, ClientboundHandshakePacket> =
+        Connection::wrap(stream);
+    let ServerboundHandshakePacket::Intention(intention) = conn.read().await.unwrap();
+    let mut conn = conn.login();
+    let hello = match conn.read().await.unwrap() {
+        ServerboundLoginPacket::Hello(p) => p,
+        p => panic!("expected hello, got {p:?}"),
+    };
+    (LoginPackets { intention, hello }, conn)
+}
+
+/// Update the app until the condition is true.
+///
+/// Panics if it's still false after [`UPDATE_UNTIL_TIMEOUT`].
+pub fn update_until(app: &mut App, mut condition: impl FnMut(&mut App) -> bool) {
+    let start = Instant::now();
+    while !condition(app) {
+        assert!(
+            start.elapsed() < UPDATE_UNTIL_TIMEOUT,
+            "condition wasn't met after {UPDATE_UNTIL_TIMEOUT:?}"
+        );
+        app.update();
+        thread::sleep(Duration::from_millis(1));
+    }
+}
+
+/// Make the app join a fake offline-mode server with the given account, and
+/// return the packets that the client sent before logging in.
+///
+/// `modify_opts` can be used to change the [`ConnectOpts`], which otherwise
+/// have every option unset.
+pub fn join_fake_server(
+    app: &mut App,
+    account: Account,
+    modify_opts: impl FnOnce(&mut ConnectOpts),
+) -> LoginPackets {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let listener = rt.block_on(TcpListener::bind("127.0.0.1:0")).unwrap();
+
+    let mut connect_opts = ConnectOpts {
+        address: listener_addr(&listener),
+        server_proxy: None,
+        sessionserver_proxy: None,
+        rng_seed: None,
+        client_information: None,
+    };
+    modify_opts(&mut connect_opts);
+
+    let server = rt.spawn(async move { accept_login(&listener).await.0 });
+
+    app.world_mut().write_message(StartJoinServerEvent {
+        account,
+        connect_opts,
+        start_join_callback_tx: None,
+    });
+    update_until(app, |_| server.is_finished());
+
+    rt.block_on(server).unwrap()
+}
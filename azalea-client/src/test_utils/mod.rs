@@ -1,6 +1,7 @@
+pub mod fake_server;
 pub mod simulation;
 pub mod tracing;
 
 pub mod prelude {
-    pub use super::{simulation::*, tracing::*};
+    pub use super::{fake_server::*, simulation::*, tracing::*};
 }
@@ -13,13 +13,14 @@ use azalea_core::{
 use azalea_entity::metadata::PlayerMetadataBundle;
 use azalea_protocol::{
     common::client_information::ClientInformation,
+    connect::Connection,
     packets::{
         ConnectionProtocol, Packet, ProtocolPacket,
         common::CommonPlayerSpawnInfo,
         config::{ClientboundFinishConfiguration, ClientboundRegistryData},
         game::{
-            ClientboundAddEntity, ClientboundLevelChunkWithLight, ClientboundLogin,
-            ClientboundRespawn, ServerboundGamePacket,
+            ClientboundAddEntity, ClientboundGamePacket, ClientboundLevelChunkWithLight,
+            ClientboundLogin, ClientboundRespawn, ServerboundGamePacket,
             c_level_chunk_with_light::ClientboundLevelChunkPacketData,
             c_light_update::ClientboundLightUpdatePacketData,
         },
@@ -32,15 +33,16 @@ use azalea_registry::{
     identifier::Identifier,
 };
 use azalea_world::{Chunk, Section, World, palette::PalettedContainer};
-use bevy_app::App;
+use bevy_app::{App, Update};
 use bevy_ecs::{
     component::Mutable,
     prelude::*,
     query::{QueryData, QueryItem},
     schedule::ExecutorKind,
 };
-use parking_lot::{Mutex, RwLock};
+use parking_lot::{Mutex, MutexGuard, RwLock};
 use simdnbt::owned::{NbtCompound, NbtTag};
+use tokio::net::{TcpListener, TcpStream};
 use uuid::Uuid;
 
 use crate::{
@@ -118,6 +120,42 @@ impl Simulation {
         self.app.world_mut().trigger(event);
     }
 
+    /// Give the client a real connection to a local TCP server, and return the
+    /// server's side of it.
+    ///
+    /// Packets that the client sends after this will actually be written to
+    /// the network. Call [`Self::update`] afterwards so limits like the
+    /// [`BandwidthLimit`] are applied to the new connection.
+    ///
+    /// [`BandwidthLimit`]: crate::connection::BandwidthLimit
+    pub fn connect_to_local_server(
+        &mut self,
+    ) -> Connection<ServerboundGamePacket, ClientboundGamePacket> {
+        let (client_stream, server_stream) = self.rt.block_on(async {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let client_stream = TcpStream::connect(listener.local_addr().unwrap())
+                .await
+                .unwrap();
+            let (server_stream, _) = listener.accept().await.unwrap();
+            (client_stream, server_stream)
+        });
+        let (reader, writer) =
+            Connection::<ClientboundGamePacket, ServerboundGamePacket>::wrap(client_stream)
+                .into_split_raw();
+        let state = self
+            .app
+            .world()
+            .get::<RawConnection>(self.entity)
+            .unwrap()
+            .state;
+        self.app
+            .world_mut()
+            .entity_mut(self.entity)
+            .insert(RawConnection::new(reader, writer, state));
+
+        Connection::wrap(server_stream)
+    }
+
     pub fn tick(&mut self) {
         tick_app(&mut self.app);
     }
@@ -190,10 +228,9 @@ impl Simulation {
 
     pub fn disconnect(&mut self) {
         // send DisconnectEvent
-        self.app.world_mut().write_message(DisconnectEvent {
-            entity: self.entity,
-            reason: None,
-        });
+        self.app
+            .world_mut()
+            .write_message(DisconnectEvent::new(self.entity, None));
     }
 }
 
@@ -276,6 +313,51 @@ impl SentPackets {
     }
 }
 
+/// A list of every message of type `M` that was sent in an app.
+///
+/// This can also be used for collecting other things, like events that are
+/// received by an observer, by calling [`Self::push`] manually.
+#[derive(Clone)]
+pub struct ReceivedMessages<M> {
+    pub list: Arc<Mutex<Vec<M>>>,
+}
+impl<M> Default for ReceivedMessages<M> {
+    fn default() -> Self {
+        Self {
+            list: Default::default(),
+        }
+    }
+}
+impl<M: Message + Clone> ReceivedMessages<M> {
+    /// Collect messages from a system that runs in [`Update`].
+    ///
+    /// If the messages have to be collected in a different schedule (or
+    /// ordered after a specific system), create this with
+    /// [`ReceivedMessages::default`] and add [`Self::system`] instead.
+    pub fn new(app: &mut App) -> Self {
+        let received = Self::default();
+        app.add_systems(Update, received.system());
+        received
+    }
+
+    /// A system that pushes every message it reads to this list.
+    pub fn system(&self) -> impl FnMut(MessageReader<M>) + Send + Sync + 'static {
+        let list = self.list.clone();
+        move |mut messages: MessageReader<M>| {
+            list.lock().extend(messages.read().cloned());
+        }
+    }
+}
+impl<M> ReceivedMessages<M> {
+    pub fn push(&self, message: M) {
+        self.list.lock().push(message);
+    }
+
+    pub fn lock(&self) -> MutexGuard<'_, Vec<M>> {
+        self.list.lock()
+    }
+}
+
 #[allow(clippy::type_complexity)]
 fn create_local_player_bundle(
     entity: Entity,
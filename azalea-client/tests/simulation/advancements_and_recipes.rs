@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use azalea_client::{advancements::Advancements, recipes::Recipes, test_utils::prelude::*};
+use azalea_protocol::{
+    common::recipe::{RecipeDisplayData, SlotDisplayData, StonecutterRecipeDisplay},
+    packets::{
+        ConnectionProtocol,
+        game::{
+            ClientboundRecipeBookAdd, ClientboundRecipeBookRemove, ClientboundUpdateAdvancements,
+            c_recipe_book_add::{Entry, RecipeDisplayEntry},
+            c_update_advancements::{Advancement, AdvancementHolder, CriterionProgress},
+        },
+    },
+};
+use azalea_registry::{builtin::RecipeBookCategory, identifier::Identifier};
+
+fn progress_packet(
+    id: &Identifier,
+    criteria: &[(&str, Option<u64>)],
+    reset: bool,
+) -> ClientboundUpdateAdvancements {
+    ClientboundUpdateAdvancements {
+        reset,
+        added: if reset {
+            vec![AdvancementHolder {
+                id: id.clone(),
+                value: Advancement {
+                    parent_id: None,
+                    display: None,
+                    // (mine_stone or mine_deepslate) and craft_pickaxe
+                    requirements: vec![
+                        vec!["mine_stone".to_owned(), "mine_deepslate".to_owned()],
+                        vec!["craft_pickaxe".to_owned()],
+                    ],
+                    sends_telemetry_event: false,
+                },
+            }]
+        } else {
+            vec![]
+        },
+        removed: vec![],
+        progress: [(
+            id.clone(),
+            criteria
+                .iter()
+                .map(|&(name, date)| (name.to_owned(), CriterionProgress { date }))
+                .collect::<HashMap<_, _>>(),
+        )]
+        .into_iter()
+        .collect(),
+        show_advancements: false,
+    }
+}
+
+#[test]
+fn test_advancement_done() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+    assert!(!simulation.has_component::<Advancements>());
+
+    let id = Identifier::new("minecraft:story/upgrade_tools");
+    simulation.receive_packet(progress_packet(
+        &id,
+        &[
+            ("mine_stone", Some(1)),
+            ("mine_deepslate", None),
+            ("craft_pickaxe", None),
+        ],
+        true,
+    ));
+    simulation.tick();
+    assert!(!simulation.component::<Advancements>().is_done(&id));
+
+    simulation.receive_packet(progress_packet(
+        &id,
+        &[
+            ("mine_stone", Some(1)),
+            ("mine_deepslate", None),
+            ("craft_pickaxe", Some(2)),
+        ],
+        false,
+    ));
+    simulation.tick();
+    let advancements = simulation.component::<Advancements>();
+    assert!(advancements.is_done(&id));
+    assert_eq!(advancements.done().collect::<Vec<_>>(), vec![&id]);
+}
+
+fn recipe_entry(id: u32) -> Entry {
+    Entry {
+        contents: RecipeDisplayEntry {
+            id,
+            display: RecipeDisplayData::Stonecutter(StonecutterRecipeDisplay {
+                input: SlotDisplayData::Empty,
+                result: SlotDisplayData::Empty,
+                crafting_station: SlotDisplayData::Empty,
+            }),
+            group: 0,
+            category: RecipeBookCategory::Stonecutter,
+            crafting_requirements: None,
+        },
+        flags: 0,
+    }
+}
+
+#[test]
+fn test_recipe_book() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(ClientboundRecipeBookAdd {
+        entries: vec![recipe_entry(1), recipe_entry(2)],
+        replace: true,
+    });
+    simulation.receive_packet(ClientboundRecipeBookAdd {
+        entries: vec![recipe_entry(3)],
+        replace: false,
+    });
+    simulation.receive_packet(ClientboundRecipeBookRemove { recipes: vec![2] });
+    simulation.tick();
+
+    let mut ids = simulation
+        .component::<Recipes>()
+        .keys()
+        .copied()
+        .collect::<Vec<_>>();
+    ids.sort();
+    assert_eq!(ids, vec![1, 3]);
+
+    // replacing the recipe book removes the recipes that were there before
+    simulation.receive_packet(ClientboundRecipeBookAdd {
+        entries: vec![recipe_entry(4)],
+        replace: true,
+    });
+    simulation.tick();
+    assert_eq!(
+        simulation
+            .component::<Recipes>()
+            .keys()
+            .copied()
+            .collect::<Vec<_>>(),
+        vec![4]
+    );
+}
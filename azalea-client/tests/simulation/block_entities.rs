@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use azalea_block::BlockState;
+use azalea_buf::AzBuf;
+use azalea_client::{local_player::WorldHolder, test_utils::prelude::*};
+use azalea_core::position::{BlockPos, ChunkPos, ChunkSectionBlockPos};
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{
+        ClientboundBlockEntityData, ClientboundBlockUpdate, c_level_chunk_with_light::BlockEntity,
+    },
+};
+use azalea_registry::builtin::{BlockEntityKind, BlockKind};
+use azalea_world::Section;
+use simdnbt::owned::{BaseNbt, Nbt, NbtCompound, NbtTag};
+
+const SECTION_COUNT: usize = (384 + 64) / 16;
+
+fn block_entities(simulation: &Simulation) -> Vec<(BlockPos, BlockEntityKind, NbtCompound)> {
+    let mut block_entities = simulation
+        .component::<WorldHolder>()
+        .shared
+        .read()
+        .block_entities()
+        .into_iter()
+        .map(|(pos, block_entity)| (pos, block_entity.kind, block_entity.data))
+        .collect::<Vec<_>>();
+    block_entities.sort_by_key(|(pos, _, _)| (pos.x, pos.y, pos.z));
+    block_entities
+}
+
+#[test]
+fn test_block_entities() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+
+    let chest_pos = BlockPos::new(1, -10, 2);
+
+    // the chest block has to be in the chunk for its block entity to be removed
+    // when it's broken later
+    let mut sections = vec![Section::default(); SECTION_COUNT];
+    let chest_section = &mut sections[((chest_pos.y + 64) / 16) as usize];
+    chest_section.block_count = 1;
+    chest_section.states.set(
+        ChunkSectionBlockPos::from(chest_pos),
+        BlockState::from(BlockKind::Chest),
+    );
+    let mut chunk_bytes = Vec::new();
+    for section in &sections {
+        section.azalea_write(&mut chunk_bytes).unwrap();
+    }
+
+    let mut chunk = make_basic_empty_chunk(ChunkPos::new(0, 0), SECTION_COUNT);
+    chunk.chunk_data.data = Arc::new(chunk_bytes.into());
+    chunk.chunk_data.block_entities.push(BlockEntity {
+        packed_xz: (1 << 4) | 2,
+        y: -10_i16 as u16,
+        kind: BlockEntityKind::Chest,
+        data: Nbt::None,
+    });
+    simulation.receive_packet(chunk);
+    simulation.tick();
+    assert_eq!(
+        simulation.get_block_state(chest_pos),
+        Some(BlockKind::Chest.into())
+    );
+    assert_eq!(
+        block_entities(&simulation),
+        vec![(chest_pos, BlockEntityKind::Chest, NbtCompound::new())]
+    );
+
+    // placing a sign sends a block update followed by the sign's data
+    let sign_pos = BlockPos::new(3, 70, 4);
+    let sign_data = NbtCompound::from_values(vec![("is_waxed".into(), NbtTag::Byte(1))]);
+    simulation.receive_packet(ClientboundBlockUpdate {
+        pos: sign_pos,
+        block_state: BlockKind::OakSign.into(),
+    });
+    simulation.receive_packet(ClientboundBlockEntityData {
+        pos: sign_pos,
+        block_entity_type: BlockEntityKind::Sign,
+        tag: Nbt::Some(BaseNbt::new("", sign_data.clone())),
+    });
+    simulation.tick();
+    assert_eq!(
+        block_entities(&simulation),
+        vec![
+            (chest_pos, BlockEntityKind::Chest, NbtCompound::new()),
+            (sign_pos, BlockEntityKind::Sign, sign_data),
+        ]
+    );
+
+    // and breaking the chest removes its block entity
+    simulation.receive_packet(ClientboundBlockUpdate {
+        pos: chest_pos,
+        block_state: BlockKind::Air.into(),
+    });
+    simulation.tick();
+    assert_eq!(block_entities(&simulation).len(), 1);
+}
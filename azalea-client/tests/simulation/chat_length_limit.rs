@@ -0,0 +1,108 @@
+use azalea_client::{
+    chat::{LongChatMessageBehavior, SendChatEvent},
+    test_utils::prelude::*,
+};
+use azalea_protocol::packets::{ConnectionProtocol, game::ServerboundGamePacket};
+
+fn long_message() -> String {
+    // 60 words of 4 characters plus spaces is 299 characters
+    let mut message = vec!["word"; 60].join(" ");
+    // this would get us kicked, so it should be removed
+    message.push('§');
+    message
+}
+
+#[test]
+fn test_split_long_chat_message() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+    sent_packets.clear();
+
+    simulation.write_message(SendChatEvent {
+        entity: simulation.entity,
+        content: long_message(),
+    });
+    simulation.update();
+
+    let mut parts = Vec::new();
+    while let Some(packet) = sent_packets.next() {
+        if let ServerboundGamePacket::Chat(p) = packet {
+            parts.push(p.message);
+        }
+    }
+    assert_eq!(parts.len(), 2);
+    assert!(parts.iter().all(|p| p.len() <= 256));
+    assert!(
+        parts
+            .iter()
+            .all(|p| !p.starts_with(' ') && !p.ends_with(' '))
+    );
+    assert_eq!(parts.join(" "), vec!["word"; 60].join(" "));
+}
+
+#[test]
+fn test_reject_long_chat_message() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(simulation.entity)
+        .insert(LongChatMessageBehavior::Reject);
+    sent_packets.clear();
+
+    simulation.write_message(SendChatEvent {
+        entity: simulation.entity,
+        content: long_message(),
+    });
+    simulation.update();
+    while let Some(packet) = sent_packets.next() {
+        assert!(
+            !matches!(packet, ServerboundGamePacket::Chat(_)),
+            "sent a chat message that was too long"
+        );
+    }
+
+    // short messages should still be sent
+    simulation.write_message(SendChatEvent {
+        entity: simulation.entity,
+        content: "hello".to_owned(),
+    });
+    simulation.update();
+    sent_packets.expect(
+        "Chat",
+        |p| matches!(p, ServerboundGamePacket::Chat(p) if p.message == "hello"),
+    );
+}
+
+#[test]
+fn test_long_commands_arent_truncated() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+    sent_packets.clear();
+
+    simulation.write_message(SendChatEvent {
+        entity: simulation.entity,
+        content: format!("/say {}", long_message()),
+    });
+    simulation.update();
+
+    // the command is sanitized but keeps its full length
+    let expected = format!("say {}", vec!["word"; 60].join(" "));
+    sent_packets.expect(
+        "ChatCommand",
+        |p| matches!(p, ServerboundGamePacket::ChatCommand(p) if p.command == expected),
+    );
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "online-mode")]
+
+use azalea_auth::certs::Certificates;
+use azalea_client::{
+    account::{Account, AccountTrait},
+    chat_signing::ChatSigningSession,
+    login::IsAuthenticated,
+    test_utils::prelude::*,
+};
+use azalea_protocol::packets::{ConnectionProtocol, game::ServerboundGamePacket};
+use base64::Engine;
+use chrono::{Duration, Utc};
+use parking_lot::Mutex;
+use rsa::{
+    RsaPrivateKey, RsaPublicKey,
+    pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePublicKey},
+};
+use uuid::Uuid;
+
+/// A 1024-bit RSA key in PKCS#8 DER, only used for this test.
+const PRIVATE_KEY_BASE64: &str = "\
+    MIICdwIBADANBgkqhkiG9w0BAQEFAASCAmEwggJdAgEAAoGBAL4aSRmlJTKG6gtp5tBQOYHEKyJB5FrgdB9lgWcAMh\
+    MSiiRHIofYy6ZrZ/tOaoqMaD7T0LLRuAJdPJmFbs2VVSMVzM2sawYPvV+gXsDX1iVZ58SdOV7JuzPlRbAePG0jDO4T\
+    07FbjY9s5ifw41nF9xmctqvdv2VJ4hE2vzVJomPRAgMBAAECgYBxjC/Hkp3SPUWEhb/pA1ImEW8WKjdklJanvjB5vm\
+    MqgzNiEd8tNzAE+jiEocT/dng5trj8Zkm7l8c/h7S0NRNBNxIWJ28RFWgwrY1MZdbeXFxXiHrd5N9psoQE2k/ivyFk\
+    9cRSJvDx1aXvPsNtrv5wy9GKfqontWi0Zb81tnSR1QJBAN74+u4dVlgWe/7DEimM164b+aeae5SuIAEZXotrG5PSBl\
+    EJSodgd3MLq8zl32quDjSUKki73KD2XKsd+/TeDYsCQQDaQuS8ypRzLbTT5Ikxn4d5iixbkRllykUhPKprwCu/D6nk\
+    uUfyO307893Ve2zARZzPi9cqvO2GnejBkpC2H3eTAkEArBvtvT0B+A6q+XRjoZToqqlHZNr2x0Y8x/xoIR+08r4sSA\
+    Lu91479Z6tpwRNFxBxQJ6ZoPj5jXl9AEytvk5kjwJBANkHcUmlKs4I4eldM2qrlduuIIOAmb+UzP7Kwy9XrGhU2/v6\
+    nObqbpIB4VDZZP8feVkLVY5CxPF57asl4U2npo8CQEYxNmSOmxELmvUa03hqW8TW4JMU5itgq3XCnYyaQCx/Yd5Vte\
+    TVV0ZZ8R6rzruDPQowszW/cD8geb+MSH2RniI=";
+
+/// An account that already has its certificates, so we don't have to ask
+/// Mojang for them.
+#[derive(Debug)]
+struct AccountWithCerts {
+    certs: Mutex<Option<Certificates>>,
+}
+impl AccountTrait for AccountWithCerts {
+    fn username(&self) -> &str {
+        "azalea"
+    }
+    fn uuid(&self) -> Uuid {
+        Uuid::from_u128(1234)
+    }
+    fn access_token(&self) -> Option<String> {
+        None
+    }
+    fn certs(&self) -> Option<Certificates> {
+        self.certs.lock().clone()
+    }
+    fn set_certs(&self, certs: Certificates) {
+        *self.certs.lock() = Some(certs);
+    }
+}
+
+fn make_certs() -> Certificates {
+    let private_key_der = base64::engine::general_purpose::STANDARD
+        .decode(PRIVATE_KEY_BASE64)
+        .unwrap();
+    let private_key = RsaPrivateKey::from_pkcs8_der(&private_key_der).unwrap();
+    let public_key_der = private_key
+        .to_public_key()
+        .to_public_key_der()
+        .unwrap()
+        .as_bytes()
+        .to_vec();
+
+    let now = Utc::now();
+    Certificates {
+        private_key,
+        public_key_der,
+        signature_v1: vec![1; 512],
+        signature_v2: vec![2; 512],
+        expires_at: now + Duration::hours(48),
+        refresh_after: now + Duration::hours(40),
+    }
+}
+
+#[test]
+fn test_chat_session_update() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let certs = make_certs();
+    let account = Account::from(AccountWithCerts {
+        certs: Mutex::new(Some(certs.clone())),
+    });
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(simulation.entity)
+        .insert((account, IsAuthenticated));
+    sent_packets.clear();
+    simulation.tick();
+
+    let mut chat_session = None;
+    while let Some(packet) = sent_packets.next() {
+        if let ServerboundGamePacket::ChatSessionUpdate(p) = packet {
+            assert!(chat_session.is_none(), "sent the chat session twice");
+            chat_session = Some(p.chat_session);
+        }
+    }
+    let chat_session = chat_session.expect("the chat session should've been sent");
+
+    let public_key = &chat_session.profile_public_key;
+    assert_eq!(
+        RsaPublicKey::from_public_key_der(&public_key.key).unwrap(),
+        certs.private_key.to_public_key()
+    );
+    assert_eq!(public_key.key_signature, certs.signature_v2);
+    assert_eq!(
+        public_key.expires_at,
+        certs.expires_at.timestamp_millis() as u64
+    );
+
+    simulation.with_component::<ChatSigningSession>(|session| {
+        assert_eq!(session.session_id, chat_session.session_id);
+    });
+
+    // we don't send it again since the certs are still valid
+    simulation.tick();
+    while let Some(packet) = sent_packets.next() {
+        assert!(!matches!(
+            packet,
+            ServerboundGamePacket::ChatSessionUpdate(_)
+        ));
+    }
+}
@@ -0,0 +1,60 @@
+use std::time::{Duration, Instant};
+
+use azalea_client::{
+    chunks::{ChunkBatchFinishedEvent, ChunkBatchInfo, ChunkBatchRateChangedEvent},
+    test_utils::prelude::*,
+};
+use azalea_protocol::packets::ConnectionProtocol;
+
+#[test]
+fn test_chunk_batch_rate_changed() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let rate_changed_events =
+        ReceivedMessages::<ChunkBatchRateChangedEvent>::new(&mut simulation.app);
+
+    // the default is 2ms per chunk (3.5 chunks per tick), and each sample is
+    // clamped to at most 3x the average, so this is 6ms
+    finish_batch(&mut simulation, 1, Duration::from_millis(20));
+    {
+        let events = rate_changed_events.lock();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].entity, simulation.entity);
+        assert_eq!(events[0].old_chunks_per_tick, 3.5);
+        // (2ms + 6ms) / 2 = 4ms per chunk
+        assert_eq!(events[0].new_chunks_per_tick, 1.75);
+    }
+    assert_eq!(
+        simulation
+            .component::<ChunkBatchInfo>()
+            .desired_chunks_per_tick(),
+        1.75
+    );
+
+    // about the same speed as before, so the rate barely changes
+    finish_batch(&mut simulation, 10, Duration::from_millis(40));
+    assert_eq!(rate_changed_events.lock().len(), 1);
+
+    // much faster
+    finish_batch(&mut simulation, 10, Duration::from_millis(10));
+    let events = rate_changed_events.lock();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[1].old_chunks_per_tick, 1.75);
+    assert!(events[1].new_chunks_per_tick > 1.75 * 1.1);
+}
+
+/// Pretend that a chunk batch started some time ago and just finished.
+fn finish_batch(simulation: &mut Simulation, batch_size: u32, batch_duration: Duration) {
+    simulation.with_component_mut::<ChunkBatchInfo>(|info| {
+        info.start_time = Instant::now() - batch_duration;
+    });
+    simulation.write_message(ChunkBatchFinishedEvent {
+        entity: simulation.entity,
+        batch_size,
+    });
+    simulation.update();
+}
@@ -0,0 +1,34 @@
+use azalea_client::{chunks::ChunkBatchSettings, test_utils::prelude::*};
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{ClientboundChunkBatchFinished, ClientboundChunkBatchStart, ServerboundGamePacket},
+};
+
+#[test]
+fn test_clamp_desired_chunks_per_tick() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let sent_packets = SentPackets::new(&mut simulation);
+    simulation.app.insert_resource(ChunkBatchSettings {
+        min_desired: 0.,
+        max_desired: 1.5,
+        ..Default::default()
+    });
+
+    // an empty batch that's received instantly would normally make us ask for a lot
+    // of chunks per tick
+    simulation.receive_packet(ClientboundChunkBatchStart);
+    simulation.receive_packet(ClientboundChunkBatchFinished { batch_size: 1 });
+    simulation.update();
+
+    sent_packets.expect("ChunkBatchReceived { desired_chunks_per_tick: 1.5 }", |p| {
+        matches!(
+            p,
+            ServerboundGamePacket::ChunkBatchReceived(p) if p.desired_chunks_per_tick == 1.5
+        )
+    });
+}
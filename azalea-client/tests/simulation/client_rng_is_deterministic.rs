@@ -0,0 +1,29 @@
+use azalea_client::{
+    DefaultPlugins, account::Account, local_player::ClientRng, test_utils::prelude::*,
+};
+use bevy_app::{App, PluginGroup};
+use rand::prelude::*;
+
+#[test]
+fn test_client_rng_is_deterministic() {
+    let _lock = init();
+
+    let decisions_for_seed = |seed: u64| {
+        let mut app = App::new();
+        app.add_plugins(DefaultPlugins.build().disable::<bevy_log::LogPlugin>());
+        join_fake_server(&mut app, Account::offline("bot"), |opts| {
+            opts.rng_seed = Some(seed);
+        });
+
+        let mut rng = app
+            .world_mut()
+            .query::<&mut ClientRng>()
+            .single_mut(app.world_mut())
+            .unwrap();
+        (0..16).map(|_| rng.next_u64()).collect::<Vec<_>>()
+    };
+
+    let decisions = decisions_for_seed(1234);
+    assert_eq!(decisions, decisions_for_seed(1234));
+    assert_ne!(decisions, decisions_for_seed(5678));
+}
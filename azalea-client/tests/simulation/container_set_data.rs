@@ -0,0 +1,78 @@
+use azalea_chat::FormattedText;
+use azalea_client::test_utils::prelude::*;
+use azalea_entity::inventory::{BrewingState, FurnaceState, Inventory};
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{ClientboundContainerClose, ClientboundContainerSetData, ClientboundOpenScreen},
+};
+use azalea_registry::builtin::MenuKind;
+
+#[test]
+fn test_container_set_data() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    simulation.receive_packet(ClientboundOpenScreen {
+        container_id: 1,
+        menu_type: MenuKind::Furnace,
+        title: FormattedText::default(),
+    });
+    for (id, value) in [(0, 1500), (1, 1600), (2, 50), (3, 200)] {
+        simulation.receive_packet(ClientboundContainerSetData {
+            container_id: 1,
+            id,
+            value,
+        });
+    }
+    // data for a different container should be ignored
+    simulation.receive_packet(ClientboundContainerSetData {
+        container_id: 2,
+        id: 2,
+        value: 150,
+    });
+    simulation.tick();
+
+    simulation.with_component(|inventory: &Inventory| {
+        let furnace_state = inventory.furnace_state().unwrap();
+        assert_eq!(
+            furnace_state,
+            FurnaceState {
+                lit_time: 1500,
+                lit_duration: 1600,
+                cook_time: 50,
+                total_cook_time: 200,
+            }
+        );
+        assert!(furnace_state.is_lit());
+        assert_eq!(furnace_state.cook_progress(), 0.25);
+        assert_eq!(inventory.brewing_state(), None);
+    });
+
+    simulation.receive_packet(ClientboundContainerClose { container_id: 1 });
+    simulation.receive_packet(ClientboundOpenScreen {
+        container_id: 2,
+        menu_type: MenuKind::BrewingStand,
+        title: FormattedText::default(),
+    });
+    simulation.receive_packet(ClientboundContainerSetData {
+        container_id: 2,
+        id: 0,
+        value: 320,
+    });
+    simulation.tick();
+
+    simulation.with_component(|inventory: &Inventory| {
+        assert_eq!(inventory.furnace_state(), None);
+        // the fuel wasn't sent yet
+        assert_eq!(
+            inventory.brewing_state(),
+            Some(BrewingState {
+                brew_time: 320,
+                fuel: 0,
+            })
+        );
+    });
+}
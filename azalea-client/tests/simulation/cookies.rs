@@ -0,0 +1,184 @@
+use std::time::Duration;
+
+use azalea_client::{
+    account::Account,
+    connection::RawConnection,
+    cookies::ServerCookies,
+    join::{ConnectOpts, PendingTransfer},
+    packet::config::SendConfigPacketEvent,
+    test_utils::prelude::*,
+};
+use azalea_protocol::{
+    address::{ResolvedAddr, ServerAddr},
+    connect::Connection,
+    packets::{
+        ClientIntention, ConnectionProtocol,
+        config::{
+            self, ClientboundFinishConfiguration, ClientboundRegistryData, ServerboundConfigPacket,
+        },
+        game::{self, ServerboundGamePacket},
+        handshake::{ClientboundHandshakePacket, ServerboundHandshakePacket},
+    },
+};
+use azalea_registry::identifier::Identifier;
+use bevy_ecs::observer::On;
+use simdnbt::owned::{NbtCompound, NbtTag};
+use tokio::net::TcpListener;
+
+#[test]
+fn test_cookie_is_echoed_back() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Configuration);
+
+    let config_responses = ReceivedMessages::default();
+    let config_responses_clone = config_responses.clone();
+    simulation
+        .app
+        .add_observer(move |send_config_packet: On<SendConfigPacketEvent>| {
+            if send_config_packet.sent_by == simulation.entity
+                && let ServerboundConfigPacket::CookieResponse(packet) = &send_config_packet.packet
+            {
+                config_responses_clone.push(packet.clone());
+            }
+        });
+
+    let key = Identifier::new("test:session");
+    simulation.receive_packet(config::ClientboundStoreCookie {
+        key: key.clone(),
+        payload: vec![1, 2, 3],
+    });
+    simulation.receive_packet(config::ClientboundCookieRequest { key: key.clone() });
+    simulation.tick();
+    assert_eq!(
+        *config_responses.lock(),
+        vec![config::ServerboundCookieResponse {
+            key: key.clone(),
+            payload: Some(vec![1, 2, 3]),
+        }]
+    );
+
+    // the cookie should still be there after we switch to the game state
+
+    simulation.receive_packet(ClientboundRegistryData {
+        registry_id: Identifier::new("minecraft:dimension_type"),
+        entries: vec![(
+            Identifier::new("minecraft:overworld"),
+            Some(NbtCompound::from_values(vec![
+                ("height".into(), NbtTag::Int(384)),
+                ("min_y".into(), NbtTag::Int(-64)),
+            ])),
+        )]
+        .into_iter()
+        .collect(),
+    });
+    simulation.receive_packet(ClientboundFinishConfiguration);
+    simulation.tick();
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let sent_packets = SentPackets::new(&mut simulation);
+    let unknown_key = Identifier::new("test:unknown");
+    simulation.receive_packet(game::ClientboundCookieRequest { key: key.clone() });
+    simulation.receive_packet(game::ClientboundCookieRequest {
+        key: unknown_key.clone(),
+    });
+    simulation.update();
+    assert_eq!(
+        sent_packets.next(),
+        Some(ServerboundGamePacket::CookieResponse(
+            game::ServerboundCookieResponse {
+                key: key.clone(),
+                payload: Some(vec![1, 2, 3]),
+            }
+        ))
+    );
+    assert_eq!(
+        sent_packets.next(),
+        Some(ServerboundGamePacket::CookieResponse(
+            game::ServerboundCookieResponse {
+                key: unknown_key,
+                payload: None,
+            }
+        ))
+    );
+
+    // but they're removed when we disconnect, so they don't get sent to the next
+    // server that we join
+    simulation.disconnect();
+    simulation.tick();
+    assert!(!simulation.has_component::<ServerCookies>());
+}
+
+#[test]
+fn test_cookies_are_kept_across_transfer() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    // these are normally inserted when we join the first server
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(simulation.entity)
+        .insert((
+            Account::offline("azalea"),
+            ConnectOpts {
+                address: ResolvedAddr {
+                    server: ServerAddr {
+                        host: "localhost".to_owned(),
+                        port: 25565,
+                    },
+                    socket: "127.0.0.1:25565".parse().unwrap(),
+                },
+                server_proxy: None,
+                sessionserver_proxy: None,
+                rng_seed: None,
+                client_information: None,
+            },
+        ));
+
+    let listener = simulation
+        .rt
+        .block_on(TcpListener::bind("127.0.0.1:0"))
+        .unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let server = simulation.rt.spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut conn: Connection<ServerboundHandshakePacket, ClientboundHandshakePacket> =
+            Connection::wrap(stream);
+        let ServerboundHandshakePacket::Intention(intention) = conn.read().await.unwrap();
+        intention
+    });
+
+    let key = Identifier::new("test:session");
+    simulation.receive_packet(game::ClientboundStoreCookie {
+        key: key.clone(),
+        payload: vec![1, 2, 3],
+    });
+    simulation.receive_packet(game::ClientboundTransfer {
+        host: "127.0.0.1".to_owned(),
+        port: port as u32,
+    });
+    simulation.update();
+    assert!(simulation.has_component::<PendingTransfer>());
+
+    for _ in 0..500 {
+        if simulation.has_component::<RawConnection>() {
+            break;
+        }
+        simulation.update();
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert!(!simulation.has_component::<PendingTransfer>());
+    assert_eq!(
+        simulation.component::<ServerCookies>().map.get(&key),
+        Some(&vec![1, 2, 3])
+    );
+
+    let intention = simulation.rt.block_on(server).unwrap();
+    assert_eq!(intention.intention, ClientIntention::Transfer);
+    assert_eq!(intention.port, port);
+}
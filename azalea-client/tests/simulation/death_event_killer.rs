@@ -0,0 +1,55 @@
+use azalea_client::{
+    packet::game::{DeathEvent, LastAttacker},
+    test_utils::prelude::*,
+};
+use azalea_core::{entity_id::MinecraftEntityId, position::ChunkPos};
+use azalea_entity::indexing::EntityIdIndex;
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{ClientboundDamageEvent, ClientboundPlayerCombatKill, c_damage_event::OptionalEntityId},
+};
+use azalea_registry::builtin::EntityKind;
+
+#[test]
+fn test_death_event_killer() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+
+    let death_events = ReceivedMessages::<DeathEvent>::new(&mut simulation.app);
+
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.tick();
+
+    simulation.receive_packet(make_basic_add_entity(
+        EntityKind::Zombie,
+        123,
+        (0.5, 64., 0.5),
+    ));
+    simulation.tick();
+    let zombie = simulation
+        .query_self::<&EntityIdIndex, _>(|index| {
+            index.get_by_minecraft_entity(MinecraftEntityId(123))
+        })
+        .unwrap();
+
+    simulation.receive_packet(ClientboundDamageEvent {
+        entity_id: simulation.minecraft_entity_id(),
+        source_type_id: 0,
+        source_cause_id: OptionalEntityId(Some(123)),
+        source_direct_id: OptionalEntityId(Some(123)),
+        source_position: None,
+    });
+    simulation.tick();
+    assert_eq!(*simulation.component::<LastAttacker>(), zombie);
+
+    simulation.receive_packet(ClientboundPlayerCombatKill {
+        player_id: simulation.minecraft_entity_id(),
+        message: "Player was slain by Zombie".into(),
+    });
+    simulation.tick();
+    let death_events = death_events.lock();
+    assert_eq!(death_events.len(), 1);
+    assert_eq!(death_events[0].killer, Some(zombie));
+}
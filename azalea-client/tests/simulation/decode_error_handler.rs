@@ -0,0 +1,80 @@
+use azalea_client::{
+    connection::{DecodeErrorAction, DecodeErrorHandler, PacketParseSettings, RawConnection},
+    test_utils::prelude::*,
+};
+use azalea_entity::metadata::Health;
+use azalea_protocol::{
+    packets::{ConnectionProtocol, game::ClientboundSetHealth},
+    read::ReadPacketError,
+};
+use azalea_world::WorldName;
+use tracing::Level;
+
+/// A packet with the ID 0x3fff, which doesn't exist in the game state.
+const UNKNOWN_PACKET: &[u8] = &[0xff, 0x7f, 1, 2, 3];
+
+fn simulation_with_handler(
+    strict_packets: bool,
+    action: DecodeErrorAction,
+) -> (Simulation, ReceivedMessages<Vec<u8>>) {
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let received = ReceivedMessages::default();
+    let received_clone = received.clone();
+    simulation.app.insert_resource(PacketParseSettings {
+        strict_packets,
+        on_decode_error: Some(DecodeErrorHandler::new(move |_, raw_packet, error| {
+            assert!(matches!(error, ReadPacketError::UnknownPacketId { .. }));
+            received_clone.push(raw_packet.to_vec());
+            action
+        })),
+        ..Default::default()
+    });
+
+    (simulation, received)
+}
+
+#[test]
+fn test_decode_error_handler_continue() {
+    let _lock = init_with_level(Level::ERROR);
+
+    // strict_packets would normally make us disconnect, but the handler overrides
+    // it
+    let (mut simulation, received) = simulation_with_handler(true, DecodeErrorAction::Continue);
+
+    simulation.with_component_mut::<RawConnection>(|raw_conn| {
+        raw_conn
+            .injected_clientbound_packets
+            .push(UNKNOWN_PACKET.into());
+    });
+    simulation.receive_packet(ClientboundSetHealth {
+        health: 15.,
+        food: 20,
+        saturation: 5.,
+    });
+    simulation.tick();
+
+    assert_eq!(*received.lock(), vec![UNKNOWN_PACKET.to_vec()]);
+    simulation.with_component::<RawConnection>(|raw_conn| assert!(raw_conn.is_alive()));
+    assert_eq!(*simulation.component::<Health>(), 15.);
+}
+
+#[test]
+fn test_decode_error_handler_disconnect() {
+    let _lock = init_with_level(Level::ERROR);
+
+    let (mut simulation, received) = simulation_with_handler(false, DecodeErrorAction::Disconnect);
+
+    simulation.with_component_mut::<RawConnection>(|raw_conn| {
+        raw_conn
+            .injected_clientbound_packets
+            .push(UNKNOWN_PACKET.into());
+    });
+    assert!(simulation.has_component::<WorldName>());
+    simulation.tick();
+
+    assert_eq!(received.lock().len(), 1);
+    assert!(!simulation.has_component::<WorldName>());
+}
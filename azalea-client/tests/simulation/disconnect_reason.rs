@@ -0,0 +1,27 @@
+use azalea_chat::translatable_component::TranslatableComponent;
+use azalea_client::{
+    disconnect::{DisconnectEvent, DisconnectReason},
+    test_utils::prelude::*,
+};
+use azalea_protocol::packets::{ConnectionProtocol, game::ClientboundDisconnect};
+
+#[test]
+fn test_disconnect_event_has_classified_reason() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let disconnect_events = ReceivedMessages::<DisconnectEvent>::new(&mut simulation.app);
+
+    simulation.receive_packet(ClientboundDisconnect {
+        reason: TranslatableComponent::new("multiplayer.disconnect.banned".to_owned(), vec![])
+            .into(),
+    });
+    simulation.tick();
+
+    let disconnect_events = disconnect_events.lock();
+    assert_eq!(disconnect_events.len(), 1);
+    assert_eq!(disconnect_events[0].kind, DisconnectReason::Banned);
+}
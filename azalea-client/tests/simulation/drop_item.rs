@@ -0,0 +1,92 @@
+use azalea_client::{
+    inventory::{DropCount, DropHeldItemEvent},
+    test_utils::prelude::*,
+};
+use azalea_core::{direction::Direction, position::BlockPos};
+use azalea_entity::inventory::Inventory;
+use azalea_inventory::ItemStack;
+use azalea_protocol::packets::{
+    ConnectionProtocol, Packet,
+    game::{
+        ClientboundContainerSetSlot, ServerboundGamePacket, ServerboundPlayerAction,
+        ServerboundSwing, s_interact::InteractionHand, s_player_action,
+    },
+};
+use azalea_registry::builtin::ItemKind;
+
+#[test]
+fn test_drop_one_item() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(ClientboundContainerSetSlot {
+        container_id: 0,
+        state_id: 1,
+        // the first hotbar slot
+        slot: 36,
+        item_stack: ItemStack::new(ItemKind::Dirt, 5),
+    });
+    simulation.tick();
+    sent_packets.clear();
+
+    simulation.trigger(DropHeldItemEvent {
+        entity: simulation.entity,
+        count: DropCount::One,
+    });
+    sent_packets.expect("ServerboundPlayerAction", |p| {
+        p == &ServerboundPlayerAction {
+            action: s_player_action::Action::DropItem,
+            pos: BlockPos::ZERO,
+            direction: Direction::Down,
+            seq: 0,
+        }
+        .into_variant()
+    });
+    sent_packets.expect("Swing", |p| {
+        p == &ServerboundSwing {
+            hand: InteractionHand::MainHand,
+        }
+        .into_variant()
+    });
+    sent_packets.expect_empty();
+
+    assert_eq!(
+        simulation.component::<Inventory>().held_item(),
+        &ItemStack::new(ItemKind::Dirt, 4)
+    );
+}
+
+#[test]
+fn test_drop_stack() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(ClientboundContainerSetSlot {
+        container_id: 0,
+        state_id: 1,
+        slot: 36,
+        item_stack: ItemStack::new(ItemKind::Dirt, 5),
+    });
+    simulation.tick();
+    sent_packets.clear();
+
+    simulation.trigger(DropHeldItemEvent {
+        entity: simulation.entity,
+        count: DropCount::Stack,
+    });
+    sent_packets.expect("ServerboundPlayerAction", |p| {
+        matches!(
+            p,
+            ServerboundGamePacket::PlayerAction(p) if p.action == s_player_action::Action::DropAllItems
+        )
+    });
+
+    assert_eq!(
+        simulation.component::<Inventory>().held_item(),
+        &ItemStack::Empty
+    );
+}
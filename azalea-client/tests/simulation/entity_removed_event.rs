@@ -0,0 +1,48 @@
+use azalea_client::{packet::game::EntityRemovedEvent, test_utils::prelude::*};
+use azalea_core::{entity_id::MinecraftEntityId, position::ChunkPos};
+use azalea_entity::indexing::EntityIdIndex;
+use azalea_protocol::packets::{ConnectionProtocol, game::ClientboundRemoveEntities};
+use azalea_registry::builtin::EntityKind;
+
+#[test]
+fn test_entity_removed_event() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.tick();
+
+    let removed_events = ReceivedMessages::<EntityRemovedEvent>::new(&mut simulation.app);
+
+    simulation.receive_packet(make_basic_add_entity(EntityKind::Cow, 123, (0.5, 64., 0.5)));
+    simulation.tick();
+
+    let mut cow_entity = None;
+    simulation.with_component::<EntityIdIndex>(|entity_id_index| {
+        cow_entity = entity_id_index.get_by_minecraft_entity(MinecraftEntityId(123));
+    });
+    let cow_entity = cow_entity.expect("the cow should be in our EntityIdIndex");
+    assert!(removed_events.lock().is_empty());
+
+    simulation.receive_packet(ClientboundRemoveEntities {
+        entity_ids: vec![MinecraftEntityId(123)],
+    });
+    simulation.tick();
+
+    {
+        let removed_events = removed_events.lock();
+        assert_eq!(removed_events.len(), 1);
+        assert_eq!(removed_events[0].entity, simulation.entity);
+        assert_eq!(removed_events[0].removed, cow_entity);
+        assert_eq!(removed_events[0].minecraft_id, MinecraftEntityId(123));
+    }
+    simulation.with_component::<EntityIdIndex>(|entity_id_index| {
+        assert_eq!(
+            entity_id_index.get_by_minecraft_entity(MinecraftEntityId(123)),
+            None
+        );
+    });
+    // the cow isn't loaded by any other clients, so it should be despawned
+    assert!(simulation.app.world().get_entity(cow_entity).is_err());
+}
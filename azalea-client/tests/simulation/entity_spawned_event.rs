@@ -0,0 +1,40 @@
+use azalea_client::{packet::game::EntitySpawnedEvent, test_utils::prelude::*};
+use azalea_core::{entity_id::MinecraftEntityId, position::ChunkPos};
+use azalea_entity::{EntityMetadataItems, indexing::EntityIdIndex};
+use azalea_protocol::packets::{ConnectionProtocol, game::ClientboundSetEntityData};
+use azalea_registry::builtin::EntityKind;
+
+#[test]
+fn test_entity_spawned_event() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.tick();
+
+    let spawned_events = ReceivedMessages::<EntitySpawnedEvent>::new(&mut simulation.app);
+
+    simulation.receive_packet(make_basic_add_entity(EntityKind::Cow, 123, (0.5, 64., 0.5)));
+    simulation.tick();
+    // neither metadata updates nor the server sending the same entity again should
+    // send the event again
+    simulation.receive_packet(ClientboundSetEntityData {
+        id: MinecraftEntityId(123),
+        packed_items: EntityMetadataItems(Vec::new()),
+    });
+    simulation.receive_packet(make_basic_add_entity(EntityKind::Cow, 123, (0.5, 64., 0.5)));
+    simulation.tick();
+
+    let mut cow_entity = None;
+    simulation.with_component::<EntityIdIndex>(|entity_id_index| {
+        cow_entity = entity_id_index.get_by_minecraft_entity(MinecraftEntityId(123));
+    });
+
+    let spawned_events = spawned_events.lock();
+    assert_eq!(spawned_events.len(), 1);
+    assert_eq!(spawned_events[0].entity, simulation.entity);
+    assert_eq!(Some(spawned_events[0].spawned), cow_entity);
+    assert_eq!(spawned_events[0].kind, EntityKind::Cow);
+    assert_eq!(spawned_events[0].minecraft_id, MinecraftEntityId(123));
+}
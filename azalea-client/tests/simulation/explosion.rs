@@ -0,0 +1,70 @@
+use azalea_client::{packet::game::ExplosionEvent, test_utils::prelude::*};
+use azalea_core::position::{BlockPos, ChunkPos, Vec3};
+use azalea_entity::{Physics, particle::Particle};
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{ClientboundBlockUpdate, ClientboundExplode},
+};
+use azalea_registry::builtin::{BlockKind, SoundEvent};
+
+#[test]
+fn test_explosion() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.tick();
+
+    let blocks = [BlockPos::new(1, 2, 3), BlockPos::new(2, 2, 3)];
+    for pos in blocks {
+        simulation.receive_packet(ClientboundBlockUpdate {
+            pos,
+            block_state: BlockKind::Stone.into(),
+        });
+    }
+    simulation.tick();
+    for pos in blocks {
+        assert_eq!(
+            simulation.get_block_state(pos),
+            Some(BlockKind::Stone.into())
+        );
+    }
+
+    let explosion_events = ReceivedMessages::<ExplosionEvent>::new(&mut simulation.app);
+
+    let velocity_before = simulation.component::<Physics>().velocity;
+    let knockback = Vec3::new(0.5, 0.25, -0.5);
+    simulation.receive_packet(ClientboundExplode {
+        center: Vec3::new(1.5, 2.5, 3.5),
+        radius: 4.,
+        block_count: 2,
+        player_knockback: Some(knockback),
+        explosion_particle: Particle::ExplosionEmitter,
+        explosion_sound: SoundEvent::EntityGenericExplode,
+        block_particles: vec![],
+    });
+    // since 1.21.2, the destroyed blocks are sent as separate block updates
+    for pos in blocks {
+        simulation.receive_packet(ClientboundBlockUpdate {
+            pos,
+            block_state: BlockKind::Air.into(),
+        });
+    }
+    simulation.update();
+
+    assert_eq!(
+        simulation.component::<Physics>().velocity,
+        velocity_before + knockback
+    );
+    for pos in blocks {
+        assert_eq!(simulation.get_block_state(pos), Some(BlockKind::Air.into()));
+    }
+
+    let explosion_events = explosion_events.lock();
+    assert_eq!(explosion_events.len(), 1);
+    assert_eq!(explosion_events[0].entity, simulation.entity);
+    assert_eq!(explosion_events[0].center, Vec3::new(1.5, 2.5, 3.5));
+    assert_eq!(explosion_events[0].block_count, 2);
+    assert_eq!(explosion_events[0].player_knockback, Some(knockback));
+}
@@ -0,0 +1,74 @@
+use azalea_client::test_utils::prelude::*;
+use azalea_core::position::{BlockPos, ChunkPos, Vec3};
+use azalea_entity::{LookDirection, OnClimbable, Physics};
+use azalea_protocol::{
+    common::movements::{PositionMoveRotation, RelativeMovements},
+    packets::{
+        ConnectionProtocol,
+        game::{ClientboundBlockUpdate, ClientboundPlayerPosition, ClientboundSetChunkCacheCenter},
+    },
+};
+use azalea_registry::builtin::BlockKind;
+
+fn teleport_to(simulation: &mut Simulation, pos: Vec3) {
+    simulation.receive_packet(ClientboundPlayerPosition {
+        id: 1,
+        change: PositionMoveRotation {
+            pos,
+            delta: Vec3::ZERO,
+            look_direction: LookDirection::default(),
+        },
+        relative: RelativeMovements::all_absolute(),
+    });
+    simulation.tick();
+}
+
+#[test]
+fn test_fluid_and_climbable_state() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(ClientboundSetChunkCacheCenter { x: 0, z: 0 });
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    for (x, block) in [
+        (0, BlockKind::Water),
+        (2, BlockKind::Lava),
+        (4, BlockKind::Ladder),
+        (6, BlockKind::Air),
+    ] {
+        simulation.receive_packet(ClientboundBlockUpdate {
+            pos: BlockPos::new(x, 63, 0),
+            block_state: BlockKind::Stone.into(),
+        });
+        simulation.receive_packet(ClientboundBlockUpdate {
+            pos: BlockPos::new(x, 64, 0),
+            block_state: block.into(),
+        });
+    }
+    simulation.tick();
+
+    teleport_to(&mut simulation, Vec3::new(0.5, 64., 0.5));
+    let physics = simulation.component::<Physics>();
+    assert!(physics.is_in_water());
+    assert!(!physics.is_in_lava());
+    assert!(!**simulation.component::<OnClimbable>());
+
+    teleport_to(&mut simulation, Vec3::new(2.5, 64., 0.5));
+    let physics = simulation.component::<Physics>();
+    assert!(!physics.is_in_water());
+    assert!(physics.is_in_lava());
+    assert!(!**simulation.component::<OnClimbable>());
+
+    teleport_to(&mut simulation, Vec3::new(4.5, 64., 0.5));
+    let physics = simulation.component::<Physics>();
+    assert!(!physics.is_in_water());
+    assert!(!physics.is_in_lava());
+    assert!(**simulation.component::<OnClimbable>());
+
+    teleport_to(&mut simulation, Vec3::new(6.5, 64., 0.5));
+    let physics = simulation.component::<Physics>();
+    assert!(!physics.is_in_water());
+    assert!(!physics.is_in_lava());
+    assert!(!**simulation.component::<OnClimbable>());
+}
@@ -0,0 +1,51 @@
+use azalea_client::{
+    connection::{BandwidthLimit, RawConnection},
+    packet::game::SendGamePacketEvent,
+    test_utils::prelude::*,
+};
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{ServerboundGamePacket, ServerboundKeepAlive},
+};
+
+#[test]
+fn test_flush_packets_on_disconnect() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let mut server_conn = simulation.connect_to_local_server();
+    let entity = simulation.entity;
+    simulation.app.world_mut().entity_mut(entity).insert(
+        // makes most of the packets get delayed, so they'd be lost if we didn't flush them
+        BandwidthLimit {
+            read_bps: None,
+            write_bps: Some(20),
+        },
+    );
+    simulation.update();
+
+    for id in 0..10 {
+        simulation.trigger(SendGamePacketEvent::new(
+            entity,
+            ServerboundKeepAlive { id },
+        ));
+    }
+    simulation.disconnect();
+    simulation.update();
+    assert!(!simulation.has_component::<RawConnection>());
+
+    let received_ids = simulation.rt.block_on(async {
+        let mut received_ids = Vec::new();
+        // this ends once the client closes the connection
+        while let Ok(packet) = server_conn.read().await {
+            if let ServerboundGamePacket::KeepAlive(p) = packet {
+                received_ids.push(p.id);
+            }
+        }
+        received_ids
+    });
+    assert_eq!(received_ids, (0..10).collect::<Vec<_>>());
+}
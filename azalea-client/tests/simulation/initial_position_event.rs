@@ -0,0 +1,66 @@
+use azalea_client::{packet::game::InitialPositionEvent, test_utils::prelude::*};
+use azalea_core::position::{ChunkPos, Vec3};
+use azalea_entity::LookDirection;
+use azalea_protocol::{
+    common::movements::{PositionMoveRotation, RelativeMovements},
+    packets::{ConnectionProtocol, game::ClientboundPlayerPosition},
+};
+use azalea_registry::{DataRegistry, data::DimensionKind, identifier::Identifier};
+
+fn teleport_packet(id: u32, pos: Vec3) -> ClientboundPlayerPosition {
+    ClientboundPlayerPosition {
+        id,
+        change: PositionMoveRotation {
+            pos,
+            delta: Vec3::ZERO,
+            look_direction: LookDirection::default(),
+        },
+        relative: RelativeMovements::all_absolute(),
+    }
+}
+
+#[test]
+fn test_initial_position_event() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+
+    let initial_position_events =
+        ReceivedMessages::<InitialPositionEvent>::new(&mut simulation.app);
+    let positions = || {
+        initial_position_events
+            .lock()
+            .iter()
+            .map(|event| event.position)
+            .collect::<Vec<_>>()
+    };
+
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.tick();
+    // we don't know where we are yet
+    assert!(positions().is_empty());
+
+    simulation.receive_packet(teleport_packet(1, Vec3::new(1.5, 70., 2.5)));
+    simulation.tick();
+    assert_eq!(positions(), [Vec3::new(1.5, 70., 2.5)]);
+
+    // later teleports shouldn't send the event again
+    simulation.receive_packet(teleport_packet(2, Vec3::new(3.5, 70., 4.5)));
+    simulation.tick();
+    assert_eq!(positions().len(), 1);
+
+    // but it should be sent again after we respawn
+    simulation.receive_packet(make_basic_respawn_packet(
+        DimensionKind::new_raw(0),
+        Identifier::new("minecraft:overworld"),
+    ));
+    simulation.tick();
+    assert_eq!(positions().len(), 1);
+    simulation.receive_packet(teleport_packet(3, Vec3::new(5.5, 70., 6.5)));
+    simulation.tick();
+    assert_eq!(
+        positions(),
+        [Vec3::new(1.5, 70., 2.5), Vec3::new(5.5, 70., 6.5)]
+    );
+}
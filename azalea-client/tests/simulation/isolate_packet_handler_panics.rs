@@ -0,0 +1,67 @@
+use azalea_auth::game_profile::GameProfile;
+use azalea_client::{
+    connection::{PacketParseSettings, RawConnection},
+    local_player::TabList,
+    test_utils::prelude::*,
+};
+use azalea_protocol::packets::{
+    ConnectionProtocol, Packet,
+    game::{
+        ClientboundPlayerInfoUpdate,
+        c_player_info_update::{ActionEnumSet, PlayerInfoEntry},
+    },
+};
+use uuid::Uuid;
+
+#[test]
+fn test_isolate_packet_handler_panics() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    simulation.app.insert_resource(PacketParseSettings {
+        catch_panics: true,
+        ..Default::default()
+    });
+
+    let uuid = Uuid::from_u128(5678);
+    let packet = ClientboundPlayerInfoUpdate {
+        actions: ActionEnumSet {
+            add_player: true,
+            initialize_chat: false,
+            update_game_mode: false,
+            update_listed: false,
+            update_latency: false,
+            update_display_name: false,
+            update_hat: false,
+            update_list_order: false,
+        },
+        entries: vec![PlayerInfoEntry {
+            profile: GameProfile::new(uuid, "player".to_owned()),
+            ..Default::default()
+        }],
+    };
+
+    // this client is missing its TabList, so the handler for the packet will panic
+    let mut broken_conn = RawConnection::new_networkless(ConnectionProtocol::Game);
+    broken_conn
+        .injected_clientbound_packets
+        .push(azalea_protocol::write::serialize_packet(&packet.clone().into_variant()).unwrap());
+    let broken_client = simulation.app.world_mut().spawn(broken_conn).id();
+
+    simulation.receive_packet(packet);
+    simulation.tick();
+
+    let broken_conn = simulation
+        .app
+        .world()
+        .get::<RawConnection>(broken_client)
+        .unwrap();
+    assert!(!broken_conn.is_alive());
+
+    // the other client should be unaffected
+    assert!(simulation.component::<TabList>().contains_key(&uuid));
+    simulation.with_component::<RawConnection>(|raw_conn| assert!(raw_conn.is_alive()));
+}
@@ -0,0 +1,59 @@
+use azalea_client::{cooldowns::ItemCooldowns, test_utils::prelude::*};
+use azalea_inventory::ItemStack;
+use azalea_protocol::packets::{ConnectionProtocol, game::ClientboundCooldown};
+use azalea_registry::{builtin::ItemKind, identifier::Identifier};
+
+#[test]
+fn test_item_cooldowns() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let ender_pearl = ItemStack::from(ItemKind::EnderPearl);
+    let chorus_fruit = ItemStack::from(ItemKind::ChorusFruit);
+    assert!(
+        !simulation
+            .component::<ItemCooldowns>()
+            .is_on_cooldown(&ender_pearl)
+    );
+
+    simulation.receive_packet(ClientboundCooldown {
+        cooldown_group: Identifier::new("minecraft:ender_pearl"),
+        duration: 2,
+    });
+    simulation.tick();
+    let cooldowns = simulation.component::<ItemCooldowns>();
+    assert!(cooldowns.is_on_cooldown(&ender_pearl));
+    assert!(!cooldowns.is_on_cooldown(&chorus_fruit));
+
+    simulation.tick();
+    assert!(
+        !simulation
+            .component::<ItemCooldowns>()
+            .is_on_cooldown(&ender_pearl)
+    );
+
+    // a duration of 0 removes the cooldown immediately
+    simulation.receive_packet(ClientboundCooldown {
+        cooldown_group: Identifier::new("minecraft:chorus_fruit"),
+        duration: 20,
+    });
+    simulation.tick();
+    assert!(
+        simulation
+            .component::<ItemCooldowns>()
+            .is_on_cooldown(&chorus_fruit)
+    );
+    simulation.receive_packet(ClientboundCooldown {
+        cooldown_group: Identifier::new("minecraft:chorus_fruit"),
+        duration: 0,
+    });
+    simulation.update();
+    assert!(
+        !simulation
+            .component::<ItemCooldowns>()
+            .is_on_cooldown(&chorus_fruit)
+    );
+}
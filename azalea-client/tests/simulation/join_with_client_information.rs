@@ -0,0 +1,28 @@
+use azalea_client::{DefaultPlugins, account::Account, test_utils::prelude::*};
+use azalea_entity::LocalEntity;
+use azalea_protocol::common::client_information::ClientInformation;
+use bevy_app::{App, PluginGroup};
+use bevy_ecs::query::With;
+
+#[test]
+fn test_join_with_client_information() {
+    let _lock = init();
+
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins.build().disable::<bevy_log::LogPlugin>());
+
+    let client_information = ClientInformation {
+        language: "de_de".to_owned(),
+        view_distance: 4,
+        ..Default::default()
+    };
+    let login = join_fake_server(&mut app, Account::offline("bot"), |opts| {
+        opts.client_information = Some(client_information.clone());
+    });
+    assert_eq!(login.hello.name, "bot");
+
+    let mut query = app
+        .world_mut()
+        .query_filtered::<&ClientInformation, With<LocalEntity>>();
+    assert_eq!(query.single(app.world()).unwrap(), &client_information);
+}
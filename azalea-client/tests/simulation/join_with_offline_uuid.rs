@@ -0,0 +1,23 @@
+use azalea_client::{DefaultPlugins, account::Account, test_utils::prelude::*};
+use azalea_entity::indexing::EntityUuidIndex;
+use bevy_app::{App, PluginGroup};
+use uuid::Uuid;
+
+#[test]
+fn test_join_with_offline_uuid() {
+    let _lock = init();
+
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins.build().disable::<bevy_log::LogPlugin>());
+
+    let uuid = Uuid::from_u128(0x1234);
+    let login = join_fake_server(&mut app, Account::offline_with_uuid("bot", uuid), |_| {});
+    assert_eq!(login.hello.name, "bot");
+    assert_eq!(login.hello.profile_id, uuid);
+
+    assert!(
+        app.world()
+            .resource::<EntityUuidIndex>()
+            .contains_key(&uuid)
+    );
+}
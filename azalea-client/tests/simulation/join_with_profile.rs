@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use azalea_auth::game_profile::{GameProfile, GameProfileProperties, ProfilePropertyValue};
+use azalea_client::{
+    DefaultPlugins, account::Account, player::GameProfileComponent, test_utils::prelude::*,
+};
+use azalea_entity::indexing::EntityUuidIndex;
+use bevy_app::{App, PluginGroup};
+use indexmap::IndexMap;
+use uuid::Uuid;
+
+#[test]
+fn test_join_with_profile() {
+    let _lock = init();
+
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins.build().disable::<bevy_log::LogPlugin>());
+
+    let uuid = Uuid::from_u128(0x1234);
+    let profile = GameProfile {
+        uuid,
+        name: "bot".to_owned(),
+        properties: Arc::new(GameProfileProperties {
+            map: IndexMap::from([(
+                "textures".to_owned(),
+                ProfilePropertyValue {
+                    value: "e30=".to_owned(),
+                    signature: None,
+                },
+            )]),
+        }),
+    };
+    let account = Account::from_profile(profile.clone());
+    // this is what makes sure that we never try to contact mojang
+    assert!(account.access_token().is_none());
+
+    let login = join_fake_server(&mut app, account, |_| {});
+    assert_eq!(login.hello.name, "bot");
+    assert_eq!(login.hello.profile_id, uuid);
+
+    let entity = app
+        .world()
+        .resource::<EntityUuidIndex>()
+        .get(&uuid)
+        .unwrap();
+    // the profile is available before the server sends us one
+    let profile_component = app.world().get::<GameProfileComponent>(entity).unwrap();
+    assert_eq!(**profile_component, profile);
+}
@@ -0,0 +1,107 @@
+use std::time::Instant;
+
+use azalea_client::{
+    keep_alive::{KeepAliveDelay, PendingKeepAlives},
+    local_player::ClientRng,
+    test_utils::prelude::*,
+};
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{ClientboundKeepAlive, ServerboundGamePacket},
+};
+
+/// Returns whether a keepalive reply with the given ID was sent, and clears the
+/// list of sent packets.
+fn sent_keep_alive(sent_packets: &SentPackets, id: u64) -> bool {
+    let mut sent = false;
+    while let Some(packet) = sent_packets.next() {
+        if let ServerboundGamePacket::KeepAlive(p) = packet {
+            assert_eq!(p.id, id);
+            sent = true;
+        }
+    }
+    sent
+}
+
+#[test]
+fn test_keep_alive_without_delay() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+    sent_packets.clear();
+
+    simulation.receive_packet(ClientboundKeepAlive { id: 1 });
+    simulation.update();
+    assert!(sent_keep_alive(&sent_packets, 1));
+}
+
+#[test]
+fn test_keep_alive_delay() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+    sent_packets.clear();
+
+    let entity = simulation.entity;
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(entity)
+        .insert((ClientRng::new(0), KeepAliveDelay::new(3..=3)));
+
+    simulation.receive_packet(ClientboundKeepAlive { id: 2 });
+    simulation.update();
+    assert!(!sent_keep_alive(&sent_packets, 2));
+    simulation.tick();
+    simulation.tick();
+    assert!(!sent_keep_alive(&sent_packets, 2));
+    simulation.tick();
+    assert!(sent_keep_alive(&sent_packets, 2));
+}
+
+#[test]
+fn test_keep_alive_delay_bounds() {
+    let mut rng = ClientRng::new(1234);
+
+    let delay = KeepAliveDelay::new(5..=10);
+    for _ in 0..1000 {
+        let ticks = delay.pick_delay(Some(&mut rng));
+        assert!((5..=10).contains(&ticks), "{ticks} is out of bounds");
+    }
+}
+
+#[test]
+fn test_keep_alive_delay_has_deadline() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+    sent_packets.clear();
+
+    // a delay that would make us time out if the server is ticking slowly
+    let entity = simulation.entity;
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(entity)
+        .insert((ClientRng::new(0), KeepAliveDelay::new(1000..=1000)));
+
+    simulation.receive_packet(ClientboundKeepAlive { id: 3 });
+    simulation.tick();
+    assert!(!sent_keep_alive(&sent_packets, 3));
+
+    // pretend that the maximum delay passed without the server ticking
+    simulation.with_component_mut::<PendingKeepAlives>(|pending| {
+        pending.0[0].deadline = Instant::now();
+    });
+    simulation.update();
+    assert!(sent_keep_alive(&sent_packets, 3));
+}
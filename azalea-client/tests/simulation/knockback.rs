@@ -0,0 +1,101 @@
+use azalea_client::test_utils::prelude::*;
+use azalea_core::{
+    delta::LpVec3,
+    entity_id::MinecraftEntityId,
+    position::{BlockPos, ChunkPos, Vec3},
+};
+use azalea_entity::{LookDirection, Physics, Position, indexing::EntityIdIndex};
+use azalea_protocol::{
+    common::movements::{PositionMoveRotation, RelativeMovements},
+    packets::{
+        ConnectionProtocol,
+        game::{
+            ClientboundBlockUpdate, ClientboundPlayerPosition, ClientboundSetChunkCacheCenter,
+            ClientboundSetEntityMotion, ServerboundGamePacket,
+        },
+    },
+};
+use azalea_registry::builtin::{BlockKind, EntityKind};
+
+#[test]
+fn test_knockback_from_set_entity_motion() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(ClientboundSetChunkCacheCenter { x: 0, z: 0 });
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.receive_packet(ClientboundBlockUpdate {
+        pos: BlockPos::new(0, 63, 0),
+        block_state: BlockKind::Stone.into(),
+    });
+    simulation.receive_packet(ClientboundPlayerPosition {
+        id: 1,
+        change: PositionMoveRotation {
+            pos: Vec3::new(0.5, 64., 0.5),
+            delta: Vec3::ZERO,
+            look_direction: LookDirection::default(),
+        },
+        relative: RelativeMovements::all_absolute(),
+    });
+    simulation.tick();
+    simulation.tick();
+    assert_eq!(
+        *simulation.component::<Position>(),
+        Vec3::new(0.5, 64., 0.5)
+    );
+
+    let knockback = LpVec3::from(Vec3::new(0.4, 0.3, 0.));
+    simulation.receive_packet(ClientboundSetEntityMotion {
+        id: MinecraftEntityId(0),
+        delta: knockback,
+    });
+    sent_packets.clear();
+    simulation.tick();
+
+    // we moved by exactly the velocity that the server sent us
+    let position = *simulation.component::<Position>();
+    let expected_position = Vec3::new(0.5, 64., 0.5) + knockback.to_vec3();
+    assert!(
+        (position.x - expected_position.x).abs() < 1e-9
+            && (position.y - expected_position.y).abs() < 1e-9,
+        "{position:?} != {expected_position:?}"
+    );
+    assert!((position.x - 0.9).abs() < 0.001);
+
+    // and the server was told about it
+    let mut sent_position = None;
+    while let Some(packet) = sent_packets.next() {
+        if let ServerboundGamePacket::MovePlayerPos(p) = packet {
+            sent_position = Some(p.pos);
+        }
+    }
+    assert_eq!(sent_position, Some(position));
+}
+
+#[test]
+fn test_set_entity_motion_for_other_entity() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.receive_packet(make_basic_add_entity(EntityKind::Cow, 123, (0.5, 64., 0.5)));
+    simulation.tick();
+
+    let knockback = LpVec3::from(Vec3::new(-0.2, 0.4, 0.1));
+    simulation.receive_packet(ClientboundSetEntityMotion {
+        id: MinecraftEntityId(123),
+        delta: knockback,
+    });
+    simulation.update();
+
+    let mut cow = None;
+    simulation.with_component::<EntityIdIndex>(|entity_id_index| {
+        cow = entity_id_index.get_by_minecraft_entity(MinecraftEntityId(123));
+    });
+    let cow_physics = simulation.app.world().get::<Physics>(cow.unwrap()).unwrap();
+    assert_eq!(cow_physics.velocity, knockback.to_vec3());
+}
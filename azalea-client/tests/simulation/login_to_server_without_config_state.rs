@@ -0,0 +1,72 @@
+use azalea_auth::game_profile::GameProfile;
+use azalea_client::{
+    InConfigState,
+    connection::RawConnection,
+    disconnect::DisconnectEvent,
+    packet::login::{AwaitingFirstConfigPacket, InLoginState},
+    test_utils::prelude::*,
+};
+use azalea_protocol::packets::{
+    ConnectionProtocol, config::ClientboundUpdateEnabledFeatures, login::ClientboundLoginFinished,
+};
+use uuid::Uuid;
+
+fn simulation_in_login_state() -> Simulation {
+    let mut simulation = Simulation::new(ConnectionProtocol::Configuration);
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(simulation.entity)
+        .remove::<InConfigState>()
+        .insert(InLoginState);
+    simulation.with_component_mut::<RawConnection>(|conn| {
+        conn.state = ConnectionProtocol::Login;
+    });
+    simulation
+}
+
+fn login_finished_packet() -> ClientboundLoginFinished {
+    ClientboundLoginFinished {
+        game_profile: GameProfile::new(Uuid::from_u128(1234), "azalea".to_owned()),
+    }
+}
+
+#[test]
+fn test_login_to_server_without_config_state() {
+    let _lock = init();
+
+    let mut simulation = simulation_in_login_state();
+
+    let disconnect_events = ReceivedMessages::<DisconnectEvent>::new(&mut simulation.app);
+
+    simulation.receive_packet(login_finished_packet());
+    simulation.tick();
+    assert!(simulation.has_component::<InConfigState>());
+    assert!(simulation.has_component::<AwaitingFirstConfigPacket>());
+
+    // an old server would skip the configuration state and go straight to the
+    // game state
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let disconnect_events = disconnect_events.lock();
+    assert_eq!(disconnect_events.len(), 1);
+    let reason = disconnect_events[0].reason.as_ref().unwrap().to_string();
+    assert!(reason.contains("1.20.2"), "unexpected reason: {reason}");
+    assert!(!simulation.has_component::<RawConnection>());
+}
+
+#[test]
+fn test_login_to_server_with_config_state() {
+    let _lock = init();
+
+    let mut simulation = simulation_in_login_state();
+    simulation.receive_packet(login_finished_packet());
+    simulation.tick();
+
+    simulation.receive_packet(ClientboundUpdateEnabledFeatures { features: vec![] });
+    simulation.tick();
+    assert!(simulation.has_component::<InConfigState>());
+    assert!(!simulation.has_component::<AwaitingFirstConfigPacket>());
+    assert!(simulation.has_component::<RawConnection>());
+}
@@ -0,0 +1,71 @@
+use azalea_client::{
+    mining::{MineProgress, Mining, StartMiningBlockEvent},
+    test_utils::prelude::*,
+};
+use azalea_core::position::{BlockPos, ChunkPos, Vec3};
+use azalea_entity::LookDirection;
+use azalea_protocol::{
+    common::movements::{PositionMoveRotation, RelativeMovements},
+    packets::{
+        ConnectionProtocol,
+        game::{ClientboundBlockUpdate, ClientboundPlayerPosition},
+    },
+};
+use azalea_registry::builtin::BlockKind;
+
+#[test]
+fn test_mine_block_progress_increases() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.tick();
+
+    let pos = BlockPos::new(0, 2, 0);
+    simulation.receive_packet(ClientboundBlockUpdate {
+        pos,
+        block_state: BlockKind::Stone.into(),
+    });
+    simulation.receive_packet(ClientboundPlayerPosition {
+        id: 1,
+        change: PositionMoveRotation {
+            pos: pos.up(1).center_bottom(),
+            delta: Vec3::ZERO,
+            look_direction: LookDirection::default(),
+        },
+        relative: RelativeMovements::all_absolute(),
+    });
+    simulation.tick();
+    simulation.with_component_mut::<LookDirection>(|look| {
+        // look down
+        look.update_x_rot(90.);
+    });
+    simulation.tick();
+
+    assert!(!simulation.has_component::<Mining>());
+
+    simulation.write_message(StartMiningBlockEvent {
+        entity: simulation.entity,
+        position: pos,
+        force: false,
+    });
+    simulation.tick();
+    assert!(simulation.has_component::<Mining>());
+
+    // mining stone by hand takes 150 ticks, so the progress should go up a
+    // little bit every tick without ever going past 1
+    let mut last_progress = *simulation.component::<MineProgress>();
+    for _ in 0..100 {
+        simulation.tick();
+        assert!(simulation.has_component::<Mining>());
+        let progress = *simulation.component::<MineProgress>();
+        assert!(
+            progress > last_progress,
+            "progress went from {last_progress} to {progress}"
+        );
+        assert!(progress < 1.);
+        last_progress = progress;
+    }
+}
@@ -1,25 +1,78 @@
 // This file is @generated by `azalea-client/build.rs`.
 
+mod advancements_and_recipes;
+mod block_entities;
 mod change_dimension_to_nether_and_back;
+mod chat_length_limit;
+mod chat_session_update;
+mod chunk_batch_rate_changed;
+mod clamp_desired_chunks_per_tick;
 mod client_disconnect;
+mod client_rng_is_deterministic;
 mod close_open_container;
+mod container_set_data;
+mod cookies;
 mod correct_movement;
 mod correct_sneak_movement;
 mod correct_sprint_sneak_movement;
+mod death_event_killer;
+mod decode_error_handler;
 mod despawn_entities_when_changing_dimension;
+mod disconnect_reason;
+mod drop_item;
 mod enchantments;
+mod entity_removed_event;
+mod entity_spawned_event;
+mod explosion;
 mod fast_login;
+mod fluid_and_climbable_state;
+mod flush_packets_on_disconnect;
+mod initial_position_event;
+mod isolate_packet_handler_panics;
+mod item_cooldowns;
+mod join_with_client_information;
+mod join_with_offline_uuid;
+mod join_with_profile;
+mod keep_alive_delay;
+mod knockback;
 mod login_to_dimension_with_same_name;
+mod login_to_server_without_config_state;
+mod mine_block_progress_increases;
 mod mine_block_rollback;
 mod mine_block_timing_hand;
 mod mine_block_without_rollback;
 mod move_and_despawn_entity;
 mod move_despawned_entity;
+mod no_movement_while_dead;
+mod on_ground_after_falling;
+mod order_systems_against_public_sets;
 mod packet_order;
 mod packet_order_set_carried_item;
+mod packet_rate_limit;
+mod player_info_update_actions;
+mod player_rotation;
+mod quick_move_from_chest;
 mod receive_spawn_entity_and_start_config_packet;
 mod receive_start_config_packet;
 mod reply_to_ping_with_pong;
+mod request_statistics;
+mod respawn_event;
+mod ride_horse;
+mod send_position_on_demand;
+mod set_default_spawn_position;
 mod set_health_before_login;
+mod set_health_food_saturation;
+mod set_velocity;
+mod skip_unknown_packet;
+mod speed_effect_movement;
+mod swing_arm;
+mod tab_list_header_footer;
+mod teleport_filter;
 mod teleport_movement;
+mod throttle_chunk_batches;
+mod ticking_state;
 mod ticks_alive;
+mod update_attributes;
+mod update_dimension_kind_on_respawn;
+mod wait_for_chat_to_be_sent;
+mod world_border;
@@ -0,0 +1,73 @@
+use azalea_client::{StartWalkEvent, WalkDirection, test_utils::prelude::*};
+use azalea_core::position::{BlockPos, ChunkPos, Vec3};
+use azalea_entity::{Dead, LookDirection};
+use azalea_protocol::{
+    common::movements::{PositionMoveRotation, RelativeMovements},
+    packets::{
+        ConnectionProtocol,
+        game::{
+            ClientboundBlockUpdate, ClientboundPlayerPosition, ClientboundSetChunkCacheCenter,
+            ClientboundSetHealth, ServerboundGamePacket,
+        },
+    },
+};
+use azalea_registry::builtin::BlockKind;
+
+#[test]
+fn test_no_movement_while_dead() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(ClientboundSetChunkCacheCenter { x: 0, z: 0 });
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.receive_packet(ClientboundBlockUpdate {
+        pos: BlockPos::new(0, 63, 0),
+        block_state: BlockKind::Stone.into(),
+    });
+    simulation.receive_packet(ClientboundPlayerPosition {
+        id: 1,
+        change: PositionMoveRotation {
+            pos: Vec3::new(0.5, 64., 0.5),
+            delta: Vec3::ZERO,
+            look_direction: LookDirection::default(),
+        },
+        relative: RelativeMovements::all_absolute(),
+    });
+    simulation.tick();
+    simulation.tick();
+
+    simulation.receive_packet(ClientboundSetHealth {
+        health: 0.,
+        food: 20,
+        saturation: 5.,
+    });
+    simulation.tick();
+    assert!(simulation.has_component::<Dead>());
+
+    simulation.write_message(StartWalkEvent {
+        entity: simulation.entity,
+        direction: WalkDirection::Forward,
+    });
+    sent_packets.clear();
+    // long enough that we'd normally send a position packet even without moving
+    for _ in 0..25 {
+        simulation.tick();
+    }
+
+    while let Some(packet) = sent_packets.next() {
+        assert!(
+            !matches!(
+                packet,
+                ServerboundGamePacket::PlayerInput(_)
+                    | ServerboundGamePacket::MovePlayerPos(_)
+                    | ServerboundGamePacket::MovePlayerPosRot(_)
+                    | ServerboundGamePacket::MovePlayerRot(_)
+                    | ServerboundGamePacket::MovePlayerStatusOnly(_)
+            ),
+            "sent a movement packet while dead: {packet:?}"
+        );
+    }
+}
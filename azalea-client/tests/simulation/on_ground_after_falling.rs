@@ -0,0 +1,89 @@
+use azalea_client::test_utils::prelude::*;
+use azalea_core::position::{BlockPos, ChunkPos, Vec3};
+use azalea_entity::{LookDirection, Physics, Position};
+use azalea_protocol::{
+    common::movements::{PositionMoveRotation, RelativeMovements},
+    packets::{
+        ConnectionProtocol,
+        game::{
+            ClientboundBlockUpdate, ClientboundMoveEntityRot, ClientboundPlayerPosition,
+            ClientboundSetChunkCacheCenter, ServerboundGamePacket,
+        },
+    },
+};
+use azalea_registry::builtin::BlockKind;
+
+fn sent_on_ground(packet: &ServerboundGamePacket) -> Option<bool> {
+    match packet {
+        ServerboundGamePacket::MovePlayerPos(p) => Some(p.flags.on_ground),
+        ServerboundGamePacket::MovePlayerPosRot(p) => Some(p.flags.on_ground),
+        ServerboundGamePacket::MovePlayerRot(p) => Some(p.flags.on_ground),
+        ServerboundGamePacket::MovePlayerStatusOnly(p) => Some(p.flags.on_ground),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_on_ground_after_falling() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(ClientboundSetChunkCacheCenter { x: 0, z: 0 });
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.receive_packet(ClientboundBlockUpdate {
+        pos: BlockPos::new(0, 63, 0),
+        block_state: BlockKind::Stone.into(),
+    });
+    simulation.receive_packet(ClientboundPlayerPosition {
+        id: 1,
+        change: PositionMoveRotation {
+            pos: Vec3::new(0.5, 67., 0.5),
+            delta: Vec3::ZERO,
+            look_direction: LookDirection::default(),
+        },
+        relative: RelativeMovements::all_absolute(),
+    });
+    simulation.tick();
+    assert!(!simulation.component::<Physics>().on_ground());
+
+    let mut landed_at_tick = None;
+    for tick in 0..20 {
+        simulation.tick();
+        let on_ground = simulation.component::<Physics>().on_ground();
+        let y = simulation.component::<Position>().y;
+        if landed_at_tick.is_none() && on_ground {
+            assert_eq!(
+                y, 64.,
+                "on_ground should only be true once we're on the block"
+            );
+            landed_at_tick = Some(tick);
+        }
+        if landed_at_tick.is_none() {
+            assert!(y > 64., "we fell through the block");
+        }
+    }
+    assert!(landed_at_tick.is_some(), "never landed on the block");
+
+    // the last packet that we sent should say that we're on the ground
+    let mut last_sent_on_ground = None;
+    while let Some(packet) = sent_packets.next() {
+        if let Some(on_ground) = sent_on_ground(&packet) {
+            last_sent_on_ground = Some(on_ground);
+        }
+    }
+    assert_eq!(last_sent_on_ground, Some(true));
+
+    // the server sending a movement packet for our own entity shouldn't change
+    // whether we're on the ground
+    simulation.receive_packet(ClientboundMoveEntityRot {
+        entity_id: simulation.minecraft_entity_id(),
+        y_rot: 0,
+        x_rot: 0,
+        on_ground: false,
+    });
+    simulation.update();
+    assert!(simulation.component::<Physics>().on_ground());
+}
@@ -0,0 +1,73 @@
+use azalea_client::{
+    movement::SendMovementSystems, packet::game::SendGamePacketEvent, test_utils::prelude::*,
+    tick_end::TickEndSystems,
+};
+use azalea_core::{
+    position::{ChunkPos, Vec3},
+    tick::GameTick,
+};
+use azalea_entity::{LocalEntity, LookDirection};
+use azalea_protocol::{
+    common::movements::{PositionMoveRotation, RelativeMovements},
+    packets::{
+        ConnectionProtocol,
+        game::{
+            ClientboundPlayerPosition, ServerboundGamePacket, ServerboundSwing,
+            s_interact::InteractionHand,
+        },
+    },
+};
+use bevy_ecs::prelude::*;
+
+#[test]
+fn test_order_systems_against_public_sets() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.receive_packet(ClientboundPlayerPosition {
+        id: 1,
+        change: PositionMoveRotation {
+            pos: Vec3::new(1.5, 2., 3.5),
+            delta: Vec3::ZERO,
+            look_direction: LookDirection::default(),
+        },
+        relative: RelativeMovements::all_absolute(),
+    });
+    simulation.tick();
+    sent_packets.clear();
+
+    // a user plugin that wants its packet to be sent after our position but
+    // still be part of the same tick
+    simulation.app.add_systems(
+        GameTick,
+        swing_every_tick
+            .after(SendMovementSystems)
+            .before(TickEndSystems),
+    );
+
+    // it takes a tick for on_ground to be true, so a movement packet is sent here
+    simulation.tick();
+    sent_packets.expect("MovePlayerStatusOnly", |p| {
+        matches!(p, ServerboundGamePacket::MovePlayerStatusOnly(_))
+    });
+    sent_packets.expect("Swing", |p| matches!(p, ServerboundGamePacket::Swing(_)));
+    sent_packets.expect_tick_end();
+    sent_packets.expect_empty();
+}
+
+fn swing_every_tick(mut commands: Commands, query: Query<Entity, With<LocalEntity>>) {
+    for entity in query.iter() {
+        commands.trigger(SendGamePacketEvent::new(
+            entity,
+            ServerboundSwing {
+                hand: InteractionHand::MainHand,
+            },
+        ));
+    }
+}
@@ -0,0 +1,246 @@
+use std::time::{Duration, Instant};
+
+use azalea_client::{
+    connection::{FlushPacketsEvent, PacketRateLimit, PacketRateLimitPolicy, RawConnection},
+    packet::game::SendGamePacketEvent,
+    test_utils::prelude::*,
+};
+use azalea_protocol::{
+    connect::Connection,
+    packets::{
+        ConnectionProtocol,
+        game::{
+            ClientboundGamePacket, ServerboundGamePacket, ServerboundKeepAlive,
+            ServerboundSelectTrade,
+        },
+    },
+};
+use tokio::sync::oneshot;
+
+/// Send packets that count towards the packet rate limit, with their index as
+/// the trade id so we can tell them apart.
+fn send_rate_limited_packets(simulation: &mut Simulation, count: u32) {
+    let entity = simulation.entity;
+    for item in 0..count {
+        simulation.trigger(SendGamePacketEvent::new(
+            entity,
+            ServerboundSelectTrade { item },
+        ));
+    }
+}
+
+/// Disconnect and return the trade ids of every packet that the server
+/// received.
+fn disconnect_and_read_trades(
+    simulation: &mut Simulation,
+    server_conn: &mut Connection<ServerboundGamePacket, ClientboundGamePacket>,
+) -> Vec<u32> {
+    simulation.disconnect();
+    simulation.update();
+    simulation.rt.block_on(async {
+        let mut received_items = Vec::new();
+        while let Ok(packet) = server_conn.read().await {
+            if let ServerboundGamePacket::SelectTrade(p) = packet {
+                received_items.push(p.item);
+            }
+        }
+        received_items
+    })
+}
+
+fn packets_dropped(simulation: &mut Simulation) -> u64 {
+    let entity = simulation.entity;
+    simulation
+        .app
+        .world_mut()
+        .get_mut::<RawConnection>(entity)
+        .unwrap()
+        .net_conn()
+        .unwrap()
+        .packets_dropped()
+}
+
+fn connected_simulation(
+    limit: PacketRateLimit,
+) -> (
+    Simulation,
+    Connection<ServerboundGamePacket, ClientboundGamePacket>,
+) {
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    simulation.app.insert_resource(limit);
+    let server_conn = simulation.connect_to_local_server();
+    simulation.update();
+    (simulation, server_conn)
+}
+
+#[test]
+fn test_packet_rate_limit_buffers_packets() {
+    let _lock = init();
+
+    let limit = PacketRateLimit::new(20).max_buffered(1000);
+    let (mut simulation, mut server_conn) = connected_simulation(limit);
+
+    let start = Instant::now();
+    send_rate_limited_packets(&mut simulation, 1000);
+    simulation.update();
+
+    let mut received_items = Vec::new();
+    simulation.rt.block_on(async {
+        // the rest of the packets won't be sent until the next update
+        while let Ok(packet) =
+            tokio::time::timeout(Duration::from_millis(200), server_conn.read()).await
+        {
+            if let ServerboundGamePacket::SelectTrade(p) = packet.unwrap() {
+                received_items.push(p.item);
+            }
+        }
+    });
+    // the limit refills over time, so a few more packets than the burst might've
+    // been sent depending on how long this took
+    let refilled = (start.elapsed().as_secs_f64() * limit.packets_per_second as f64).ceil();
+    assert!(
+        received_items.len() >= limit.burst as usize
+            && received_items.len() <= limit.burst as usize + refilled as usize,
+        "{} packets were sent before the buffer was flushed",
+        received_items.len()
+    );
+
+    // the buffered packets are flushed when we disconnect
+    received_items.extend(disconnect_and_read_trades(
+        &mut simulation,
+        &mut server_conn,
+    ));
+    assert_eq!(received_items, (0..1000).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_packet_rate_limit_drops_packets() {
+    let _lock = init();
+
+    let limit = PacketRateLimit::new(20).policy(PacketRateLimitPolicy::Drop);
+    let (mut simulation, mut server_conn) = connected_simulation(limit);
+
+    send_rate_limited_packets(&mut simulation, 1000);
+    let dropped = packets_dropped(&mut simulation);
+
+    let received_items = disconnect_and_read_trades(&mut simulation, &mut server_conn);
+    assert_eq!(received_items, (0..limit.burst).collect::<Vec<_>>());
+    assert_eq!(dropped, 1000 - limit.burst as u64);
+}
+
+#[test]
+fn test_packet_rate_limit_buffer_is_bounded() {
+    let _lock = init();
+
+    let limit = PacketRateLimit::new(20).max_buffered(100);
+    let (mut simulation, mut server_conn) = connected_simulation(limit);
+
+    send_rate_limited_packets(&mut simulation, 1000);
+    let dropped = packets_dropped(&mut simulation);
+
+    let received_items = disconnect_and_read_trades(&mut simulation, &mut server_conn);
+    // the burst is sent immediately and then the buffer fills up
+    assert!(
+        received_items.len() >= (limit.burst + limit.max_buffered) as usize,
+        "only {} packets were sent",
+        received_items.len()
+    );
+    assert!(received_items.len() < 1000);
+    assert_eq!(received_items.len() as u64 + dropped, 1000);
+}
+
+#[test]
+fn test_packet_rate_limit_never_drops_keep_alives() {
+    let _lock = init();
+
+    let limit = PacketRateLimit::new(20).policy(PacketRateLimitPolicy::Drop);
+    let (mut simulation, mut server_conn) = connected_simulation(limit);
+
+    let entity = simulation.entity;
+    for id in 0..100 {
+        simulation.trigger(SendGamePacketEvent::new(
+            entity,
+            ServerboundKeepAlive { id },
+        ));
+    }
+    assert_eq!(packets_dropped(&mut simulation), 0);
+
+    simulation.disconnect();
+    simulation.update();
+    let received_ids = simulation.rt.block_on(async {
+        let mut received_ids = Vec::new();
+        while let Ok(packet) = server_conn.read().await {
+            if let ServerboundGamePacket::KeepAlive(p) = packet {
+                received_ids.push(p.id);
+            }
+        }
+        received_ids
+    });
+    assert_eq!(received_ids, (0..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_packet_rate_limit_exempt_packets_skip_buffered_packets() {
+    let _lock = init();
+
+    let limit = PacketRateLimit::new(20).max_buffered(1000);
+    let (mut simulation, mut server_conn) = connected_simulation(limit);
+    let entity = simulation.entity;
+
+    send_rate_limited_packets(&mut simulation, 100);
+    simulation.trigger(SendGamePacketEvent::new(
+        entity,
+        ServerboundKeepAlive { id: 1 },
+    ));
+    simulation.update();
+
+    let trades_before_keep_alive = simulation.rt.block_on(async {
+        let mut trades = 0;
+        loop {
+            let packet = tokio::time::timeout(Duration::from_secs(5), server_conn.read())
+                .await
+                .unwrap()
+                .unwrap();
+            match packet {
+                ServerboundGamePacket::SelectTrade(_) => trades += 1,
+                ServerboundGamePacket::KeepAlive(p) => {
+                    assert_eq!(p.id, 1);
+                    return trades;
+                }
+                _ => {}
+            }
+        }
+    });
+    // the keep-alive didn't wait for the buffered packets
+    assert!(trades_before_keep_alive >= limit.burst);
+    assert!(trades_before_keep_alive < 100);
+}
+
+#[test]
+fn test_packet_rate_limit_flushing_ignores_dropped_packets() {
+    let _lock = init();
+
+    let limit = PacketRateLimit::new(20).policy(PacketRateLimitPolicy::Drop);
+    let (mut simulation, _server_conn) = connected_simulation(limit);
+    let entity = simulation.entity;
+
+    send_rate_limited_packets(&mut simulation, limit.burst + 1);
+    simulation.trigger(SendGamePacketEvent::new(
+        entity,
+        ServerboundKeepAlive { id: 1 },
+    ));
+    let (sender, receiver) = oneshot::channel();
+    simulation.write_message(FlushPacketsEvent { entity, sender });
+    simulation.update();
+    // one of the packets was dropped, but the rest were still sent
+    simulation.rt.block_on(async {
+        tokio::time::timeout(Duration::from_secs(5), receiver)
+            .await
+            .unwrap()
+            .unwrap();
+    });
+    assert_eq!(packets_dropped(&mut simulation), 1);
+}
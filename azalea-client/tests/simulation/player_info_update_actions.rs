@@ -0,0 +1,97 @@
+use azalea_auth::game_profile::GameProfile;
+use azalea_client::{local_player::TabList, test_utils::prelude::*};
+use azalea_core::game_type::GameMode;
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{
+        ClientboundPlayerInfoUpdate,
+        c_player_info_update::{ActionEnumSet, PlayerInfoEntry},
+    },
+};
+use uuid::Uuid;
+
+fn actions() -> ActionEnumSet {
+    ActionEnumSet {
+        add_player: false,
+        initialize_chat: false,
+        update_game_mode: false,
+        update_listed: false,
+        update_latency: false,
+        update_display_name: false,
+        update_hat: false,
+        update_list_order: false,
+    }
+}
+
+#[test]
+fn test_player_info_update_actions() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let uuid = Uuid::from_u128(1234);
+    let profile = GameProfile::new(uuid, "player".to_owned());
+    simulation.receive_packet(ClientboundPlayerInfoUpdate {
+        actions: ActionEnumSet {
+            add_player: true,
+            update_game_mode: true,
+            update_listed: true,
+            update_latency: true,
+            ..actions()
+        },
+        entries: vec![PlayerInfoEntry {
+            profile: profile.clone(),
+            listed: true,
+            latency: 50,
+            game_mode: GameMode::Survival,
+            ..Default::default()
+        }],
+    });
+    simulation.tick();
+
+    // the other fields in the entry should be ignored since only the latency is
+    // being updated
+    simulation.receive_packet(ClientboundPlayerInfoUpdate {
+        actions: ActionEnumSet {
+            update_latency: true,
+            ..actions()
+        },
+        entries: vec![PlayerInfoEntry {
+            profile: profile.clone(),
+            listed: false,
+            latency: 120,
+            game_mode: GameMode::Creative,
+            ..Default::default()
+        }],
+    });
+    simulation.tick();
+
+    let tab_list = simulation.component::<TabList>();
+    let info = tab_list.get(&uuid).unwrap();
+    assert_eq!(info.latency, 120);
+    assert_eq!(info.gamemode, GameMode::Survival);
+    assert!(info.listed);
+
+    simulation.receive_packet(ClientboundPlayerInfoUpdate {
+        actions: ActionEnumSet {
+            update_game_mode: true,
+            update_listed: true,
+            ..actions()
+        },
+        entries: vec![PlayerInfoEntry {
+            profile,
+            listed: false,
+            game_mode: GameMode::Creative,
+            ..Default::default()
+        }],
+    });
+    simulation.tick();
+
+    let tab_list = simulation.component::<TabList>();
+    let info = tab_list.get(&uuid).unwrap();
+    assert_eq!(info.latency, 120);
+    assert_eq!(info.gamemode, GameMode::Creative);
+    assert!(!info.listed);
+}
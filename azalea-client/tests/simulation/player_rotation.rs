@@ -0,0 +1,48 @@
+use azalea_client::test_utils::prelude::*;
+use azalea_entity::LookDirection;
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{ClientboundPlayerRotation, ServerboundGamePacket},
+};
+
+#[test]
+fn test_player_rotation() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+    sent_packets.clear();
+
+    simulation.receive_packet(ClientboundPlayerRotation {
+        y_rot: 90.,
+        relative_y: false,
+        x_rot: 20.,
+        relative_x: false,
+    });
+    simulation.update();
+    assert_eq!(
+        simulation.component::<LookDirection>(),
+        LookDirection::new(90., 20.)
+    );
+    sent_packets.expect("MovePlayerRot", |p| {
+        matches!(
+            p,
+            ServerboundGamePacket::MovePlayerRot(p)
+            if p.look_direction == LookDirection::new(90., 20.)
+        )
+    });
+
+    simulation.receive_packet(ClientboundPlayerRotation {
+        y_rot: -45.,
+        relative_y: true,
+        x_rot: 10.,
+        relative_x: false,
+    });
+    simulation.update();
+    assert_eq!(
+        simulation.component::<LookDirection>(),
+        LookDirection::new(45., 10.)
+    );
+}
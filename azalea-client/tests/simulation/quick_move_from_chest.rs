@@ -0,0 +1,94 @@
+use azalea_chat::FormattedText;
+use azalea_client::{inventory::ContainerClickEvent, test_utils::prelude::*};
+use azalea_entity::inventory::Inventory;
+use azalea_inventory::{
+    ItemStack,
+    operations::{ClickType, QuickMoveClick},
+};
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{ClientboundContainerSetContent, ClientboundOpenScreen, ServerboundGamePacket},
+};
+use azalea_registry::builtin::{ItemKind, MenuKind};
+
+#[test]
+fn test_quick_move_from_chest() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(ClientboundOpenScreen {
+        container_id: 1,
+        menu_type: MenuKind::Generic9x3,
+        title: FormattedText::default(),
+    });
+    simulation.tick();
+
+    // the chest is in slots 0..=26, and our inventory is in 27..=62 with the hotbar
+    // at the end. our inventory is full of unstackable items, except for a slot
+    // that can fit 4 more stone and the last hotbar slot, which is empty.
+    let mut items = vec![ItemStack::Empty; 63];
+    items[0] = ItemStack::new(ItemKind::Stone, 32);
+    items[1] = ItemStack::new(ItemKind::Dirt, 16);
+    for item in &mut items[27..=61] {
+        *item = ItemStack::new(ItemKind::DiamondSword, 1);
+    }
+    items[40] = ItemStack::new(ItemKind::Stone, 60);
+    simulation.receive_packet(ClientboundContainerSetContent {
+        container_id: 1,
+        state_id: 1,
+        items,
+        carried_item: ItemStack::Empty,
+    });
+    simulation.tick();
+    sent_packets.clear();
+
+    let shift_click = |simulation: &mut Simulation, slot: u16| {
+        simulation.trigger(ContainerClickEvent {
+            entity: simulation.entity,
+            window_id: 1,
+            operation: QuickMoveClick::Left { slot }.into(),
+        });
+    };
+    let slot = |simulation: &Simulation, slot: usize| {
+        simulation
+            .component::<Inventory>()
+            .menu()
+            .slot(slot)
+            .unwrap()
+            .clone()
+    };
+
+    // moving from a chest fills our inventory starting from the end, like in
+    // vanilla
+    shift_click(&mut simulation, 1);
+    sent_packets.expect("ContainerClick", |p| {
+        matches!(
+            p,
+            ServerboundGamePacket::ContainerClick(p)
+                if p.container_id == 1
+                    && p.slot_num == 1
+                    && p.button_num == 0
+                    && p.click_type == ClickType::QuickMove
+                    && p.changed_slots.keys().copied().collect::<Vec<_>>() == [1, 62]
+        )
+    });
+    assert_eq!(slot(&simulation, 1), ItemStack::Empty);
+    assert_eq!(slot(&simulation, 62), ItemStack::new(ItemKind::Dirt, 16));
+
+    // there's only space for some of the stone now, so the rest stays in the chest
+    shift_click(&mut simulation, 0);
+    sent_packets.expect("ContainerClick", |p| {
+        matches!(
+            p,
+            ServerboundGamePacket::ContainerClick(p)
+                if p.slot_num == 0
+                    && p.click_type == ClickType::QuickMove
+                    && p.changed_slots.keys().copied().collect::<Vec<_>>() == [0, 40]
+        )
+    });
+    sent_packets.expect_empty();
+    assert_eq!(slot(&simulation, 0), ItemStack::new(ItemKind::Stone, 28));
+    assert_eq!(slot(&simulation, 40), ItemStack::new(ItemKind::Stone, 64));
+}
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use azalea_client::{
+    stats::{RequestStatisticsEvent, Statistics, WaitingForStatistics},
+    test_utils::prelude::*,
+};
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{ClientboundAwardStats, ServerboundGamePacket, c_award_stats::Stat, s_client_command},
+};
+use azalea_registry::builtin::{BlockKind, CustomStat};
+
+#[test]
+fn test_request_statistics() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+    sent_packets.clear();
+
+    simulation.write_message(RequestStatisticsEvent {
+        entity: simulation.entity,
+    });
+    simulation.update();
+    sent_packets.expect("ClientCommand", |p| {
+        matches!(
+            p,
+            ServerboundGamePacket::ClientCommand(p)
+                if p.action == s_client_command::Action::RequestStats
+        )
+    });
+    assert!(simulation.has_component::<WaitingForStatistics>());
+    assert!(!simulation.has_component::<Statistics>());
+
+    simulation.receive_packet(ClientboundAwardStats {
+        stats: HashMap::from([
+            (Stat::Custom(CustomStat::PlayTime), 1200),
+            (Stat::Mined(BlockKind::Stone), 5),
+        ]),
+    });
+    simulation.tick();
+    assert!(!simulation.has_component::<WaitingForStatistics>());
+    let statistics = simulation.component::<Statistics>();
+    assert_eq!(statistics[&Stat::Custom(CustomStat::PlayTime)], 1200);
+    assert_eq!(statistics[&Stat::Mined(BlockKind::Stone)], 5);
+
+    // stats that aren't in the next response are kept
+    simulation.receive_packet(ClientboundAwardStats {
+        stats: HashMap::from([(Stat::Mined(BlockKind::Stone), 6)]),
+    });
+    simulation.tick();
+    let statistics = simulation.component::<Statistics>();
+    assert_eq!(statistics[&Stat::Custom(CustomStat::PlayTime)], 1200);
+    assert_eq!(statistics[&Stat::Mined(BlockKind::Stone)], 6);
+}
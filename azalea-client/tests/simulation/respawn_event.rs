@@ -0,0 +1,129 @@
+use azalea_client::{
+    local_player::LastDeathLocation,
+    packet::game::{PendingRespawn, RespawnEvent},
+    test_utils::prelude::*,
+};
+use azalea_core::position::{BlockPos, ChunkPos, GlobalPos, Vec3};
+use azalea_entity::{Dead, LookDirection};
+use azalea_protocol::{
+    common::movements::{PositionMoveRotation, RelativeMovements},
+    packets::{
+        ConnectionProtocol,
+        game::{
+            ClientboundGameEvent, ClientboundPlayerPosition, ClientboundSetDefaultSpawnPosition,
+            ClientboundSetHealth, c_game_event::EventType,
+        },
+    },
+};
+use azalea_registry::{data::DimensionKind, identifier::Identifier};
+
+fn die_and_respawn(
+    simulation: &mut Simulation,
+    missing_respawn_block: bool,
+    respawn_pos: Vec3,
+) -> Vec<RespawnEvent> {
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.receive_packet(ClientboundSetDefaultSpawnPosition {
+        global_pos: GlobalPos {
+            dimension: Identifier::new("minecraft:overworld"),
+            pos: BlockPos::new(0, 64, 0),
+        },
+        yaw: 0.,
+        pitch: 0.,
+    });
+    simulation.tick();
+
+    let respawn_events = ReceivedMessages::<RespawnEvent>::new(&mut simulation.app);
+
+    simulation.receive_packet(ClientboundSetHealth {
+        health: 0.,
+        food: 20,
+        saturation: 5.,
+    });
+    simulation.tick();
+    assert!(simulation.has_component::<Dead>());
+
+    let mut respawn_packet = make_basic_respawn_packet(
+        DimensionKind::new_raw(0),
+        Identifier::new("minecraft:overworld"),
+    );
+    respawn_packet.common.last_death_location = Some(GlobalPos {
+        dimension: Identifier::new("minecraft:overworld"),
+        pos: BlockPos::new(5, 10, 5),
+    });
+    simulation.receive_packet(respawn_packet);
+    if missing_respawn_block {
+        simulation.receive_packet(ClientboundGameEvent {
+            event: EventType::NoRespawnBlockAvailable,
+            param: 0.,
+        });
+    }
+    simulation.tick();
+    assert!(simulation.has_component::<PendingRespawn>());
+    // we don't know where we are yet
+    assert!(respawn_events.lock().is_empty());
+
+    simulation.receive_packet(ClientboundPlayerPosition {
+        id: 1,
+        change: PositionMoveRotation {
+            pos: respawn_pos,
+            delta: Vec3::ZERO,
+            look_direction: LookDirection::default(),
+        },
+        relative: RelativeMovements::all_absolute(),
+    });
+    simulation.tick();
+    assert!(!simulation.has_component::<PendingRespawn>());
+
+    respawn_events.lock().clone()
+}
+
+#[test]
+fn test_respawn_event_at_bed() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let respawn_events = die_and_respawn(&mut simulation, false, Vec3::new(100.5, 64., 0.5));
+
+    assert_eq!(respawn_events.len(), 1);
+    assert_eq!(respawn_events[0].entity, simulation.entity);
+    assert_eq!(
+        respawn_events[0].dimension,
+        Identifier::new("minecraft:overworld")
+    );
+    assert_eq!(respawn_events[0].position, Vec3::new(100.5, 64., 0.5));
+    assert!(respawn_events[0].is_probably_bed_spawn);
+
+    assert_eq!(
+        *simulation.component::<LastDeathLocation>(),
+        GlobalPos {
+            dimension: Identifier::new("minecraft:overworld"),
+            pos: BlockPos::new(5, 10, 5),
+        }
+    );
+}
+
+#[test]
+fn test_respawn_event_at_world_spawn() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let respawn_events = die_and_respawn(&mut simulation, false, Vec3::new(3.5, 64., -2.5));
+
+    assert_eq!(respawn_events.len(), 1);
+    assert!(!respawn_events[0].is_probably_bed_spawn);
+}
+
+#[test]
+fn test_respawn_event_missing_respawn_block() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    // even though we're far from the world spawn, the server told us that our bed
+    // was missing
+    let respawn_events = die_and_respawn(&mut simulation, true, Vec3::new(100.5, 64., 0.5));
+
+    assert_eq!(respawn_events.len(), 1);
+    assert!(!respawn_events[0].is_probably_bed_spawn);
+}
@@ -0,0 +1,77 @@
+use azalea_client::test_utils::prelude::*;
+use azalea_core::{entity_id::MinecraftEntityId, position::ChunkPos};
+use azalea_entity::{
+    Attributes, EntityDataItem, EntityDataValue, EntityMetadataItems, Vehicle,
+    indexing::EntityIdIndex, metadata::Health,
+};
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{
+        ClientboundSetEntityData, ClientboundSetPassengers, ClientboundUpdateAttributes,
+        c_update_attributes::AttributeSnapshot,
+    },
+};
+use azalea_registry::builtin::{Attribute, EntityKind};
+
+#[test]
+fn test_ride_horse() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.tick();
+
+    simulation.receive_packet(make_basic_add_entity(
+        EntityKind::Horse,
+        123,
+        (0.5, 64., 0.5),
+    ));
+    simulation.receive_packet(ClientboundSetEntityData {
+        id: MinecraftEntityId(123),
+        packed_items: EntityMetadataItems(vec![EntityDataItem {
+            index: 9,
+            value: EntityDataValue::Float(15.),
+        }]),
+    });
+    simulation.receive_packet(ClientboundUpdateAttributes {
+        entity_id: MinecraftEntityId(123),
+        values: vec![AttributeSnapshot {
+            attribute: Attribute::JumpStrength,
+            base: 0.8,
+            modifiers: vec![],
+        }],
+    });
+    simulation.receive_packet(ClientboundSetPassengers {
+        vehicle: MinecraftEntityId(123),
+        passengers: vec![MinecraftEntityId(0)],
+    });
+    simulation.tick();
+
+    let mut horse = None;
+    simulation.with_component::<EntityIdIndex>(|entity_id_index| {
+        horse = entity_id_index.get_by_minecraft_entity(MinecraftEntityId(123));
+    });
+    let horse = horse.unwrap();
+
+    assert_eq!(*simulation.component::<Vehicle>(), horse);
+
+    let horse_ref = simulation.app.world().entity(horse);
+    assert_eq!(**horse_ref.get::<Health>().unwrap(), 15.);
+    assert_eq!(
+        horse_ref
+            .get::<Attributes>()
+            .unwrap()
+            .jump_strength
+            .calculate(),
+        0.8
+    );
+
+    // dismount
+    simulation.receive_packet(ClientboundSetPassengers {
+        vehicle: MinecraftEntityId(123),
+        passengers: vec![],
+    });
+    simulation.tick();
+    assert!(!simulation.has_component::<Vehicle>());
+}
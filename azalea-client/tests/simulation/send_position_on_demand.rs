@@ -0,0 +1,77 @@
+use azalea_client::{
+    movement::{LastSentLookDirection, SendPositionEvent},
+    test_utils::prelude::*,
+};
+use azalea_core::position::{BlockPos, ChunkPos, Vec3};
+use azalea_entity::LookDirection;
+use azalea_protocol::{
+    common::movements::{PositionMoveRotation, RelativeMovements},
+    packets::{
+        ConnectionProtocol,
+        game::{
+            ClientboundBlockUpdate, ClientboundPlayerPosition, ClientboundSetChunkCacheCenter,
+            ServerboundGamePacket,
+        },
+    },
+};
+use azalea_registry::builtin::BlockKind;
+
+#[test]
+fn test_send_position_on_demand() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(ClientboundSetChunkCacheCenter { x: 0, z: 0 });
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.receive_packet(ClientboundBlockUpdate {
+        pos: BlockPos::new(0, 63, 0),
+        block_state: BlockKind::Stone.into(),
+    });
+    simulation.receive_packet(ClientboundPlayerPosition {
+        id: 1,
+        change: PositionMoveRotation {
+            pos: Vec3::new(0.5, 64., 0.5),
+            delta: Vec3::ZERO,
+            look_direction: LookDirection::default(),
+        },
+        relative: RelativeMovements::all_absolute(),
+    });
+    simulation.tick();
+    simulation.tick();
+    sent_packets.clear();
+
+    let look_direction = LookDirection::new(90., 30.);
+    simulation.with_component_mut::<LookDirection>(|direction| *direction = look_direction);
+    simulation.trigger(SendPositionEvent {
+        entity: simulation.entity,
+    });
+
+    sent_packets.expect("MovePlayerPosRot", |p| {
+        let ServerboundGamePacket::MovePlayerPosRot(p) = p else {
+            return false;
+        };
+        p.pos == Vec3::new(0.5, 64., 0.5) && p.look_direction == look_direction
+    });
+    sent_packets.expect_empty();
+
+    let last_sent_direction = simulation.component::<LastSentLookDirection>();
+    assert_eq!(last_sent_direction.y_rot, 90.);
+    assert_eq!(last_sent_direction.x_rot, 30.);
+
+    // we already sent the rotation, so the next tick shouldn't send it again
+    simulation.tick();
+    while let Some(packet) = sent_packets.next() {
+        assert!(
+            !matches!(
+                packet,
+                ServerboundGamePacket::MovePlayerPos(_)
+                    | ServerboundGamePacket::MovePlayerPosRot(_)
+                    | ServerboundGamePacket::MovePlayerRot(_)
+            ),
+            "sent a movement packet after it was already sent: {packet:?}"
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use azalea_client::{local_player::WorldSpawn, test_utils::prelude::*};
+use azalea_core::position::{BlockPos, GlobalPos};
+use azalea_protocol::packets::{ConnectionProtocol, game::ClientboundSetDefaultSpawnPosition};
+use azalea_registry::identifier::Identifier;
+
+#[test]
+fn test_set_default_spawn_position() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+    assert!(!simulation.has_component::<WorldSpawn>());
+
+    let global_pos = GlobalPos {
+        dimension: Identifier::new("minecraft:overworld"),
+        pos: BlockPos::new(12, 64, -30),
+    };
+    simulation.receive_packet(ClientboundSetDefaultSpawnPosition {
+        global_pos: global_pos.clone(),
+        yaw: 90.,
+        pitch: 0.,
+    });
+    simulation.tick();
+    assert_eq!(
+        simulation.component::<WorldSpawn>(),
+        WorldSpawn {
+            global_pos,
+            yaw: 90.,
+            pitch: 0.,
+        }
+    );
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use azalea_client::{
+    local_player::Hunger,
+    packet::{death_event_on_0_health, game::DeathEvent},
+    test_utils::prelude::*,
+};
+use azalea_entity::{Dead, metadata::Health};
+use azalea_protocol::packets::{ConnectionProtocol, game::ClientboundSetHealth};
+use bevy_app::Update;
+use bevy_ecs::{message::MessageReader, schedule::IntoScheduleConfigs};
+use parking_lot::Mutex;
+
+#[test]
+fn test_set_health_food_saturation() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+
+    let deaths = Arc::new(Mutex::new(0));
+    let deaths_clone = deaths.clone();
+    simulation.app.add_systems(
+        Update,
+        (move |mut events: MessageReader<DeathEvent>| {
+            *deaths_clone.lock() += events.read().count();
+        })
+        .after(death_event_on_0_health),
+    );
+
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    simulation.receive_packet(ClientboundSetHealth {
+        health: 15.,
+        food: 12,
+        saturation: 3.5,
+    });
+    simulation.tick();
+    assert_eq!(*simulation.component::<Health>(), 15.);
+    let hunger = simulation.component::<Hunger>();
+    assert_eq!(hunger.food, 12);
+    assert_eq!(hunger.saturation, 3.5);
+    assert_eq!(*deaths.lock(), 0);
+
+    simulation.receive_packet(ClientboundSetHealth {
+        health: 0.,
+        food: 10,
+        saturation: 0.,
+    });
+    simulation.tick();
+    assert!(simulation.has_component::<Dead>());
+    assert_eq!(simulation.component::<Hunger>().food, 10);
+    assert_eq!(*deaths.lock(), 1);
+
+    // updating our food while we're dead shouldn't make us die again
+    simulation.receive_packet(ClientboundSetHealth {
+        health: 0.,
+        food: 8,
+        saturation: 0.,
+    });
+    simulation.tick();
+    assert_eq!(simulation.component::<Hunger>().food, 8);
+    assert_eq!(*deaths.lock(), 1);
+}
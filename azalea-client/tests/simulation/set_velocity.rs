@@ -0,0 +1,65 @@
+use azalea_client::test_utils::prelude::*;
+use azalea_core::position::{BlockPos, ChunkPos, Vec3};
+use azalea_entity::{LookDirection, Physics, Position};
+use azalea_protocol::{
+    common::movements::{PositionMoveRotation, RelativeMovements},
+    packets::{
+        ConnectionProtocol,
+        game::{ClientboundBlockUpdate, ClientboundPlayerPosition, ClientboundSetChunkCacheCenter},
+    },
+};
+use azalea_registry::builtin::BlockKind;
+
+#[test]
+fn test_set_velocity() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(ClientboundSetChunkCacheCenter { x: 0, z: 0 });
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    for x in 0..4 {
+        simulation.receive_packet(ClientboundBlockUpdate {
+            pos: BlockPos::new(x, 63, 0),
+            block_state: BlockKind::Stone.into(),
+        });
+    }
+    // a wall
+    simulation.receive_packet(ClientboundBlockUpdate {
+        pos: BlockPos::new(3, 64, 0),
+        block_state: BlockKind::Stone.into(),
+    });
+    simulation.receive_packet(ClientboundPlayerPosition {
+        id: 1,
+        change: PositionMoveRotation {
+            pos: Vec3::new(0.5, 64., 0.5),
+            delta: Vec3::ZERO,
+            look_direction: LookDirection::default(),
+        },
+        relative: RelativeMovements::all_absolute(),
+    });
+    simulation.tick();
+    simulation.tick();
+    assert_eq!(
+        *simulation.component::<Position>(),
+        Vec3::new(0.5, 64., 0.5)
+    );
+
+    simulation.with_component_mut::<Physics>(|physics| {
+        physics.velocity = Vec3::new(0.3, 0., 0.);
+    });
+    simulation.tick();
+    let position = *simulation.component::<Position>();
+    assert!((position.x - 0.8).abs() < 1e-6, "{position:?}");
+    assert_eq!(position.y, 64.);
+    assert_eq!(position.z, 0.5);
+
+    // we can't go through walls, no matter how fast we're going
+    simulation.with_component_mut::<Physics>(|physics| {
+        physics.velocity = Vec3::new(5., 0., 0.);
+    });
+    simulation.tick();
+    let position = *simulation.component::<Position>();
+    assert!((position.x - 2.7).abs() < 1e-6, "{position:?}");
+    assert_eq!(position.y, 64.);
+}
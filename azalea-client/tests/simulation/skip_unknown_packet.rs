@@ -0,0 +1,64 @@
+use azalea_client::{
+    connection::{PacketParseSettings, RawConnection},
+    test_utils::prelude::*,
+};
+use azalea_entity::metadata::Health;
+use azalea_protocol::packets::{ConnectionProtocol, game::ClientboundSetHealth};
+use azalea_world::WorldName;
+use tracing::Level;
+
+/// A packet with the ID 0x3fff, which doesn't exist in the game state.
+const UNKNOWN_PACKET: &[u8] = &[0xff, 0x7f, 1, 2, 3];
+
+#[test]
+fn test_skip_unknown_packet() {
+    let _lock = init_with_level(Level::ERROR); // a warning is expected here
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    simulation.with_component_mut::<RawConnection>(|raw_conn| {
+        raw_conn
+            .injected_clientbound_packets
+            .push(UNKNOWN_PACKET.into());
+    });
+    simulation.receive_packet(ClientboundSetHealth {
+        health: 15.,
+        food: 20,
+        saturation: 5.,
+    });
+    simulation.tick();
+
+    // the unknown packet was skipped, and the packet after it was still handled
+    assert!(simulation.component::<RawConnection>().is_alive());
+    assert_eq!(*simulation.component::<Health>(), 15.);
+}
+
+#[test]
+fn test_disconnect_on_unknown_packet_with_strict_packets() {
+    let _lock = init_with_level(Level::ERROR);
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+    assert!(simulation.has_component::<WorldName>());
+
+    let entity = simulation.entity;
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(entity)
+        .insert(PacketParseSettings {
+            strict_packets: true,
+            ..Default::default()
+        });
+    simulation.with_component_mut::<RawConnection>(|raw_conn| {
+        raw_conn
+            .injected_clientbound_packets
+            .push(UNKNOWN_PACKET.into());
+    });
+    simulation.tick();
+
+    assert!(!simulation.has_component::<WorldName>());
+}
@@ -0,0 +1,139 @@
+use azalea_client::{StartWalkEvent, WalkDirection, test_utils::prelude::*};
+use azalea_core::position::{BlockPos, ChunkPos, Vec3};
+use azalea_entity::{Attributes, LookDirection, MobEffectData, Position, attributes};
+use azalea_protocol::{
+    common::movements::{PositionMoveRotation, RelativeMovements},
+    packets::{
+        ConnectionProtocol,
+        game::{
+            ClientboundBlockUpdate, ClientboundPlayerPosition, ClientboundSetChunkCacheCenter,
+            ClientboundUpdateAttributes, ClientboundUpdateMobEffect,
+            c_update_attributes::AttributeSnapshot,
+        },
+    },
+};
+use azalea_registry::builtin::{Attribute, BlockKind, MobEffect};
+
+/// Walk forward for a few ticks and return how far we went.
+fn distance_walked(effect: Option<(MobEffect, u32)>) -> f64 {
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(ClientboundSetChunkCacheCenter { x: 0, z: 0 });
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    for z in 0..16 {
+        simulation.receive_packet(ClientboundBlockUpdate {
+            pos: BlockPos::new(0, 63, z),
+            block_state: BlockKind::Stone.into(),
+        });
+    }
+    simulation.receive_packet(ClientboundPlayerPosition {
+        id: 1,
+        change: PositionMoveRotation {
+            pos: Vec3::new(0.5, 64., 0.5),
+            delta: Vec3::ZERO,
+            look_direction: LookDirection::default(),
+        },
+        relative: RelativeMovements::all_absolute(),
+    });
+    if let Some((mob_effect, amplifier)) = effect {
+        simulation.receive_packet(ClientboundUpdateMobEffect {
+            entity_id: simulation.minecraft_entity_id(),
+            mob_effect,
+            data: MobEffectData {
+                amplifier,
+                duration_ticks: 600,
+                ..Default::default()
+            },
+        });
+    }
+    simulation.tick();
+    simulation.tick();
+
+    let start_z = simulation.component::<Position>().z;
+    simulation.write_message(StartWalkEvent {
+        entity: simulation.entity,
+        direction: WalkDirection::Forward,
+    });
+    for _ in 0..10 {
+        simulation.tick();
+    }
+    simulation.component::<Position>().z - start_z
+}
+
+#[test]
+fn test_speed_effect_movement() {
+    let _lock = init();
+
+    let normal = distance_walked(None);
+    let with_speed = distance_walked(Some((MobEffect::Speed, 1)));
+    let with_slowness = distance_walked(Some((MobEffect::Slowness, 0)));
+
+    assert!(normal > 0.);
+    assert!(
+        with_speed > normal * 1.3,
+        "speed II should make us walk faster ({with_speed} vs {normal})"
+    );
+    assert!(
+        with_slowness < normal,
+        "slowness should make us walk slower ({with_slowness} vs {normal})"
+    );
+}
+
+#[test]
+fn test_speed_effect_survives_update_attributes() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(ClientboundUpdateMobEffect {
+        entity_id: simulation.minecraft_entity_id(),
+        mob_effect: MobEffect::Speed,
+        data: MobEffectData {
+            amplifier: 1,
+            duration_ticks: 600,
+            ..Default::default()
+        },
+    });
+    simulation.tick();
+    let with_speed = simulation
+        .component::<Attributes>()
+        .movement_speed
+        .calculate();
+    assert!(with_speed > 0.1);
+
+    // the server resets our movement speed without mentioning the effect
+    simulation.receive_packet(ClientboundUpdateAttributes {
+        entity_id: simulation.minecraft_entity_id(),
+        values: vec![AttributeSnapshot {
+            attribute: Attribute::MovementSpeed,
+            base: 0.1,
+            modifiers: vec![],
+        }],
+    });
+    simulation.tick();
+    assert_eq!(
+        simulation
+            .component::<Attributes>()
+            .movement_speed
+            .calculate(),
+        with_speed
+    );
+
+    // and if it does mention it, it must not be applied twice
+    simulation.receive_packet(ClientboundUpdateAttributes {
+        entity_id: simulation.minecraft_entity_id(),
+        values: vec![AttributeSnapshot {
+            attribute: Attribute::MovementSpeed,
+            base: 0.1,
+            modifiers: vec![attributes::speed_effect_modifier(1)],
+        }],
+    });
+    simulation.tick();
+    assert_eq!(
+        simulation
+            .component::<Attributes>()
+            .movement_speed
+            .calculate(),
+        with_speed
+    );
+}
@@ -0,0 +1,75 @@
+use azalea_client::{
+    interact::SwingArmEvent, packet::game::EntitySwingEvent, test_utils::prelude::*,
+};
+use azalea_core::{entity_id::MinecraftEntityId, position::ChunkPos};
+use azalea_entity::indexing::EntityIdIndex;
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{
+        ClientboundAnimate, ServerboundGamePacket, c_animate::AnimationAction,
+        s_interact::InteractionHand,
+    },
+};
+use azalea_registry::builtin::EntityKind;
+
+#[test]
+fn test_swing_arm() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.tick();
+    sent_packets.clear();
+
+    simulation.trigger(SwingArmEvent {
+        entity: simulation.entity,
+        hand: InteractionHand::OffHand,
+    });
+    sent_packets.expect("Swing", |p| {
+        matches!(
+            p,
+            ServerboundGamePacket::Swing(p) if p.hand == InteractionHand::OffHand
+        )
+    });
+}
+
+#[test]
+fn test_entity_swing_event() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.receive_packet(make_basic_add_entity(
+        EntityKind::Zombie,
+        123,
+        (0.5, 64., 0.5),
+    ));
+    simulation.tick();
+
+    let swing_events = ReceivedMessages::<EntitySwingEvent>::new(&mut simulation.app);
+
+    simulation.receive_packet(ClientboundAnimate {
+        id: MinecraftEntityId(123),
+        action: AnimationAction::SwingMainHand,
+    });
+    // other animations shouldn't be counted as swings
+    simulation.receive_packet(ClientboundAnimate {
+        id: MinecraftEntityId(123),
+        action: AnimationAction::CriticalHit,
+    });
+    simulation.tick();
+
+    let mut zombie = None;
+    simulation.with_component::<EntityIdIndex>(|entity_id_index| {
+        zombie = entity_id_index.get_by_minecraft_entity(MinecraftEntityId(123));
+    });
+
+    let swing_events = swing_events.lock();
+    assert_eq!(swing_events.len(), 1);
+    assert_eq!(swing_events[0].entity, simulation.entity);
+    assert_eq!(Some(swing_events[0].swung), zombie);
+    assert_eq!(swing_events[0].hand, InteractionHand::MainHand);
+}
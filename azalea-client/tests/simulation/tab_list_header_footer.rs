@@ -0,0 +1,87 @@
+use azalea_auth::game_profile::GameProfile;
+use azalea_chat::FormattedText;
+use azalea_client::{
+    local_player::{TabList, TabListHeaderFooter},
+    test_utils::prelude::*,
+};
+use azalea_core::game_type::GameMode;
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{
+        ClientboundPlayerInfoUpdate, ClientboundTabList,
+        c_player_info_update::{ActionEnumSet, PlayerInfoEntry},
+    },
+};
+use uuid::Uuid;
+
+#[test]
+fn test_tab_list_header_footer() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    assert_eq!(
+        simulation.component::<TabListHeaderFooter>(),
+        TabListHeaderFooter::default()
+    );
+
+    simulation.receive_packet(ClientboundTabList {
+        header: FormattedText::from("Welcome to the server"),
+        footer: FormattedText::from("play.example.com"),
+    });
+    simulation.tick();
+
+    let header_footer = simulation.component::<TabListHeaderFooter>();
+    assert_eq!(header_footer.header.to_string(), "Welcome to the server");
+    assert_eq!(header_footer.footer.to_string(), "play.example.com");
+}
+
+#[test]
+fn test_tab_list_display_order() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let entry = |id: u128, name: &str, list_order: i32, game_mode: GameMode, listed: bool| {
+        PlayerInfoEntry {
+            profile: GameProfile::new(Uuid::from_u128(id), name.to_owned()),
+            listed,
+            game_mode,
+            list_order,
+            ..Default::default()
+        }
+    };
+    simulation.receive_packet(ClientboundPlayerInfoUpdate {
+        actions: ActionEnumSet {
+            add_player: true,
+            initialize_chat: false,
+            update_game_mode: true,
+            update_listed: true,
+            update_latency: false,
+            update_display_name: false,
+            update_hat: false,
+            update_list_order: true,
+        },
+        entries: vec![
+            entry(1, "bob", 0, GameMode::Survival, true),
+            entry(2, "Alice", 0, GameMode::Survival, true),
+            entry(3, "watcher", 5, GameMode::Spectator, true),
+            entry(4, "admin", 10, GameMode::Creative, true),
+            entry(5, "hidden", 20, GameMode::Survival, false),
+            entry(6, "aaron", 5, GameMode::Survival, true),
+        ],
+    });
+    simulation.tick();
+
+    let tab_list = simulation.component::<TabList>();
+    let names = tab_list
+        .display_order()
+        .into_iter()
+        .map(|info| info.profile.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["admin", "aaron", "watcher", "Alice", "bob"]);
+}
@@ -0,0 +1,70 @@
+use azalea_client::{movement::TeleportFilter, test_utils::prelude::*};
+use azalea_core::position::{ChunkPos, Vec3};
+use azalea_entity::{LookDirection, Position};
+use azalea_protocol::{
+    common::movements::{PositionMoveRotation, RelativeMovements},
+    packets::{
+        ConnectionProtocol,
+        game::{ClientboundPlayerPosition, ServerboundGamePacket},
+    },
+};
+
+fn teleport_packet(id: u32, pos: Vec3) -> ClientboundPlayerPosition {
+    ClientboundPlayerPosition {
+        id,
+        change: PositionMoveRotation {
+            pos,
+            delta: Vec3::ZERO,
+            look_direction: LookDirection::default(),
+        },
+        relative: RelativeMovements::all_absolute(),
+    }
+}
+
+#[test]
+fn test_teleport_filter() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.tick();
+
+    let entity = simulation.entity;
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(entity)
+        .insert(TeleportFilter::new(|teleport| {
+            teleport.current_position.distance_to(teleport.new_position) < 10.
+        }));
+
+    // the initial teleport is always accepted
+    let start = Vec3::new(0.5, -64., 0.5);
+    simulation.receive_packet(teleport_packet(1, start));
+    simulation.update();
+    assert_eq!(*simulation.component::<Position>(), start);
+    sent_packets.clear();
+
+    // small teleports are accepted
+    let nearby = Vec3::new(2.5, -64., 0.5);
+    simulation.receive_packet(teleport_packet(2, nearby));
+    simulation.update();
+    assert_eq!(*simulation.component::<Position>(), nearby);
+    sent_packets.expect(
+        "AcceptTeleportation { id: 2 }",
+        |p| matches!(p, ServerboundGamePacket::AcceptTeleportation(p) if p.id == 2),
+    );
+    sent_packets.clear();
+
+    // and large ones are rejected, so our current position is sent instead
+    simulation.receive_packet(teleport_packet(3, Vec3::new(1000.5, -64., 0.5)));
+    simulation.update();
+    assert_eq!(*simulation.component::<Position>(), nearby);
+    sent_packets.expect(
+        "MovePlayerPosRot at our current position",
+        |p| matches!(p, ServerboundGamePacket::MovePlayerPosRot(p) if p.pos == nearby),
+    );
+    sent_packets.expect_empty();
+}
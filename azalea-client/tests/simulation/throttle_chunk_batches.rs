@@ -0,0 +1,72 @@
+use azalea_client::{
+    chunks::ChunkBatchInfo,
+    connection::{BandwidthLimit, RawConnection},
+    test_utils::prelude::*,
+};
+use azalea_core::position::ChunkPos;
+use azalea_protocol::packets::{
+    ConnectionProtocol, Packet,
+    game::{ClientboundChunkBatchFinished, ClientboundChunkBatchStart},
+};
+use bevy_app::App;
+
+#[test]
+fn test_throttle_chunk_batches() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let chunk_packet_size = azalea_protocol::write::serialize_packet(
+        &make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16).into_variant(),
+    )
+    .unwrap()
+    .len() as u32;
+    // enough to read one chunk per tick, so a batch of 4 chunks takes at least
+    // 100ms to read
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(simulation.entity)
+        .insert(BandwidthLimit {
+            read_bps: Some(chunk_packet_size * 20),
+            write_bps: None,
+        });
+
+    let entity = simulation.entity;
+    let has_unread_packets = |app: &mut App| {
+        let conn = app.world().get::<RawConnection>(entity).unwrap();
+        !conn.injected_clientbound_packets.is_empty()
+    };
+
+    let mut desired_chunks_per_tick = simulation
+        .component::<ChunkBatchInfo>()
+        .desired_chunks_per_tick();
+    for _ in 0..3 {
+        simulation.receive_packet(ClientboundChunkBatchStart);
+        for x in 0..2 {
+            for z in 0..2 {
+                simulation
+                    .receive_packet(make_basic_empty_chunk(ChunkPos::new(x, z), (384 + 64) / 16));
+            }
+        }
+        simulation.receive_packet(ClientboundChunkBatchFinished { batch_size: 4 });
+
+        // the packets should take multiple updates to be read
+        simulation.update();
+        assert!(has_unread_packets(&mut simulation.app));
+        update_until(&mut simulation.app, |app| !has_unread_packets(app));
+
+        let new_desired_chunks_per_tick = simulation
+            .component::<ChunkBatchInfo>()
+            .desired_chunks_per_tick();
+        assert!(
+            new_desired_chunks_per_tick < desired_chunks_per_tick,
+            "{new_desired_chunks_per_tick} should be less than {desired_chunks_per_tick}"
+        );
+        desired_chunks_per_tick = new_desired_chunks_per_tick;
+    }
+    // the default is 3.5
+    assert!(desired_chunks_per_tick < 2.);
+}
@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use azalea_client::{test_utils::prelude::*, tick_rate::WorldTickRate};
+use azalea_core::position::{ChunkPos, Vec3};
+use azalea_entity::{LookDirection, Position};
+use azalea_protocol::{
+    common::movements::{PositionMoveRotation, RelativeMovements},
+    packets::{
+        ConnectionProtocol,
+        game::{ClientboundPlayerPosition, ClientboundTickingState, ClientboundTickingStep},
+    },
+};
+
+#[test]
+fn test_ticking_state_changes_tick_rate() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let tick_duration = |simulation: &Simulation| {
+        simulation
+            .app
+            .world()
+            .resource::<WorldTickRate>()
+            .tick_duration()
+    };
+    assert_eq!(tick_duration(&simulation), Duration::from_millis(50));
+
+    simulation.receive_packet(ClientboundTickingState {
+        tick_rate: 10.,
+        is_frozen: false,
+    });
+    simulation.tick();
+    assert_eq!(tick_duration(&simulation), Duration::from_millis(100));
+}
+
+#[test]
+fn test_frozen_world_keeps_local_player_moving() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.receive_packet(ClientboundPlayerPosition {
+        id: 1,
+        change: PositionMoveRotation {
+            pos: Vec3::new(0.5, 100., 0.5),
+            delta: Vec3::ZERO,
+            look_direction: LookDirection::default(),
+        },
+        relative: RelativeMovements::all_absolute(),
+    });
+    simulation.tick();
+    simulation.tick();
+
+    let y = |simulation: &Simulation| simulation.component::<Position>().y;
+    let world_tick_rate =
+        |simulation: &Simulation| *simulation.app.world().resource::<WorldTickRate>();
+
+    simulation.receive_packet(ClientboundTickingState {
+        tick_rate: 20.,
+        is_frozen: true,
+    });
+    simulation.tick();
+    assert!(!world_tick_rate(&simulation).runs_normally);
+
+    // players aren't frozen, so we keep falling
+    let frozen_y = y(&simulation);
+    simulation.tick();
+    assert!(y(&simulation) < frozen_y);
+
+    // stepping makes the world run for exactly that many ticks
+    simulation.receive_packet(ClientboundTickingStep { tick_steps: 1 });
+    simulation.tick();
+    assert!(world_tick_rate(&simulation).runs_normally);
+    simulation.tick();
+    assert!(!world_tick_rate(&simulation).runs_normally);
+
+    simulation.receive_packet(ClientboundTickingState {
+        tick_rate: 20.,
+        is_frozen: false,
+    });
+    simulation.tick();
+    assert!(world_tick_rate(&simulation).runs_normally);
+}
@@ -0,0 +1,58 @@
+use azalea_client::test_utils::prelude::*;
+use azalea_core::attribute_modifier_operation::AttributeModifierOperation;
+use azalea_entity::Attributes;
+use azalea_inventory::components::AttributeModifier;
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{ClientboundUpdateAttributes, c_update_attributes::AttributeSnapshot},
+};
+use azalea_registry::{builtin::Attribute, identifier::Identifier};
+
+#[test]
+fn test_update_attributes_for_local_player() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let block_interaction_range =
+        |simulation: &Simulation| simulation.component::<Attributes>().block_interaction_range;
+    assert_eq!(block_interaction_range(&simulation).calculate(), 4.5);
+
+    simulation.receive_packet(ClientboundUpdateAttributes {
+        entity_id: simulation.minecraft_entity_id(),
+        values: vec![
+            AttributeSnapshot {
+                attribute: Attribute::BlockInteractionRange,
+                base: 5.,
+                modifiers: vec![AttributeModifier {
+                    id: Identifier::new("test:reach"),
+                    amount: 1.,
+                    operation: AttributeModifierOperation::AddValue,
+                }],
+            },
+            // we don't keep track of this attribute, so it should be ignored
+            AttributeSnapshot {
+                attribute: Attribute::Luck,
+                base: 1.,
+                modifiers: vec![],
+            },
+        ],
+    });
+    simulation.tick();
+    assert_eq!(block_interaction_range(&simulation).base, 5.);
+    assert_eq!(block_interaction_range(&simulation).calculate(), 6.);
+
+    // the packet has every modifier, so ones that aren't in it anymore are removed
+    simulation.receive_packet(ClientboundUpdateAttributes {
+        entity_id: simulation.minecraft_entity_id(),
+        values: vec![AttributeSnapshot {
+            attribute: Attribute::BlockInteractionRange,
+            base: 5.,
+            modifiers: vec![],
+        }],
+    });
+    simulation.tick();
+    assert_eq!(block_interaction_range(&simulation).calculate(), 5.);
+}
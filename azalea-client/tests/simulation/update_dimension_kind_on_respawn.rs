@@ -0,0 +1,67 @@
+use azalea_client::{
+    local_player::{LocalDimensionKind, WorldHolder},
+    test_utils::prelude::*,
+};
+use azalea_core::data_registry::ResolvableDataRegistry;
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    config::{ClientboundFinishConfiguration, ClientboundRegistryData},
+};
+use azalea_registry::{DataRegistry, data::DimensionKind, identifier::Identifier};
+use simdnbt::owned::{NbtCompound, NbtTag};
+
+#[test]
+fn test_update_dimension_kind_on_respawn() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Configuration);
+    simulation.receive_packet(ClientboundRegistryData {
+        registry_id: Identifier::new("minecraft:dimension_type"),
+        entries: vec![
+            (
+                Identifier::new("minecraft:overworld"),
+                Some(NbtCompound::from_values(vec![
+                    ("height".into(), NbtTag::Int(384)),
+                    ("min_y".into(), NbtTag::Int(-64)),
+                ])),
+            ),
+            (
+                Identifier::new("minecraft:the_nether"),
+                Some(NbtCompound::from_values(vec![
+                    ("height".into(), NbtTag::Int(256)),
+                    ("min_y".into(), NbtTag::Int(0)),
+                ])),
+            ),
+        ]
+        .into_iter()
+        .collect(),
+    });
+    simulation.tick();
+    simulation.receive_packet(ClientboundFinishConfiguration);
+    simulation.tick();
+
+    simulation.receive_packet(make_basic_login_packet(
+        DimensionKind::new_raw(0), // overworld
+        Identifier::new("minecraft:overworld"),
+    ));
+    simulation.tick();
+    assert_eq!(
+        *simulation.component::<LocalDimensionKind>(),
+        DimensionKind::new_raw(0)
+    );
+
+    simulation.receive_packet(make_basic_respawn_packet(
+        DimensionKind::new_raw(1), // nether
+        Identifier::new("minecraft:the_nether"),
+    ));
+    simulation.tick();
+
+    let dimension_kind = *simulation.component::<LocalDimensionKind>();
+    assert_eq!(dimension_kind, DimensionKind::new_raw(1));
+
+    let world = simulation.component::<WorldHolder>().shared;
+    let world = world.read();
+    let (name, data) = dimension_kind.resolve(&world.registries).unwrap();
+    assert_eq!(*name, Identifier::new("minecraft:the_nether"));
+    assert_eq!((data.min_y, data.height), (0, 256));
+}
@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+use azalea_client::{
+    chat::SendChatEvent,
+    connection::{BandwidthLimit, FlushPacketsEvent},
+    packet::game::SendGamePacketEvent,
+    test_utils::prelude::*,
+};
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{ServerboundGamePacket, ServerboundKeepAlive},
+};
+use tokio::sync::oneshot::{self, error::TryRecvError};
+
+#[test]
+fn test_wait_for_chat_to_be_sent() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let mut server_conn = simulation.connect_to_local_server();
+    let entity = simulation.entity;
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(entity)
+        .insert(BandwidthLimit {
+            read_bps: None,
+            write_bps: Some(20),
+        });
+    simulation.update();
+
+    // use up our bandwidth so the chat message gets delayed
+    simulation.trigger(SendGamePacketEvent::new(
+        entity,
+        ServerboundKeepAlive { id: 1 },
+    ));
+    let (sender, mut receiver) = oneshot::channel();
+    simulation.write_message(SendChatEvent {
+        entity,
+        content: "hello".to_owned(),
+    });
+    simulation.write_message(FlushPacketsEvent { entity, sender });
+    simulation.update();
+    simulation.update();
+    assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+
+    // now let the chat message be sent
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(entity)
+        .remove::<BandwidthLimit>();
+    simulation.update();
+    simulation.update();
+
+    simulation.rt.block_on(async {
+        tokio::time::timeout(Duration::from_secs(5), receiver)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let packet = server_conn.read().await.unwrap();
+        assert!(matches!(packet, ServerboundGamePacket::KeepAlive(_)));
+        // the chat packet was already sent by the time that we were notified
+        let packet = server_conn.read().await.unwrap();
+        let ServerboundGamePacket::Chat(chat) = packet else {
+            panic!("expected chat packet, got {packet:?}");
+        };
+        assert_eq!(chat.message, "hello");
+    });
+}
@@ -0,0 +1,117 @@
+use azalea_client::{test_utils::prelude::*, world_border::WorldBorder};
+use azalea_core::position::Vec3;
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{
+        ClientboundInitializeBorder, ClientboundSetBorderCenter, ClientboundSetBorderLerpSize,
+        ClientboundSetBorderSize, ClientboundSetBorderWarningDelay,
+        ClientboundSetBorderWarningDistance,
+    },
+};
+
+#[test]
+fn test_initialize_border() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    // the default border is effectively infinite
+    assert!(
+        simulation
+            .component::<WorldBorder>()
+            .is_within_bounds(Vec3::new(1_000_000., 0., 1_000_000.))
+    );
+
+    simulation.receive_packet(ClientboundInitializeBorder {
+        new_center_x: 0.,
+        new_center_z: 0.,
+        old_size: 100.,
+        new_size: 100.,
+        lerp_time: 0,
+        new_absolute_max_size: 29_999_984,
+        warning_blocks: 5,
+        warning_time: 15,
+    });
+    simulation.tick();
+
+    let world_border = simulation.component::<WorldBorder>();
+    assert_eq!(world_border.size, 100.);
+    assert!(world_border.is_within_bounds(Vec3::new(10., 64., -10.)));
+    assert!(!world_border.is_within_bounds(Vec3::new(60., 64., 0.)));
+    assert!(!world_border.is_within_bounds(Vec3::new(0., 64., -60.)));
+
+    assert_eq!(
+        world_border.distance_to_border(Vec3::new(10., 64., 0.)),
+        40.
+    );
+    assert_eq!(
+        world_border.distance_to_border(Vec3::new(60., 64., 0.)),
+        -10.
+    );
+}
+
+#[test]
+fn test_border_lerp_size() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    simulation.receive_packet(ClientboundSetBorderSize { size: 100. });
+    simulation.tick();
+    assert_eq!(simulation.component::<WorldBorder>().size, 100.);
+
+    simulation.receive_packet(ClientboundSetBorderLerpSize {
+        old_size: 100.,
+        new_size: 50.,
+        lerp_time: 10,
+    });
+    // the packet is handled and then the border ticks once
+    simulation.tick();
+    assert_eq!(simulation.component::<WorldBorder>().size, 95.);
+
+    for _ in 0..4 {
+        simulation.tick();
+    }
+    let world_border = simulation.component::<WorldBorder>();
+    assert_eq!(world_border.size, 75.);
+    assert!(world_border.is_moving());
+
+    for _ in 0..5 {
+        simulation.tick();
+    }
+    let world_border = simulation.component::<WorldBorder>();
+    assert_eq!(world_border.size, 50.);
+    assert!(!world_border.is_moving());
+
+    // doesn't keep changing after the lerp is done
+    simulation.tick();
+    assert_eq!(simulation.component::<WorldBorder>().size, 50.);
+}
+
+#[test]
+fn test_border_center_and_warnings() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    simulation.receive_packet(ClientboundSetBorderSize { size: 20. });
+    simulation.receive_packet(ClientboundSetBorderCenter {
+        new_center_x: 100.,
+        new_center_z: -100.,
+    });
+    simulation.receive_packet(ClientboundSetBorderWarningDelay { warning_delay: 30 });
+    simulation.receive_packet(ClientboundSetBorderWarningDistance { warning_blocks: 8 });
+    simulation.tick();
+
+    let world_border = simulation.component::<WorldBorder>();
+    assert_eq!(world_border.warning_time, 30);
+    assert_eq!(world_border.warning_blocks, 8);
+    assert!(world_border.is_within_bounds(Vec3::new(105., 64., -95.)));
+    assert!(!world_border.is_within_bounds(Vec3::new(0., 64., 0.)));
+}
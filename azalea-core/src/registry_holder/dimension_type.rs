@@ -7,7 +7,7 @@ use simdnbt::{
     owned::{NbtCompound, NbtTag},
 };
 
-use crate::codec_utils::*;
+use crate::{codec_utils::*, position::Vec3};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "strict_registry", simdnbt(deny_unknown_fields))]
@@ -84,6 +84,49 @@ pub struct DimensionKindElement {
     pub _extra: HashMap<String, NbtTag>,
 }
 
+impl DimensionKindElement {
+    /// The scale of horizontal coordinates in this dimension, relative to
+    /// other dimensions.
+    ///
+    /// In vanilla, this is `1` for the overworld and the end, and `8` for the
+    /// nether.
+    #[cfg(feature = "strict_registry")]
+    pub fn coordinate_scale(&self) -> f64 {
+        self.coordinate_scale as f64
+    }
+    /// The scale of horizontal coordinates in this dimension, relative to
+    /// other dimensions.
+    ///
+    /// In vanilla, this is `1` for the overworld and the end, and `8` for the
+    /// nether.
+    #[cfg(not(feature = "strict_registry"))]
+    pub fn coordinate_scale(&self) -> f64 {
+        match self._extra.get("coordinate_scale") {
+            Some(NbtTag::Double(scale)) => *scale,
+            Some(NbtTag::Float(scale)) => *scale as f64,
+            _ => 1.,
+        }
+    }
+
+    /// The number that horizontal coordinates should be multiplied by when
+    /// going from this dimension to the `to` dimension, like when going
+    /// through a nether portal.
+    pub fn teleportation_scale(&self, to: &DimensionKindElement) -> f64 {
+        // DimensionType.getTeleportationScale
+        self.coordinate_scale() / to.coordinate_scale()
+    }
+
+    /// Convert a position in this dimension to the equivalent position in the
+    /// `to` dimension.
+    ///
+    /// Only the horizontal coordinates are scaled, and they're not rounded. Use
+    /// [`Vec3::to_block_pos_floor`] if you need a block position.
+    pub fn scale_position_to(&self, to: &DimensionKindElement, pos: Vec3) -> Vec3 {
+        let scale = self.teleportation_scale(to);
+        Vec3::new(pos.x * scale, pos.y, pos.z * scale)
+    }
+}
+
 /// The light level at which monsters can spawn.
 ///
 /// This can be either a single minimum value, or a formula with a min and
@@ -258,3 +301,45 @@ pub struct DamageTypeElement {
     #[serde(skip_serializing_if = "is_default")]
     pub death_message_type: Option<String>,
 }
+
+#[cfg(all(test, not(feature = "strict_registry")))]
+mod tests {
+    use super::*;
+    use crate::{position::BlockPos, registry_holder::RegistryHolder};
+
+    fn dimension(coordinate_scale: f64) -> NbtCompound {
+        NbtCompound::from_values(vec![
+            ("height".into(), NbtTag::Int(384)),
+            ("min_y".into(), NbtTag::Int(-64)),
+            ("coordinate_scale".into(), NbtTag::Double(coordinate_scale)),
+        ])
+    }
+
+    #[test]
+    fn test_teleportation_scale() {
+        let mut registries = RegistryHolder::default();
+        registries.append(
+            Identifier::new("minecraft:dimension_type"),
+            vec![
+                (Identifier::new("minecraft:overworld"), Some(dimension(1.))),
+                (Identifier::new("minecraft:the_nether"), Some(dimension(8.))),
+            ],
+        );
+        let overworld = &registries.dimension_type.map[&Identifier::new("minecraft:overworld")];
+        let nether = &registries.dimension_type.map[&Identifier::new("minecraft:the_nether")];
+
+        assert_eq!(overworld.coordinate_scale(), 1.);
+        assert_eq!(nether.coordinate_scale(), 8.);
+        assert_eq!(overworld.teleportation_scale(nether), 0.125);
+        assert_eq!(nether.teleportation_scale(overworld), 8.);
+
+        let overworld_pos = Vec3::new(100.5, 64., -20.);
+        let nether_pos = overworld.scale_position_to(nether, overworld_pos);
+        assert_eq!(nether_pos, Vec3::new(12.5625, 64., -2.5));
+        assert_eq!(nether_pos.to_block_pos_floor(), BlockPos::new(12, 64, -3));
+        assert_eq!(
+            nether.scale_position_to(overworld, nether_pos),
+            overworld_pos
+        );
+    }
+}
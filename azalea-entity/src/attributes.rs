@@ -26,6 +26,12 @@ pub struct Attributes {
     pub entity_interaction_range: AttributeInstance,
 
     pub step_height: AttributeInstance,
+
+    /// How high the entity can jump.
+    ///
+    /// This is mostly useful for horses, since each horse has a different
+    /// jump strength.
+    pub jump_strength: AttributeInstance,
 }
 
 impl Attributes {
@@ -41,6 +47,7 @@ impl Attributes {
             Attribute::BlockInteractionRange => &mut self.block_interaction_range,
             Attribute::EntityInteractionRange => &mut self.entity_interaction_range,
             Attribute::StepHeight => &mut self.step_height,
+            Attribute::JumpStrength => &mut self.jump_strength,
             _ => return None,
         };
         Some(value)
@@ -107,6 +114,11 @@ impl AttributeInstance {
     pub fn remove(&mut self, id: &Identifier) -> Option<AttributeModifier> {
         self.modifiers_by_id.remove(id)
     }
+
+    /// Remove every modifier from this attribute.
+    pub fn clear_modifiers(&mut self) {
+        self.modifiers_by_id.clear();
+    }
 }
 
 pub fn sprinting_modifier() -> AttributeModifier {
@@ -116,6 +128,20 @@ pub fn sprinting_modifier() -> AttributeModifier {
         operation: AttributeModifierOperation::AddMultipliedTotal,
     }
 }
+pub fn speed_effect_modifier(amplifier: u32) -> AttributeModifier {
+    AttributeModifier {
+        id: Identifier::new("effect.speed"),
+        amount: 0.2f32 as f64 * (amplifier + 1) as f64,
+        operation: AttributeModifierOperation::AddMultipliedTotal,
+    }
+}
+pub fn slowness_effect_modifier(amplifier: u32) -> AttributeModifier {
+    AttributeModifier {
+        id: Identifier::new("effect.slowness"),
+        amount: -0.15f32 as f64 * (amplifier + 1) as f64,
+        operation: AttributeModifierOperation::AddMultipliedTotal,
+    }
+}
 pub fn base_attack_speed_modifier(amount: f64) -> AttributeModifier {
     AttributeModifier {
         id: Identifier::new("base_attack_speed"),
@@ -1,4 +1,7 @@
-use std::{cmp, collections::HashSet};
+use std::{
+    cmp,
+    collections::{HashMap, HashSet},
+};
 
 use azalea_chat::FormattedText;
 use azalea_inventory::{
@@ -37,6 +40,14 @@ pub struct Inventory {
     ///
     /// This can only be `Some` when `container_menu` is `Some`.
     pub container_menu_title: Option<FormattedText>,
+    /// The properties that the server set for the container that's currently
+    /// open, as a map of property IDs to their values.
+    ///
+    /// These are used for things like the progress bars in furnaces, and are
+    /// cleared whenever a container is opened or closed. Consider using
+    /// [`Self::furnace_state`] or [`Self::brewing_state`] instead of reading
+    /// this directly.
+    pub container_data: HashMap<u16, u16>,
     /// The item that is currently held by the cursor, or `Slot::Empty` if
     /// nothing is currently being held.
     ///
@@ -90,6 +101,37 @@ impl Inventory {
         }
     }
 
+    /// Returns the progress of the furnace that's currently open, or `None` if
+    /// we don't have a furnace, blast furnace, or smoker open.
+    pub fn furnace_state(&self) -> Option<FurnaceState> {
+        if !matches!(
+            self.container_menu,
+            Some(Menu::Furnace { .. } | Menu::BlastFurnace { .. } | Menu::Smoker { .. })
+        ) {
+            return None;
+        }
+        let get = |id| self.container_data.get(&id).copied().unwrap_or_default();
+        Some(FurnaceState {
+            lit_time: get(0),
+            lit_duration: get(1),
+            cook_time: get(2),
+            total_cook_time: get(3),
+        })
+    }
+
+    /// Returns the progress of the brewing stand that's currently open, or
+    /// `None` if we don't have a brewing stand open.
+    pub fn brewing_state(&self) -> Option<BrewingState> {
+        if !matches!(self.container_menu, Some(Menu::BrewingStand { .. })) {
+            return None;
+        }
+        let get = |id| self.container_data.get(&id).copied().unwrap_or_default();
+        Some(BrewingState {
+            brew_time: get(0),
+            fuel: get(1),
+        })
+    }
+
     /// Modify the inventory as if the given operation was performed on it.
     pub fn simulate_click(
         &mut self,
@@ -451,16 +493,18 @@ impl Inventory {
                 let Some(slot) = self.menu_mut().slot_mut(slot_index) else {
                     return;
                 };
-                let ItemStack::Present(slot_item) = slot else {
+                if slot.is_empty() {
                     return;
-                };
+                }
 
                 let dropping_count = match c {
                     ThrowClick::Single { .. } => 1,
-                    ThrowClick::All { .. } => slot_item.count,
+                    ThrowClick::All { .. } => slot.count(),
                 };
 
-                let _dropping = slot_item.split(dropping_count as u32);
+                // splitting the ItemStack instead of the ItemStackData makes the slot empty if
+                // we dropped everything
+                let _dropping = slot.split(dropping_count as u32);
                 // player.drop(dropping, true);
             }
             ClickOperation::PickupAll(PickupAllClick {
@@ -530,6 +574,27 @@ impl Inventory {
             .expect("The main hand item should always be present")
     }
 
+    /// Get the item that the player is holding in their off hand.
+    pub fn off_hand_item(&self) -> &ItemStack {
+        &self.inventory_menu.as_player().offhand
+    }
+
+    /// Remove one item from the stack that the player is holding in their main
+    /// hand (or the whole stack if `full_stack` is true), and return the items
+    /// that were removed.
+    ///
+    /// This is what happens to the held item when the player presses the drop
+    /// key.
+    pub fn remove_from_selected(&mut self, full_stack: bool) -> ItemStack {
+        let main_hand_slot_idx =
+            *self.menu().hotbar_slots_range().start() + self.selected_hotbar_slot as usize;
+        let Some(slot) = self.menu_mut().slot_mut(main_hand_slot_idx) else {
+            return ItemStack::Empty;
+        };
+        let count = if full_stack { slot.count() } else { 1 };
+        slot.split(count as u32)
+    }
+
     /// TODO: implement bundles
     fn try_item_click_behavior_override(
         &self,
@@ -662,6 +727,55 @@ fn get_quick_craft_slot_count(
     item.count += slot_item_count;
 }
 
+/// The progress of a furnace, blast furnace, or smoker, as sent by the server
+/// while its menu is open.
+///
+/// All of the times are in ticks.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FurnaceState {
+    /// The number of ticks until the current fuel runs out.
+    pub lit_time: u16,
+    /// The total number of ticks that the current fuel lasts for.
+    pub lit_duration: u16,
+    /// How many ticks the current item has been cooking for.
+    pub cook_time: u16,
+    /// The number of ticks it takes for the current item to finish cooking.
+    pub total_cook_time: u16,
+}
+impl FurnaceState {
+    /// Whether the furnace is currently burning fuel.
+    pub fn is_lit(&self) -> bool {
+        self.lit_time > 0
+    }
+
+    /// How far along the current item is in cooking, from 0 to 1.
+    ///
+    /// This is what the arrow in the vanilla furnace screen shows.
+    pub fn cook_progress(&self) -> f32 {
+        if self.total_cook_time == 0 {
+            return 0.;
+        }
+        (self.cook_time as f32 / self.total_cook_time as f32).clamp(0., 1.)
+    }
+}
+
+/// The progress of a brewing stand, as sent by the server while its menu is
+/// open.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct BrewingState {
+    /// The number of ticks until the potions finish brewing, counting down
+    /// from 400. This is 0 when nothing is being brewed.
+    pub brew_time: u16,
+    /// The amount of fuel (blaze powder) left, from 0 to 20.
+    pub fuel: u16,
+}
+impl BrewingState {
+    /// Whether the brewing stand is currently brewing potions.
+    pub fn is_brewing(&self) -> bool {
+        self.brew_time > 0
+    }
+}
+
 impl Default for Inventory {
     fn default() -> Self {
         Inventory {
@@ -669,6 +783,7 @@ impl Default for Inventory {
             id: 0,
             container_menu: None,
             container_menu_title: None,
+            container_data: HashMap::new(),
             carried: ItemStack::Empty,
             state_id: 0,
             quick_craft_status: QuickCraftStatusKind::Start,
@@ -700,6 +815,7 @@ mod tests {
                 player: SlotList::default(),
             }),
             container_menu_title: None,
+            container_data: HashMap::new(),
             carried: ItemStack::Empty,
             state_id: 0,
             quick_craft_status: QuickCraftStatusKind::Start,
@@ -720,4 +836,47 @@ mod tests {
             &spruce_planks
         );
     }
+
+    #[test]
+    fn test_held_and_off_hand_item() {
+        let mut inventory = Inventory::default();
+        let menu = inventory.inventory_menu.as_player_mut();
+        // the hotbar is the last 9 slots of the inventory
+        menu.inventory[27 + 2] = ItemStack::new(ItemKind::DiamondSword, 1);
+        menu.offhand = ItemStack::new(ItemKind::Shield, 1);
+
+        assert_eq!(inventory.held_item(), &ItemStack::Empty);
+        inventory.selected_hotbar_slot = 2;
+        assert_eq!(
+            inventory.held_item(),
+            &ItemStack::new(ItemKind::DiamondSword, 1)
+        );
+        assert_eq!(
+            inventory.off_hand_item(),
+            &ItemStack::new(ItemKind::Shield, 1)
+        );
+    }
+
+    #[test]
+    fn test_remove_from_selected() {
+        let mut inventory = Inventory::default();
+        inventory.inventory_menu.as_player_mut().inventory[27] =
+            ItemStack::new(ItemKind::Cobblestone, 10);
+
+        assert_eq!(
+            inventory.remove_from_selected(false),
+            ItemStack::new(ItemKind::Cobblestone, 1)
+        );
+        assert_eq!(
+            inventory.held_item(),
+            &ItemStack::new(ItemKind::Cobblestone, 9)
+        );
+
+        assert_eq!(
+            inventory.remove_from_selected(true),
+            ItemStack::new(ItemKind::Cobblestone, 9)
+        );
+        assert_eq!(inventory.held_item(), &ItemStack::Empty);
+        assert_eq!(inventory.remove_from_selected(true), ItemStack::Empty);
+    }
 }
@@ -70,6 +70,9 @@ pub fn input_vector(direction: LookDirection, speed: f32, acceleration: Vec3) ->
     }
 }
 
+/// Get the unit vector pointing in the given direction.
+///
+/// See [`LookDirection::view_vector`].
 pub fn view_vector(look_direction: LookDirection) -> Vec3 {
     let x_rot = look_direction.x_rot * 0.017453292;
     let y_rot = -look_direction.y_rot * 0.017453292;
@@ -226,6 +229,14 @@ impl LookDirection {
         self.x_rot += delta_x_rot;
     }
 
+    /// Returns the unit vector pointing in this direction.
+    ///
+    /// Like in vanilla, a `y_rot` of 0 faces south (+Z) and 90 faces west
+    /// (-X), and an `x_rot` of -90 faces up and 90 faces down.
+    pub fn view_vector(&self) -> Vec3 {
+        view_vector(*self)
+    }
+
     /// Force the [`Self::x_rot`] to be between -90 and 90 degrees, and return
     /// the new look direction.
     #[must_use]
@@ -398,6 +409,7 @@ impl Attributes {
             block_interaction_range: AttributeInstance::new(4.5),
             entity_interaction_range: AttributeInstance::new(3.0),
             step_height: AttributeInstance::new(0.6),
+            jump_strength: AttributeInstance::new(0.42),
         }
     }
 }
@@ -426,3 +438,36 @@ pub struct PlayerAbilities {
 #[cfg_attr(feature = "bevy_ecs", derive(bevy_ecs::component::Component))]
 #[derive(Clone, Copy, Debug, Deref, DerefMut, PartialEq)]
 pub struct FluidOnEyes(FluidKind);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_vec3_approx_eq(a: Vec3, b: Vec3) {
+        assert!(
+            (a.x - b.x).abs() < 1e-3 && (a.y - b.y).abs() < 1e-3 && (a.z - b.z).abs() < 1e-3,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn test_view_vector() {
+        let south = LookDirection::new(0., 0.);
+        assert_vec3_approx_eq(south.view_vector(), Vec3::new(0., 0., 1.));
+        let west = LookDirection::new(90., 0.);
+        assert_vec3_approx_eq(west.view_vector(), Vec3::new(-1., 0., 0.));
+        let north = LookDirection::new(180., 0.);
+        assert_vec3_approx_eq(north.view_vector(), Vec3::new(0., 0., -1.));
+        let east = LookDirection::new(-90., 0.);
+        assert_vec3_approx_eq(east.view_vector(), Vec3::new(1., 0., 0.));
+
+        let up = LookDirection::new(0., -90.);
+        assert_vec3_approx_eq(up.view_vector(), Vec3::new(0., 1., 0.));
+        let down = LookDirection::new(0., 90.);
+        assert_vec3_approx_eq(down.view_vector(), Vec3::new(0., -1., 0.));
+
+        let south_west_down = LookDirection::new(45., 45.).view_vector();
+        assert_vec3_approx_eq(south_west_down, Vec3::new(-0.5, -0.70710677, 0.5));
+        assert!((south_west_down.length() - 1.).abs() < 1e-3);
+    }
+}
@@ -1,11 +1,11 @@
-use azalea_block::{BlockBehavior, BlockTrait};
+use azalea_block::{BlockBehavior, BlockTrait, fluid_state::FluidKind};
 use azalea_core::tier::get_item_tier;
 use azalea_registry::{
-    builtin::{BlockKind, ItemKind, MobEffect},
+    builtin::{BlockKind, EntityKind, ItemKind, MobEffect},
     tags,
 };
 
-use crate::{ActiveEffects, Attributes, FluidOnEyes, Physics};
+use crate::{ActiveEffects, Attributes, FluidOnEyes, MobEffectData, Physics};
 
 /// How much progress is made towards mining the block per tick, as a
 /// percentage.
@@ -48,6 +48,71 @@ pub fn get_mine_progress(
     (base_destroy_speed / destroy_time) / divisor as f32
 }
 
+/// Returns the number of ticks that it takes to mine the given block, or `None`
+/// if the block can't be mined at all.
+///
+/// This is a simpler version of [`get_mine_progress`] for when you don't have
+/// the components of a player. `efficiency` is the level of the Efficiency
+/// enchantment on the tool (or 0), and `haste` is the amplifier of the Haste
+/// effect if it's active.
+///
+/// Blocks that are broken instantly will return `Some(0)`.
+pub fn calculate_dig_ticks(
+    block: &dyn BlockTrait,
+    tool: ItemKind,
+    efficiency: u32,
+    haste: Option<u32>,
+    on_ground: bool,
+    in_water: bool,
+) -> Option<u32> {
+    let mut physics = Physics::default();
+    physics.set_on_ground(on_ground);
+
+    let mut attributes = Attributes::new(EntityKind::Player);
+    if efficiency > 0 {
+        attributes.mining_efficiency.base = (efficiency * efficiency + 1) as f64;
+    }
+
+    let mut active_effects = ActiveEffects::default();
+    if let Some(amplifier) = haste {
+        active_effects.insert(
+            MobEffect::Haste,
+            MobEffectData {
+                amplifier,
+                ..Default::default()
+            },
+        );
+    }
+
+    let fluid_on_eyes = FluidOnEyes::new(if in_water {
+        FluidKind::Water
+    } else {
+        FluidKind::Empty
+    });
+
+    let progress = get_mine_progress(
+        block,
+        tool,
+        &fluid_on_eyes,
+        &physics,
+        &attributes,
+        &active_effects,
+    );
+    dig_ticks_from_progress(progress)
+}
+
+/// Convert the progress per tick from [`get_mine_progress`] into the number of
+/// ticks that it takes to mine the block.
+pub fn dig_ticks_from_progress(progress: f32) -> Option<u32> {
+    if progress <= 0. {
+        None
+    } else if progress >= 1. {
+        Some(0)
+    } else {
+        Some((1. / progress).ceil() as u32)
+    }
+}
+
 fn has_correct_tool_for_drops(block: &dyn BlockTrait, tool: ItemKind) -> bool {
     if !block.behavior().requires_correct_tool_for_drops {
         return true;
@@ -171,3 +236,51 @@ fn base_destroy_speed(block: BlockKind, tool: ItemKind) -> f32 {
         1.
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use azalea_block::BlockState;
+
+    use super::*;
+
+    fn dig_ticks(block: BlockKind, tool: ItemKind) -> Option<u32> {
+        let block = Box::<dyn BlockTrait>::from(BlockState::from(block));
+        calculate_dig_ticks(block.as_ref(), tool, 0, None, true, false)
+    }
+
+    #[test]
+    fn test_dig_ticks() {
+        // these match the values on the wiki
+        assert_eq!(
+            dig_ticks(BlockKind::Stone, ItemKind::DiamondPickaxe),
+            Some(6)
+        );
+        assert_eq!(
+            dig_ticks(BlockKind::Stone, ItemKind::WoodenPickaxe),
+            Some(23)
+        );
+        assert_eq!(dig_ticks(BlockKind::Stone, ItemKind::Air), Some(150));
+        assert_eq!(dig_ticks(BlockKind::Dirt, ItemKind::Air), Some(15));
+        assert_eq!(dig_ticks(BlockKind::ShortGrass, ItemKind::Air), Some(0));
+        assert_eq!(
+            dig_ticks(BlockKind::Bedrock, ItemKind::DiamondPickaxe),
+            None
+        );
+    }
+
+    #[test]
+    fn test_dig_ticks_with_modifiers() {
+        let stone = Box::<dyn BlockTrait>::from(BlockState::from(BlockKind::Stone));
+        let stone = stone.as_ref();
+        // efficiency V diamond pickaxe with haste II instamines stone
+        assert_eq!(
+            calculate_dig_ticks(stone, ItemKind::DiamondPickaxe, 5, Some(1), true, false),
+            Some(0)
+        );
+        // mining while in the air is 5x slower
+        assert_eq!(
+            calculate_dig_ticks(stone, ItemKind::DiamondPickaxe, 0, None, false, false),
+            Some(29)
+        );
+    }
+}
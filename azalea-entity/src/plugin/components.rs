@@ -2,7 +2,7 @@ use azalea_block::fluid_state::FluidKind;
 use azalea_core::position::{BlockPos, ChunkPos, Vec3};
 use azalea_registry::builtin::EntityKind;
 use azalea_world::WorldName;
-use bevy_ecs::{bundle::Bundle, component::Component};
+use bevy_ecs::{bundle::Bundle, component::Component, entity::Entity};
 use derive_more::{Deref, DerefMut};
 use uuid::Uuid;
 
@@ -67,6 +67,13 @@ impl EntityBundle {
 #[derive(Clone, Component, Copy, Default)]
 pub struct Dead;
 
+/// The entity that this entity is riding, like a horse or a boat.
+///
+/// This is set when the server sends a `SetPassengers` packet, and is removed
+/// when the entity dismounts.
+#[derive(Clone, Component, Copy, Debug, Deref, PartialEq)]
+pub struct Vehicle(pub Entity);
+
 /// A component NewType for [`EntityKind`].
 ///
 /// Most of the time, you should be using `azalea_registry::EntityKind`
@@ -318,7 +318,7 @@ impl Menu {
             },
             MenuLocation::Generic9x1(l) => match l {
                 Generic9x1MenuLocation::Contents => {
-                    self.try_move_item_to_slots(slot_index, self.player_slots_range());
+                    self.try_move_item_to_slots(slot_index, self.player_slots_range().rev());
                 }
                 Generic9x1MenuLocation::Player => {
                     self.try_move_item_to_slots_or_toggle_hotbar(
@@ -329,7 +329,7 @@ impl Menu {
             },
             MenuLocation::Generic9x2(l) => match l {
                 Generic9x2MenuLocation::Contents => {
-                    self.try_move_item_to_slots(slot_index, self.player_slots_range());
+                    self.try_move_item_to_slots(slot_index, self.player_slots_range().rev());
                 }
                 Generic9x2MenuLocation::Player => {
                     self.try_move_item_to_slots_or_toggle_hotbar(
@@ -340,7 +340,7 @@ impl Menu {
             },
             MenuLocation::Generic9x3(l) => match l {
                 Generic9x3MenuLocation::Contents => {
-                    self.try_move_item_to_slots(slot_index, self.player_slots_range());
+                    self.try_move_item_to_slots(slot_index, self.player_slots_range().rev());
                 }
                 Generic9x3MenuLocation::Player => {
                     self.try_move_item_to_slots_or_toggle_hotbar(
@@ -351,7 +351,7 @@ impl Menu {
             },
             MenuLocation::Generic9x4(l) => match l {
                 Generic9x4MenuLocation::Contents => {
-                    self.try_move_item_to_slots(slot_index, self.player_slots_range());
+                    self.try_move_item_to_slots(slot_index, self.player_slots_range().rev());
                 }
                 Generic9x4MenuLocation::Player => {
                     self.try_move_item_to_slots_or_toggle_hotbar(
@@ -362,7 +362,7 @@ impl Menu {
             },
             MenuLocation::Generic9x5(l) => match l {
                 Generic9x5MenuLocation::Contents => {
-                    self.try_move_item_to_slots(slot_index, self.player_slots_range());
+                    self.try_move_item_to_slots(slot_index, self.player_slots_range().rev());
                 }
                 Generic9x5MenuLocation::Player => {
                     self.try_move_item_to_slots_or_toggle_hotbar(
@@ -373,7 +373,7 @@ impl Menu {
             },
             MenuLocation::Generic9x6(l) => match l {
                 Generic9x6MenuLocation::Contents => {
-                    self.try_move_item_to_slots(slot_index, self.player_slots_range());
+                    self.try_move_item_to_slots(slot_index, self.player_slots_range().rev());
                 }
                 Generic9x6MenuLocation::Player => {
                     self.try_move_item_to_slots_or_toggle_hotbar(
@@ -384,7 +384,7 @@ impl Menu {
             },
             MenuLocation::Generic3x3(l) => match l {
                 Generic3x3MenuLocation::Contents => {
-                    self.try_move_item_to_slots(slot_index, self.player_slots_range());
+                    self.try_move_item_to_slots(slot_index, self.player_slots_range().rev());
                 }
                 Generic3x3MenuLocation::Player => {
                     self.try_move_item_to_slots_or_toggle_hotbar(
@@ -501,7 +501,7 @@ impl Menu {
                     );
                 }
                 _ => {
-                    self.try_move_item_to_slots(slot_index, self.player_slots_range());
+                    self.try_move_item_to_slots(slot_index, self.player_slots_range().rev());
                 }
             },
             MenuLocation::Lectern(l) => match l {
@@ -545,7 +545,7 @@ impl Menu {
                     );
                 }
                 _ => {
-                    self.try_move_item_to_slots(slot_index, self.player_slots_range());
+                    self.try_move_item_to_slots(slot_index, self.player_slots_range().rev());
                 }
             },
             MenuLocation::Smithing(l) => match l {
@@ -645,13 +645,14 @@ impl Menu {
         64
     }
 
-    /// Try moving an item to a set of slots in this menu.
+    /// Try moving an item to a set of slots in this menu, in the order that
+    /// the slots are given in.
     ///
-    /// Returns the updated item slot.
+    /// Returns whether the source slot is now empty.
     fn try_move_item_to_slots(
         &mut self,
         item_slot_index: usize,
-        target_slot_indexes: RangeInclusive<usize>,
+        target_slot_indexes: impl Iterator<Item = usize> + Clone,
     ) -> bool {
         let mut item_slot = self.slot(item_slot_index).unwrap().clone();
 
@@ -693,16 +694,26 @@ impl Menu {
         };
         let target_slot = self.slot(target_slot_index).unwrap();
         if let ItemStack::Present(target_item) = target_slot {
-            // the target slot is empty, so we can just move the item there
             if self.may_place(target_slot_index, item)
                 && target_item.is_same_item_and_components(item)
             {
-                let slot_item_limit = self.max_stack_size(target_slot_index);
-                let new_target_slot_data = item.split(i32::min(slot_item_limit, item.count) as u32);
+                let slot_item_limit = i32::min(
+                    self.max_stack_size(target_slot_index),
+                    item.kind.max_stack_size(),
+                );
+                // only move as many items as there's space for, the rest stay in the source
+                // slot
+                let moved_count = i32::min(slot_item_limit - target_item.count, item.count);
+                if moved_count <= 0 {
+                    return;
+                }
+                item.count -= moved_count;
 
                 // get the target slot again but mut this time so we can update it
                 let target_slot = self.slot_mut(target_slot_index).unwrap();
-                *target_slot = ItemStack::from(new_target_slot_data);
+                if let ItemStack::Present(target_item) = target_slot {
+                    target_item.count += moved_count;
+                }
 
                 item_slot.update_empty();
             }
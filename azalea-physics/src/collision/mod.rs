@@ -475,6 +475,55 @@ fn calculate_shape_for_fluid(amount: u8) -> VoxelShape {
     box_shape(0.0, 0.0, 0.0, 1.0, (f32::from(amount) / 9.0) as f64, 1.0)
 }
 
+/// Find the block that an entity with the given bounding box is standing on.
+///
+/// This uses the actual collision shapes of the blocks, so it works correctly
+/// for things like slabs and fences. If the entity is standing on multiple
+/// blocks, then the one closest to `position` is returned.
+// Level.findSupportingBlock
+pub fn find_supporting_block(
+    world: &World,
+    position: Vec3,
+    bounding_box: &Aabb,
+) -> Option<BlockPos> {
+    let below_box = Aabb {
+        min: bounding_box.min.with_y(bounding_box.min.y - 1.0E-6),
+        max: bounding_box.max.with_y(bounding_box.min.y),
+    };
+    // fences and walls are taller than one block, so we have to check an extra
+    // block lower
+    let search_box = Aabb {
+        min: below_box.min.with_y(below_box.min.y - 1.),
+        max: below_box.max,
+    };
+
+    let mut closest: Option<(BlockPos, f64)> = None;
+    for block_pos in BlockPos::between_closed_aabb(&search_box) {
+        let Some(block_state) = world.get_block_state(block_pos) else {
+            continue;
+        };
+        let shape = block_state.collision_shape();
+        if shape.is_empty() {
+            continue;
+        }
+        let collides = shape
+            .move_relative(block_pos.to_vec3_floored())
+            .to_aabbs()
+            .iter()
+            .any(|aabb| aabb.intersects_aabb(&below_box));
+        if !collides {
+            continue;
+        }
+
+        let distance = block_pos.center().distance_squared_to(position);
+        if closest.is_none_or(|(_, closest_distance)| distance < closest_distance) {
+            closest = Some((block_pos, distance));
+        }
+    }
+
+    closest.map(|(block_pos, _)| block_pos)
+}
+
 /// Whether the block is treated as "motion blocking".
 ///
 /// This is marked as deprecated in Minecraft.
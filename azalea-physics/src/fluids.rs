@@ -6,7 +6,10 @@ use azalea_core::{
     direction::Direction,
     position::{BlockPos, Vec3},
 };
-use azalea_entity::{HasClientLoaded, LocalEntity, Physics, Position};
+use azalea_entity::{
+    FluidOnEyes, HasClientLoaded, LocalEntity, Physics, Position,
+    metadata::{Sprinting, Swimming},
+};
 use azalea_registry::builtin::BlockKind;
 use azalea_world::{World, WorldName, Worlds};
 use bevy_ecs::prelude::*;
@@ -175,8 +178,44 @@ fn update_fluid_height_and_do_fluid_pushing(
     touching_fluid
 }
 
-pub fn update_swimming() {
-    // TODO: swimming
+/// Update whether local players are swimming, based on whether they're
+/// sprinting in water.
+// Entity.updateSwimming
+#[allow(clippy::type_complexity)]
+pub fn update_swimming(
+    mut query: Query<
+        (
+            &mut Swimming,
+            &Sprinting,
+            &Physics,
+            &FluidOnEyes,
+            &Position,
+            &WorldName,
+        ),
+        (With<LocalEntity>, With<HasClientLoaded>),
+    >,
+    worlds: Res<Worlds>,
+) {
+    for (mut swimming, sprinting, physics, fluid_on_eyes, position, world_name) in &mut query {
+        // TODO: vehicles can't swim, so this should also check `!is_passenger`
+        let new_swimming = if **swimming {
+            **sprinting && physics.is_in_water()
+        } else {
+            let is_under_water = **fluid_on_eyes == FluidKind::Water;
+            **sprinting
+                && is_under_water
+                && worlds.get(world_name).is_some_and(|world| {
+                    world
+                        .read()
+                        .get_fluid_state(BlockPos::from(position))
+                        .is_some_and(|fluid| fluid.kind == FluidKind::Water)
+                })
+        };
+        // avoid triggering change detection when nothing changed
+        if **swimming != new_swimming {
+            **swimming = new_swimming;
+        }
+    }
 }
 
 // FlowingFluid.getFlow
@@ -39,6 +39,7 @@ impl Plugin for PhysicsPlugin {
         app.add_systems(
             GameTick,
             (
+                update_effect_attribute_modifiers,
                 fluids::update_in_water_state_and_do_fluid_pushing,
                 update_old_position,
                 fluids::update_swimming,
@@ -58,6 +59,48 @@ impl Plugin for PhysicsPlugin {
     }
 }
 
+/// Update the movement speed modifiers from the Speed and Slowness effects.
+///
+/// The server also sends these modifiers in the update attributes packet, but
+/// it doesn't resend them when the effects change, so we have to apply them
+/// ourselves or else we'd move at the wrong speed while the effects are active.
+pub fn update_effect_attribute_modifiers(
+    mut query: Query<
+        (&ActiveEffects, &mut Attributes),
+        (With<LocalEntity>, Changed<ActiveEffects>),
+    >,
+) {
+    for (active_effects, mut attributes) in &mut query {
+        apply_effect_attribute_modifiers(active_effects, &mut attributes);
+    }
+}
+
+/// Insert or remove the attribute modifiers for the Speed and Slowness effects,
+/// based on the given active effects.
+///
+/// This replaces any existing modifiers with the same IDs, so it's fine to call
+/// it again after the modifiers were already applied.
+pub fn apply_effect_attribute_modifiers(
+    active_effects: &ActiveEffects,
+    attributes: &mut Attributes,
+) {
+    let movement_speed = &mut attributes.movement_speed;
+
+    if let Some(amplifier) = active_effects.get_level(MobEffect::Speed) {
+        movement_speed.insert(azalea_entity::attributes::speed_effect_modifier(amplifier));
+    } else {
+        movement_speed.remove(&azalea_entity::attributes::speed_effect_modifier(0).id);
+    }
+
+    if let Some(amplifier) = active_effects.get_level(MobEffect::Slowness) {
+        movement_speed.insert(azalea_entity::attributes::slowness_effect_modifier(
+            amplifier,
+        ));
+    } else {
+        movement_speed.remove(&azalea_entity::attributes::slowness_effect_modifier(0).id);
+    }
+}
+
 /// Applies air resistance and handles jumping.
 ///
 /// Happens before [`travel::travel`].
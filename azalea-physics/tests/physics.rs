@@ -556,3 +556,56 @@ fn test_afk_pool() {
     );
     assert_eq!(loops_done, 1);
 }
+
+#[test]
+fn test_find_supporting_block_on_fence() {
+    let mut app = make_test_app();
+    let world_lock = insert_overworld(&mut app);
+    let mut partial_world = PartialWorld::default();
+
+    partial_world.chunks.set(
+        &ChunkPos { x: 0, z: 0 },
+        Some(Chunk::default()),
+        &mut world_lock.write().chunks,
+    );
+    let entity = app
+        .world_mut()
+        .spawn((
+            EntityBundle::new(
+                Uuid::nil(),
+                Vec3 {
+                    x: 0.5,
+                    y: 71.,
+                    z: 0.5,
+                },
+                EntityKind::Player,
+                WorldName::new("minecraft:overworld"),
+            ),
+            MinecraftEntityId(0),
+            LocalEntity,
+            HasClientLoaded,
+        ))
+        .id();
+    let fence_pos = BlockPos { x: 0, y: 69, z: 0 };
+    partial_world.chunks.set_block_state(
+        fence_pos,
+        BlockKind::OakFence.into(),
+        &world_lock.write().chunks,
+    );
+    // do a few steps so we fall on the fence
+    for _ in 0..20 {
+        app.world_mut().run_schedule(GameTick);
+        app.update();
+    }
+    let entity_pos = *app.world_mut().get::<Position>(entity).unwrap();
+    // fences are 1.5 blocks tall, so the block at our feet is air
+    assert_eq!(entity_pos.y, 70.5);
+
+    let physics = app.world_mut().get::<Physics>(entity).unwrap();
+    let supporting_block = azalea_physics::collision::find_supporting_block(
+        &world_lock.read(),
+        *entity_pos,
+        &physics.bounding_box,
+    );
+    assert_eq!(supporting_block, Some(fence_pos));
+}
@@ -0,0 +1,63 @@
+//! Make sure that packets are written the same way that they're read.
+//!
+//! If the input can be read as a packet, the packet is written and read
+//! again, and then written a second time. Both of the written packets must be
+//! identical, otherwise the reading and writing code for the packet disagree.
+//!
+//! We compare the written bytes instead of the packets so NaN floats don't
+//! cause false positives, and so packets that ignore some bits of the input
+//! (like unused flags) don't either.
+
+#![no_main]
+
+use std::{fmt::Debug, io::Cursor};
+
+use azalea_protocol::{
+    packets::{
+        ProtocolPacket,
+        config::{ClientboundConfigPacket, ServerboundConfigPacket},
+        game::{ClientboundGamePacket, ServerboundGamePacket},
+        handshake::{ClientboundHandshakePacket, ServerboundHandshakePacket},
+        login::{ClientboundLoginPacket, ServerboundLoginPacket},
+        status::{ClientboundStatusPacket, ServerboundStatusPacket},
+    },
+    read::deserialize_packet,
+    write::serialize_packet,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&kind, data)) = data.split_first() else {
+        return;
+    };
+    match kind % 10 {
+        0 => round_trip::<ClientboundConfigPacket>(data),
+        1 => round_trip::<ClientboundGamePacket>(data),
+        2 => round_trip::<ClientboundHandshakePacket>(data),
+        3 => round_trip::<ClientboundLoginPacket>(data),
+        4 => round_trip::<ClientboundStatusPacket>(data),
+        5 => round_trip::<ServerboundConfigPacket>(data),
+        6 => round_trip::<ServerboundGamePacket>(data),
+        7 => round_trip::<ServerboundHandshakePacket>(data),
+        8 => round_trip::<ServerboundLoginPacket>(data),
+        _ => round_trip::<ServerboundStatusPacket>(data),
+    }
+});
+
+fn round_trip<P: ProtocolPacket + Debug>(data: &[u8]) {
+    let Ok(packet) = deserialize_packet::<P>(&mut Cursor::new(data)) else {
+        return;
+    };
+    // packets that are too big to be written are fine
+    let Ok(written) = serialize_packet(&packet) else {
+        return;
+    };
+
+    let reread = deserialize_packet::<P>(&mut Cursor::new(&*written))
+        .unwrap_or_else(|e| panic!("couldn't read written packet {packet:?}: {e}"));
+    let rewritten = serialize_packet(&reread).unwrap();
+    assert_eq!(
+        written, rewritten,
+        "packet was written differently after being read again: {packet:?} became {reread:?}"
+    );
+}
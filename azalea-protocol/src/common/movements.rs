@@ -109,10 +109,12 @@ impl AzBuf for RelativeMovements {
         })
     }
     fn azalea_write(&self, buf: &mut impl Write) -> io::Result<()> {
-        let mut set = FixedBitSet::<32>::new();
+        // this is a big-endian int, so we can't write it as a FixedBitSet directly
+        // (since that'd put the first bit in the first byte instead of the last one)
+        let mut set = 0u32;
         let mut set_bit = |index: usize, value: bool| {
             if value {
-                set.set(index);
+                set |= 1 << index;
             }
         };
 
@@ -157,3 +159,25 @@ impl AzBuf for MoveFlags {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_movements_round_trip() {
+        let relative = RelativeMovements {
+            x: true,
+            delta_z: true,
+            rotate_delta: true,
+            ..Default::default()
+        };
+        let mut buf = Vec::new();
+        relative.azalea_write(&mut buf).unwrap();
+        // this is written as a big-endian int
+        assert_eq!(buf, [0, 0, 0b1, 0b1000_0001]);
+
+        let read = RelativeMovements::azalea_read(&mut Cursor::new(buf.as_slice())).unwrap();
+        assert_eq!(read, relative);
+    }
+}
@@ -36,7 +36,10 @@ use crate::{
         login::{ClientboundLoginPacket, ServerboundLoginPacket},
         status::{ClientboundStatusPacket, ServerboundStatusPacket},
     },
-    read::{ReadPacketError, deserialize_packet, read_raw_packet, try_read_raw_packet},
+    read::{
+        MAXIMUM_UNCOMPRESSED_LENGTH, ReadPacketError, deserialize_packet, read_raw_packet_with_max,
+        try_read_raw_packet_with_max,
+    },
     write::{serialize_packet, write_raw_packet},
 };
 
@@ -44,6 +47,15 @@ pub struct RawReadConnection {
     pub read_stream: OwnedReadHalf,
     pub buffer: Cursor<Vec<u8>>,
     pub compression_threshold: Option<u32>,
+    /// The largest size that a compressed packet is allowed to declare for its
+    /// decompressed data.
+    ///
+    /// Packets that are larger than this are rejected with
+    /// [`DecompressionError::AboveCompressionThreshold`] before they're
+    /// decompressed. Defaults to [`MAXIMUM_UNCOMPRESSED_LENGTH`].
+    ///
+    /// [`DecompressionError::AboveCompressionThreshold`]: crate::read::DecompressionError::AboveCompressionThreshold
+    pub max_decompressed_size: u32,
     pub dec_cipher: Option<Aes128CfbDec>,
 }
 
@@ -148,20 +160,22 @@ pub struct Connection<R: ProtocolPacket, W: ProtocolPacket> {
 
 impl RawReadConnection {
     pub async fn read(&mut self) -> Result<Box<[u8]>, Box<ReadPacketError>> {
-        read_raw_packet::<_>(
+        read_raw_packet_with_max::<_>(
             &mut self.read_stream,
             &mut self.buffer,
             self.compression_threshold,
+            self.max_decompressed_size,
             &mut self.dec_cipher,
         )
         .await
     }
 
     pub fn try_read(&mut self) -> Result<Option<Box<[u8]>>, Box<ReadPacketError>> {
-        try_read_raw_packet::<_>(
+        try_read_raw_packet_with_max::<_>(
             &mut self.read_stream,
             &mut self.buffer,
             self.compression_threshold,
+            self.max_decompressed_size,
             &mut self.dec_cipher,
         )
     }
@@ -348,6 +362,7 @@ impl Connection<ClientboundHandshakePacket, ServerboundHandshakePacket> {
                     read_stream,
                     buffer: Cursor::new(Vec::new()),
                     compression_threshold: None,
+                    max_decompressed_size: MAXIMUM_UNCOMPRESSED_LENGTH,
                     dec_cipher: None,
                 },
                 _reading: PhantomData,
@@ -633,6 +648,7 @@ where
                     read_stream,
                     buffer: Cursor::new(Vec::new()),
                     compression_threshold: None,
+                    max_decompressed_size: MAXIMUM_UNCOMPRESSED_LENGTH,
                     dec_cipher: None,
                 },
                 _reading: PhantomData,
@@ -33,7 +33,10 @@ mod tests {
     use crate::{
         packets::{
             Packet,
-            game::s_chat::{LastSeenMessagesUpdate, ServerboundChat},
+            game::{
+                ServerboundGamePacket,
+                s_chat::{LastSeenMessagesUpdate, ServerboundChat},
+            },
             login::{ServerboundLoginPacket, s_hello::ServerboundHello},
         },
         read::{compression_decoder, read_packet},
@@ -118,4 +121,71 @@ mod tests {
 
         compression_decoder(&mut Cursor::new(&buf), compression_threshold).unwrap();
     }
+
+    fn chat_packet(message: String) -> ServerboundGamePacket {
+        ServerboundChat {
+            message,
+            timestamp: 0,
+            salt: 0,
+            signature: None,
+            last_seen_messages: LastSeenMessagesUpdate::default(),
+        }
+        .into_variant()
+    }
+
+    #[tokio::test]
+    async fn test_compressed_packet_round_trip() {
+        let compression_threshold = 256;
+        let packet = chat_packet("a".repeat(1000));
+        let raw_packet = serialize_packet(&packet).unwrap();
+
+        let compressed = compression_encoder(&raw_packet, compression_threshold).unwrap();
+        // a long run of the same character should compress well
+        assert!(compressed.len() < raw_packet.len());
+        let decompressed =
+            compression_decoder(&mut Cursor::new(&compressed), compression_threshold).unwrap();
+        assert_eq!(&*decompressed, &*raw_packet);
+
+        let mut stream = Vec::new();
+        write_packet(&packet, &mut stream, Some(compression_threshold), &mut None)
+            .await
+            .unwrap();
+        let received = read_packet::<ServerboundGamePacket, _>(
+            &mut Cursor::new(stream),
+            &mut Cursor::new(Vec::new()),
+            Some(compression_threshold),
+            &mut None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(received, packet);
+    }
+
+    #[tokio::test]
+    async fn test_uncompressed_packet_below_threshold() {
+        let compression_threshold = 256;
+        let packet = chat_packet("hello".to_owned());
+        let raw_packet = serialize_packet(&packet).unwrap();
+        assert!(raw_packet.len() < compression_threshold as usize);
+
+        // packets below the threshold are sent with a data length of 0, followed by
+        // the uncompressed data
+        let encoded = compression_encoder(&raw_packet, compression_threshold).unwrap();
+        assert_eq!(encoded[0], 0);
+        assert_eq!(&encoded[1..], &*raw_packet);
+
+        let mut stream = Vec::new();
+        write_packet(&packet, &mut stream, Some(compression_threshold), &mut None)
+            .await
+            .unwrap();
+        let received = read_packet::<ServerboundGamePacket, _>(
+            &mut Cursor::new(stream),
+            &mut Cursor::new(Vec::new()),
+            Some(compression_threshold),
+            &mut None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(received, packet);
+    }
 }
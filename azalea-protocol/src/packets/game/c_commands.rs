@@ -1,5 +1,10 @@
 use std::io::{self, Cursor, Write};
 
+use azalea_brigadier::{
+    arguments::{ArgumentParser, string_argument_type::StringArgument},
+    command_dispatcher::CommandDispatcher,
+    tree::{PacketNode, PacketNodeType},
+};
 use azalea_buf::{AzBuf, AzBufVar, BufReadError};
 use azalea_core::bitset::FixedBitSet;
 use azalea_protocol_macros::ClientboundGamePacket;
@@ -13,6 +18,25 @@ pub struct ClientboundCommands {
     pub root_index: u32,
 }
 
+impl ClientboundCommands {
+    /// Create a Commands packet that describes every command in the given
+    /// dispatcher.
+    ///
+    /// See [`CommandDispatcher::to_packet_nodes`] and the [`From<PacketNode>`]
+    /// implementation for [`BrigadierNodeStub`] for details.
+    pub fn from_dispatcher<S>(dispatcher: &CommandDispatcher<S>) -> Self {
+        Self {
+            entries: dispatcher
+                .to_packet_nodes()
+                .into_iter()
+                .map(BrigadierNodeStub::from)
+                .collect(),
+            // to_packet_nodes always puts the root first
+            root_index: 0,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct BrigadierNodeStub {
     pub is_executable: bool,
@@ -326,6 +350,69 @@ impl BrigadierNodeStub {
     }
 }
 
+/// The suggestion provider that tells the client to ask the server for
+/// suggestions.
+pub const ASK_SERVER_SUGGESTIONS: &str = "minecraft:ask_server";
+
+impl From<PacketNode> for BrigadierNodeStub {
+    /// Convert a node from [`CommandDispatcher::to_packet_nodes`].
+    ///
+    /// Custom argument types don't have a parser that the client knows about,
+    /// so they're sent as a single-word string. Arguments with custom
+    /// suggestions use [`ASK_SERVER_SUGGESTIONS`].
+    fn from(node: PacketNode) -> Self {
+        let node_type = match node.node_type {
+            PacketNodeType::Root => NodeType::Root,
+            PacketNodeType::Literal { name } => NodeType::Literal { name },
+            PacketNodeType::Argument {
+                name,
+                parser,
+                has_custom_suggestions,
+            } => NodeType::Argument {
+                name,
+                parser: parser.map_or(
+                    BrigadierParser::String(BrigadierString::SingleWord),
+                    BrigadierParser::from,
+                ),
+                suggestions_type: has_custom_suggestions
+                    .then(|| Identifier::new(ASK_SERVER_SUGGESTIONS)),
+            },
+        };
+        BrigadierNodeStub {
+            is_executable: node.is_executable,
+            children: node.children,
+            redirect_node: node.redirect_node,
+            node_type,
+            is_restricted: false,
+        }
+    }
+}
+
+impl From<ArgumentParser> for BrigadierParser {
+    fn from(parser: ArgumentParser) -> Self {
+        match parser {
+            ArgumentParser::Bool => BrigadierParser::Bool,
+            ArgumentParser::Float { min, max } => {
+                BrigadierParser::Float(BrigadierNumber::new(min, max))
+            }
+            ArgumentParser::Double { min, max } => {
+                BrigadierParser::Double(BrigadierNumber::new(min, max))
+            }
+            ArgumentParser::Integer { min, max } => {
+                BrigadierParser::Integer(BrigadierNumber::new(min, max))
+            }
+            ArgumentParser::Long { min, max } => {
+                BrigadierParser::Long(BrigadierNumber::new(min, max))
+            }
+            ArgumentParser::String(string) => BrigadierParser::String(match string {
+                StringArgument::SingleWord => BrigadierString::SingleWord,
+                StringArgument::QuotablePhrase => BrigadierString::QuotablePhrase,
+                StringArgument::GreedyPhrase => BrigadierString::GreedyPhrase,
+            }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -383,4 +470,54 @@ mod tests {
         let read_data = BrigadierNodeStub::azalea_read(&mut data_cursor).unwrap();
         assert_eq!(data, read_data);
     }
+
+    #[test]
+    fn test_commands_from_dispatcher_round_trip() {
+        use azalea_brigadier::prelude::*;
+
+        let mut dispatcher = CommandDispatcher::<()>::new();
+        let foo = dispatcher.register(literal("foo").then(
+            argument("bar", integer()).then(argument("baz", greedy_string()).executes(|_| 0)),
+        ));
+        dispatcher.register(literal("redirect").redirect(foo));
+
+        let packet = ClientboundCommands::from_dispatcher(&dispatcher);
+        let mut buf = Vec::new();
+        packet.azalea_write(&mut buf).unwrap();
+        let read_packet = ClientboundCommands::azalea_read(&mut Cursor::new(&buf[..])).unwrap();
+        assert_eq!(packet, read_packet);
+
+        let root = &read_packet.entries[read_packet.root_index as usize];
+        assert_eq!(root.node_type, NodeType::Root);
+        let bar = read_packet
+            .entries
+            .iter()
+            .find(|node| node.name() == Some("bar"))
+            .unwrap();
+        assert_eq!(
+            bar.node_type,
+            NodeType::Argument {
+                name: "bar".to_owned(),
+                parser: BrigadierParser::Integer(BrigadierNumber::new(None, None)),
+                suggestions_type: None,
+            }
+        );
+        let baz = &read_packet.entries[bar.children[0] as usize];
+        assert!(baz.is_executable);
+        assert_eq!(
+            baz.node_type,
+            NodeType::Argument {
+                name: "baz".to_owned(),
+                parser: BrigadierParser::String(BrigadierString::GreedyPhrase),
+                suggestions_type: None,
+            }
+        );
+        let redirect = read_packet
+            .entries
+            .iter()
+            .find(|node| node.name() == Some("redirect"))
+            .unwrap();
+        let foo = &read_packet.entries[redirect.redirect_node.unwrap() as usize];
+        assert_eq!(foo.name(), Some("foo"));
+    }
 }
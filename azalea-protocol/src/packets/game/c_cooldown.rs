@@ -1,10 +1,16 @@
 use azalea_buf::AzBuf;
 use azalea_protocol_macros::ClientboundGamePacket;
-use azalea_registry::builtin::ItemKind;
+use azalea_registry::identifier::Identifier;
 
 #[derive(AzBuf, ClientboundGamePacket, Clone, Debug, PartialEq)]
 pub struct ClientboundCooldown {
-    pub item: ItemKind,
+    /// The cooldown group that this applies to.
+    ///
+    /// This is the `cooldown_group` from the item's `use_cooldown` component if
+    /// it has one, and otherwise it's the item's ID (like
+    /// `minecraft:ender_pearl`).
+    pub cooldown_group: Identifier,
+    /// The length of the cooldown in ticks, or 0 if it was removed.
     #[var]
     pub duration: u32,
 }
@@ -160,6 +160,12 @@ pub fn deserialize_packet<P: ProtocolPacket + Debug>(
 // this is always true in multiplayer, false in singleplayer
 static VALIDATE_DECOMPRESSED: bool = true;
 
+/// The maximum size that a packet is allowed to be after it's decompressed.
+///
+/// This is the same as vanilla, and it's the default for
+/// [`RawReadConnection::max_decompressed_size`].
+///
+/// [`RawReadConnection::max_decompressed_size`]: crate::connect::RawReadConnection::max_decompressed_size
 pub static MAXIMUM_UNCOMPRESSED_LENGTH: u32 = 8_388_608;
 
 #[derive(Debug, Error)]
@@ -190,6 +196,18 @@ pub enum DecompressionError {
 pub fn compression_decoder(
     stream: &mut Cursor<&[u8]>,
     compression_threshold: u32,
+) -> Result<Box<[u8]>, DecompressionError> {
+    compression_decoder_with_max(stream, compression_threshold, MAXIMUM_UNCOMPRESSED_LENGTH)
+}
+
+/// Get the decompressed bytes from a packet, with a custom maximum for the
+/// decompressed size.
+///
+/// See [`compression_decoder`].
+pub fn compression_decoder_with_max(
+    stream: &mut Cursor<&[u8]>,
+    compression_threshold: u32,
+    max_decompressed_size: u32,
 ) -> Result<Box<[u8]>, DecompressionError> {
     // Data Length
     let n = u32::azalea_read_var(stream)?;
@@ -203,22 +221,11 @@ pub fn compression_decoder(
     }
 
     if VALIDATE_DECOMPRESSED {
-        if n < compression_threshold {
-            return Err(DecompressionError::BelowCompressionThreshold {
-                size: n,
-                threshold: compression_threshold,
-            });
-        }
-        if n > MAXIMUM_UNCOMPRESSED_LENGTH {
-            return Err(DecompressionError::AboveCompressionThreshold {
-                size: n,
-                maximum: MAXIMUM_UNCOMPRESSED_LENGTH,
-            });
-        }
+        validate_decompressed_size(n, compression_threshold, max_decompressed_size)?;
     }
 
     // VALIDATE_DECOMPRESSED should always be true, so the max they can make us
-    // allocate here is 2mb
+    // allocate here is max_decompressed_size
     let mut decoded_buf = Vec::with_capacity(n as usize);
 
     let mut decoder = ZlibDecoder::new(stream);
@@ -227,6 +234,28 @@ pub fn compression_decoder(
     Ok(decoded_buf.into_boxed_slice())
 }
 
+/// Check the decompressed size that a packet declares, before we allocate a
+/// buffer for it.
+fn validate_decompressed_size(
+    size: u32,
+    compression_threshold: u32,
+    max_decompressed_size: u32,
+) -> Result<(), DecompressionError> {
+    if size < compression_threshold {
+        return Err(DecompressionError::BelowCompressionThreshold {
+            size,
+            threshold: compression_threshold,
+        });
+    }
+    if size > max_decompressed_size {
+        return Err(DecompressionError::AboveCompressionThreshold {
+            size,
+            maximum: max_decompressed_size,
+        });
+    }
+    Ok(())
+}
+
 /// Read a single packet from a stream.
 ///
 /// The buffer is required because servers may send multiple packets in the
@@ -276,11 +305,39 @@ pub async fn read_raw_packet<R>(
     // otherwise the borrow checker complains about the cipher being moved
     cipher: &mut Option<Aes128CfbDec>,
 ) -> Result<Box<[u8]>, Box<ReadPacketError>>
+where
+    R: AsyncRead + Unpin + Send + Sync,
+{
+    read_raw_packet_with_max(
+        stream,
+        buffer,
+        compression_threshold,
+        MAXIMUM_UNCOMPRESSED_LENGTH,
+        cipher,
+    )
+    .await
+}
+
+/// Like [`read_raw_packet`], but with a custom maximum for the decompressed
+/// size of packets.
+pub async fn read_raw_packet_with_max<R>(
+    stream: &mut R,
+    buffer: &mut Cursor<Vec<u8>>,
+    compression_threshold: Option<u32>,
+    max_decompressed_size: u32,
+    // this has to be a &mut Option<T> instead of an Option<&mut T> because
+    // otherwise the borrow checker complains about the cipher being moved
+    cipher: &mut Option<Aes128CfbDec>,
+) -> Result<Box<[u8]>, Box<ReadPacketError>>
 where
     R: AsyncRead + Unpin + Send + Sync,
 {
     loop {
-        if let Some(buf) = read_raw_packet_from_buffer::<R>(buffer, compression_threshold)? {
+        if let Some(buf) = read_raw_packet_from_buffer_with_max::<R>(
+            buffer,
+            compression_threshold,
+            max_decompressed_size,
+        )? {
             // we got a full packet!!
             return Ok(buf);
         };
@@ -297,11 +354,36 @@ pub fn try_read_raw_packet<R>(
     compression_threshold: Option<u32>,
     cipher: &mut Option<Aes128CfbDec>,
 ) -> Result<Option<Box<[u8]>>, Box<ReadPacketError>>
+where
+    R: AsyncRead + Unpin + Send + Sync,
+{
+    try_read_raw_packet_with_max(
+        stream,
+        buffer,
+        compression_threshold,
+        MAXIMUM_UNCOMPRESSED_LENGTH,
+        cipher,
+    )
+}
+
+/// Like [`try_read_raw_packet`], but with a custom maximum for the
+/// decompressed size of packets.
+pub fn try_read_raw_packet_with_max<R>(
+    stream: &mut R,
+    buffer: &mut Cursor<Vec<u8>>,
+    compression_threshold: Option<u32>,
+    max_decompressed_size: u32,
+    cipher: &mut Option<Aes128CfbDec>,
+) -> Result<Option<Box<[u8]>>, Box<ReadPacketError>>
 where
     R: AsyncRead + Unpin + Send + Sync,
 {
     loop {
-        if let Some(buf) = read_raw_packet_from_buffer::<R>(buffer, compression_threshold)? {
+        if let Some(buf) = read_raw_packet_from_buffer_with_max::<R>(
+            buffer,
+            compression_threshold,
+            max_decompressed_size,
+        )? {
             // we got a full packet!!
             return Ok(Some(buf));
         };
@@ -368,6 +450,23 @@ pub fn read_raw_packet_from_buffer<R>(
     buffer: &mut Cursor<Vec<u8>>,
     compression_threshold: Option<u32>,
 ) -> Result<Option<Box<[u8]>>, Box<ReadPacketError>>
+where
+    R: AsyncRead + Unpin + Send + Sync,
+{
+    read_raw_packet_from_buffer_with_max::<R>(
+        buffer,
+        compression_threshold,
+        MAXIMUM_UNCOMPRESSED_LENGTH,
+    )
+}
+
+/// Like [`read_raw_packet_from_buffer`], but with a custom maximum for the
+/// decompressed size of packets.
+pub fn read_raw_packet_from_buffer_with_max<R>(
+    buffer: &mut Cursor<Vec<u8>>,
+    compression_threshold: Option<u32>,
+    max_decompressed_size: u32,
+) -> Result<Option<Box<[u8]>>, Box<ReadPacketError>>
 where
     R: AsyncRead + Unpin + Send + Sync,
 {
@@ -377,8 +476,12 @@ where
     };
 
     if let Some(compression_threshold) = compression_threshold {
-        buf = compression_decoder(&mut Cursor::new(&buf[..]), compression_threshold)
-            .map_err(ReadPacketError::from)?;
+        buf = compression_decoder_with_max(
+            &mut Cursor::new(&buf[..]),
+            compression_threshold,
+            max_decompressed_size,
+        )
+        .map_err(ReadPacketError::from)?;
     }
 
     if tracing::enabled!(tracing::Level::TRACE) {
@@ -406,9 +509,64 @@ where
 mod tests {
     use std::io::Cursor;
 
-    use azalea_buf::AzBuf as _;
+    use azalea_buf::{AzBuf as _, AzBufVar as _};
+    use tokio::net::tcp::OwnedReadHalf;
 
-    use crate::{packets::game::ClientboundGamePacket, read::deserialize_packet};
+    use crate::{
+        packets::game::ClientboundGamePacket,
+        read::{
+            DecompressionError, MAXIMUM_UNCOMPRESSED_LENGTH, ReadPacketError, deserialize_packet,
+            read_raw_packet_from_buffer, read_raw_packet_from_buffer_with_max,
+        },
+    };
+
+    /// A frame with a declared decompressed length, followed by some garbage
+    /// that would be the compressed data.
+    fn frame_with_declared_size(declared_size: u32) -> Vec<u8> {
+        let mut data = Vec::new();
+        declared_size.azalea_write_var(&mut data).unwrap();
+        data.extend_from_slice(&[1, 2, 3, 4]);
+        let mut frame = Vec::new();
+        (data.len() as u32).azalea_write_var(&mut frame).unwrap();
+        frame.extend_from_slice(&data);
+        frame
+    }
+
+    #[test]
+    fn test_reject_oversized_decompressed_length() {
+        let declared_size: u32 = 100_000_000;
+        let err = read_raw_packet_from_buffer::<OwnedReadHalf>(
+            &mut Cursor::new(frame_with_declared_size(declared_size)),
+            Some(256),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            *err,
+            ReadPacketError::Decompress {
+                source: DecompressionError::AboveCompressionThreshold { size, maximum }
+            }
+            if size == declared_size && maximum == MAXIMUM_UNCOMPRESSED_LENGTH
+        ));
+    }
+
+    #[test]
+    fn test_reject_decompressed_length_above_custom_max() {
+        let err = read_raw_packet_from_buffer_with_max::<OwnedReadHalf>(
+            &mut Cursor::new(frame_with_declared_size(2048)),
+            Some(256),
+            1024,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            *err,
+            ReadPacketError::Decompress {
+                source: DecompressionError::AboveCompressionThreshold {
+                    size: 2048,
+                    maximum: 1024
+                }
+            }
+        ));
+    }
 
     #[test]
     fn fuzzed_1() {
@@ -8,6 +8,7 @@
 pub mod builtin;
 pub mod data;
 pub mod identifier;
+pub mod mob_category;
 pub mod tags;
 
 use std::{
@@ -0,0 +1,269 @@
+//! Classifying entities by how they behave towards players.
+
+use crate::builtin::EntityKind;
+
+/// The category that an entity is spawned in, which roughly corresponds to how
+/// it behaves.
+///
+/// This is the same as vanilla's `MobCategory`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MobCategory {
+    Monster,
+    Creature,
+    Ambient,
+    Axolotls,
+    UndergroundWaterCreature,
+    WaterCreature,
+    WaterAmbient,
+    /// Anything that isn't naturally spawned as a mob, like players,
+    /// projectiles, and vehicles.
+    Misc,
+}
+
+impl EntityKind {
+    /// Returns the [`MobCategory`] that this kind of entity is in.
+    pub fn category(self) -> MobCategory {
+        // this match statement is automatically generated by
+        // codegen/genregistries.py, don't edit it manually!
+        match self {
+            EntityKind::AcaciaBoat => MobCategory::Misc,
+            EntityKind::AcaciaChestBoat => MobCategory::Misc,
+            EntityKind::Allay => MobCategory::Creature,
+            EntityKind::AreaEffectCloud => MobCategory::Misc,
+            EntityKind::Armadillo => MobCategory::Creature,
+            EntityKind::ArmorStand => MobCategory::Misc,
+            EntityKind::Arrow => MobCategory::Misc,
+            EntityKind::Axolotl => MobCategory::Axolotls,
+            EntityKind::BambooChestRaft => MobCategory::Misc,
+            EntityKind::BambooRaft => MobCategory::Misc,
+            EntityKind::Bat => MobCategory::Ambient,
+            EntityKind::Bee => MobCategory::Creature,
+            EntityKind::BirchBoat => MobCategory::Misc,
+            EntityKind::BirchChestBoat => MobCategory::Misc,
+            EntityKind::Blaze => MobCategory::Monster,
+            EntityKind::BlockDisplay => MobCategory::Misc,
+            EntityKind::Bogged => MobCategory::Monster,
+            EntityKind::Breeze => MobCategory::Monster,
+            EntityKind::BreezeWindCharge => MobCategory::Misc,
+            EntityKind::Camel => MobCategory::Creature,
+            EntityKind::CamelHusk => MobCategory::Monster,
+            EntityKind::Cat => MobCategory::Creature,
+            EntityKind::CaveSpider => MobCategory::Monster,
+            EntityKind::CherryBoat => MobCategory::Misc,
+            EntityKind::CherryChestBoat => MobCategory::Misc,
+            EntityKind::ChestMinecart => MobCategory::Misc,
+            EntityKind::Chicken => MobCategory::Creature,
+            EntityKind::Cod => MobCategory::WaterAmbient,
+            EntityKind::CopperGolem => MobCategory::Misc,
+            EntityKind::CommandBlockMinecart => MobCategory::Misc,
+            EntityKind::Cow => MobCategory::Creature,
+            EntityKind::Creaking => MobCategory::Monster,
+            EntityKind::Creeper => MobCategory::Monster,
+            EntityKind::DarkOakBoat => MobCategory::Misc,
+            EntityKind::DarkOakChestBoat => MobCategory::Misc,
+            EntityKind::Dolphin => MobCategory::WaterCreature,
+            EntityKind::Donkey => MobCategory::Creature,
+            EntityKind::DragonFireball => MobCategory::Misc,
+            EntityKind::Drowned => MobCategory::Monster,
+            EntityKind::Egg => MobCategory::Misc,
+            EntityKind::ElderGuardian => MobCategory::Monster,
+            EntityKind::Enderman => MobCategory::Monster,
+            EntityKind::Endermite => MobCategory::Monster,
+            EntityKind::EnderDragon => MobCategory::Monster,
+            EntityKind::EnderPearl => MobCategory::Misc,
+            EntityKind::EndCrystal => MobCategory::Misc,
+            EntityKind::Evoker => MobCategory::Monster,
+            EntityKind::EvokerFangs => MobCategory::Misc,
+            EntityKind::ExperienceBottle => MobCategory::Misc,
+            EntityKind::ExperienceOrb => MobCategory::Misc,
+            EntityKind::EyeOfEnder => MobCategory::Misc,
+            EntityKind::FallingBlock => MobCategory::Misc,
+            EntityKind::Fireball => MobCategory::Misc,
+            EntityKind::FireworkRocket => MobCategory::Misc,
+            EntityKind::Fox => MobCategory::Creature,
+            EntityKind::Frog => MobCategory::Creature,
+            EntityKind::FurnaceMinecart => MobCategory::Misc,
+            EntityKind::Ghast => MobCategory::Monster,
+            EntityKind::HappyGhast => MobCategory::Creature,
+            EntityKind::Giant => MobCategory::Monster,
+            EntityKind::GlowItemFrame => MobCategory::Misc,
+            EntityKind::GlowSquid => MobCategory::UndergroundWaterCreature,
+            EntityKind::Goat => MobCategory::Creature,
+            EntityKind::Guardian => MobCategory::Monster,
+            EntityKind::Hoglin => MobCategory::Monster,
+            EntityKind::HopperMinecart => MobCategory::Misc,
+            EntityKind::Horse => MobCategory::Creature,
+            EntityKind::Husk => MobCategory::Monster,
+            EntityKind::Illusioner => MobCategory::Monster,
+            EntityKind::Interaction => MobCategory::Misc,
+            EntityKind::IronGolem => MobCategory::Misc,
+            EntityKind::Item => MobCategory::Misc,
+            EntityKind::ItemDisplay => MobCategory::Misc,
+            EntityKind::ItemFrame => MobCategory::Misc,
+            EntityKind::JungleBoat => MobCategory::Misc,
+            EntityKind::JungleChestBoat => MobCategory::Misc,
+            EntityKind::LeashKnot => MobCategory::Misc,
+            EntityKind::LightningBolt => MobCategory::Misc,
+            EntityKind::Llama => MobCategory::Creature,
+            EntityKind::LlamaSpit => MobCategory::Misc,
+            EntityKind::MagmaCube => MobCategory::Monster,
+            EntityKind::MangroveBoat => MobCategory::Misc,
+            EntityKind::MangroveChestBoat => MobCategory::Misc,
+            EntityKind::Mannequin => MobCategory::Misc,
+            EntityKind::Marker => MobCategory::Misc,
+            EntityKind::Minecart => MobCategory::Misc,
+            EntityKind::Mooshroom => MobCategory::Creature,
+            EntityKind::Mule => MobCategory::Creature,
+            EntityKind::Nautilus => MobCategory::WaterCreature,
+            EntityKind::OakBoat => MobCategory::Misc,
+            EntityKind::OakChestBoat => MobCategory::Misc,
+            EntityKind::Ocelot => MobCategory::Creature,
+            EntityKind::OminousItemSpawner => MobCategory::Misc,
+            EntityKind::Painting => MobCategory::Misc,
+            EntityKind::PaleOakBoat => MobCategory::Misc,
+            EntityKind::PaleOakChestBoat => MobCategory::Misc,
+            EntityKind::Panda => MobCategory::Creature,
+            EntityKind::Parched => MobCategory::Monster,
+            EntityKind::Parrot => MobCategory::Creature,
+            EntityKind::Phantom => MobCategory::Monster,
+            EntityKind::Pig => MobCategory::Creature,
+            EntityKind::Piglin => MobCategory::Monster,
+            EntityKind::PiglinBrute => MobCategory::Monster,
+            EntityKind::Pillager => MobCategory::Monster,
+            EntityKind::PolarBear => MobCategory::Creature,
+            EntityKind::SplashPotion => MobCategory::Misc,
+            EntityKind::LingeringPotion => MobCategory::Misc,
+            EntityKind::Pufferfish => MobCategory::WaterAmbient,
+            EntityKind::Rabbit => MobCategory::Creature,
+            EntityKind::Ravager => MobCategory::Monster,
+            EntityKind::Salmon => MobCategory::WaterAmbient,
+            EntityKind::Sheep => MobCategory::Creature,
+            EntityKind::Shulker => MobCategory::Monster,
+            EntityKind::ShulkerBullet => MobCategory::Misc,
+            EntityKind::Silverfish => MobCategory::Monster,
+            EntityKind::Skeleton => MobCategory::Monster,
+            EntityKind::SkeletonHorse => MobCategory::Creature,
+            EntityKind::Slime => MobCategory::Monster,
+            EntityKind::SmallFireball => MobCategory::Misc,
+            EntityKind::Sniffer => MobCategory::Creature,
+            EntityKind::Snowball => MobCategory::Misc,
+            EntityKind::SnowGolem => MobCategory::Misc,
+            EntityKind::SpawnerMinecart => MobCategory::Misc,
+            EntityKind::SpectralArrow => MobCategory::Misc,
+            EntityKind::Spider => MobCategory::Monster,
+            EntityKind::SpruceBoat => MobCategory::Misc,
+            EntityKind::SpruceChestBoat => MobCategory::Misc,
+            EntityKind::Squid => MobCategory::WaterCreature,
+            EntityKind::Stray => MobCategory::Monster,
+            EntityKind::Strider => MobCategory::Creature,
+            EntityKind::Tadpole => MobCategory::Creature,
+            EntityKind::TextDisplay => MobCategory::Misc,
+            EntityKind::Tnt => MobCategory::Misc,
+            EntityKind::TntMinecart => MobCategory::Misc,
+            EntityKind::TraderLlama => MobCategory::Creature,
+            EntityKind::Trident => MobCategory::Misc,
+            EntityKind::TropicalFish => MobCategory::WaterAmbient,
+            EntityKind::Turtle => MobCategory::Creature,
+            EntityKind::Vex => MobCategory::Monster,
+            EntityKind::Villager => MobCategory::Misc,
+            EntityKind::Vindicator => MobCategory::Monster,
+            EntityKind::WanderingTrader => MobCategory::Creature,
+            EntityKind::Warden => MobCategory::Monster,
+            EntityKind::WindCharge => MobCategory::Misc,
+            EntityKind::Witch => MobCategory::Monster,
+            EntityKind::Wither => MobCategory::Monster,
+            EntityKind::WitherSkeleton => MobCategory::Monster,
+            EntityKind::WitherSkull => MobCategory::Misc,
+            EntityKind::Wolf => MobCategory::Creature,
+            EntityKind::Zoglin => MobCategory::Monster,
+            EntityKind::Zombie => MobCategory::Monster,
+            EntityKind::ZombieHorse => MobCategory::Creature,
+            EntityKind::ZombieNautilus => MobCategory::Monster,
+            EntityKind::ZombieVillager => MobCategory::Monster,
+            EntityKind::ZombifiedPiglin => MobCategory::Monster,
+            EntityKind::Player => MobCategory::Misc,
+            EntityKind::FishingBobber => MobCategory::Misc,
+        }
+    }
+
+    /// Returns whether this is a player.
+    pub fn is_player(self) -> bool {
+        self == EntityKind::Player
+    }
+
+    /// Returns whether this kind of mob will attack players on sight.
+    ///
+    /// Neutral mobs like endermen aren't included, see [`Self::is_neutral`].
+    pub fn is_hostile(self) -> bool {
+        self.category() == MobCategory::Monster && !self.is_neutral()
+    }
+
+    /// Returns whether this kind of mob only attacks players when provoked,
+    /// like wolves, endermen, and zombified piglins.
+    ///
+    /// Spiders are included here since they're only hostile in the dark.
+    pub fn is_neutral(self) -> bool {
+        matches!(
+            self,
+            EntityKind::Bee
+                | EntityKind::CaveSpider
+                | EntityKind::Dolphin
+                | EntityKind::Enderman
+                | EntityKind::Goat
+                | EntityKind::IronGolem
+                | EntityKind::Llama
+                | EntityKind::Panda
+                | EntityKind::Piglin
+                | EntityKind::PolarBear
+                | EntityKind::Spider
+                | EntityKind::TraderLlama
+                | EntityKind::Wolf
+                | EntityKind::ZombifiedPiglin
+        )
+    }
+
+    /// Returns whether this kind of mob never attacks players, like cows and
+    /// villagers.
+    pub fn is_passive(self) -> bool {
+        if self.is_neutral() {
+            return false;
+        }
+        match self.category() {
+            MobCategory::Monster => false,
+            MobCategory::Misc => matches!(
+                self,
+                EntityKind::CopperGolem | EntityKind::SnowGolem | EntityKind::Villager
+            ),
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_entities() {
+        assert!(EntityKind::Zombie.is_hostile());
+        assert!(!EntityKind::Zombie.is_passive());
+        assert!(!EntityKind::Zombie.is_neutral());
+
+        assert!(EntityKind::Cow.is_passive());
+        assert!(!EntityKind::Cow.is_hostile());
+        assert!(EntityKind::Villager.is_passive());
+
+        for neutral in [EntityKind::Wolf, EntityKind::Enderman] {
+            assert!(neutral.is_neutral());
+            assert!(!neutral.is_hostile());
+            assert!(!neutral.is_passive());
+        }
+
+        assert!(EntityKind::Player.is_player());
+        for not_a_mob in [EntityKind::Player, EntityKind::Arrow, EntityKind::Minecart] {
+            assert!(!not_a_mob.is_hostile());
+            assert!(!not_a_mob.is_passive());
+            assert!(!not_a_mob.is_neutral());
+        }
+    }
+}
@@ -19,9 +19,13 @@ use azalea_core::{
         ChunkSectionBlockPos,
     },
 };
-use azalea_registry::data::Biome;
+use azalea_registry::{
+    builtin::{BlockEntityKind, BlockKind},
+    data::Biome,
+};
 use nohash_hasher::IntMap;
 use parking_lot::RwLock;
+use simdnbt::owned::NbtCompound;
 use tracing::{debug, trace, warn};
 
 use crate::{heightmap::Heightmap, palette::PalettedContainer};
@@ -66,9 +70,9 @@ pub struct ChunkStorage {
 
 /// A single chunk in a world (16*?*16 blocks).
 ///
-/// This only contains blocks and biomes. You can derive the height of the chunk
-/// from the number of sections, but you need a [`ChunkStorage`] to get the
-/// minimum Y coordinate.
+/// This only contains blocks, biomes, and block entities. You can derive the
+/// height of the chunk from the number of sections, but you need a
+/// [`ChunkStorage`] to get the minimum Y coordinate.
 #[derive(Debug)]
 pub struct Chunk {
     pub sections: Box<[Section]>,
@@ -76,6 +80,21 @@ pub struct Chunk {
     /// Usually for clients only `WorldSurface` and `MotionBlocking` are
     /// present.
     pub heightmaps: HashMap<HeightmapKind, Heightmap>,
+    /// The block entities (like chests and signs) in this chunk that the server
+    /// told us about.
+    pub block_entities: HashMap<ChunkBlockPos, BlockEntity>,
+}
+
+/// Extra data for a block that doesn't fit in its block state, like the items
+/// in a chest or the text on a sign.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockEntity {
+    pub kind: BlockEntityKind,
+    /// The data that the server sent for this block entity.
+    ///
+    /// Servers only send the data that the client needs to render the block,
+    /// so for example this won't contain the items in a chest.
+    pub data: NbtCompound,
 }
 
 /// A section of a chunk, i.e. a 16*16*16 block area.
@@ -103,6 +122,7 @@ impl Default for Chunk {
         Chunk {
             sections: vec![Section::default(); (384 / 16) as usize].into(),
             heightmaps: HashMap::new(),
+            block_entities: HashMap::new(),
         }
     }
 }
@@ -345,6 +365,50 @@ impl ChunkStorage {
         let mut chunk = chunk.write();
         Some(chunk.get_and_set_block_state(&ChunkBlockPos::from(pos), state, self.min_y))
     }
+
+    /// Get the block entity at the given position, or `None` if there isn't one
+    /// or the chunk isn't loaded.
+    pub fn get_block_entity(&self, pos: BlockPos) -> Option<BlockEntity> {
+        let chunk = self.get(&ChunkPos::from(pos))?;
+        let chunk = chunk.read();
+        chunk.block_entities.get(&ChunkBlockPos::from(pos)).cloned()
+    }
+
+    /// Set the block entity at the given position.
+    ///
+    /// Returns the block entity that was previously there, or `None` if there
+    /// wasn't one or the chunk isn't loaded.
+    pub fn set_block_entity(
+        &self,
+        pos: BlockPos,
+        block_entity: BlockEntity,
+    ) -> Option<BlockEntity> {
+        let chunk = self.get(&ChunkPos::from(pos))?;
+        let mut chunk = chunk.write();
+        chunk
+            .block_entities
+            .insert(ChunkBlockPos::from(pos), block_entity)
+    }
+
+    /// Get every block entity in the loaded chunks, along with their positions.
+    ///
+    /// The order of the returned block entities is unspecified.
+    pub fn block_entities(&self) -> Vec<(BlockPos, BlockEntity)> {
+        let mut block_entities = Vec::new();
+        for (chunk_pos, chunk) in &self.map {
+            let Some(chunk) = chunk.upgrade() else {
+                continue;
+            };
+            let chunk = chunk.read();
+            block_entities.extend(
+                chunk
+                    .block_entities
+                    .iter()
+                    .map(|(pos, block_entity)| (*chunk_pos + *pos, block_entity.clone())),
+            );
+        }
+        block_entities
+    }
 }
 
 pub fn in_range_for_view_center_and_radius(
@@ -381,6 +445,7 @@ impl Chunk {
         Ok(Chunk {
             sections,
             heightmaps,
+            block_entities: HashMap::new(),
         })
     }
 
@@ -408,11 +473,24 @@ impl Chunk {
         for heightmap in self.heightmaps.values_mut() {
             heightmap.update(pos, state, &self.sections);
         }
+        if !self.block_entities.is_empty()
+            && BlockKind::from(previous_state) != BlockKind::from(state)
+        {
+            // like vanilla, block entities only survive state changes that keep the same
+            // block (like a chest being opened)
+            self.block_entities.remove(pos);
+        }
 
         previous_state
     }
 
     pub fn set_block_state(&mut self, pos: &ChunkBlockPos, state: BlockState, min_y: i32) {
+        if self.block_entities.contains_key(pos) {
+            // the block entity might have to be removed, which needs the previous state
+            let _ = self.get_and_set_block_state(pos, state, min_y);
+            return;
+        }
+
         let section_index = section_index(pos.y, min_y);
         let Some(section) = self.sections.get_mut(section_index as usize) else {
             warn!(
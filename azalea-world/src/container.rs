@@ -47,6 +47,17 @@ impl Worlds {
         self.map.get(name).and_then(|world| world.upgrade())
     }
 
+    /// Returns the names of all the worlds in the container.
+    ///
+    /// This only includes worlds that are still being used by at least one
+    /// client.
+    pub fn world_names(&self) -> impl Iterator<Item = &WorldName> {
+        self.map
+            .iter()
+            .filter(|(_, world)| world.strong_count() > 0)
+            .map(|(name, _)| name)
+    }
+
     /// Add an empty world to the container (unless it already exists) and
     /// returns a strong reference to the world.
     #[must_use = "the world will be immediately forgotten if unused"]
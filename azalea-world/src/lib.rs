@@ -8,11 +8,13 @@ pub mod find_blocks;
 pub mod heightmap;
 pub mod iterators;
 pub mod palette;
+pub mod snapshot;
 mod world;
 
 pub use bit_storage::BitStorage;
-pub use chunk_storage::{Chunk, ChunkStorage, PartialChunkStorage, Section};
+pub use chunk_storage::{BlockEntity, Chunk, ChunkStorage, PartialChunkStorage, Section};
 pub use container::{WorldName, Worlds};
+pub use snapshot::WorldSnapshot;
 pub use world::*;
 
 #[deprecated = "renamed to `WorldName`."]
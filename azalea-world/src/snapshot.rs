@@ -0,0 +1,184 @@
+//! Copies of the blocks in a region of the world, which can be compared to
+//! find out what changed.
+
+use azalea_block::BlockState;
+use azalea_core::position::BlockPos;
+
+use crate::World;
+
+/// A copy of the block states in a box-shaped region of a [`World`].
+///
+/// Use [`World::snapshot`] to make one, and [`WorldSnapshot::diff`] to compare
+/// it with a later snapshot of the same area.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorldSnapshot {
+    /// The corner of the region with the lowest coordinates (inclusive).
+    pub min: BlockPos,
+    /// The corner of the region with the highest coordinates (inclusive).
+    pub max: BlockPos,
+    /// The block states in the region, or `None` for blocks that weren't
+    /// loaded when the snapshot was taken.
+    ///
+    /// This is ordered by x, then z, then y.
+    blocks: Box<[Option<BlockState>]>,
+}
+
+impl WorldSnapshot {
+    /// Get the block state at the given position, or `None` if it's outside of
+    /// the snapshot or it wasn't loaded when the snapshot was taken.
+    pub fn get_block_state(&self, pos: BlockPos) -> Option<BlockState> {
+        self.index(pos).and_then(|index| self.blocks[index])
+    }
+
+    /// Find the blocks that are different in `other`, as a list of
+    /// `(position, block state in self, block state in other)`.
+    ///
+    /// Only the region that's in both snapshots is compared, and blocks that
+    /// weren't loaded in either snapshot are skipped.
+    pub fn diff(&self, other: &WorldSnapshot) -> Vec<(BlockPos, BlockState, BlockState)> {
+        let min = BlockPos::new(
+            self.min.x.max(other.min.x),
+            self.min.y.max(other.min.y),
+            self.min.z.max(other.min.z),
+        );
+        let max = BlockPos::new(
+            self.max.x.min(other.max.x),
+            self.max.y.min(other.max.y),
+            self.max.z.min(other.max.z),
+        );
+
+        let mut changes = Vec::new();
+        for pos in positions_between(min, max) {
+            if let (Some(old), Some(new)) = (self.get_block_state(pos), other.get_block_state(pos))
+                && old != new
+            {
+                changes.push((pos, old, new));
+            }
+        }
+        changes
+    }
+
+    fn index(&self, pos: BlockPos) -> Option<usize> {
+        if pos.x < self.min.x
+            || pos.y < self.min.y
+            || pos.z < self.min.z
+            || pos.x > self.max.x
+            || pos.y > self.max.y
+            || pos.z > self.max.z
+        {
+            return None;
+        }
+        let size_y = (self.max.y - self.min.y + 1) as usize;
+        let size_z = (self.max.z - self.min.z + 1) as usize;
+        let x = (pos.x - self.min.x) as usize;
+        let y = (pos.y - self.min.y) as usize;
+        let z = (pos.z - self.min.z) as usize;
+        Some((x * size_z + z) * size_y + y)
+    }
+}
+
+impl World {
+    /// Copy the block states between the two corners (inclusive) so they can
+    /// be compared later with [`WorldSnapshot::diff`].
+    ///
+    /// ```
+    /// # use azalea_core::position::BlockPos;
+    /// # fn example(world: &azalea_world::World) {
+    /// let area = (BlockPos::new(-8, 60, -8), BlockPos::new(8, 80, 8));
+    /// let before = world.snapshot(area.0, area.1);
+    /// // ...
+    /// let after = world.snapshot(area.0, area.1);
+    /// for (pos, old, new) in before.diff(&after) {
+    ///     println!("{pos} changed from {old:?} to {new:?}");
+    /// }
+    /// # }
+    /// ```
+    pub fn snapshot(&self, corner1: BlockPos, corner2: BlockPos) -> WorldSnapshot {
+        let min = BlockPos::new(
+            corner1.x.min(corner2.x),
+            corner1.y.min(corner2.y),
+            corner1.z.min(corner2.z),
+        );
+        let max = BlockPos::new(
+            corner1.x.max(corner2.x),
+            corner1.y.max(corner2.y),
+            corner1.z.max(corner2.z),
+        );
+
+        let blocks = positions_between(min, max)
+            .map(|pos| self.get_block_state(pos))
+            .collect();
+        WorldSnapshot { min, max, blocks }
+    }
+}
+
+/// Iterate over the positions between the two corners (inclusive), in the same
+/// order that they're stored in a [`WorldSnapshot`].
+fn positions_between(min: BlockPos, max: BlockPos) -> impl Iterator<Item = BlockPos> {
+    (min.x..=max.x).flat_map(move |x| {
+        (min.z..=max.z).flat_map(move |z| (min.y..=max.y).map(move |y| BlockPos::new(x, y, z)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use azalea_core::position::ChunkPos;
+    use azalea_registry::builtin::BlockKind;
+
+    use super::*;
+    use crate::{Chunk, PartialChunkStorage};
+
+    #[test]
+    fn test_diff_single_block_change() {
+        let mut world = World::default();
+        let mut partial_chunk_storage = PartialChunkStorage::default();
+        partial_chunk_storage.set(
+            &ChunkPos { x: 0, z: 0 },
+            Some(Chunk::default()),
+            &mut world.chunks,
+        );
+
+        let corners = (BlockPos::new(0, 0, 0), BlockPos::new(3, 3, 3));
+        let before = world.snapshot(corners.0, corners.1);
+        world.set_block_state(BlockPos::new(1, 2, 3), BlockKind::Stone.into());
+        let after = world.snapshot(corners.1, corners.0);
+
+        assert_eq!(
+            before.diff(&after),
+            vec![(
+                BlockPos::new(1, 2, 3),
+                BlockState::AIR,
+                BlockKind::Stone.into()
+            )]
+        );
+        assert!(after.diff(&after).is_empty());
+    }
+
+    #[test]
+    fn test_diff_only_overlap() {
+        let mut world = World::default();
+        let mut partial_chunk_storage = PartialChunkStorage::default();
+        partial_chunk_storage.set(
+            &ChunkPos { x: 0, z: 0 },
+            Some(Chunk::default()),
+            &mut world.chunks,
+        );
+
+        // the second snapshot goes into an unloaded chunk and only partly overlaps
+        // the first one
+        let before = world.snapshot(BlockPos::new(0, 0, 0), BlockPos::new(4, 4, 4));
+        world.set_block_state(BlockPos::new(0, 0, 0), BlockKind::Stone.into());
+        world.set_block_state(BlockPos::new(3, 3, 3), BlockKind::Stone.into());
+        let after = world.snapshot(BlockPos::new(2, 2, 2), BlockPos::new(20, 6, 6));
+        assert_eq!(after.get_block_state(BlockPos::new(20, 6, 6)), None);
+
+        assert_eq!(
+            before.diff(&after),
+            vec![(
+                BlockPos::new(3, 3, 3),
+                BlockState::AIR,
+                BlockKind::Stone.into()
+            )]
+        );
+    }
+}
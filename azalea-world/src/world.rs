@@ -12,7 +12,7 @@ use azalea_registry::data::Biome;
 use bevy_ecs::entity::Entity;
 use nohash_hasher::IntMap;
 
-use crate::{ChunkStorage, PartialChunkStorage};
+use crate::{BlockEntity, ChunkStorage, PartialChunkStorage};
 
 /// A reference to a slice of the world, as seen by an individual client.
 ///
@@ -137,6 +137,20 @@ impl World {
     pub fn set_block_state(&self, pos: BlockPos, state: BlockState) -> Option<BlockState> {
         self.chunks.set_block_state(pos, state)
     }
+
+    /// Get the block entity (like a chest or sign) at the given position, or
+    /// `None` if there isn't one or it's outside of the world that we have
+    /// loaded.
+    pub fn get_block_entity(&self, pos: BlockPos) -> Option<BlockEntity> {
+        self.chunks.get_block_entity(pos)
+    }
+
+    /// Get every block entity in the chunks that we have loaded.
+    ///
+    /// See [`ChunkStorage::block_entities`].
+    pub fn block_entities(&self) -> Vec<(BlockPos, BlockEntity)> {
+        self.chunks.block_entities()
+    }
 }
 
 impl Debug for PartialWorld {
@@ -4,6 +4,7 @@ use azalea::{
     BlockPos,
     pathfinder::{
         astar::{self, PathfinderTimeout, a_star, heap::WeightedNode},
+        costs::PathfinderCosts,
         custom_state::CustomPathfinderStateRef,
         goals::{BlockPosGoal, Goal},
         mining::MiningCache,
@@ -140,6 +141,7 @@ fn run_pathfinder_benchmark(
                 &cached_world,
                 &mining_cache,
                 &CustomPathfinderStateRef::default(),
+                &PathfinderCosts::default(),
                 successors_fn,
                 pos,
             )
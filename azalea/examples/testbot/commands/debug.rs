@@ -9,8 +9,8 @@ use azalea::{
     inventory,
     packet::game,
     pathfinder::{
-        ExecutingPath, Pathfinder, custom_state::CustomPathfinderStateRef, mining::MiningCache,
-        moves::MovesCtx, positions::RelBlockPos, world::CachedWorld,
+        ExecutingPath, Pathfinder, costs::PathfinderCosts, custom_state::CustomPathfinderStateRef,
+        mining::MiningCache, moves::MovesCtx, positions::RelBlockPos, world::CachedWorld,
     },
 };
 use azalea_core::hit_result::HitResult;
@@ -203,6 +203,7 @@ pub fn register(commands: &mut CommandDispatcher<Mutex<CommandSource>>) {
         let cached_world = CachedWorld::new(source.bot.world(), position);
         let mining_cache = MiningCache::new(Some(Menu::Player(inventory::Player::default())));
         let custom_state = CustomPathfinderStateRef::default();
+        let costs = source.bot.resource::<PathfinderCosts>();
 
         azalea::pathfinder::moves::default_move(
             &mut MovesCtx {
@@ -210,6 +211,7 @@ pub fn register(commands: &mut CommandDispatcher<Mutex<CommandSource>>) {
                 world: &cached_world,
                 mining_cache: &mining_cache,
                 custom_state: &custom_state,
+                costs: &costs,
             },
             RelBlockPos::from_origin(position, position),
         );
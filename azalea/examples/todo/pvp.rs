@@ -48,7 +48,7 @@ async fn swarm_handle(swarm: Swarm, event: SwarmEvent, state: SwarmState) -> any
                     bot.tick_goto_goal(pathfinder::Goals::Reach(target_bounding_box));
                     // if target.bounding_box.distance(bot.eyes) < bot.reach_distance() {
                     if bot.can_reach(target_bounding_box) {
-                        bot.swing();
+                        bot.attack_when_ready(target_entity);
                     }
                     if !bot.using_held_item() && bot.hunger() <= 17 {
                         bot.hold(azalea::ItemGroup::Food);
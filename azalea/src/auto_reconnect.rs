@@ -4,12 +4,14 @@
 
 use std::time::{Duration, Instant};
 
+use azalea_core::entity_id::MinecraftEntityId;
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
+use tracing::info;
 
 use super::{
-    disconnect::DisconnectEvent,
-    join::{ConnectOpts, ConnectionFailedEvent, StartJoinServerEvent},
+    disconnect::{DisconnectEvent, DisconnectReason},
+    join::{ConnectOpts, ConnectionFailedEvent, PendingTransfer, StartJoinServerEvent},
 };
 use crate::account::Account;
 
@@ -26,31 +28,53 @@ pub const DEFAULT_RECONNECT_DELAY: Duration = Duration::from_secs(5);
 /// by removing the resource from the ECS.
 ///
 /// The delay defaults to [`DEFAULT_RECONNECT_DELAY`].
+///
+/// Which disconnects we reconnect after, how many times we try, and how the
+/// delay increases after failed attempts is decided by the
+/// [`AutoReconnectPolicy`] resource/component.
 pub struct AutoReconnectPlugin;
 impl Plugin for AutoReconnectPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(AutoReconnectDelay::new(DEFAULT_RECONNECT_DELAY))
+            .init_resource::<AutoReconnectPolicy>()
             .add_systems(
                 Update,
-                (start_rejoin_on_disconnect, rejoin_after_delay)
+                (
+                    reset_reconnect_attempts,
+                    start_rejoin_on_disconnect,
+                    rejoin_after_delay,
+                )
                     .chain()
                     .before(super::join::handle_start_join_server_event),
             );
     }
 }
 
+#[allow(clippy::type_complexity)]
 pub fn start_rejoin_on_disconnect(
     mut commands: Commands,
     mut disconnect_events: MessageReader<DisconnectEvent>,
     mut connection_failed_events: MessageReader<ConnectionFailedEvent>,
     auto_reconnect_delay_res: Option<Res<AutoReconnectDelay>>,
     auto_reconnect_delay_query: Query<&AutoReconnectDelay>,
+    auto_reconnect_policy_res: Option<Res<AutoReconnectPolicy>>,
+    query: Query<(Option<&AutoReconnectPolicy>, Option<&ReconnectAttempts>)>,
+    pending_transfer_query: Query<(), With<PendingTransfer>>,
 ) {
-    for entity in disconnect_events
+    for (entity, reason) in disconnect_events
         .read()
-        .map(|e| e.entity)
-        .chain(connection_failed_events.read().map(|e| e.entity))
+        .map(|e| (e.entity, e.kind.clone()))
+        .chain(
+            connection_failed_events
+                .read()
+                .map(|e| (e.entity, DisconnectReason::ConnectionLost)),
+        )
     {
+        if pending_transfer_query.contains(entity) {
+            // we disconnected because the server is sending us somewhere else
+            continue;
+        }
+
         let Some(delay) = get_delay(
             &auto_reconnect_delay_res,
             auto_reconnect_delay_query,
@@ -60,10 +84,41 @@ pub fn start_rejoin_on_disconnect(
             continue;
         };
 
-        let reconnect_after = Instant::now() + delay;
-        commands.entity(entity).insert(InternalReconnectAfter {
-            instant: reconnect_after,
-        });
+        let (policy, attempts) = query.get(entity).unwrap_or_default();
+        let default_policy = AutoReconnectPolicy::default();
+        let policy = policy
+            .or(auto_reconnect_policy_res.as_deref())
+            .unwrap_or(&default_policy);
+        let attempts = attempts.map(|a| a.0).unwrap_or_default();
+
+        if !(policy.should_retry)(&reason) {
+            info!("Not reconnecting {entity} since we were disconnected for {reason:?}");
+            continue;
+        }
+        if let Some(max_attempts) = policy.max_attempts
+            && attempts >= max_attempts
+        {
+            info!("Not reconnecting {entity} since we already tried {attempts} times");
+            continue;
+        }
+
+        let reconnect_after = Instant::now() + policy.backoff(delay, attempts);
+        commands.entity(entity).insert((
+            InternalReconnectAfter {
+                instant: reconnect_after,
+            },
+            ReconnectAttempts(attempts + 1),
+        ));
+    }
+}
+
+/// Reset the number of reconnect attempts when we successfully log in.
+pub fn reset_reconnect_attempts(
+    mut commands: Commands,
+    query: Query<Entity, (Added<MinecraftEntityId>, With<ReconnectAttempts>)>,
+) {
+    for entity in &query {
+        commands.entity(entity).remove::<ReconnectAttempts>();
     }
 }
 
@@ -132,3 +187,186 @@ impl AutoReconnectDelay {
 pub struct InternalReconnectAfter {
     pub instant: Instant,
 }
+
+/// A resource *and* component that decides when and how often we try to
+/// reconnect.
+///
+/// Like [`AutoReconnectDelay`], you can modify the resource to change it for
+/// every client, or insert it as a component to change it for a single client.
+///
+/// The default policy always reconnects after the same delay, no matter why we
+/// were disconnected. Use [`AutoReconnectPolicy::with_backoff`] to stop
+/// reconnecting when we're banned and to wait longer after every failed
+/// attempt.
+#[derive(Clone, Component, Debug, Resource)]
+pub struct AutoReconnectPolicy {
+    /// Decides whether we'll reconnect after being disconnected for the given
+    /// reason.
+    ///
+    /// Defaults to always reconnecting. [`DisconnectReason::is_retryable`] can
+    /// be used to not reconnect if we're banned or not whitelisted.
+    pub should_retry: fn(&DisconnectReason) -> bool,
+    /// The maximum number of times that we'll try to reconnect in a row before
+    /// giving up, or `None` to never give up.
+    ///
+    /// The count is reset when we successfully log in.
+    pub max_attempts: Option<u32>,
+    /// How much the delay is multiplied by after every failed attempt.
+    ///
+    /// Defaults to 1, which means we always use the same delay.
+    pub backoff_multiplier: f64,
+    /// The longest that the delay can become from backoff.
+    pub max_delay: Duration,
+}
+impl AutoReconnectPolicy {
+    /// A policy that doesn't reconnect after disconnects that aren't
+    /// [retryable](DisconnectReason::is_retryable), and doubles the delay after
+    /// every failed attempt up to two minutes.
+    pub fn with_backoff() -> Self {
+        Self {
+            should_retry: DisconnectReason::is_retryable,
+            max_attempts: None,
+            backoff_multiplier: 2.,
+            max_delay: Duration::from_secs(120),
+        }
+    }
+
+    /// Get the delay to use before the given attempt (starting at 0), based on
+    /// the [`AutoReconnectDelay`].
+    pub fn backoff(&self, delay: Duration, attempts: u32) -> Duration {
+        let multiplier = self.backoff_multiplier.powi(attempts as i32);
+        let backoff_secs = f64::min(
+            delay.as_secs_f64() * multiplier,
+            self.max_delay.as_secs_f64(),
+        );
+        // backoff never makes the delay shorter than the AutoReconnectDelay
+        Duration::try_from_secs_f64(backoff_secs)
+            .unwrap_or(self.max_delay)
+            .max(delay)
+    }
+}
+impl Default for AutoReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            should_retry: |_| true,
+            max_attempts: None,
+            backoff_multiplier: 1.,
+            max_delay: Duration::MAX,
+        }
+    }
+}
+
+/// The number of times in a row that we've tried to reconnect.
+///
+/// This is removed when we successfully log in.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq)]
+pub struct ReconnectAttempts(pub u32);
+
+#[cfg(test)]
+mod tests {
+    use azalea_chat::translatable_component::TranslatableComponent;
+    use azalea_protocol::address::{ResolvedAddr, ServerAddr};
+    use bevy_ecs::message::Messages;
+
+    use super::*;
+
+    fn make_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(AutoReconnectPlugin)
+            .add_message::<DisconnectEvent>()
+            .add_message::<ConnectionFailedEvent>()
+            .add_message::<StartJoinServerEvent>()
+            .insert_resource(AutoReconnectDelay::new(Duration::ZERO));
+
+        let entity = app
+            .world_mut()
+            .spawn((
+                Account::offline("bot"),
+                ConnectOpts {
+                    address: ResolvedAddr {
+                        server: ServerAddr {
+                            host: "localhost".to_owned(),
+                            port: 25565,
+                        },
+                        socket: "127.0.0.1:25565".parse().unwrap(),
+                    },
+                    server_proxy: None,
+                    sessionserver_proxy: None,
+                    rng_seed: None,
+                    client_information: None,
+                },
+            ))
+            .id();
+        (app, entity)
+    }
+
+    fn disconnect(app: &mut App, entity: Entity, key: &str) -> usize {
+        app.world_mut().write_message(DisconnectEvent::new(
+            entity,
+            Some(TranslatableComponent::new(key.to_owned(), vec![]).into()),
+        ));
+        app.update();
+        app.world_mut()
+            .resource_mut::<Messages<StartJoinServerEvent>>()
+            .drain()
+            .count()
+    }
+
+    #[test]
+    fn test_reconnect_after_retryable_disconnect() {
+        let (mut app, entity) = make_app();
+        assert_eq!(disconnect(&mut app, entity, "disconnect.timeout"), 1);
+        assert_eq!(
+            app.world().get::<ReconnectAttempts>(entity),
+            Some(&ReconnectAttempts(1))
+        );
+    }
+
+    #[test]
+    fn test_reconnect_when_banned_by_default() {
+        let (mut app, entity) = make_app();
+        assert_eq!(
+            disconnect(&mut app, entity, "multiplayer.disconnect.banned"),
+            1
+        );
+    }
+
+    #[test]
+    fn test_dont_reconnect_when_banned_with_backoff() {
+        let (mut app, entity) = make_app();
+        app.insert_resource(AutoReconnectPolicy::with_backoff());
+        assert_eq!(
+            disconnect(&mut app, entity, "multiplayer.disconnect.banned"),
+            0
+        );
+    }
+
+    #[test]
+    fn test_max_reconnect_attempts() {
+        let (mut app, entity) = make_app();
+        app.insert_resource(AutoReconnectPolicy {
+            max_attempts: Some(2),
+            ..Default::default()
+        });
+        assert_eq!(disconnect(&mut app, entity, "disconnect.timeout"), 1);
+        assert_eq!(disconnect(&mut app, entity, "disconnect.timeout"), 1);
+        assert_eq!(disconnect(&mut app, entity, "disconnect.timeout"), 0);
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = AutoReconnectPolicy::with_backoff();
+        let delay = Duration::from_secs(5);
+        assert_eq!(policy.backoff(delay, 0), Duration::from_secs(5));
+        assert_eq!(policy.backoff(delay, 2), Duration::from_secs(20));
+        assert_eq!(policy.backoff(delay, 100), policy.max_delay);
+    }
+
+    #[test]
+    fn test_default_policy_uses_fixed_delay() {
+        let policy = AutoReconnectPolicy::default();
+        let delay = Duration::from_secs(5);
+        assert_eq!(policy.backoff(delay, 0), delay);
+        assert_eq!(policy.backoff(delay, 100), delay);
+    }
+}
@@ -4,10 +4,14 @@ use azalea_client::{
 };
 use bevy_app::Update;
 use bevy_ecs::prelude::*;
+use derive_more::{Deref, DerefMut};
 
 use crate::app::{App, Plugin};
 
 /// A plugin that makes [`DeathEvent`]s send [`PerformRespawnEvent`]s.
+///
+/// This can be disabled for individual clients with the [`AutoRespawn`]
+/// component.
 #[derive(Clone, Default)]
 pub struct AutoRespawnPlugin;
 impl Plugin for AutoRespawnPlugin {
@@ -21,13 +25,81 @@ impl Plugin for AutoRespawnPlugin {
     }
 }
 
+/// A component that controls whether the client should respawn immediately
+/// after dying.
+///
+/// Clients without this component will respawn automatically. If it's set to
+/// false, you can respawn manually with [`Client::respawn`].
+///
+/// [`Client::respawn`]: crate::Client::respawn
+#[derive(Clone, Component, Copy, Debug, Deref, DerefMut, Eq, PartialEq)]
+pub struct AutoRespawn(pub bool);
+impl Default for AutoRespawn {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
 fn auto_respawn(
     mut events: MessageReader<DeathEvent>,
     mut perform_respawn_events: MessageWriter<PerformRespawnEvent>,
+    query: Query<&AutoRespawn>,
 ) {
     for event in events.read() {
+        if query.get(event.entity).is_ok_and(|a| !**a) {
+            continue;
+        }
+
         perform_respawn_events.write(PerformRespawnEvent {
             entity: event.entity,
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use azalea_client::{packet::game::SendGamePacketEvent, respawn::RespawnPlugin};
+
+    use super::*;
+
+    fn respawn_packets_after_death(auto_respawn: Option<AutoRespawn>) -> usize {
+        let mut app = App::new();
+        app.add_plugins((RespawnPlugin, AutoRespawnPlugin))
+            .add_message::<DeathEvent>();
+        let sent = Arc::new(AtomicUsize::new(0));
+        let sent_clone = sent.clone();
+        app.add_observer(move |_: On<SendGamePacketEvent>| {
+            sent_clone.fetch_add(1, Ordering::Relaxed);
+        });
+
+        let mut entity = app.world_mut().spawn_empty();
+        if let Some(auto_respawn) = auto_respawn {
+            entity.insert(auto_respawn);
+        }
+        let entity = entity.id();
+
+        app.world_mut().write_message(DeathEvent {
+            entity,
+            packet: None,
+            killer: None,
+        });
+        app.update();
+        sent.load(Ordering::Relaxed)
+    }
+
+    #[test]
+    fn test_auto_respawn_by_default() {
+        assert_eq!(respawn_packets_after_death(None), 1);
+        assert_eq!(respawn_packets_after_death(Some(AutoRespawn(true))), 1);
+    }
+
+    #[test]
+    fn test_auto_respawn_disabled() {
+        assert_eq!(respawn_packets_after_death(Some(AutoRespawn(false))), 0);
+    }
+}
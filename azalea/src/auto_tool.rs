@@ -1,7 +1,17 @@
+use std::iter;
+
 use azalea_block::{BlockState, BlockTrait, fluid_state::FluidKind};
-use azalea_core::position::BlockPos;
+use azalea_client::{
+    inventory::{ContainerClickEvent, equipment_effects::collect_attribute_modifiers_from_item},
+    local_player::WorldHolder,
+};
+use azalea_core::{position::BlockPos, registry_holder::RegistryHolder};
 use azalea_entity::{ActiveEffects, Attributes, FluidOnEyes, Physics, inventory::Inventory};
-use azalea_inventory::{ItemStack, Menu, components};
+use azalea_inventory::{
+    ItemStack, Menu,
+    components::{self, EquipmentSlot},
+    operations::SwapClick,
+};
 use azalea_registry::builtin::{BlockKind, EntityKind, ItemKind};
 
 use crate::Client;
@@ -45,6 +55,66 @@ impl Client {
         self.set_selected_hotbar_slot(best_tool_result.index as u8);
         self.mine(block_pos).await;
     }
+
+    /// Find the best item in our inventory for mining the given block and
+    /// start holding it.
+    ///
+    /// Unlike [`Self::best_tool_in_hotbar_for_block`], this checks the whole
+    /// inventory and takes enchantments like Efficiency into account. If the
+    /// best tool isn't in the hotbar, it gets swapped into the selected hotbar
+    /// slot.
+    ///
+    /// Returns the index of the slot in the current menu that the tool was in
+    /// (the same as in [`Menu::slot`]), or `None` if nothing would mine the
+    /// block faster than an empty hand.
+    ///
+    /// Like [`Self::set_selected_hotbar_slot`], the change is applied on the
+    /// next `Update`.
+    pub fn equip_best_tool_for(&self, block: BlockState) -> Option<usize> {
+        let (best_slot, window_id, hotbar_slots_range) = self.query_self::<(
+            &Inventory,
+            &Physics,
+            &FluidOnEyes,
+            &Attributes,
+            &ActiveEffects,
+            &WorldHolder,
+        ), _>(
+            |(inventory, physics, fluid_on_eyes, attributes, active_effects, world_holder)| {
+                let best_slot = best_tool_in_inventory_for_block(
+                    block,
+                    inventory,
+                    physics,
+                    fluid_on_eyes,
+                    attributes,
+                    active_effects,
+                    &world_holder.shared.read().registries,
+                );
+                (
+                    best_slot,
+                    inventory.id,
+                    inventory.menu().hotbar_slots_range(),
+                )
+            },
+        );
+        let best_slot = best_slot?;
+
+        if hotbar_slots_range.contains(&best_slot) {
+            self.set_selected_hotbar_slot((best_slot - hotbar_slots_range.start()) as u8);
+        } else {
+            let selected_hotbar_slot = self.selected_hotbar_slot();
+            self.ecs.write().trigger(ContainerClickEvent {
+                entity: self.entity,
+                window_id,
+                operation: SwapClick {
+                    source_slot: best_slot as u16,
+                    target_slot: selected_hotbar_slot,
+                }
+                .into(),
+            });
+        }
+
+        Some(best_slot)
+    }
 }
 
 /// Returns the best tool in the hotbar for the given block.
@@ -153,3 +223,214 @@ pub fn accurate_best_tool_in_hotbar_for_block(
         percentage_per_tick: best_speed,
     }
 }
+
+/// Returns the index of the slot in the current menu with the best item for
+/// mining the given block, out of the player's whole inventory.
+///
+/// Unlike [`accurate_best_tool_in_hotbar_for_block`], this also applies the
+/// attribute modifiers that each item would give us while being held, so
+/// enchantments like Efficiency are accounted for. The registries are needed
+/// to look up what the enchantments do.
+///
+/// Returns `None` if no item would mine the block faster than an empty hand.
+/// If the held item is tied with another item, the held item is preferred.
+pub fn best_tool_in_inventory_for_block(
+    block: BlockState,
+    inventory: &Inventory,
+    physics: &Physics,
+    fluid_on_eyes: &FluidOnEyes,
+    attributes: &Attributes,
+    active_effects: &ActiveEffects,
+    registries: &RegistryHolder,
+) -> Option<usize> {
+    let block = Box::<dyn BlockTrait>::from(block);
+    if matches!(
+        block.as_registry_block(),
+        BlockKind::Water | BlockKind::Lava
+    ) {
+        // can't mine fluids
+        return None;
+    }
+
+    // the modifiers from the item we're holding are already applied, so remove them
+    // to get the attributes we'd have with an empty hand
+    let mut empty_hand_attributes = attributes.clone();
+    for (attribute, modifier) in collect_attribute_modifiers_from_item(
+        EquipmentSlot::Mainhand,
+        inventory.held_item(),
+        registries,
+    ) {
+        if let Some(attribute) = empty_hand_attributes.get_mut(attribute) {
+            attribute.remove(&modifier.id);
+        }
+    }
+    let mine_progress_with = |item: &ItemStack| {
+        let mut attributes = empty_hand_attributes.clone();
+        for (attribute, modifier) in
+            collect_attribute_modifiers_from_item(EquipmentSlot::Mainhand, item, registries)
+        {
+            if let Some(attribute) = attributes.get_mut(attribute) {
+                attribute.insert(modifier);
+            }
+        }
+        azalea_entity::mining::get_mine_progress(
+            block.as_ref(),
+            item.kind(),
+            fluid_on_eyes,
+            physics,
+            &attributes,
+            active_effects,
+        )
+    };
+
+    let menu = inventory.menu();
+    let held_slot = *menu.hotbar_slots_range().start() + inventory.selected_hotbar_slot as usize;
+
+    let mut best_slot = None;
+    let mut best_progress = mine_progress_with(&ItemStack::Empty);
+    // check the held item first so we don't swap if it's already one of the best
+    for i in iter::once(held_slot).chain(menu.player_slots_range()) {
+        let Some(item) = menu.slot(i).filter(|item| item.is_present()) else {
+            continue;
+        };
+        let progress = mine_progress_with(item);
+        if progress > best_progress {
+            best_slot = Some(i);
+            best_progress = progress;
+        }
+    }
+    best_slot
+}
+
+#[cfg(test)]
+mod tests {
+    use azalea_inventory::components::Enchantments;
+    use azalea_registry::{DataRegistry, data::Enchantment, identifier::Identifier};
+    use simdnbt::owned::NbtCompound;
+
+    use super::*;
+
+    fn best_tool_for(block: BlockState, inventory: &Inventory) -> Option<usize> {
+        best_tool_with_registries_for(block, inventory, &RegistryHolder::default())
+    }
+
+    fn best_tool_with_registries_for(
+        block: BlockState,
+        inventory: &Inventory,
+        registries: &RegistryHolder,
+    ) -> Option<usize> {
+        let mut physics = Physics::default();
+        physics.set_on_ground(true);
+        best_tool_in_inventory_for_block(
+            block,
+            inventory,
+            &physics,
+            &FluidOnEyes::new(FluidKind::Empty),
+            &Attributes::new(EntityKind::Player),
+            &ActiveEffects::default(),
+            registries,
+        )
+    }
+
+    /// Registries that only contain the vanilla Efficiency enchantment, which
+    /// has the protocol ID 0.
+    fn registries_with_efficiency() -> RegistryHolder {
+        let mut registries = RegistryHolder::default();
+        registries.append(
+            Identifier::new("minecraft:enchantment"),
+            vec![(
+                Identifier::new("minecraft:efficiency"),
+                Some(NbtCompound::from([
+                    (
+                        "description",
+                        [("translate", "enchantment.minecraft.efficiency".into())].into(),
+                    ),
+                    ("anvil_cost", 1.into()),
+                    (
+                        "max_cost",
+                        [("base", 51.into()), ("per_level_above_first", 10.into())].into(),
+                    ),
+                    (
+                        "min_cost",
+                        [("base", 1.into()), ("per_level_above_first", 10.into())].into(),
+                    ),
+                    (
+                        "effects",
+                        [(
+                            "minecraft:attributes",
+                            [
+                                ("operation", "add_value".into()),
+                                ("attribute", "minecraft:mining_efficiency".into()),
+                                (
+                                    "amount",
+                                    [
+                                        ("type", "minecraft:levels_squared".into()),
+                                        ("added", 1.0f32.into()),
+                                    ]
+                                    .into(),
+                                ),
+                                ("id", "minecraft:enchantment.efficiency".into()),
+                            ]
+                            .into(),
+                        )]
+                        .into(),
+                    ),
+                    ("max_level", 5.into()),
+                    ("weight", 10.into()),
+                    ("slots", ["mainhand"].into()),
+                    ("supported_items", "#minecraft:enchantable/mining".into()),
+                ])),
+            )],
+        );
+        registries
+    }
+
+    #[test]
+    fn test_best_tool_in_inventory() {
+        let mut inventory = Inventory::default();
+        // holding a shovel, with a pickaxe in the main inventory
+        *inventory.inventory_menu.slot_mut(36).unwrap() = ItemKind::IronShovel.into();
+        *inventory.inventory_menu.slot_mut(9).unwrap() = ItemKind::IronPickaxe.into();
+
+        assert_eq!(best_tool_for(BlockKind::Stone.into(), &inventory), Some(9));
+        assert_eq!(best_tool_for(BlockKind::Dirt.into(), &inventory), Some(36));
+    }
+
+    #[test]
+    fn test_no_tool_helps() {
+        let mut inventory = Inventory::default();
+        *inventory.inventory_menu.slot_mut(36).unwrap() = ItemKind::Stick.into();
+
+        assert_eq!(best_tool_for(BlockKind::Stone.into(), &inventory), None);
+        assert_eq!(best_tool_for(BlockKind::Water.into(), &inventory), None);
+    }
+
+    #[test]
+    fn test_efficiency_beats_faster_tool() {
+        let registries = registries_with_efficiency();
+        let mut inventory = Inventory::default();
+        // holding a diamond pickaxe, with a stone pickaxe that has Efficiency V in the
+        // main inventory
+        *inventory.inventory_menu.slot_mut(36).unwrap() = ItemKind::DiamondPickaxe.into();
+        *inventory.inventory_menu.slot_mut(9).unwrap() = ItemStack::from(ItemKind::StonePickaxe)
+            .with_component(Enchantments {
+                levels: [(Enchantment::new_raw(0), 5)].into(),
+            });
+
+        // without the registries, we don't know what the enchantment does
+        assert_eq!(best_tool_for(BlockKind::Stone.into(), &inventory), Some(36));
+        assert_eq!(
+            best_tool_with_registries_for(BlockKind::Stone.into(), &inventory, &registries),
+            Some(9)
+        );
+
+        // if we're already holding the enchanted pickaxe, we keep holding it
+        let enchanted_pickaxe = inventory.inventory_menu.slot(9).unwrap().clone();
+        *inventory.inventory_menu.slot_mut(9).unwrap() = ItemKind::DiamondPickaxe.into();
+        *inventory.inventory_menu.slot_mut(36).unwrap() = enchanted_pickaxe;
+        assert_eq!(
+            best_tool_with_registries_for(BlockKind::Stone.into(), &inventory, &registries),
+            Some(36)
+        );
+    }
+}
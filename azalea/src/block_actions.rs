@@ -0,0 +1,481 @@
+//! Queue up block placements, block breaks, and movements so they're done one
+//! at a time.
+
+use std::collections::VecDeque;
+
+use azalea_block::BlockTrait;
+use azalea_client::{
+    interact::{BlockStatePredictionHandler, SwingArmEvent, handle_start_use_item_queued},
+    local_player::WorldHolder,
+    mining::{Mining, MiningSystems, StartMiningBlockEvent},
+    packet::game::SendGamePacketEvent,
+};
+use azalea_core::{
+    direction::Direction, hit_result::BlockHitResult, position::BlockPos, tick::GameTick,
+};
+use azalea_entity::{Attributes, LookDirection, Position, dimensions::EntityDimensions};
+use azalea_physics::{PhysicsSystems, collision::BlockWithShape};
+use azalea_protocol::packets::game::{ServerboundUseItemOn, s_interact::InteractionHand};
+use azalea_world::World;
+use bevy_ecs::prelude::*;
+use tracing::debug;
+
+use crate::{
+    Client,
+    app::{App, Plugin},
+    bot::direction_looking_at,
+    pathfinder::{
+        GotoEvent, Pathfinder, PathfinderOpts,
+        goals::{BlockPosGoal, ReachBlockPosGoal},
+    },
+};
+
+/// A plugin that makes clients do the actions in their [`BlockActionQueue`].
+#[derive(Clone, Default)]
+pub struct BlockActionsPlugin;
+impl Plugin for BlockActionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<BlockActionProgressEvent>().add_systems(
+            GameTick,
+            tick_block_action_queue
+                .after(MiningSystems)
+                .before(handle_start_use_item_queued)
+                .before(PhysicsSystems),
+        );
+    }
+}
+
+/// Something that a client can do as part of a [`BlockActionQueue`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BlockAction {
+    /// Place the block that we're holding at the given position.
+    ///
+    /// The block is placed against one of its neighbors, so there must be a
+    /// solid block next to the position.
+    Place(BlockPos),
+    /// Mine the block at the given position until it's broken.
+    Mine(BlockPos),
+    /// Walk to the given position with the pathfinder.
+    MoveTo(BlockPos),
+}
+
+/// The reason that a [`BlockAction`] couldn't be done.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BlockActionError {
+    /// The block isn't in a loaded chunk.
+    NotLoaded,
+    /// We tried to place a block somewhere that already has a block.
+    Occupied,
+    /// There's no block next to the position for us to place against.
+    NothingToPlaceAgainst,
+    /// The block can't be broken, like bedrock.
+    Unbreakable,
+    /// We stopped mining the block before it broke.
+    MiningInterrupted,
+    /// The block is out of reach and the pathfinder couldn't get us closer.
+    OutOfReach,
+    /// The pathfinder couldn't get us to the position of a
+    /// [`BlockAction::MoveTo`].
+    PathNotFound,
+}
+
+/// What a [`BlockActionQueue`] should do when one of its actions fails.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum OnBlockActionFailure {
+    /// Skip the action and continue with the rest of the queue.
+    #[default]
+    Skip,
+    /// Clear the rest of the queue.
+    Abort,
+}
+
+/// A component that contains the block actions that a client is going to do.
+///
+/// Only one action is done at a time, and at most one action is finished per
+/// tick, so placing a row of blocks will take at least one tick per block.
+/// When an action is out of reach, the pathfinder is used to get closer to it.
+///
+/// You'll usually want to use [`Client::queue_block_actions`] instead of
+/// inserting this directly.
+#[derive(Clone, Component, Debug, Default)]
+pub struct BlockActionQueue {
+    pub actions: VecDeque<BlockAction>,
+    pub on_failure: OnBlockActionFailure,
+    /// What we're currently doing for the action at the front of the queue.
+    state: BlockActionState,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum BlockActionState {
+    #[default]
+    Idle,
+    /// We asked the pathfinder to move us and we're waiting for it to finish.
+    Pathing,
+    /// The pathfinder finished moving us, so we shouldn't ask it again.
+    Pathed,
+    /// We started mining the block and are waiting for it to break.
+    Mining,
+}
+
+/// A message that's sent every time one of the actions in a
+/// [`BlockActionQueue`] finishes or fails.
+#[derive(Clone, Debug, Message)]
+pub struct BlockActionProgressEvent {
+    pub entity: Entity,
+    pub action: BlockAction,
+    pub result: Result<(), BlockActionError>,
+    /// The number of actions that are left in the queue.
+    pub remaining: usize,
+}
+
+#[allow(clippy::type_complexity)]
+pub fn tick_block_action_queue(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &mut BlockActionQueue,
+        &Position,
+        &EntityDimensions,
+        &Attributes,
+        &WorldHolder,
+        &mut LookDirection,
+        &mut BlockStatePredictionHandler,
+        Has<Mining>,
+        Option<&Pathfinder>,
+    )>,
+    mut start_mining_events: MessageWriter<StartMiningBlockEvent>,
+    mut goto_events: MessageWriter<GotoEvent>,
+    mut progress_events: MessageWriter<BlockActionProgressEvent>,
+) {
+    for (
+        entity,
+        mut queue,
+        position,
+        dimensions,
+        attributes,
+        world_holder,
+        mut look_direction,
+        mut prediction_handler,
+        is_mining,
+        pathfinder,
+    ) in &mut query
+    {
+        let Some(&action) = queue.actions.front() else {
+            continue;
+        };
+
+        if queue.state == BlockActionState::Pathing {
+            if pathfinder.is_some_and(|p| p.goal.is_some() || p.is_calculating) {
+                continue;
+            }
+            queue.state = BlockActionState::Pathed;
+        }
+
+        let world = world_holder.shared.read();
+        let eye_position = position.up(dimensions.eye_height.into());
+        let reach = attributes.block_interaction_range.calculate();
+
+        // if the action needs us to be closer, this is the block that we have to
+        // be able to reach
+        let mut needs_reach = None;
+
+        let result = match action {
+            BlockAction::Place(pos) => match world.get_block_state(pos) {
+                None => Some(Err(BlockActionError::NotLoaded)),
+                Some(state) if !state.is_collision_shape_empty() => {
+                    Some(Err(BlockActionError::Occupied))
+                }
+                Some(_) => match find_block_to_place_against(&world, pos) {
+                    None => Some(Err(BlockActionError::NothingToPlaceAgainst)),
+                    Some(hit) if eye_position.distance_to(hit.location) > reach => {
+                        needs_reach = Some(hit.block_pos);
+                        None
+                    }
+                    Some(hit) => {
+                        look_direction.update(direction_looking_at(eye_position, hit.location));
+                        let seq = prediction_handler.start_predicting();
+                        commands.trigger(SendGamePacketEvent::new(
+                            entity,
+                            ServerboundUseItemOn {
+                                hand: InteractionHand::MainHand,
+                                block_hit: (&hit).into(),
+                                seq,
+                            },
+                        ));
+                        commands.trigger(SwingArmEvent {
+                            entity,
+                            hand: InteractionHand::MainHand,
+                        });
+                        Some(Ok(()))
+                    }
+                },
+            },
+            BlockAction::Mine(pos) => match world.get_block_state(pos) {
+                None => Some(Err(BlockActionError::NotLoaded)),
+                Some(state) if state.is_air() => Some(Ok(())),
+                Some(_) if queue.state == BlockActionState::Mining => {
+                    if is_mining {
+                        None
+                    } else {
+                        Some(Err(BlockActionError::MiningInterrupted))
+                    }
+                }
+                Some(state) if Box::<dyn BlockTrait>::from(state).behavior().destroy_time < 0. => {
+                    Some(Err(BlockActionError::Unbreakable))
+                }
+                Some(_) if eye_position.distance_to(pos.center()) > reach => {
+                    needs_reach = Some(pos);
+                    None
+                }
+                Some(_) => {
+                    look_direction.update(direction_looking_at(eye_position, pos.center()));
+                    start_mining_events.write(StartMiningBlockEvent {
+                        entity,
+                        position: pos,
+                        force: true,
+                    });
+                    queue.state = BlockActionState::Mining;
+                    None
+                }
+            },
+            BlockAction::MoveTo(pos) => {
+                if BlockPos::from(**position) == pos {
+                    Some(Ok(()))
+                } else if queue.state == BlockActionState::Pathed || pathfinder.is_none() {
+                    Some(Err(BlockActionError::PathNotFound))
+                } else {
+                    goto_events.write(GotoEvent::new(
+                        entity,
+                        BlockPosGoal(pos),
+                        PathfinderOpts::new().retry_on_no_path(false),
+                    ));
+                    queue.state = BlockActionState::Pathing;
+                    None
+                }
+            }
+        };
+
+        let result = if let Some(target) = needs_reach {
+            if queue.state == BlockActionState::Pathed || pathfinder.is_none() {
+                Some(Err(BlockActionError::OutOfReach))
+            } else {
+                debug!("{target:?} is out of reach, pathfinding to it");
+                goto_events.write(GotoEvent::new(
+                    entity,
+                    ReachBlockPosGoal::new(target, world.chunks.clone()),
+                    PathfinderOpts::new().retry_on_no_path(false),
+                ));
+                queue.state = BlockActionState::Pathing;
+                None
+            }
+        } else {
+            result
+        };
+
+        let Some(result) = result else {
+            continue;
+        };
+
+        queue.actions.pop_front();
+        queue.state = BlockActionState::Idle;
+        if result.is_err() && queue.on_failure == OnBlockActionFailure::Abort {
+            queue.actions.clear();
+        }
+        debug!("finished block action {action:?} with {result:?}");
+        progress_events.write(BlockActionProgressEvent {
+            entity,
+            action,
+            result,
+            remaining: queue.actions.len(),
+        });
+    }
+}
+
+/// Find a face of a block next to the given position that we can click to
+/// place a block at the position.
+fn find_block_to_place_against(world: &World, pos: BlockPos) -> Option<BlockHitResult> {
+    // prefer placing on top of blocks, since that's the most common
+    let directions = [
+        Direction::Down,
+        Direction::North,
+        Direction::South,
+        Direction::West,
+        Direction::East,
+        Direction::Up,
+    ];
+    for direction in directions {
+        let against = pos.offset_with_direction(direction);
+        let Some(state) = world.get_block_state(against) else {
+            continue;
+        };
+        if state.is_collision_shape_empty() {
+            continue;
+        }
+        let face = direction.opposite();
+        return Some(BlockHitResult {
+            location: against.center() + face.normal_vec3() * 0.5,
+            miss: false,
+            direction: face,
+            block_pos: against,
+            inside: false,
+            world_border: false,
+        });
+    }
+    None
+}
+
+impl Client {
+    /// Add actions to the end of this client's [`BlockActionQueue`].
+    ///
+    /// The actions are done in order, one at a time, and a
+    /// [`BlockActionProgressEvent`] is sent every time one finishes. If an
+    /// action is out of reach then the pathfinder will be used to get closer.
+    pub fn queue_block_actions(&self, actions: Vec<BlockAction>) {
+        let mut ecs = self.ecs.write();
+        let mut entity = ecs.entity_mut(self.entity);
+        if let Some(mut queue) = entity.get_mut::<BlockActionQueue>() {
+            queue.actions.extend(actions);
+        } else {
+            entity.insert(BlockActionQueue {
+                actions: actions.into(),
+                ..Default::default()
+            });
+        }
+    }
+
+    /// Set what this client's [`BlockActionQueue`] should do when an action
+    /// fails.
+    pub fn set_on_block_action_failure(&self, on_failure: OnBlockActionFailure) {
+        let mut ecs = self.ecs.write();
+        let mut entity = ecs.entity_mut(self.entity);
+        if let Some(mut queue) = entity.get_mut::<BlockActionQueue>() {
+            queue.on_failure = on_failure;
+        } else {
+            entity.insert(BlockActionQueue {
+                on_failure,
+                ..Default::default()
+            });
+        }
+    }
+
+    /// Returns the number of actions that are left in this client's
+    /// [`BlockActionQueue`], including the one that's currently being done.
+    pub fn remaining_block_actions(&self) -> usize {
+        self.get_component::<BlockActionQueue>()
+            .map(|q| q.actions.len())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use azalea_client::test_utils::simulation::ReceivedMessages;
+    use azalea_core::position::ChunkPos;
+    use azalea_registry::builtin::BlockKind;
+    use azalea_world::{Chunk, ChunkStorage, PartialChunkStorage};
+
+    use super::*;
+    use crate::pathfinder::simulation::{SimulatedPlayerBundle, Simulation};
+
+    #[test]
+    fn test_place_row_of_blocks() {
+        let mut partial_chunks = PartialChunkStorage::default();
+        let mut chunks = ChunkStorage::default();
+        partial_chunks.set(&ChunkPos::new(0, 0), Some(Chunk::default()), &mut chunks);
+        for x in 0..4 {
+            chunks.set_block_state(BlockPos::new(x, 70, 0), BlockKind::Stone.into());
+        }
+        let mut simulation = Simulation::new(
+            chunks,
+            SimulatedPlayerBundle::new(BlockPos::new(0, 71, 0).center_bottom()),
+        );
+        simulation.app.add_plugins(BlockActionsPlugin);
+
+        let progress = ReceivedMessages::<BlockActionProgressEvent>::default();
+        simulation
+            .app
+            .add_systems(GameTick, progress.system().after(tick_block_action_queue));
+
+        simulation
+            .app
+            .world_mut()
+            .entity_mut(simulation.entity)
+            .insert(BlockActionQueue {
+                actions: [
+                    BlockAction::Place(BlockPos::new(1, 71, 0)),
+                    BlockAction::Place(BlockPos::new(2, 71, 0)),
+                    // this one has nothing next to it
+                    BlockAction::Place(BlockPos::new(2, 73, 0)),
+                    BlockAction::Place(BlockPos::new(3, 71, 0)),
+                ]
+                .into(),
+                ..Default::default()
+            });
+
+        for tick in 1..=4 {
+            simulation.tick();
+            assert_eq!(progress.lock().len(), tick);
+        }
+        simulation.tick();
+
+        let progress = progress.lock();
+        assert_eq!(progress.len(), 4);
+        assert_eq!(
+            progress[0].action,
+            BlockAction::Place(BlockPos::new(1, 71, 0))
+        );
+        assert_eq!(progress[0].result, Ok(()));
+        assert_eq!(progress[0].remaining, 3);
+        assert_eq!(
+            progress[2].result,
+            Err(BlockActionError::NothingToPlaceAgainst)
+        );
+        assert_eq!(
+            progress[3].action,
+            BlockAction::Place(BlockPos::new(3, 71, 0))
+        );
+        assert_eq!(progress[3].remaining, 0);
+        assert!(
+            simulation
+                .component::<BlockActionQueue>()
+                .actions
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_abort_on_failure() {
+        let mut partial_chunks = PartialChunkStorage::default();
+        let mut chunks = ChunkStorage::default();
+        partial_chunks.set(&ChunkPos::new(0, 0), Some(Chunk::default()), &mut chunks);
+        chunks.set_block_state(BlockPos::new(0, 70, 0), BlockKind::Stone.into());
+        chunks.set_block_state(BlockPos::new(1, 71, 0), BlockKind::Bedrock.into());
+        let mut simulation = Simulation::new(
+            chunks,
+            SimulatedPlayerBundle::new(BlockPos::new(0, 71, 0).center_bottom()),
+        );
+        simulation.app.add_plugins(BlockActionsPlugin);
+
+        simulation
+            .app
+            .world_mut()
+            .entity_mut(simulation.entity)
+            .insert(BlockActionQueue {
+                actions: [
+                    BlockAction::Mine(BlockPos::new(1, 71, 0)),
+                    BlockAction::Place(BlockPos::new(1, 72, 0)),
+                ]
+                .into(),
+                on_failure: OnBlockActionFailure::Abort,
+                ..Default::default()
+            });
+        simulation.tick();
+
+        assert!(
+            simulation
+                .component::<BlockActionQueue>()
+                .actions
+                .is_empty()
+        );
+    }
+}
@@ -211,5 +211,6 @@ impl PluginGroup for DefaultBotPlugins {
             .add(crate::tick_broadcast::TickBroadcastPlugin)
             .add(crate::events::EventsPlugin)
             .add(crate::auto_reconnect::AutoReconnectPlugin)
+            .add(crate::block_actions::BlockActionsPlugin)
     }
 }
@@ -1,12 +1,15 @@
 use std::time::Duration;
 
 use azalea_client::{DefaultPlugins, account::Account};
-use azalea_protocol::address::ResolvableAddr;
+use azalea_protocol::{
+    address::ResolvableAddr, common::client_information::ClientInformation, connect::Proxy,
+};
 use bevy_app::{AppExit, Plugins};
 use bevy_ecs::component::Component;
 
 use crate::{
     HandleFn, JoinOpts, NoState,
+    auto_reconnect::AutoReconnectPolicy,
     bot::DefaultBotPlugins,
     swarm::{self, SwarmBuilder},
 };
@@ -16,10 +19,12 @@ use crate::{
 ///
 /// ```no_run
 /// # use azalea::prelude::*;
+/// # use std::time::Duration;
 /// # #[tokio::main]
 /// # async fn main() {
 /// ClientBuilder::new()
 ///     .set_handler(handle)
+///     .reconnect_after(Duration::from_secs(10))
 ///     .start(Account::offline("bot"), "localhost")
 ///     .await;
 /// # }
@@ -39,6 +44,9 @@ where
     /// technically just a subset of it so we can avoid duplicating code this
     /// way.
     swarm: SwarmBuilder<S, swarm::NoSwarmState, R, ()>,
+    /// The options that will be used when the client joins, set with methods
+    /// like [`Self::proxy`] and [`Self::client_information`].
+    join_opts: JoinOpts,
 }
 impl ClientBuilder<NoState, ()> {
     /// Start building a client that can join the world.
@@ -81,6 +89,7 @@ impl ClientBuilder<NoState, ()> {
     pub fn new_without_plugins() -> Self {
         Self {
             swarm: SwarmBuilder::new_without_plugins(),
+            join_opts: JoinOpts::default(),
         }
     }
 
@@ -117,6 +126,7 @@ impl ClientBuilder<NoState, ()> {
     {
         ClientBuilder {
             swarm: self.swarm.set_handler(handler),
+            join_opts: self.join_opts,
         }
     }
 }
@@ -162,6 +172,42 @@ where
         self.swarm.reconnect_after = delay.into();
         self
     }
+    /// Set which disconnects our bot will reconnect after, how many times it'll
+    /// try, and how the delay increases after failed attempts.
+    ///
+    /// See [`AutoReconnectPolicy`] for more details.
+    #[must_use]
+    pub fn reconnect_policy(mut self, policy: AutoReconnectPolicy) -> Self {
+        self.swarm = self.swarm.reconnect_policy(policy);
+        self
+    }
+
+    /// Configure the SOCKS5 proxy used for connecting to the server and for
+    /// authenticating with Mojang.
+    ///
+    /// See [`JoinOpts::proxy`] for more details. Use [`Self::join_opts`] if you
+    /// want to only proxy one of them.
+    #[must_use]
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.join_opts = self.join_opts.proxy(proxy);
+        self
+    }
+    /// Set the [`ClientInformation`] that the client will send to the server
+    /// when it joins, like its view distance and language.
+    #[must_use]
+    pub fn client_information(mut self, client_information: ClientInformation) -> Self {
+        self.join_opts = self.join_opts.client_information(client_information);
+        self
+    }
+    /// Set the options that the client will use when joining.
+    ///
+    /// These are merged with the options passed to [`Self::start_with_opts`],
+    /// with the latter taking priority.
+    #[must_use]
+    pub fn join_opts(mut self, join_opts: JoinOpts) -> Self {
+        self.join_opts = join_opts;
+        self
+    }
 
     /// Build this `ClientBuilder` into an actual [`Client`](crate::Client) and
     /// join the given server.
@@ -179,12 +225,9 @@ where
     /// [`ServerAddr`]: ../azalea_protocol/address/struct.ServerAddr.html
     /// [`ResolvedAddr`]: ../azalea_protocol/address/struct.ResolvedAddr.html
     /// [`ResolvableAddr`]: ../azalea_protocol/address/trait.ResolvableAddr.html
-    pub async fn start(mut self, account: Account, address: impl ResolvableAddr) -> AppExit {
-        self.swarm.accounts = vec![(account, JoinOpts::default())];
-        if self.swarm.states.is_empty() {
-            self.swarm.states = vec![S::default()];
-        }
-        self.swarm.start(address).await
+    pub async fn start(self, account: Account, address: impl ResolvableAddr) -> AppExit {
+        self.start_with_opts(account, address, JoinOpts::default())
+            .await
     }
 
     /// Do the same as [`Self::start`], but allow passing in custom join
@@ -195,11 +238,14 @@ where
         address: impl ResolvableAddr,
         opts: JoinOpts,
     ) -> AppExit {
-        self.swarm.accounts = vec![(account, opts.clone())];
+        let mut join_opts = self.join_opts.clone();
+        join_opts.update(&opts);
+
+        self.swarm.accounts = vec![(account, join_opts.clone())];
         if self.swarm.states.is_empty() {
             self.swarm.states = vec![S::default()];
         }
-        self.swarm.start_with_opts(address, opts).await
+        self.swarm.start_with_opts(address, join_opts).await
     }
 }
 impl Default for ClientBuilder<NoState, ()> {
@@ -207,3 +253,87 @@ impl Default for ClientBuilder<NoState, ()> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_join_opts() {
+        let proxy = Proxy::new("127.0.0.1:1080".parse().unwrap(), None);
+        let client_information = ClientInformation {
+            view_distance: 2,
+            ..Default::default()
+        };
+        let builder = ClientBuilder::new_without_plugins()
+            .proxy(proxy.clone())
+            .client_information(client_information.clone());
+
+        let join_opts = &builder.join_opts;
+        assert_eq!(join_opts.server_proxy.as_ref().unwrap().addr, proxy.addr);
+        assert_eq!(
+            join_opts.sessionserver_proxy.as_ref().unwrap().addr,
+            proxy.addr
+        );
+        assert_eq!(join_opts.client_information, Some(client_information));
+    }
+
+    #[tokio::test]
+    async fn test_builder_join_with_reconnect_policy() {
+        use azalea_chat::translatable_component::TranslatableComponent;
+        use azalea_client::test_utils::fake_server::{accept_login, listener_addr};
+        use azalea_protocol::packets::login::c_login_disconnect::ClientboundLoginDisconnect;
+        use bevy_app::{App, Update};
+        use bevy_ecs::system::Res;
+        use tokio::{net::TcpListener, sync::mpsc};
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener_addr(&listener);
+
+        // a fake server that bans us the first time we join
+        let server = tokio::spawn(async move {
+            let (first_login, mut conn) = accept_login(&listener).await;
+            conn.write(ClientboundLoginDisconnect {
+                reason: TranslatableComponent::new(
+                    "multiplayer.disconnect.banned".to_owned(),
+                    vec![],
+                )
+                .into(),
+            })
+            .await
+            .unwrap();
+            let (second_login, _conn) = accept_login(&listener).await;
+            vec![first_login.hello, second_login.hello]
+        });
+
+        let (policy_tx, mut policy_rx) = mpsc::unbounded_channel();
+        let client = ClientBuilder::new()
+            .reconnect_after(Duration::ZERO)
+            .reconnect_policy(AutoReconnectPolicy {
+                // with_backoff wouldn't reconnect after being banned
+                should_retry: |_| true,
+                max_attempts: Some(3),
+                ..AutoReconnectPolicy::with_backoff()
+            })
+            .add_plugins(move |app: &mut App| {
+                let policy_tx = policy_tx.clone();
+                app.add_systems(Update, move |policy: Res<AutoReconnectPolicy>| {
+                    let _ = policy_tx.send(policy.max_attempts);
+                });
+            })
+            .start(Account::offline("bot"), address);
+
+        let hellos = tokio::time::timeout(Duration::from_secs(5), async {
+            tokio::select! {
+                hellos = server => hellos.unwrap(),
+                app_exit = client => panic!("client exited early with {app_exit:?}"),
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(hellos[0].name, "bot");
+        assert_eq!(hellos[1].name, "bot");
+        assert_eq!(policy_rx.recv().await, Some(Some(3)));
+    }
+}
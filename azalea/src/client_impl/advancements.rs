@@ -0,0 +1,37 @@
+use azalea_client::{advancements::Advancements, recipes::Recipes};
+use azalea_registry::identifier::Identifier;
+
+use crate::Client;
+
+impl Client {
+    /// Get the advancements that the server sent us, along with our progress
+    /// on them.
+    ///
+    /// Returns `None` if the server hasn't sent us any advancements yet.
+    pub fn advancements(&self) -> Option<Advancements> {
+        self.get_component::<Advancements>().map(|a| a.clone())
+    }
+
+    /// Returns whether we've completed the advancement with the given id, like
+    /// `minecraft:story/mine_stone`.
+    ///
+    /// ```
+    /// # use azalea_registry::identifier::Identifier;
+    /// # fn example(bot: &azalea::Client) {
+    /// if bot.is_advancement_done(&Identifier::new("minecraft:story/smelt_iron")) {
+    ///     println!("we have iron!");
+    /// }
+    /// # }
+    /// ```
+    pub fn is_advancement_done(&self, id: &Identifier) -> bool {
+        self.get_component::<Advancements>()
+            .is_some_and(|advancements| advancements.is_done(id))
+    }
+
+    /// Get the recipes that are unlocked in our recipe book.
+    ///
+    /// Returns `None` if the server hasn't sent us our recipe book yet.
+    pub fn recipes(&self) -> Option<Recipes> {
+        self.get_component::<Recipes>().map(|r| r.clone())
+    }
+}
@@ -1,7 +1,8 @@
 use azalea_client::attack::{
-    AttackEvent, AttackStrengthScale, TicksSinceLastAttack, get_attack_strength_delay,
+    AttackEvent, AttackQueued, AttackStrengthScale, TicksSinceLastAttack,
+    get_attack_strength_delay, get_attack_strength_scale,
 };
-use azalea_entity::Attributes;
+use azalea_entity::{Attributes, Dead};
 use bevy_ecs::entity::Entity;
 
 use crate::Client;
@@ -18,6 +19,38 @@ impl Client {
         });
     }
 
+    /// Attack an entity, but only if our attack cooldown has fully recharged.
+    ///
+    /// The cooldown depends on the attack speed of the item that we're holding,
+    /// so attacking before it's ready would deal less damage. Returns whether
+    /// the attack was queued.
+    ///
+    /// Like [`Client::attack`], this doesn't look at the entity or perform any
+    /// range checks.
+    pub fn attack_when_ready(&self, target: Entity) -> bool {
+        let mut ecs = self.ecs.write();
+        let mut entity_mut = ecs.entity_mut(self.entity);
+
+        // an attack that's queued for next tick hasn't reset the cooldown yet
+        if entity_mut.contains::<AttackQueued>() || entity_mut.contains::<Dead>() {
+            return false;
+        }
+        // AttackStrengthScale is only updated at the start of the tick, so it'd be
+        // stale if we attacked earlier in this one
+        let (Some(attributes), Some(ticks_since_last_attack)) = (
+            entity_mut.get::<Attributes>(),
+            entity_mut.get::<TicksSinceLastAttack>(),
+        ) else {
+            return false;
+        };
+        if get_attack_strength_scale(**ticks_since_last_attack, attributes, 0.5) < 1. {
+            return false;
+        }
+
+        entity_mut.insert(AttackQueued { target });
+        true
+    }
+
     /// Whether the player has an attack cooldown.
     ///
     /// Also see [`Client::attack_cooldown_remaining_ticks`].
@@ -49,3 +82,47 @@ impl Client {
         remaining_ticks.max(0.).ceil() as usize
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use azalea_registry::builtin::EntityKind;
+    use bevy_ecs::world::World;
+    use parking_lot::RwLock;
+
+    use super::*;
+
+    #[test]
+    fn test_attack_when_ready_only_attacks_once_per_cooldown() {
+        let mut ecs = World::new();
+        let target = ecs.spawn_empty().id();
+        let entity = ecs
+            .spawn((
+                Attributes::new(EntityKind::Player),
+                TicksSinceLastAttack(100),
+            ))
+            .id();
+        let client = Client::new(entity, Arc::new(RwLock::new(ecs)));
+
+        assert!(client.attack_when_ready(target));
+        assert!(!client.attack_when_ready(target));
+
+        // pretend that the queued attack was sent, which resets the cooldown
+        {
+            let mut ecs = client.ecs.write();
+            let mut entity_mut = ecs.entity_mut(entity);
+            entity_mut.remove::<AttackQueued>();
+            entity_mut.insert(TicksSinceLastAttack(0));
+        }
+        assert!(!client.attack_when_ready(target));
+
+        // the default attack speed of 4 means a cooldown of 5 ticks
+        client
+            .ecs
+            .write()
+            .entity_mut(entity)
+            .insert(TicksSinceLastAttack(5));
+        assert!(client.attack_when_ready(target));
+    }
+}
@@ -1,4 +1,11 @@
-use azalea_client::chat::{ChatKind, SendChatEvent, handler::SendChatKindEvent};
+use azalea_client::{
+    chat::{
+        ChatError, ChatKind, MAX_CHAT_LENGTH, SendChatEvent,
+        handler::{SendChatKindEvent, sanitize_chat_message},
+    },
+    connection::{FlushPacketsEvent, WritePacketError},
+};
+use tokio::sync::oneshot;
 
 use crate::Client;
 
@@ -40,10 +47,72 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Messages that are longer than [`MAX_CHAT_LENGTH`] are split into
+    /// multiple messages, unless the client has the
+    /// [`LongChatMessageBehavior::Reject`] component. Also see
+    /// [`Client::try_chat`].
+    ///
+    /// [`LongChatMessageBehavior::Reject`]: azalea_client::chat::LongChatMessageBehavior::Reject
     pub fn chat(&self, content: impl Into<String>) {
         self.ecs.write().write_message(SendChatEvent {
             entity: self.entity,
             content: content.into(),
         });
     }
+
+    /// Send a message in chat, or return an error if it's too long to be sent
+    /// in one packet.
+    ///
+    /// Characters that the server doesn't allow (like `§`) are removed before
+    /// the length is checked. Commands (content that starts with a slash)
+    /// don't have a length limit.
+    pub fn try_chat(&self, content: impl Into<String>) -> Result<(), ChatError> {
+        let content = content.into();
+        if let (ChatKind::Message, message) = ChatKind::from_content(&content) {
+            let length = sanitize_chat_message(message).chars().count();
+            if length > MAX_CHAT_LENGTH {
+                return Err(ChatError::TooLong { length });
+            }
+        }
+        self.chat(content);
+        Ok(())
+    }
+
+    /// Send a message in chat, and wait until it's been sent to the server.
+    ///
+    /// This works the same as [`Client::chat`], except the returned future
+    /// doesn't resolve until the chat packet has been written to the network,
+    /// which can be useful if you're rate-limiting your messages. Note that
+    /// this doesn't mean that the server has received or accepted the message.
+    ///
+    /// An error is returned if we disconnect before the message can be sent.
+    ///
+    /// ```rust,no_run
+    /// # use azalea::Client;
+    /// # async fn example(bot: Client) -> anyhow::Result<()> {
+    /// bot.chat_and_wait("Hello, world!").await?;
+    /// println!("sent!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn chat_and_wait(&self, content: impl Into<String>) -> Result<(), WritePacketError> {
+        let (sender, receiver) = oneshot::channel();
+        {
+            let mut ecs = self.ecs.write();
+            ecs.write_message(SendChatEvent {
+                entity: self.entity,
+                content: content.into(),
+            });
+            // the chat packet is sent in the same update, before the FlushPacketsEvent is
+            // handled
+            ecs.write_message(FlushPacketsEvent {
+                entity: self.entity,
+                sender,
+            });
+        }
+        receiver
+            .await
+            .map_err(|_| WritePacketError::ConnectionClosed)
+    }
 }
@@ -10,6 +10,9 @@ impl Client {
     ///
     /// If this is not set before the login packet, the default will be sent.
     ///
+    /// This also accepts a [`ClientInformationBuilder`], which makes sure that
+    /// the values are valid.
+    ///
     /// ```rust,no_run
     /// # use azalea::{Client, ClientInformation};
     /// # async fn example(bot: Client) -> Result<(), Box<dyn std::error::Error>> {
@@ -20,7 +23,10 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set_client_information(&self, client_information: ClientInformation) {
+    ///
+    /// [`ClientInformationBuilder`]: azalea_client::ClientInformationBuilder
+    pub fn set_client_information(&self, client_information: impl Into<ClientInformation>) {
+        let client_information = client_information.into();
         self.query_self::<&mut ClientInformation, _>(|mut ci| {
             *ci = client_information.clone();
         });
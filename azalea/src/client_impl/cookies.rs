@@ -0,0 +1,31 @@
+use azalea_client::cookies::ServerCookies;
+use azalea_registry::identifier::Identifier;
+
+use crate::Client;
+
+impl Client {
+    /// Get the cookie that the server stored on our client with the given key.
+    ///
+    /// Cookies are arbitrary data that servers can ask us to keep. They're kept
+    /// when the server transfers us to another server, but not when we
+    /// disconnect for any other reason.
+    pub fn get_cookie(&self, key: &Identifier) -> Option<Vec<u8>> {
+        self.get_component::<ServerCookies>()
+            .and_then(|cookies| cookies.map.get(key).cloned())
+    }
+
+    /// Store a cookie on our client, as if the server had sent it to us.
+    ///
+    /// It'll be sent to the server if it asks for a cookie with this key.
+    pub fn set_cookie(&self, key: Identifier, payload: Vec<u8>) {
+        self.with_entity_mut(|mut entity| {
+            if let Some(mut cookies) = entity.get_mut::<ServerCookies>() {
+                cookies.map.insert(key, payload);
+            } else {
+                entity.insert(ServerCookies {
+                    map: [(key, payload)].into(),
+                });
+            }
+        });
+    }
+}
@@ -0,0 +1,148 @@
+use azalea_client::{
+    connection::RawConnection,
+    local_player::{Hunger, LocalGameMode, TabList, WorldHolder},
+    player::GameProfileComponent,
+    tick_counter::TicksConnected,
+};
+use azalea_core::{game_type::GameMode, position::Vec3};
+use azalea_entity::{Position, metadata::Health};
+use azalea_protocol::packets::ConnectionProtocol;
+use azalea_world::WorldName;
+use bevy_ecs::entity::Entity;
+
+use crate::Client;
+
+/// A snapshot of the most useful parts of a client's state, made by
+/// [`Client::debug_dump`].
+///
+/// Most of the fields are optional since the components they come from might
+/// not be present yet (for example, if the client hasn't logged in).
+#[derive(Clone, Debug)]
+pub struct ClientDebugInfo {
+    pub entity: Entity,
+    pub username: Option<String>,
+    /// The protocol state of our connection to the server, or `None` if we
+    /// aren't connected.
+    pub connection_state: Option<ConnectionProtocol>,
+    pub world_name: Option<WorldName>,
+    pub position: Option<Vec3>,
+    pub health: Option<f32>,
+    pub hunger: Option<Hunger>,
+    pub game_mode: Option<GameMode>,
+    /// The number of chunks that are loaded in the client's partial world.
+    pub loaded_chunks: usize,
+    /// The number of entities in the client's (potentially shared) world.
+    pub entity_count: usize,
+    /// The number of ticks since the client received the login packet.
+    pub ticks_connected: u64,
+    /// Our latency in milliseconds, as reported in the tab list.
+    pub ping: Option<i32>,
+}
+
+impl Client {
+    /// Gather some of the client's components into a [`ClientDebugInfo`] that
+    /// can be printed with `{:?}`.
+    ///
+    /// This is meant to be used for diagnosing a misbehaving bot, and all the
+    /// components are read while holding a single lock on the ECS.
+    pub fn debug_dump(&self) -> ClientDebugInfo {
+        let mut ecs = self.ecs.write();
+        let mut query = ecs.query::<(
+            Option<&GameProfileComponent>,
+            Option<&RawConnection>,
+            Option<&WorldName>,
+            Option<&Position>,
+            Option<&Health>,
+            Option<&Hunger>,
+            Option<&LocalGameMode>,
+            Option<&WorldHolder>,
+            Option<&TicksConnected>,
+            Option<&TabList>,
+        )>();
+        let (
+            profile,
+            raw_conn,
+            world_name,
+            position,
+            health,
+            hunger,
+            game_mode,
+            world_holder,
+            ticks_connected,
+            tab_list,
+        ) = query
+            .get(&ecs, self.entity)
+            .expect("Our client is missing from the ECS");
+
+        let (loaded_chunks, entity_count) = world_holder
+            .map(|world_holder| {
+                let loaded_chunks = world_holder
+                    .partial
+                    .read()
+                    .chunks
+                    .chunks()
+                    .filter(|c| c.is_some())
+                    .count();
+                let entity_count = world_holder.shared.read().entity_by_id.len();
+                (loaded_chunks, entity_count)
+            })
+            .unwrap_or_default();
+        let ping = profile
+            .zip(tab_list)
+            .and_then(|(profile, tab_list)| tab_list.get(&profile.uuid))
+            .map(|info| info.latency);
+
+        ClientDebugInfo {
+            entity: self.entity,
+            username: profile.map(|p| p.name.clone()),
+            connection_state: raw_conn.map(|c| c.state),
+            world_name: world_name.cloned(),
+            position: position.map(|p| **p),
+            health: health.map(|h| **h),
+            hunger: hunger.cloned(),
+            game_mode: game_mode.map(|g| g.current),
+            loaded_chunks,
+            entity_count,
+            ticks_connected: ticks_connected.map(|t| t.0).unwrap_or(0),
+            ping,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use parking_lot::RwLock;
+
+    use super::*;
+
+    #[test]
+    fn test_debug_dump() {
+        let mut ecs = bevy_ecs::world::World::new();
+        let entity = ecs.spawn_empty().id();
+        let world_holder = WorldHolder::new(
+            entity,
+            Arc::new(RwLock::new(azalea_world::World::default())),
+        );
+        ecs.entity_mut(entity).insert((
+            WorldName::new("minecraft:overworld"),
+            Position::new(Vec3::new(1.5, 64., -3.5)),
+            Health(17.),
+            LocalGameMode::from(GameMode::Creative),
+            TicksConnected(40),
+            world_holder,
+        ));
+
+        let client = Client::new(entity, Arc::new(RwLock::new(ecs)));
+        let info = client.debug_dump();
+
+        assert_eq!(info.position, Some(Vec3::new(1.5, 64., -3.5)));
+        assert_eq!(info.health, Some(17.));
+        assert_eq!(info.game_mode, Some(GameMode::Creative));
+        assert_eq!(info.ticks_connected, 40);
+        assert_eq!(info.loaded_chunks, 0);
+        assert_eq!(info.connection_state, None);
+        assert!(format!("{info:?}").contains("1.5"));
+    }
+}
@@ -1,15 +1,18 @@
 use std::{any, sync::Arc};
 
+use azalea_client::local_player::Hunger;
 use azalea_core::position::Vec3;
-use azalea_entity::Position;
+use azalea_entity::{EntityKindComponent, EntityUuid, PlayerAbilities, Position, metadata::Health};
+use azalea_registry::builtin::EntityKind;
 use azalea_world::WorldName;
 use bevy_ecs::{
     component::Component,
     entity::Entity,
     query::{QueryData, QueryEntityError, QueryFilter, QueryItem, ROQueryItem},
-    world::World,
+    world::{EntityWorldMut, World},
 };
 use parking_lot::{MappedRwLockReadGuard, RwLock, RwLockReadGuard};
+use uuid::Uuid;
 
 use crate::{Client, entity_ref::EntityRef};
 
@@ -107,6 +110,48 @@ impl Client {
         qs.get_mut(&mut ecs, self.entity).map(f)
     }
 
+    /// Lock the ECS and run a function with a reference to our client entity.
+    ///
+    /// This is useful if you need to access several components at once
+    /// without having to write a query. Note that the ECS is locked for the
+    /// whole time that the function is running.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if our client entity was despawned.
+    pub fn with_entity<R>(&self, f: impl FnOnce(bevy_ecs::world::EntityRef) -> R) -> R {
+        let ecs = self.ecs.read();
+        f(ecs.entity(self.entity))
+    }
+
+    /// Lock the ECS and run a function with mutable access to our client
+    /// entity.
+    ///
+    /// You can use this to insert or remove components on the client. Also
+    /// see [`Self::with_entity`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use azalea::ecs::component::Component;
+    /// #[derive(Component)]
+    /// struct IsMiner;
+    ///
+    /// # fn example(client: azalea::Client) {
+    /// client.with_entity_mut(|mut entity| {
+    ///     entity.insert(IsMiner);
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This will panic if our client entity was despawned.
+    pub fn with_entity_mut<R>(&self, f: impl FnOnce(EntityWorldMut) -> R) -> R {
+        let mut ecs = self.ecs.write();
+        f(ecs.entity_mut(self.entity))
+    }
+
     /// Query the ECS for data from an entity.
     ///
     /// Note that it is often simpler to use [`Self::entity_component`].
@@ -273,6 +318,86 @@ impl Client {
         predicate.find_all_sorted(self.ecs.clone(), &world_name, position)
     }
 
+    /// Returns an owned snapshot of every entity in the same world as the
+    /// client.
+    ///
+    /// All of the entities are read while the ECS is locked only once, so
+    /// unlike calling [`EntityRef::position`] (or similar) on many entities,
+    /// the returned values are guaranteed to be consistent with each other.
+    ///
+    /// The client itself is included in the snapshot.
+    ///
+    /// ```
+    /// # fn example(bot: &azalea::Client) {
+    /// for info in bot.entities_snapshot() {
+    ///     println!("{:?} at {}", info.kind, info.position);
+    /// }
+    /// # }
+    /// ```
+    pub fn entities_snapshot(&self) -> Vec<EntityInfo> {
+        let world_name = {
+            let Some(world_name) = self.get_component::<WorldName>() else {
+                return Vec::new();
+            };
+            world_name.clone()
+        };
+
+        let mut ecs = self.ecs.write();
+        let mut query = ecs.query::<(
+            Entity,
+            &WorldName,
+            &Position,
+            &EntityKindComponent,
+            &EntityUuid,
+            Option<&Health>,
+        )>();
+        query
+            .iter(&ecs)
+            .filter(|(_, e_world_name, ..)| **e_world_name == world_name)
+            .map(|(entity, _, position, kind, uuid, health)| EntityInfo {
+                entity,
+                kind: **kind,
+                uuid: **uuid,
+                position: **position,
+                health: health.map(|h| **h),
+            })
+            .collect()
+    }
+
+    /// Returns an owned copy of some commonly used data about the client.
+    ///
+    /// Calling methods like [`Client::position`] and [`Client::health`] one
+    /// after another locks the ECS separately each time, so the values may
+    /// come from different ticks. This reads everything while the ECS is
+    /// locked only once, so the returned values are always consistent with
+    /// each other.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the client isn't in a world yet. Use
+    /// [`Self::try_snapshot`] to avoid this.
+    pub fn snapshot(&self) -> ClientSnapshot {
+        self.try_snapshot()
+            .expect("Our client is missing a component required for a snapshot")
+    }
+
+    /// Returns an owned copy of some commonly used data about the client, or
+    /// `None` if the client isn't in a world.
+    ///
+    /// Also see [`Self::snapshot`].
+    pub fn try_snapshot(&self) -> Option<ClientSnapshot> {
+        self.try_query_self::<(&Position, &Health, &Hunger, &PlayerAbilities, &WorldName), _>(
+            |(position, health, hunger, abilities, world_name)| ClientSnapshot {
+                position: **position,
+                health: **health,
+                hunger: hunger.clone(),
+                abilities: abilities.clone(),
+                world_name: world_name.clone(),
+            },
+        )
+        .ok()
+    }
+
     /// Get a component from an entity.
     ///
     /// This allows you to access data stored about entities that isn't
@@ -315,6 +440,32 @@ impl Client {
     }
 }
 
+/// The state of an entity at the time [`Client::entities_snapshot`] was
+/// called.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntityInfo {
+    /// The ECS identifier for the entity.
+    pub entity: Entity,
+    pub kind: EntityKind,
+    pub uuid: Uuid,
+    /// The entity's feet position.
+    pub position: Vec3,
+    /// The entity's health, or `None` if it isn't a living entity.
+    pub health: Option<f32>,
+}
+
+/// The state of our client at the time [`Client::snapshot`] was called.
+#[derive(Clone, Debug)]
+pub struct ClientSnapshot {
+    /// The client's feet position.
+    pub position: Vec3,
+    pub health: f32,
+    pub hunger: Hunger,
+    pub abilities: PlayerAbilities,
+    /// The name of the world that the client is in.
+    pub world_name: WorldName,
+}
+
 pub trait EntityPredicate<Q: QueryData, Filter: QueryFilter> {
     fn find_any(&self, ecs_lock: Arc<RwLock<World>>, world_name: &WorldName) -> Option<Entity>;
     fn find_all_sorted(
@@ -363,3 +514,120 @@ where
             .collect::<Box<[Entity]>>()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn(
+        ecs: &mut World,
+        world_name: &WorldName,
+        kind: EntityKind,
+        x: f64,
+        health: Option<f32>,
+    ) -> Entity {
+        let mut entity = ecs.spawn((
+            world_name.clone(),
+            Position::new(Vec3::new(x, 64., 0.)),
+            EntityKindComponent(kind),
+            EntityUuid::new(Uuid::from_u128(x as u128)),
+        ));
+        if let Some(health) = health {
+            entity.insert(Health(health));
+        }
+        entity.id()
+    }
+
+    #[test]
+    fn test_entities_snapshot() {
+        let overworld = WorldName::new("minecraft:overworld");
+        let nether = WorldName::new("minecraft:the_nether");
+
+        let mut ecs = World::new();
+        let client = spawn(&mut ecs, &overworld, EntityKind::Player, 0., Some(20.));
+        let zombie = spawn(&mut ecs, &overworld, EntityKind::Zombie, 1., Some(15.));
+        let item = spawn(&mut ecs, &overworld, EntityKind::Item, 2., None);
+        // in a different world, so it shouldn't be included
+        spawn(&mut ecs, &nether, EntityKind::Zombie, 3., Some(20.));
+
+        let client = Client::new(client, Arc::new(RwLock::new(ecs)));
+        let mut snapshot = client.entities_snapshot();
+        snapshot.sort_by(|a, b| a.position.x.total_cmp(&b.position.x));
+
+        assert_eq!(snapshot.len(), 3);
+        assert_eq!(snapshot[0].entity, client.entity);
+        assert_eq!(
+            snapshot[1],
+            EntityInfo {
+                entity: zombie,
+                kind: EntityKind::Zombie,
+                uuid: Uuid::from_u128(1),
+                position: Vec3::new(1., 64., 0.),
+                health: Some(15.),
+            }
+        );
+        assert_eq!(snapshot[2].entity, item);
+        assert_eq!(snapshot[2].health, None);
+    }
+
+    #[test]
+    fn test_snapshot_is_consistent() {
+        let overworld = WorldName::new("minecraft:overworld");
+
+        let mut ecs = World::new();
+        let entity = spawn(&mut ecs, &overworld, EntityKind::Player, 0., Some(0.));
+        ecs.entity_mut(entity)
+            .insert((Hunger::default(), PlayerAbilities::default()));
+        let client = Client::new(entity, Arc::new(RwLock::new(ecs)));
+        assert!(client.try_snapshot().is_some());
+
+        // the position and health are always updated together, so a snapshot
+        // should never see one of them changed without the other
+        let writer = {
+            let client = client.clone();
+            std::thread::spawn(move || {
+                for i in 1..=1000 {
+                    client.query_self::<(&mut Position, &mut Health), _>(
+                        |(mut position, mut health)| {
+                            position.x = i as f64;
+                            **health = i as f32;
+                        },
+                    );
+                }
+            })
+        };
+
+        let mut snapshots = Vec::new();
+        while !writer.is_finished() {
+            snapshots.push(client.snapshot());
+        }
+        writer.join().unwrap();
+        snapshots.push(client.snapshot());
+
+        let last = snapshots.last().unwrap();
+        assert_eq!(last.position.x, 1000.);
+        assert_eq!(last.world_name, overworld);
+        for snapshot in &snapshots {
+            assert_eq!(snapshot.position.x as f32, snapshot.health);
+        }
+    }
+
+    #[test]
+    fn test_with_entity() {
+        #[derive(Component)]
+        struct Marker(u32);
+
+        let mut ecs = World::new();
+        let entity = ecs.spawn_empty().id();
+        let client = Client::new(entity, Arc::new(RwLock::new(ecs)));
+
+        assert!(!client.with_entity(|entity| entity.contains::<Marker>()));
+        client.with_entity_mut(|mut entity| {
+            entity.insert(Marker(5));
+        });
+        assert_eq!(
+            client.with_entity(|entity| entity.get::<Marker>().unwrap().0),
+            5
+        );
+    }
+}
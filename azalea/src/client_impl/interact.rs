@@ -1,4 +1,7 @@
-use azalea_client::interact::{EntityInteractEvent, StartUseItemEvent, pick::HitResultComponent};
+use azalea_block::BlockState;
+use azalea_client::interact::{
+    EntityInteractEvent, StartUseItemEvent, SwingArmEvent, pick::HitResultComponent,
+};
 use azalea_core::{hit_result::HitResult, position::BlockPos};
 use azalea_protocol::packets::game::s_interact::InteractionHand;
 use bevy_ecs::entity::Entity;
@@ -12,6 +15,32 @@ impl Client {
         (**self.component::<HitResultComponent>()).clone()
     }
 
+    /// Returns the position and state of the block in the client's crosshair,
+    /// or `None` if it's not looking at a block.
+    ///
+    /// This is based on [`Self::hit_result`], so it won't return blocks that
+    /// are out of reach or behind an entity.
+    pub fn targeted_block(&self) -> Option<(BlockPos, BlockState)> {
+        let block_pos = self
+            .component::<HitResultComponent>()
+            .as_block_hit_result_if_not_miss()?
+            .block_pos;
+        let block_state = self.world().read().get_block_state(block_pos)?;
+        Some((block_pos, block_state))
+    }
+
+    /// Swing the client's arm.
+    ///
+    /// This is purely a visual effect and won't interact with anything in the
+    /// world. Use [`Self::attack`] or [`Self::start_mining`] if you want to hit
+    /// something.
+    pub fn swing_arm(&self, hand: InteractionHand) {
+        self.ecs.write().trigger(SwingArmEvent {
+            entity: self.entity,
+            hand,
+        });
+    }
+
     /// Right-click a block.
     ///
     /// The behavior of this depends on the target block,
@@ -1,6 +1,9 @@
-use azalea_client::inventory::SetSelectedHotbarSlotEvent;
+use azalea_client::{
+    cooldowns::ItemCooldowns,
+    inventory::{ContainerClickEvent, DropCount, DropHeldItemEvent, SetSelectedHotbarSlotEvent},
+};
 use azalea_entity::inventory::Inventory;
-use azalea_inventory::Menu;
+use azalea_inventory::{ItemStack, Menu, operations::ThrowClick};
 
 use crate::Client;
 
@@ -14,6 +17,16 @@ impl Client {
         self.component::<Inventory>().menu().clone()
     }
 
+    /// Returns the ID of the container that's currently open, or `None` if we
+    /// don't have a container open.
+    ///
+    /// Our own inventory doesn't count as a container here, even though its ID
+    /// is 0.
+    pub fn open_container_id(&self) -> Option<i32> {
+        let inventory = self.component::<Inventory>();
+        inventory.container_menu.is_some().then_some(inventory.id)
+    }
+
     /// Returns the index of the hotbar slot that's currently selected.
     ///
     /// If you want to access the actual held item, you can get the current menu
@@ -25,6 +38,17 @@ impl Client {
         self.component::<Inventory>().selected_hotbar_slot
     }
 
+    /// Returns the item that's in the selected hotbar slot, which is the one
+    /// that the client is holding in its main hand.
+    pub fn held_item(&self) -> ItemStack {
+        self.component::<Inventory>().held_item().clone()
+    }
+
+    /// Returns the item that the client is holding in its off hand.
+    pub fn off_hand_item(&self) -> ItemStack {
+        self.component::<Inventory>().off_hand_item().clone()
+    }
+
     /// Update the selected hotbar slot index.
     ///
     /// This will run next `Update`, so you might want to call
@@ -45,4 +69,220 @@ impl Client {
             slot: new_hotbar_slot_index,
         });
     }
+
+    /// Drop the item that we're holding in our main hand, like pressing the
+    /// drop key (Q).
+    ///
+    /// Use [`DropCount::Stack`] to drop the whole stack at once.
+    pub fn drop_item(&self, count: DropCount) {
+        self.ecs.write().trigger(DropHeldItemEvent {
+            entity: self.entity,
+            count,
+        });
+    }
+
+    /// Drop the item in the given slot of the current menu, like hovering over
+    /// it and pressing the drop key.
+    ///
+    /// The slot index is the same as in [`Menu::slot`].
+    pub fn drop_slot(&self, slot: usize, count: DropCount) {
+        let window_id = self.component::<Inventory>().id;
+        let slot = slot as u16;
+        let operation = match count {
+            DropCount::One => ThrowClick::Single { slot },
+            DropCount::Stack => ThrowClick::All { slot },
+        };
+        self.ecs.write().trigger(ContainerClickEvent {
+            entity: self.entity,
+            window_id,
+            operation: operation.into(),
+        });
+    }
+
+    /// Shift-click the given slot of the current menu, which usually moves the
+    /// item between the open container and our inventory.
+    ///
+    /// The slot index is the same as in [`Menu::slot`]. If the destination
+    /// doesn't have enough space for the whole stack, only part of it will be
+    /// moved.
+    ///
+    /// This is a shortcut for [`ContainerHandleRef::shift_click`] on
+    /// [`Self::get_inventory`], for when you don't need a handle to the menu.
+    ///
+    /// [`ContainerHandleRef::shift_click`]: crate::container::ContainerHandleRef::shift_click
+    pub fn shift_click_slot(&self, slot: u16) {
+        self.get_inventory().shift_click(slot);
+    }
+
+    /// Returns whether the given item can't be used right now because it's on
+    /// cooldown, like an ender pearl that was just thrown.
+    ///
+    /// This uses the item's cooldown group, so items that share a group (with
+    /// the `use_cooldown` component) are on cooldown together.
+    pub fn is_on_cooldown(&self, item: &ItemStack) -> bool {
+        self.get_component::<ItemCooldowns>()
+            .is_some_and(|cooldowns| cooldowns.is_on_cooldown(item))
+    }
+
+    /// Wait until any item in the current menu (or the item held by the
+    /// cursor) changes, like when we pick up an item or a furnace finishes
+    /// smelting.
+    ///
+    /// This has no timeout, use
+    /// [`Self::wait_for_inventory_change_with_timeout_ticks`] if you need one.
+    ///
+    /// ```
+    /// # async fn example(bot: azalea::Client) {
+    /// bot.wait_for_inventory_change().await;
+    /// println!(
+    ///     "our inventory changed, we're now holding {:?}",
+    ///     bot.held_item()
+    /// );
+    /// # }
+    /// ```
+    pub async fn wait_for_inventory_change(&self) {
+        self.wait_for_inventory_change_with_timeout_ticks(None)
+            .await;
+    }
+
+    /// Wait until any item in the current menu changes, or time out after the
+    /// given number of ticks.
+    ///
+    /// Returns whether the inventory changed before the timeout. If
+    /// `timeout_ticks` is `None`, there will be no timeout.
+    pub async fn wait_for_inventory_change_with_timeout_ticks(
+        &self,
+        timeout_ticks: Option<usize>,
+    ) -> bool {
+        self.wait_for_inventory_value_change(timeout_ticks, |inventory| {
+            (inventory.menu().slots(), inventory.carried.clone())
+        })
+        .await
+    }
+
+    /// Wait until the item in the given slot of the current menu changes.
+    ///
+    /// The slot index is the same as in [`Menu::slot`]. This has no timeout,
+    /// use [`Self::wait_for_slot_change_with_timeout_ticks`] if you need one.
+    pub async fn wait_for_slot_change(&self, slot: usize) {
+        self.wait_for_slot_change_with_timeout_ticks(slot, None)
+            .await;
+    }
+
+    /// Wait until the item in the given slot of the current menu changes, or
+    /// time out after the given number of ticks.
+    ///
+    /// Returns whether the slot changed before the timeout. If `timeout_ticks`
+    /// is `None`, there will be no timeout.
+    pub async fn wait_for_slot_change_with_timeout_ticks(
+        &self,
+        slot: usize,
+        timeout_ticks: Option<usize>,
+    ) -> bool {
+        self.wait_for_inventory_value_change(timeout_ticks, |inventory| {
+            inventory.menu().slot(slot).cloned()
+        })
+        .await
+    }
+
+    /// Check the value returned by `f` every tick, and return true once it's
+    /// different from what it was when this function was called.
+    async fn wait_for_inventory_value_change<T: PartialEq>(
+        &self,
+        timeout_ticks: Option<usize>,
+        f: impl Fn(&Inventory) -> T,
+    ) -> bool {
+        let mut ticks = self.get_tick_broadcaster();
+        let initial_value = f(&*self.component::<Inventory>());
+
+        let mut elapsed_ticks = 0;
+        while ticks.recv().await.is_ok() {
+            let Some(inventory) = self.get_component::<Inventory>() else {
+                // we got disconnected
+                return false;
+            };
+            if f(&*inventory) != initial_value {
+                return true;
+            }
+
+            elapsed_ticks += 1;
+            if let Some(timeout_ticks) = timeout_ticks
+                && elapsed_ticks >= timeout_ticks
+            {
+                return false;
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use azalea_client::packet::game::process_packet;
+    use azalea_protocol::packets::game::{ClientboundContainerSetSlot, ClientboundGamePacket};
+    use azalea_registry::builtin::ItemKind;
+    use bevy_app::App;
+    use parking_lot::RwLock;
+
+    use super::*;
+    use crate::tick_broadcast::{TickBroadcast, TickBroadcastPlugin};
+
+    fn make_client() -> Client {
+        let mut app = App::new();
+        app.add_plugins(TickBroadcastPlugin);
+        let mut ecs = std::mem::take(app.world_mut());
+        let entity = ecs.spawn(Inventory::default()).id();
+        Client::new(entity, Arc::new(RwLock::new(ecs)))
+    }
+
+    fn send_tick(client: &Client) {
+        let _ = client.ecs.read().resource::<TickBroadcast>().send(());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_slot_change() {
+        let client = make_client();
+
+        let (changed, ()) = tokio::join!(
+            client.wait_for_slot_change_with_timeout_ticks(36, Some(20)),
+            async {
+                // a tick where nothing changes shouldn't resolve it
+                tokio::task::yield_now().await;
+                send_tick(&client);
+                tokio::task::yield_now().await;
+
+                process_packet(
+                    &mut *client.ecs.write(),
+                    client.entity,
+                    &ClientboundGamePacket::ContainerSetSlot(ClientboundContainerSetSlot {
+                        container_id: 0,
+                        state_id: 1,
+                        slot: 36,
+                        item_stack: ItemStack::new(ItemKind::Stone, 1),
+                    }),
+                );
+                send_tick(&client);
+            }
+        );
+        assert!(changed);
+        assert_eq!(client.held_item().kind(), ItemKind::Stone);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_inventory_change_timeout() {
+        let client = make_client();
+
+        let (changed, ()) = tokio::join!(
+            client.wait_for_inventory_change_with_timeout_ticks(Some(3)),
+            async {
+                for _ in 0..3 {
+                    tokio::task::yield_now().await;
+                    send_tick(&client);
+                }
+            }
+        );
+        assert!(!changed);
+    }
 }
@@ -1,5 +1,16 @@
-use azalea_client::mining::{LeftClickMine, Mining, StartMiningBlockEvent};
-use azalea_core::position::BlockPos;
+use std::time::Duration;
+
+use azalea_block::BlockTrait;
+use azalea_client::{
+    local_player::LocalGameMode,
+    mining::{LeftClickMine, MineProgress, Mining, StartMiningBlockEvent},
+};
+use azalea_core::{game_type::GameMode, position::BlockPos};
+use azalea_entity::{
+    ActiveEffects, Attributes, FluidOnEyes, Physics, PlayerAbilities,
+    inventory::Inventory,
+    mining::{dig_ticks_from_progress, get_mine_progress},
+};
 
 use crate::Client;
 
@@ -19,6 +30,20 @@ impl Client {
         self.get_component::<Mining>().is_some()
     }
 
+    /// Returns how far the client is through breaking the block that it's
+    /// currently mining, as a number between 0 and 1.
+    ///
+    /// The progress accumulates every tick based on the block's hardness and
+    /// the item that's being used to mine it, so it won't necessarily increase
+    /// linearly if the held item or the client's effects change.
+    ///
+    /// Returns `None` if the client isn't mining anything.
+    pub fn mining_progress(&self) -> Option<f32> {
+        self.query_self::<(Option<&Mining>, &MineProgress), _>(|(mining, progress)| {
+            mining.map(|_| (**progress).clamp(0., 1.))
+        })
+    }
+
     /// When enabled, the bot will mine any block that it is looking at if it is
     /// reachable.
     pub fn left_click_mine(&self, enabled: bool) {
@@ -31,4 +56,81 @@ impl Client {
             entity_mut.remove::<LeftClickMine>();
         }
     }
+
+    /// Returns how long it would take for the client to mine the block at the
+    /// given position with the item that it's currently holding.
+    ///
+    /// This takes into account the client's effects, enchantments, and whether
+    /// it's on the ground or underwater. If the block can be broken instantly
+    /// (or the client is in creative mode), this returns [`Duration::ZERO`].
+    ///
+    /// Returns `None` if the block can't be broken (like bedrock) or isn't
+    /// loaded.
+    pub fn dig_time(&self, pos: BlockPos) -> Option<Duration> {
+        let block_state = self.world().read().get_block_state(pos)?;
+
+        let progress = self.query_self::<(
+            &LocalGameMode,
+            &PlayerAbilities,
+            &Inventory,
+            &FluidOnEyes,
+            &Physics,
+            &Attributes,
+            &ActiveEffects,
+        ), _>(
+            |(game_mode, abilities, inventory, fluid_on_eyes, physics, attributes, effects)| {
+                if game_mode.current == GameMode::Creative || abilities.instant_break {
+                    return 1.;
+                }
+                let block = Box::<dyn BlockTrait>::from(block_state);
+                get_mine_progress(
+                    block.as_ref(),
+                    inventory.held_item().kind(),
+                    fluid_on_eyes,
+                    physics,
+                    attributes,
+                    effects,
+                )
+            },
+        );
+
+        let ticks = dig_ticks_from_progress(progress)?;
+        Some(Duration::from_millis(50) * ticks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use azalea_core::direction::Direction;
+    use bevy_ecs::world::World;
+    use parking_lot::RwLock;
+
+    use super::*;
+
+    #[test]
+    fn test_mining_progress() {
+        let mut ecs = World::new();
+        let entity = ecs.spawn(MineProgress(0.5)).id();
+        let client = Client::new(entity, Arc::new(RwLock::new(ecs)));
+
+        // the progress is left over from the last block, but we're not mining
+        assert_eq!(client.mining_progress(), None);
+
+        client.ecs.write().entity_mut(entity).insert(Mining {
+            pos: BlockPos::new(0, 0, 0),
+            dir: Direction::Down,
+            force: true,
+        });
+        assert_eq!(client.mining_progress(), Some(0.5));
+
+        // the progress can go over 1 on the tick that the block breaks
+        client
+            .ecs
+            .write()
+            .entity_mut(entity)
+            .insert(MineProgress(1.3));
+        assert_eq!(client.mining_progress(), Some(1.));
+    }
 }
@@ -1,52 +1,72 @@
 use std::{collections::HashMap, sync::Arc};
 
 use azalea_auth::game_profile::GameProfile;
+use azalea_chat::FormattedText;
 use azalea_client::{
     DefaultPlugins,
     account::Account,
+    chunks::ChunkBatchInfo,
     connection::RawConnection,
     disconnect::DisconnectEvent,
-    join::{ConnectOpts, StartJoinServerEvent},
-    local_player::{Experience, Hunger, TabList, WorldHolder},
+    join::{ConnectOpts, CreateConnectionTask, StartJoinServerEvent},
+    local_player::{
+        Experience, Hunger, LastDeathLocation, LocalDimensionKind, TabList, TabListHeaderFooter,
+        WorldHolder, WorldSpawn,
+    },
     packet::game::SendGamePacketEvent,
     player::{GameProfileComponent, PlayerInfo},
+    respawn::PerformRespawnEvent,
     start_ecs_runner,
     tick_counter::TicksConnected,
+    tick_rate::WorldTickRate,
+    world_border::WorldBorder,
 };
 use azalea_core::{
     data_registry::{DataRegistryWithKey, ResolvableDataRegistry},
     entity_id::MinecraftEntityId,
+    position::{BlockPos, GlobalPos, Vec3},
+};
+use azalea_entity::{
+    Attributes, Dead, Vehicle,
+    indexing::{EntityIdIndex, EntityUuidIndex},
+    metadata::{AbstractHorse, Health},
 };
-use azalea_entity::indexing::{EntityIdIndex, EntityUuidIndex};
 use azalea_protocol::{
     address::{ResolvableAddr, ResolvedAddr},
     connect::Proxy,
     packets::{Packet, game::ServerboundGamePacket},
     resolve::ResolveError,
 };
-use azalea_registry::{DataRegistryKeyRef, identifier::Identifier};
+use azalea_registry::{DataRegistryKeyRef, builtin::BlockEntityKind, identifier::Identifier};
 use azalea_world::{PartialWorld, World, WorldName};
 use bevy_app::{App, AppExit};
-use bevy_ecs::{entity::Entity, resource::Resource, world::Mut};
+use bevy_ecs::{entity::Entity, query::Has, resource::Resource, world::Mut};
 use parking_lot::RwLock;
+use simdnbt::owned::NbtCompound;
 use tokio::sync::mpsc;
 use uuid::Uuid;
 
 use crate::{
+    auto_reconnect::InternalReconnectAfter,
+    auto_respawn::AutoRespawn,
     bot::DefaultBotPlugins,
     entity_ref::EntityRef,
     events::{Event, LocalPlayerEvents},
     swarm::DefaultSwarmPlugins,
 };
 
+pub mod advancements;
 pub mod attack;
 pub mod chat;
 pub mod client_information;
+pub mod cookies;
+pub mod debug;
 pub mod entity_query;
 pub mod interact;
 pub mod inventory;
 pub mod mining;
 pub mod movement;
+pub mod stats;
 
 /// A Minecraft client instance that can interact with the world.
 ///
@@ -86,9 +106,24 @@ impl StartClientOpts {
         account: Account,
         address: ResolvedAddr,
         event_sender: Option<mpsc::UnboundedSender<Event>>,
+    ) -> StartClientOpts {
+        Self::new_with_app(account, address, event_sender, |_| {})
+    }
+
+    /// Like [`Self::new`], but runs the given function on the [`App`] before
+    /// the ECS runner is started.
+    ///
+    /// This can be used to add your own plugins and systems when you're only
+    /// using a single client. Also see [`Client::join_with_plugins`].
+    pub fn new_with_app(
+        account: Account,
+        address: ResolvedAddr,
+        event_sender: Option<mpsc::UnboundedSender<Event>>,
+        configure_app: impl FnOnce(&mut App),
     ) -> StartClientOpts {
         let mut app = App::new();
         app.add_plugins((DefaultPlugins, DefaultBotPlugins, DefaultSwarmPlugins));
+        configure_app(&mut app);
 
         // appexit_rx is unused here since the user should be able to handle it
         // themselves if they're using StartClientOpts::new
@@ -102,6 +137,8 @@ impl StartClientOpts {
                 address,
                 server_proxy: None,
                 sessionserver_proxy: None,
+                rng_seed: None,
+                client_information: None,
             },
             event_sender,
         }
@@ -135,6 +172,29 @@ impl StartClientOpts {
         self.connect_opts.sessionserver_proxy = Some(proxy);
         self
     }
+    /// Set the seed for the client's [`ClientRng`], so its random decisions
+    /// are reproducible.
+    ///
+    /// By default, the seed is picked from entropy.
+    ///
+    /// [`ClientRng`]: azalea_client::local_player::ClientRng
+    pub fn rng_seed(mut self, seed: u64) -> Self {
+        self.connect_opts.rng_seed = Some(seed);
+        self
+    }
+}
+
+/// The reason that [`Client::reconnect`] failed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReconnectError {
+    /// We're still connected to the server, or we're already in the process of
+    /// connecting.
+    AlreadyConnected,
+    /// The client was never connected to a server, so we don't know where to
+    /// reconnect to.
+    NeverConnected,
+    /// The client's entity doesn't exist in the ECS anymore.
+    Despawned,
 }
 
 impl Client {
@@ -195,6 +255,47 @@ impl Client {
         Ok((client, rx))
     }
 
+    /// Connect to a Minecraft server, after running the given function on the
+    /// [`App`].
+    ///
+    /// This is the same as [`Client::join`], except it lets you add your own
+    /// plugins and systems before the client starts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use azalea::{Account, Client, app::Update};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let account = Account::offline("bot");
+    ///     let (client, _rx) = Client::join_with_plugins(account, "localhost", |app| {
+    ///         app.add_systems(Update, my_system);
+    ///     })
+    ///     .await?;
+    ///     Ok(())
+    /// }
+    ///
+    /// fn my_system() {}
+    /// ```
+    pub async fn join_with_plugins(
+        account: Account,
+        address: impl ResolvableAddr,
+        configure_app: impl FnOnce(&mut App),
+    ) -> Result<(Self, mpsc::UnboundedReceiver<Event>), ResolveError> {
+        let address = address.resolve().await?;
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        let client = Self::start_client(StartClientOpts::new_with_app(
+            account,
+            address,
+            Some(tx),
+            configure_app,
+        ))
+        .await;
+        Ok((client, rx))
+    }
+
     /// Create a [`Client`] when you already have the ECS made with
     /// [`start_ecs_runner`]. You'd usually want to use [`Self::join`] instead.
     pub async fn start_client(
@@ -242,15 +343,78 @@ impl Client {
     /// Disconnect this client from the server by ending all tasks.
     ///
     /// The OwnedReadHalf for the TCP connection is in one of the tasks, so it
-    /// automatically closes the connection when that's dropped.
+    /// automatically closes the connection when that's dropped. Any packets
+    /// that were sent before calling this are still flushed to the server
+    /// before the connection is closed.
     ///
     /// Note that this will not return from your client builder. If you need
     /// that, consider using [`Self::exit`] instead.
     pub fn disconnect(&self) {
-        self.ecs.write().write_message(DisconnectEvent {
-            entity: self.entity,
-            reason: None,
-        });
+        self.ecs
+            .write()
+            .write_message(DisconnectEvent::new(self.entity, None));
+    }
+
+    /// Join the server that we were last connected to again, reusing the same
+    /// ECS entity.
+    ///
+    /// This is useful for reconnecting manually after being kicked, since
+    /// unlike calling [`Client::join`] again, it keeps this `Client` valid and
+    /// doesn't remove any components that you added to the entity. The same
+    /// account, address, and proxies that we originally connected with are
+    /// used, and any automatic reconnect that was scheduled is cancelled.
+    ///
+    /// This returns once the connection has started being made, not when we've
+    /// finished logging in. Connection errors are sent as
+    /// [`Event::ConnectionFailed`] like usual.
+    ///
+    /// ```rust,no_run
+    /// # use azalea::prelude::*;
+    /// # async fn example(bot: Client, event: Event) {
+    /// if let Event::Disconnect(_) = event {
+    ///     bot.reconnect().await.unwrap();
+    /// }
+    /// # }
+    /// ```
+    pub async fn reconnect(&self) -> Result<(), ReconnectError> {
+        let (start_join_callback_tx, mut start_join_callback_rx) =
+            mpsc::unbounded_channel::<Entity>();
+
+        {
+            let mut ecs = self.ecs.write();
+            let mut entity_mut = ecs
+                .get_entity_mut(self.entity)
+                .map_err(|_| ReconnectError::Despawned)?;
+            let is_connected = entity_mut
+                .get::<RawConnection>()
+                .is_some_and(|conn| conn.is_alive());
+            if is_connected || entity_mut.contains::<CreateConnectionTask>() {
+                return Err(ReconnectError::AlreadyConnected);
+            }
+            let (Some(account), Some(connect_opts)) = (
+                entity_mut.get::<Account>().cloned(),
+                entity_mut.get::<ConnectOpts>().cloned(),
+            ) else {
+                return Err(ReconnectError::NeverConnected);
+            };
+            // so auto-reconnect doesn't make us join twice
+            entity_mut.remove::<InternalReconnectAfter>();
+
+            // the entity is reused since the account's uuid is still in the
+            // EntityUuidIndex
+            ecs.write_message(StartJoinServerEvent {
+                account,
+                connect_opts,
+                start_join_callback_tx: Some(start_join_callback_tx),
+            });
+        }
+
+        let entity = start_join_callback_rx.recv().await.expect(
+            "start_join_callback should not be dropped before sending a message, this is a bug in Azalea",
+        );
+        debug_assert_eq!(entity, self.entity);
+
+        Ok(())
     }
 
     /// End the entire client or swarm, and return from
@@ -350,12 +514,195 @@ impl Client {
         world_holder.partial.clone()
     }
 
+    /// Get every block entity (like chests, signs, and spawners) in the
+    /// chunks that are loaded in our world.
+    ///
+    /// The data is whatever the server sent to us in chunk and block entity
+    /// packets, which is usually only the data that's needed to render the
+    /// block. For example, chests won't include their items.
+    ///
+    /// ```
+    /// # use azalea_registry::builtin::BlockEntityKind;
+    /// # fn example(client: &azalea::Client) {
+    /// let chests = client
+    ///     .block_entities()
+    ///     .into_iter()
+    ///     .filter(|(_, kind, _)| *kind == BlockEntityKind::Chest)
+    ///     .map(|(pos, _, _)| pos)
+    ///     .collect::<Vec<_>>();
+    /// # }
+    /// ```
+    pub fn block_entities(&self) -> Vec<(BlockPos, BlockEntityKind, NbtCompound)> {
+        self.world()
+            .read()
+            .block_entities()
+            .into_iter()
+            .map(|(pos, block_entity)| (pos, block_entity.kind, block_entity.data))
+            .collect()
+    }
+
+    /// Get the identifier of the dimension type that the client is in, like
+    /// `minecraft:overworld` or `minecraft:the_nether`.
+    ///
+    /// This is the dimension type that was sent in the last login or respawn
+    /// packet, which isn't necessarily the same as the
+    /// [`world_name`](Self::world_name).
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the client isn't in a world, or if the dimension type
+    /// isn't in the client's registries.
+    pub fn dimension(&self) -> Identifier {
+        let dimension_kind = **self.component::<LocalDimensionKind>();
+        self.with_resolved_registry(dimension_kind, |name, _| name.clone())
+            .expect("the dimension type should be present in the client's registries")
+    }
+
+    /// Get the minimum y level and the height of the dimension that the client
+    /// is in, as `(min_y, height)`.
+    ///
+    /// The highest y level where blocks can exist is `min_y + height - 1`.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the client isn't in a world, or if the dimension type
+    /// isn't in the client's registries.
+    pub fn dimension_height(&self) -> (i32, u32) {
+        let dimension_kind = **self.component::<LocalDimensionKind>();
+        self.with_resolved_registry(dimension_kind, |_, data| (data.min_y, data.height))
+            .expect("the dimension type should be present in the client's registries")
+    }
+
+    /// Get the position of the world spawn point, which is where compasses
+    /// point to.
+    ///
+    /// Returns `None` if the server hasn't told us where the spawn point is
+    /// yet. Note that the spawn point may be in a different dimension than the
+    /// one we're currently in; use [`WorldSpawn::global_pos`] if you need to
+    /// check that.
+    pub fn world_spawn(&self) -> Option<BlockPos> {
+        self.get_component::<WorldSpawn>()
+            .map(|world_spawn| world_spawn.global_pos.pos)
+    }
+
+    /// Get the place where the client last died, which is useful for going
+    /// back to pick up our items.
+    ///
+    /// Returns `None` if we haven't died yet, or if the server didn't tell us
+    /// where we died.
+    pub fn last_death_location(&self) -> Option<GlobalPos> {
+        self.get_component::<LastDeathLocation>()
+            .map(|last_death_location| (**last_death_location).clone())
+    }
+
+    /// Returns whether the server froze the game with `/tick freeze`.
+    ///
+    /// Our own player keeps moving while the world is frozen, but other
+    /// entities only move for the ticks that the server steps through with
+    /// `/tick step`. See [`WorldTickRate`].
+    pub fn is_world_frozen(&self) -> bool {
+        self.map_get_resource::<WorldTickRate, _>(|world_tick_rate| {
+            world_tick_rate.is_some_and(|world_tick_rate| world_tick_rate.is_frozen)
+        })
+    }
+
+    /// Get the world border for the dimension that the client is in.
+    ///
+    /// Also see [`Self::is_within_border`] and [`Self::distance_to_border`].
+    pub fn world_border(&self) -> WorldBorder {
+        self.component::<WorldBorder>().clone()
+    }
+
+    /// Returns whether the given position is inside of the world border.
+    ///
+    /// Only the X and Z coordinates are checked.
+    pub fn is_within_border(&self, pos: Vec3) -> bool {
+        self.component::<WorldBorder>().is_within_bounds(pos)
+    }
+
+    /// Get the distance from the client to the closest edge of the world
+    /// border.
+    ///
+    /// This is negative if the client is outside of the border.
+    pub fn distance_to_border(&self) -> f64 {
+        let position = self.position();
+        self.component::<WorldBorder>().distance_to_border(position)
+    }
+
+    /// Convert a position in the overworld to the equivalent position in the
+    /// nether, like what happens when going through a nether portal.
+    ///
+    /// This uses the `coordinate_scale` of the dimension types from the
+    /// server's registries, so it also works for servers that changed the
+    /// scale. With vanilla data, the horizontal coordinates are divided by 8.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `minecraft:overworld` or `minecraft:the_nether`
+    /// aren't in the client's dimension type registry.
+    pub fn overworld_to_nether(&self, pos: Vec3) -> Vec3 {
+        self.scale_position_between_dimensions(pos, "minecraft:overworld", "minecraft:the_nether")
+    }
+
+    /// Convert a position in the nether to the equivalent position in the
+    /// overworld.
+    ///
+    /// See [`Self::overworld_to_nether`] for more details.
+    pub fn nether_to_overworld(&self, pos: Vec3) -> Vec3 {
+        self.scale_position_between_dimensions(pos, "minecraft:the_nether", "minecraft:overworld")
+    }
+
+    fn scale_position_between_dimensions(&self, pos: Vec3, from: &str, to: &str) -> Vec3 {
+        self.with_registry_holder(|registries| {
+            let dimension_type = |name: &str| {
+                registries
+                    .dimension_type
+                    .map
+                    .get(&Identifier::new(name))
+                    .unwrap_or_else(|| panic!("dimension type {name} should be in the registry"))
+            };
+            dimension_type(from).scale_position_to(dimension_type(to), pos)
+        })
+    }
+
     /// Returns whether we have a received the login packet yet.
     pub fn logged_in(&self) -> bool {
         // the login packet tells us the world name
         self.query_self::<Option<&WorldName>, _>(|ins| ins.is_some())
     }
 
+    /// Returns whether the client is dead and waiting to respawn.
+    ///
+    /// While this is true, the client won't send movement packets and will
+    /// ignore attempts to attack or mine. It becomes false again when we
+    /// receive the respawn packet, shortly before [`Event::Respawn`] is sent.
+    ///
+    /// Unlike [`Self::is_alive`], this returns false if the client isn't in the
+    /// world.
+    pub fn is_dead(&self) -> bool {
+        self.query_self::<Has<Dead>, _>(|dead| dead)
+    }
+
+    /// Tell the server that we want to respawn.
+    ///
+    /// This is only necessary if auto-respawning was disabled with
+    /// [`Self::set_auto_respawn`].
+    pub fn respawn(&self) {
+        self.ecs.write().write_message(PerformRespawnEvent {
+            entity: self.entity,
+        });
+    }
+
+    /// Set whether the client should respawn immediately after dying.
+    ///
+    /// This is true by default. See [`AutoRespawn`] for more information.
+    pub fn set_auto_respawn(&self, auto_respawn: bool) {
+        self.ecs
+            .write()
+            .entity_mut(self.entity)
+            .insert(AutoRespawn(auto_respawn));
+    }
+
     /// Returns the client as an [`EntityRef`], allowing you to treat it as any
     /// other entity.
     pub fn entity(&self) -> EntityRef {
@@ -366,6 +713,42 @@ impl Client {
     pub fn entity_ref_for(&self, entity: Entity) -> EntityRef {
         EntityRef::new(self.clone(), entity)
     }
+
+    /// Returns the entity that the client is riding, like a horse or a boat.
+    ///
+    /// This is `None` if we're not riding anything.
+    pub fn vehicle(&self) -> Option<EntityRef> {
+        let vehicle = self.get_component::<Vehicle>().map(|vehicle| **vehicle)?;
+        Some(self.entity_ref_for(vehicle))
+    }
+
+    /// Returns the health of the entity that the client is riding.
+    ///
+    /// This is `None` if we're not riding anything, or if the vehicle doesn't
+    /// have health (like a boat or minecart).
+    pub fn vehicle_health(&self) -> Option<f32> {
+        let vehicle = self.vehicle()?;
+        vehicle.get_component::<Health>().map(|health| **health)
+    }
+
+    /// Returns the jump strength of the horse that the client is riding.
+    ///
+    /// This is `None` if we're not riding a horse-like entity (including
+    /// camels, donkeys, and llamas).
+    ///
+    /// Note that the owner of a horse isn't sent to clients, but you can check
+    /// whether it's tamed with the [`Tamed`] component.
+    ///
+    /// [`Tamed`]: azalea_entity::metadata::Tamed
+    pub fn vehicle_jump_strength(&self) -> Option<f64> {
+        let vehicle = self.vehicle()?;
+        if vehicle.get_component::<AbstractHorse>().is_none() {
+            return None;
+        }
+        vehicle
+            .get_component::<Attributes>()
+            .map(|attributes| attributes.jump_strength.calculate())
+    }
 }
 
 impl Client {
@@ -399,6 +782,51 @@ impl Client {
         (**self.component::<TabList>()).clone()
     }
 
+    /// Get the display name that the server set for our client in the tab
+    /// list.
+    ///
+    /// This is `None` if the server didn't set a display name (in which case
+    /// it'll just show our [`username`](Self::username)), or if we aren't in
+    /// the tab list yet.
+    pub fn display_name(&self) -> Option<FormattedText> {
+        self.query_self::<(&GameProfileComponent, &TabList), _>(|(profile, tab_list)| {
+            tab_list
+                .get(&profile.uuid)?
+                .display_name
+                .as_deref()
+                .cloned()
+        })
+    }
+
+    /// Get the players in the tab list in the order that they're displayed by
+    /// the vanilla client.
+    ///
+    /// Unlike [`Self::tab_list`], this excludes players that aren't listed.
+    /// See [`TabList::display_order`] for more details.
+    pub fn tab_list_in_display_order(&self) -> Vec<PlayerInfo> {
+        self.component::<TabList>()
+            .display_order()
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Get the text that's displayed above the tab list.
+    ///
+    /// This is a shortcut for
+    /// `bot.component::<TabListHeaderFooter>().header.clone()`.
+    pub fn tab_list_header(&self) -> FormattedText {
+        self.component::<TabListHeaderFooter>().header.clone()
+    }
+
+    /// Get the text that's displayed below the tab list.
+    ///
+    /// This is a shortcut for
+    /// `bot.component::<TabListHeaderFooter>().footer.clone()`.
+    pub fn tab_list_footer(&self) -> FormattedText {
+        self.component::<TabListHeaderFooter>().footer.clone()
+    }
+
     /// Returns the [`GameProfile`] for our client. This contains your username,
     /// UUID, and skin data.
     ///
@@ -533,4 +961,215 @@ impl Client {
             .map(|c| c.0)
             .unwrap_or(0)
     }
+
+    /// Returns our current estimate of how many chunks we can receive per
+    /// tick, which is sent to the server after every chunk batch.
+    ///
+    /// This is calculated from how many nanoseconds it took to receive each
+    /// chunk in the previous batches, see
+    /// [`ChunkBatchInfo::desired_chunks_per_tick`]. It isn't clamped by
+    /// [`ChunkBatchSettings`], so the value that's sent to the server may be
+    /// different.
+    ///
+    /// If you want to know when this changes, you can read
+    /// [`ChunkBatchRateChangedEvent`] messages.
+    ///
+    /// [`ChunkBatchSettings`]: azalea_client::chunks::ChunkBatchSettings
+    /// [`ChunkBatchRateChangedEvent`]: azalea_client::chunks::ChunkBatchRateChangedEvent
+    pub fn chunk_receive_rate(&self) -> f32 {
+        self.component::<ChunkBatchInfo>().desired_chunks_per_tick()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use azalea_client::packet::game::process_packet;
+    use azalea_protocol::{
+        address::ServerAddr,
+        packets::game::{ClientboundGamePacket, ClientboundTickingState, ClientboundTickingStep},
+    };
+    use bevy_app::Update;
+    use bevy_ecs::prelude::*;
+    use tokio::task::LocalSet;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_new_with_app_runs_custom_systems() {
+        LocalSet::new()
+            .run_until(async {
+                let (update_tx, mut update_rx) = mpsc::unbounded_channel();
+                let opts = StartClientOpts::new_with_app(
+                    Account::offline("bot"),
+                    ResolvedAddr {
+                        server: ServerAddr {
+                            host: "localhost".to_owned(),
+                            port: 25565,
+                        },
+                        socket: "127.0.0.1:25565".parse().unwrap(),
+                    },
+                    None,
+                    |app| {
+                        app.add_systems(Update, move || {
+                            let _ = update_tx.send(());
+                        });
+                    },
+                );
+
+                tokio::time::timeout(Duration::from_secs(5), update_rx.recv())
+                    .await
+                    .expect("the custom system should've run in Update")
+                    .expect("the app shouldn't have been dropped");
+                opts.ecs_lock.write().write_message(AppExit::Success);
+            })
+            .await;
+    }
+
+    #[derive(Component)]
+    struct UserComponent;
+
+    #[tokio::test]
+    async fn test_reconnect_reuses_entity() {
+        use azalea_client::test_utils::fake_server::{accept_login, listener_addr};
+        use azalea_protocol::packets::login::c_login_disconnect::ClientboundLoginDisconnect;
+        use tokio::net::TcpListener;
+
+        use crate::auto_reconnect::AutoReconnectDelay;
+
+        LocalSet::new()
+            .run_until(async {
+                let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+                let address = listener_addr(&listener);
+
+                // a fake server that kicks us the first time we join
+                let (kick_tx, kick_rx) = tokio::sync::oneshot::channel::<()>();
+                let server = tokio::spawn(async move {
+                    let (first_login, mut conn) = accept_login(&listener).await;
+                    kick_rx.await.unwrap();
+                    conn.write(ClientboundLoginDisconnect {
+                        reason: "kicked".into(),
+                    })
+                    .await
+                    .unwrap();
+                    let (second_login, _conn) = accept_login(&listener).await;
+                    vec![first_login.hello, second_login.hello]
+                });
+
+                let opts =
+                    StartClientOpts::new_with_app(Account::offline("bot"), address, None, |app| {
+                        // we want to reconnect manually
+                        app.world_mut().remove_resource::<AutoReconnectDelay>();
+                    });
+                let ecs_lock = opts.ecs_lock.clone();
+                let bot = Client::start_client(opts).await;
+                bot.ecs.write().entity_mut(bot.entity).insert(UserComponent);
+                assert_eq!(bot.reconnect().await, Err(ReconnectError::AlreadyConnected));
+                kick_tx.send(()).unwrap();
+
+                let is_connected = || {
+                    let ecs = bot.ecs.read();
+                    ecs.get::<RawConnection>(bot.entity).is_some()
+                        || ecs.get::<CreateConnectionTask>(bot.entity).is_some()
+                };
+                // wait until we get kicked
+                tokio::time::timeout(Duration::from_secs(5), async {
+                    while is_connected() {
+                        tokio::time::sleep(Duration::from_millis(1)).await;
+                    }
+                })
+                .await
+                .unwrap();
+
+                bot.reconnect().await.unwrap();
+                let hellos = tokio::time::timeout(Duration::from_secs(5), server)
+                    .await
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(hellos.len(), 2);
+                assert_eq!(hellos[1].name, "bot");
+
+                let ecs = ecs_lock.read();
+                assert_eq!(
+                    ecs.resource::<EntityUuidIndex>().get(&hellos[1].profile_id),
+                    Some(bot.entity)
+                );
+                // components added by the user aren't removed when we reconnect
+                assert!(ecs.get::<UserComponent>(bot.entity).is_some());
+                drop(ecs);
+
+                ecs_lock.write().write_message(AppExit::Success);
+            })
+            .await;
+    }
+
+    #[test]
+    fn test_display_name_from_tab_list() {
+        let uuid = Uuid::from_u128(1234);
+        let profile = GameProfile::new(uuid, "bot".to_owned());
+        let mut tab_list = TabList::default();
+        tab_list.insert(
+            uuid,
+            PlayerInfo {
+                profile: profile.clone(),
+                uuid,
+                gamemode: Default::default(),
+                latency: 0,
+                display_name: Some(Box::new(FormattedText::from("[Admin] bot"))),
+                listed: true,
+                list_order: 0,
+            },
+        );
+
+        let mut ecs = bevy_ecs::world::World::new();
+        let entity = ecs.spawn((GameProfileComponent(profile), tab_list)).id();
+        let bot = Client::new(entity, Arc::new(RwLock::new(ecs)));
+
+        assert_eq!(
+            bot.display_name().map(|name| name.to_string()),
+            Some("[Admin] bot".to_owned())
+        );
+
+        bot.ecs
+            .write()
+            .entity_mut(entity)
+            .insert(TabList::default());
+        assert_eq!(bot.display_name(), None);
+    }
+
+    #[test]
+    fn test_is_world_frozen() {
+        let mut ecs = bevy_ecs::world::World::new();
+        let entity = ecs.spawn_empty().id();
+        let bot = Client::new(entity, Arc::new(RwLock::new(ecs)));
+        // the server hasn't sent a ticking state yet
+        assert!(!bot.is_world_frozen());
+
+        let receive_packet = |packet: ClientboundGamePacket| {
+            process_packet(&mut bot.ecs.write(), bot.entity, &packet);
+        };
+
+        receive_packet(ClientboundGamePacket::TickingState(
+            ClientboundTickingState {
+                tick_rate: 20.,
+                is_frozen: true,
+            },
+        ));
+        assert!(bot.is_world_frozen());
+
+        // stepping doesn't unfreeze the world
+        receive_packet(ClientboundGamePacket::TickingStep(ClientboundTickingStep {
+            tick_steps: 5,
+        }));
+        assert!(bot.is_world_frozen());
+
+        receive_packet(ClientboundGamePacket::TickingState(
+            ClientboundTickingState {
+                tick_rate: 20.,
+                is_frozen: false,
+            },
+        ));
+        assert!(!bot.is_world_frozen());
+    }
 }
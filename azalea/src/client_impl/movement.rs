@@ -1,7 +1,11 @@
+use azalea_block::BlockState;
 use azalea_client::{
     PhysicsState, SprintDirection, StartSprintEvent, StartWalkEvent, WalkDirection,
+    movement::SendPositionEvent,
 };
-use azalea_entity::{Jumping, LookDirection};
+use azalea_core::position::{BlockPos, Vec3};
+use azalea_entity::{Jumping, LookDirection, OnClimbable, Physics, Position, metadata::Swimming};
+use azalea_physics::collision::find_supporting_block;
 
 use crate::Client;
 
@@ -22,6 +26,91 @@ impl Client {
         **self.component::<Jumping>()
     }
 
+    /// Returns our current velocity, which is how far we'll try to move next
+    /// tick before friction and gravity are applied.
+    pub fn velocity(&self) -> Vec3 {
+        self.query_self::<&Physics, _>(|p| p.velocity)
+    }
+
+    /// Set our velocity, so we'll try to move by exactly this much during the
+    /// next physics tick.
+    ///
+    /// This is an alternative to [`Self::walk`] for when you need precise
+    /// control over your movement. Collisions still apply, so we'll stop at
+    /// walls instead of going through them, and the velocity will be slowed
+    /// down by friction and gravity after the tick like usual.
+    ///
+    /// Note that the server validates our movement, so setting a velocity that
+    /// moves us much faster than a player normally could (more than about 10
+    /// blocks in a tick) will get us teleported back.
+    ///
+    /// ```
+    /// # use azalea::core::position::Vec3;
+    /// # async fn example(bot: azalea::Client) {
+    /// // move 0.2 blocks east
+    /// bot.set_velocity(Vec3::new(0.2, 0., 0.));
+    /// bot.wait_ticks(1).await;
+    /// # }
+    /// ```
+    pub fn set_velocity(&self, velocity: Vec3) {
+        self.query_self::<&mut Physics, _>(|mut p| p.velocity = velocity);
+    }
+
+    /// Add the given delta to our velocity, like what happens when we get
+    /// knocked back.
+    ///
+    /// See [`Self::set_velocity`] for more details.
+    pub fn move_by(&self, delta: Vec3) {
+        self.query_self::<&mut Physics, _>(|mut p| p.velocity += delta);
+    }
+
+    /// Returns whether the client is standing on the ground.
+    ///
+    /// This is the value that's sent to the server in our movement packets, so
+    /// it's what the server uses to decide when to apply fall damage.
+    pub fn on_ground(&self) -> bool {
+        self.query_self::<&Physics, _>(|p| p.on_ground())
+    }
+
+    /// Returns the position and state of the block that the client is standing
+    /// on, or `None` if it's not standing on anything.
+    ///
+    /// This uses the collision shapes of the blocks, so if you're standing on
+    /// the edge of a slab or on top of a fence then that's the block that'll be
+    /// returned. If you're standing on several blocks at once, the one closest
+    /// to the center of the client is picked.
+    pub fn block_below(&self) -> Option<(BlockPos, BlockState)> {
+        let (position, bounding_box) =
+            self.query_self::<(&Position, &Physics), _>(|(p, physics)| (**p, physics.bounding_box));
+        let world = self.world();
+        let world = world.read();
+        let block_pos = find_supporting_block(&world, position, &bounding_box)?;
+        let block_state = world.get_block_state(block_pos)?;
+        Some((block_pos, block_state))
+    }
+
+    /// Returns whether the client is touching water.
+    pub fn is_in_water(&self) -> bool {
+        self.query_self::<&Physics, _>(|p| p.is_in_water())
+    }
+
+    /// Returns whether the client is touching lava.
+    pub fn is_in_lava(&self) -> bool {
+        self.query_self::<&Physics, _>(|p| p.is_in_lava())
+    }
+
+    /// Returns whether the client is on a block that can be climbed, like a
+    /// ladder, vine, or scaffolding.
+    pub fn is_on_ladder(&self) -> bool {
+        **self.component::<OnClimbable>()
+    }
+
+    /// Returns whether the client is swimming, which happens when sprinting
+    /// while underwater.
+    pub fn is_swimming(&self) -> bool {
+        **self.component::<Swimming>()
+    }
+
     pub fn set_crouching(&self, crouching: bool) {
         self.query_self::<&mut PhysicsState, _>(|mut p| p.trying_to_crouch = crouching);
     }
@@ -52,6 +141,28 @@ impl Client {
         *self.component::<LookDirection>()
     }
 
+    /// Returns a unit vector pointing in the direction that the client is
+    /// looking.
+    ///
+    /// See [`LookDirection::view_vector`] for more details.
+    pub fn look_vector(&self) -> Vec3 {
+        self.direction().view_vector()
+    }
+
+    /// Immediately send our current position and look direction to the server,
+    /// instead of waiting for the next tick to send it (which only happens if
+    /// it changed).
+    ///
+    /// This can be useful if the server needs to know about a rotation right
+    /// away, for example right before attacking or using an item.
+    ///
+    /// Nothing will be sent if the client is dead.
+    pub fn send_position_packet(&self) {
+        self.ecs.write().trigger(SendPositionEvent {
+            entity: self.entity,
+        });
+    }
+
     /// Start walking in the given direction.
     ///
     /// To sprint, use [`Client::sprint`]. To stop walking, call walk with
@@ -101,3 +212,40 @@ impl Client {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use azalea_entity::dimensions::EntityDimensions;
+    use bevy_ecs::world::World;
+    use parking_lot::RwLock;
+
+    use super::*;
+
+    #[test]
+    fn test_set_velocity_and_move_by() {
+        let mut ecs = World::new();
+        let pos = Vec3::new(0.5, 64., 0.5);
+        let entity = ecs
+            .spawn(Physics::new(&EntityDimensions::new(0.6, 1.8), pos))
+            .id();
+        let client = Client::new(entity, Arc::new(RwLock::new(ecs)));
+
+        client.set_velocity(Vec3::new(0.3, 0., 0.));
+        assert_eq!(
+            client.component::<Physics>().velocity,
+            Vec3::new(0.3, 0., 0.)
+        );
+
+        // knockback adds onto the velocity that we already had
+        client.move_by(Vec3::new(0., 0.4, -0.1));
+        assert_eq!(
+            client.component::<Physics>().velocity,
+            Vec3::new(0.3, 0.4, -0.1)
+        );
+
+        client.set_velocity(Vec3::ZERO);
+        assert_eq!(client.component::<Physics>().velocity, Vec3::ZERO);
+    }
+}
@@ -0,0 +1,74 @@
+use azalea_client::stats::{RequestStatisticsEvent, Statistics, WaitingForStatistics};
+
+use crate::Client;
+
+impl Client {
+    /// Ask the server for our statistics, and wait until it responds.
+    ///
+    /// Returns `None` if the server didn't respond within 5 seconds (100
+    /// ticks). Use [`Self::request_statistics_with_timeout_ticks`] to change
+    /// the timeout.
+    ///
+    /// ```
+    /// # use azalea::protocol::packets::game::c_award_stats::Stat;
+    /// # use azalea_registry::builtin::CustomStat;
+    /// # async fn example(bot: azalea::Client) {
+    /// if let Some(statistics) = bot.request_statistics().await {
+    ///     let play_time = statistics
+    ///         .get(&Stat::Custom(CustomStat::PlayTime))
+    ///         .copied()
+    ///         .unwrap_or_default();
+    ///     println!("played for {play_time} ticks");
+    /// }
+    /// # }
+    /// ```
+    pub async fn request_statistics(&self) -> Option<Statistics> {
+        self.request_statistics_with_timeout_ticks(Some(20 * 5))
+            .await
+    }
+
+    /// Ask the server for our statistics, or time out after the given number
+    /// of ticks.
+    ///
+    /// If `timeout_ticks` is `None`, there will be no timeout.
+    pub async fn request_statistics_with_timeout_ticks(
+        &self,
+        timeout_ticks: Option<usize>,
+    ) -> Option<Statistics> {
+        let mut ticks = self.get_tick_broadcaster();
+        {
+            let mut ecs = self.ecs.write();
+            // inserted here instead of only in the system so we don't stop waiting before
+            // the request is sent
+            ecs.entity_mut(self.entity).insert(WaitingForStatistics);
+            ecs.write_message(RequestStatisticsEvent {
+                entity: self.entity,
+            });
+        }
+
+        let mut elapsed_ticks = 0;
+        while ticks.recv().await.is_ok() {
+            let ecs = self.ecs.read();
+            if ecs.get::<WaitingForStatistics>(self.entity).is_none() {
+                break;
+            }
+
+            elapsed_ticks += 1;
+            if let Some(timeout_ticks) = timeout_ticks
+                && elapsed_ticks >= timeout_ticks
+            {
+                return None;
+            }
+        }
+
+        self.statistics()
+    }
+
+    /// Get the statistics that the server last sent us, without asking it for
+    /// new ones.
+    ///
+    /// Also see [`Self::request_statistics`].
+    pub fn statistics(&self) -> Option<Statistics> {
+        self.get_component::<Statistics>().map(|s| s.clone())
+    }
+}
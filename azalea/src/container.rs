@@ -6,7 +6,7 @@ use azalea_client::{
     packet::game::ReceiveGamePacketEvent,
 };
 use azalea_core::position::BlockPos;
-use azalea_entity::inventory::Inventory;
+use azalea_entity::inventory::{BrewingState, FurnaceState, Inventory};
 use azalea_inventory::{
     ItemStack, Menu,
     operations::{ClickOperation, PickupClick, QuickMoveClick},
@@ -261,6 +261,22 @@ impl ContainerHandleRef {
             .flatten()
     }
 
+    /// Returns the cooking progress if the container is a furnace, blast
+    /// furnace, or smoker.
+    ///
+    /// If the container is closed or isn't a furnace, this will return `None`.
+    pub fn furnace_state(&self) -> Option<FurnaceState> {
+        self.map_inventory(|inv| inv.furnace_state()).flatten()
+    }
+
+    /// Returns the brewing progress if the container is a brewing stand.
+    ///
+    /// If the container is closed or isn't a brewing stand, this will return
+    /// `None`.
+    pub fn brewing_state(&self) -> Option<BrewingState> {
+        self.map_inventory(|inv| inv.brewing_state()).flatten()
+    }
+
     /// A shortcut for [`Self::click`] with `PickupClick::Left`.
     pub fn left_click(&self, slot: impl Into<usize>) {
         self.click(PickupClick::Left {
@@ -42,7 +42,10 @@ impl_entity_functions! {
     ///
     /// Note that this value is given a default of [`Vec3::ZERO`] when it
     /// receives the login packet, its true position may be set ticks
-    /// later.
+    /// later. You can wait for [`Event::InitialSpawn`] to know when it's
+    /// been set.
+    ///
+    /// [`Event::InitialSpawn`]: crate::Event::InitialSpawn
     EntityRef:
     /// Get the entity's position in the world, which is the same as its feet
     /// position.
@@ -5,10 +5,21 @@ use std::sync::Arc;
 
 use azalea_chat::FormattedText;
 use azalea_client::join::ConnectionFailedEvent;
-use azalea_core::{entity_id::MinecraftEntityId, position::ChunkPos, tick::GameTick};
-use azalea_entity::{Dead, InLoadedChunk};
+use azalea_core::{
+    entity_id::MinecraftEntityId,
+    position::{ChunkPos, Vec3},
+    tick::GameTick,
+};
+use azalea_entity::{Dead, InLoadedChunk, LookDirection};
 use azalea_protocol::{
-    connect::ConnectionError, packets::game::c_player_combat_kill::ClientboundPlayerCombatKill,
+    connect::ConnectionError,
+    packets::game::{
+        c_player_combat_kill::ClientboundPlayerCombatKill, s_interact::InteractionHand,
+    },
+};
+use azalea_registry::{
+    builtin::{EntityKind, MenuKind},
+    identifier::Identifier,
 };
 use azalea_world::WorldName;
 use bevy_app::{App, Plugin, PreUpdate, Update};
@@ -20,10 +31,18 @@ use crate::{
     chat::{ChatPacket, ChatReceivedEvent},
     chunks::ReceiveChunkEvent,
     disconnect::DisconnectEvent,
-    packet::game::{
-        AddPlayerEvent, DeathEvent, KeepAliveEvent, RemovePlayerEvent, UpdatePlayerEvent,
+    inventory::{ClientsideCloseContainerEvent, MenuOpenedEvent},
+    local_player::WorldSpawn,
+    packet::{
+        config::ConfigPingEvent,
+        game::{
+            AddPlayerEvent, DeathEvent, EntityRemovedEvent, EntitySpawnedEvent, EntitySwingEvent,
+            ExplosionEvent, GamePingEvent, InitialPositionEvent, KeepAliveEvent, RemovePlayerEvent,
+            RespawnEvent, RotatedEvent, UpdatePlayerEvent,
+        },
     },
     player::PlayerInfo,
+    swarm::BroadcastMessage,
 };
 
 // (for contributors):
@@ -88,6 +107,23 @@ pub enum Event {
     /// This event will be sent every time the client respawns or switches
     /// worlds, as long as the server sends chunks to the client.
     Spawn,
+    /// Fired when the server first tells us our position after we log in or
+    /// respawn.
+    ///
+    /// Our position is [`Vec3::ZERO`] until this happens, so this is the
+    /// earliest that you can act on [`Client::position`]. Also see the related
+    /// ECS event [`InitialPositionEvent`].
+    ///
+    /// [`Vec3::ZERO`]: azalea_core::position::Vec3::ZERO
+    /// [`Client::position`]: crate::Client::position
+    InitialSpawn {
+        pos: Vec3,
+    },
+    /// The server changed the direction that we're looking in.
+    ///
+    /// The new direction will have already been applied by the time you
+    /// receive this event. Also see the related ECS event [`RotatedEvent`].
+    Rotated(LookDirection),
     /// A chat message was sent in the game chat.
     Chat(ChatPacket),
     /// Happens 20 times per second, but only when the world is loaded.
@@ -122,8 +158,37 @@ pub enum Event {
     UpdatePlayer(PlayerInfo),
     /// The client player died in-game.
     Death(Option<Arc<ClientboundPlayerCombatKill>>),
+    /// The client player respawned after dying.
+    ///
+    /// This is sent once the server tells us where we respawned, so the
+    /// client's position can be trusted by then. It isn't sent when
+    /// respawning because of changing worlds.
+    ///
+    /// Use [`Client::last_death_location`] to find where we died.
+    ///
+    /// [`Client::last_death_location`]: crate::Client::last_death_location
+    Respawn {
+        /// The dimension that we respawned in, like `minecraft:overworld`.
+        dimension: Identifier,
+        /// Whether we probably respawned at our own respawn point (like a bed
+        /// or a respawn anchor) instead of at the world spawn.
+        ///
+        /// The server doesn't tell us this, so it's a guess. See
+        /// [`RespawnEvent::is_probably_bed_spawn`] for how it's determined.
+        is_probably_bed_spawn: bool,
+    },
     /// A `KeepAlive` packet was sent by the server.
     KeepAlive(u64),
+    /// A `Ping` packet was sent by the server.
+    ///
+    /// This can happen in both the `game` and `config` states. You don't have
+    /// to reply to it yourself, since [`PongPlugin`] already responds with a
+    /// `Pong` with the same id.
+    ///
+    /// [`PongPlugin`]: azalea_client::pong::PongPlugin
+    Ping {
+        id: u32,
+    },
     /// The client disconnected from the server.
     ///
     /// Also see [`Event::ConnectionFailed`].
@@ -134,6 +199,82 @@ pub enum Event {
     /// [`ConnectionFailedEvent`].
     ConnectionFailed(Arc<ConnectionError>),
     ReceiveChunk(ChunkPos),
+    /// A new entity was added to our world.
+    ///
+    /// This is only sent once per entity, and the entity's metadata will
+    /// usually already be set by the time you receive it. Also see the related
+    /// ECS event [`EntitySpawnedEvent`].
+    EntitySpawned {
+        entity: Entity,
+        kind: EntityKind,
+    },
+    /// An entity was removed from our world, usually because it went out of
+    /// render distance or died.
+    ///
+    /// The entity won't be returned by
+    /// [`Client::entity_by_minecraft_id`] anymore, and it may have already
+    /// been despawned from the ECS. Also see the related ECS event
+    /// [`EntityRemovedEvent`].
+    ///
+    /// [`Client::entity_by_minecraft_id`]: crate::Client::entity_by_minecraft_id
+    EntityRemoved {
+        entity: Entity,
+        mc_id: MinecraftEntityId,
+    },
+    /// An entity in our world swung its arm, which usually means that it
+    /// attacked something or is mining a block.
+    ///
+    /// This is also sent for our own client if the server tells us about it.
+    /// Also see the related ECS event [`EntitySwingEvent`].
+    EntitySwing {
+        entity: Entity,
+        hand: InteractionHand,
+    },
+    /// An explosion happened near us.
+    ///
+    /// Any knockback from the explosion has already been applied to our
+    /// velocity. The blocks that were destroyed are removed from the world by
+    /// separate block updates, so they may still be there when this event is
+    /// received. Also see the related ECS event [`ExplosionEvent`].
+    Explosion {
+        /// The position where the explosion happened.
+        pos: Vec3,
+        radius: f32,
+        /// The number of blocks that the explosion destroyed.
+        block_count: u32,
+    },
+    /// The server told us where the world spawn point is, which happens when
+    /// we join and whenever it changes.
+    ///
+    /// Also see [`Client::world_spawn`].
+    ///
+    /// [`Client::world_spawn`]: crate::Client::world_spawn
+    WorldSpawn(WorldSpawn),
+    /// The server opened a container for us, like a chest or a furnace.
+    ///
+    /// This usually happens after we interact with a block. The container's
+    /// contents are sent separately, so they may not be available yet when
+    /// this event is received. Also see the related ECS event
+    /// [`MenuOpenedEvent`].
+    ContainerOpened {
+        /// The ID of the container, which is the same as what
+        /// [`Client::open_container_id`] returns while it's open.
+        ///
+        /// [`Client::open_container_id`]: crate::Client::open_container_id
+        window_id: i32,
+        menu_type: MenuKind,
+        title: FormattedText,
+    },
+    /// The container that we had open was closed, either by us or by the
+    /// server.
+    ///
+    /// Also see the related ECS event [`ClientsideCloseContainerEvent`].
+    ContainerClosed,
+    /// A message that was sent to every bot in the swarm with
+    /// [`Swarm::broadcast`].
+    ///
+    /// [`Swarm::broadcast`]: crate::swarm::Swarm::broadcast
+    Broadcast(BroadcastMessage),
 }
 
 /// A component that contains an event sender for events that are only
@@ -153,6 +294,8 @@ impl Plugin for EventsPlugin {
                 chat_listener,
                 login_listener,
                 spawn_listener,
+                initial_spawn_listener,
+                rotated_listener,
                 #[cfg(feature = "packet-event")]
                 packet_listener,
                 add_player_listener,
@@ -160,16 +303,26 @@ impl Plugin for EventsPlugin {
                 remove_player_listener,
                 keepalive_listener,
                 death_listener.after(azalea_client::packet::death_event_on_0_health),
+                respawn_listener.after(azalea_client::packet::send_respawn_event),
                 disconnect_listener,
                 connection_failed_listener.after(azalea_client::join::poll_create_connection_task),
                 receive_chunk_listener,
+                entity_spawned_listener,
+                entity_removed_listener,
+                entity_swing_listener,
+                explosion_listener,
+                world_spawn_listener,
             ),
         )
         .add_systems(
             PreUpdate,
             init_listener.before(super::connection::read_packets),
         )
-        .add_systems(GameTick, tick_listener);
+        .add_systems(GameTick, tick_listener)
+        .add_observer(game_ping_listener)
+        .add_observer(config_ping_listener)
+        .add_observer(container_opened_listener)
+        .add_observer(container_closed_listener);
     }
 }
 
@@ -210,6 +363,27 @@ pub fn spawn_listener(
     }
 }
 
+pub fn initial_spawn_listener(
+    query: Query<&LocalPlayerEvents>,
+    mut events: MessageReader<InitialPositionEvent>,
+) {
+    for event in events.read() {
+        if let Ok(local_player_events) = query.get(event.entity) {
+            let _ = local_player_events.send(Event::InitialSpawn {
+                pos: event.position,
+            });
+        }
+    }
+}
+
+pub fn rotated_listener(query: Query<&LocalPlayerEvents>, mut events: MessageReader<RotatedEvent>) {
+    for event in events.read() {
+        if let Ok(local_player_events) = query.get(event.entity) {
+            let _ = local_player_events.send(Event::Rotated(event.look_direction));
+        }
+    }
+}
+
 pub fn chat_listener(
     query: Query<&LocalPlayerEvents>,
     mut events: MessageReader<ChatReceivedEvent>,
@@ -290,6 +464,20 @@ pub fn dead_component_listener(query: Query<&LocalPlayerEvents, Added<Dead>>) {
     }
 }
 
+/// Send the "Respawn" event for [`LocalEntity`]s that respawned after dying.
+///
+/// [`LocalEntity`]: azalea_entity::LocalEntity
+pub fn respawn_listener(query: Query<&LocalPlayerEvents>, mut events: MessageReader<RespawnEvent>) {
+    for event in events.read() {
+        if let Ok(local_player_events) = query.get(event.entity) {
+            let _ = local_player_events.send(Event::Respawn {
+                dimension: event.dimension.clone(),
+                is_probably_bed_spawn: event.is_probably_bed_spawn,
+            });
+        }
+    }
+}
+
 pub fn keepalive_listener(
     query: Query<&LocalPlayerEvents>,
     mut events: MessageReader<KeepAliveEvent>,
@@ -301,6 +489,41 @@ pub fn keepalive_listener(
     }
 }
 
+pub fn world_spawn_listener(query: Query<(&LocalPlayerEvents, &WorldSpawn), Changed<WorldSpawn>>) {
+    for (local_player_events, world_spawn) in &query {
+        let _ = local_player_events.send(Event::WorldSpawn(world_spawn.clone()));
+    }
+}
+
+pub fn game_ping_listener(ping: On<GamePingEvent>, query: Query<&LocalPlayerEvents>) {
+    if let Ok(local_player_events) = query.get(ping.entity) {
+        let _ = local_player_events.send(Event::Ping { id: ping.packet.id });
+    }
+}
+pub fn config_ping_listener(ping: On<ConfigPingEvent>, query: Query<&LocalPlayerEvents>) {
+    if let Ok(local_player_events) = query.get(ping.entity) {
+        let _ = local_player_events.send(Event::Ping { id: ping.packet.id });
+    }
+}
+
+pub fn container_opened_listener(opened: On<MenuOpenedEvent>, query: Query<&LocalPlayerEvents>) {
+    if let Ok(local_player_events) = query.get(opened.entity) {
+        let _ = local_player_events.send(Event::ContainerOpened {
+            window_id: opened.window_id,
+            menu_type: opened.menu_type,
+            title: opened.title.clone(),
+        });
+    }
+}
+pub fn container_closed_listener(
+    closed: On<ClientsideCloseContainerEvent>,
+    query: Query<&LocalPlayerEvents>,
+) {
+    if let Ok(local_player_events) = query.get(closed.entity) {
+        let _ = local_player_events.send(Event::ContainerClosed);
+    }
+}
+
 pub fn disconnect_listener(
     query: Query<&LocalPlayerEvents>,
     mut events: MessageReader<DisconnectEvent>,
@@ -336,3 +559,129 @@ pub fn receive_chunk_listener(
         }
     }
 }
+
+pub fn entity_spawned_listener(
+    query: Query<&LocalPlayerEvents>,
+    mut events: MessageReader<EntitySpawnedEvent>,
+) {
+    for event in events.read() {
+        if let Ok(local_player_events) = query.get(event.entity) {
+            let _ = local_player_events.send(Event::EntitySpawned {
+                entity: event.spawned,
+                kind: event.kind,
+            });
+        }
+    }
+}
+
+pub fn entity_removed_listener(
+    query: Query<&LocalPlayerEvents>,
+    mut events: MessageReader<EntityRemovedEvent>,
+) {
+    for event in events.read() {
+        if let Ok(local_player_events) = query.get(event.entity) {
+            let _ = local_player_events.send(Event::EntityRemoved {
+                entity: event.removed,
+                mc_id: event.minecraft_id,
+            });
+        }
+    }
+}
+
+pub fn entity_swing_listener(
+    query: Query<&LocalPlayerEvents>,
+    mut events: MessageReader<EntitySwingEvent>,
+) {
+    for event in events.read() {
+        if let Ok(local_player_events) = query.get(event.entity) {
+            let _ = local_player_events.send(Event::EntitySwing {
+                entity: event.swung,
+                hand: event.hand,
+            });
+        }
+    }
+}
+
+pub fn explosion_listener(
+    query: Query<&LocalPlayerEvents>,
+    mut events: MessageReader<ExplosionEvent>,
+) {
+    for event in events.read() {
+        if let Ok(local_player_events) = query.get(event.entity) {
+            let _ = local_player_events.send(Event::Explosion {
+                pos: event.center,
+                radius: event.radius,
+                block_count: event.block_count,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use azalea_protocol::packets::{Packet, config, game};
+
+    use super::*;
+    use crate::packet::game::process_packet;
+
+    #[test]
+    fn test_ping_event_in_both_states() {
+        let mut ecs = World::new();
+        ecs.add_observer(game_ping_listener);
+        ecs.add_observer(config_ping_listener);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let entity = ecs.spawn(LocalPlayerEvents(tx)).id();
+
+        ecs.trigger(ConfigPingEvent {
+            entity,
+            packet: config::ClientboundPing { id: 321 },
+        });
+        ecs.trigger(GamePingEvent {
+            entity,
+            packet: game::ClientboundPing { id: 123 },
+        });
+
+        assert!(matches!(rx.try_recv(), Ok(Event::Ping { id: 321 })));
+        assert!(matches!(rx.try_recv(), Ok(Event::Ping { id: 123 })));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_container_open_and_close_events() {
+        let mut ecs = World::new();
+        ecs.add_observer(container_opened_listener);
+        ecs.add_observer(container_closed_listener);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let entity = ecs.spawn(LocalPlayerEvents(tx)).id();
+
+        process_packet(
+            &mut ecs,
+            entity,
+            &game::ClientboundOpenScreen {
+                container_id: 3,
+                menu_type: MenuKind::Generic9x3,
+                title: "Chest".into(),
+            }
+            .into_variant(),
+        );
+        process_packet(
+            &mut ecs,
+            entity,
+            &game::ClientboundContainerClose { container_id: 3 }.into_variant(),
+        );
+
+        let Ok(Event::ContainerOpened {
+            window_id,
+            menu_type,
+            title,
+        }) = rx.try_recv()
+        else {
+            panic!("expected a ContainerOpened event");
+        };
+        assert_eq!(window_id, 3);
+        assert_eq!(menu_type, MenuKind::Generic9x3);
+        assert_eq!(title.to_string(), "Chest");
+        assert!(matches!(rx.try_recv(), Ok(Event::ContainerClosed)));
+        assert!(rx.try_recv().is_err());
+    }
+}
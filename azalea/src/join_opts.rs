@@ -1,6 +1,8 @@
 use std::net::SocketAddr;
 
-use azalea_protocol::{address::ServerAddr, connect::Proxy};
+use azalea_protocol::{
+    address::ServerAddr, common::client_information::ClientInformation, connect::Proxy,
+};
 
 /// Optional settings when adding an account to a swarm or client.
 #[derive(Clone, Debug, Default)]
@@ -26,6 +28,11 @@ pub struct JoinOpts {
     /// to the server.
     #[doc(alias = "custom_resolved_address")]
     pub custom_socket_addr: Option<SocketAddr>,
+    /// The [`ClientInformation`] that this bot will send to the server when it
+    /// joins.
+    ///
+    /// If this is `None`, [`ClientInformation::default`] will be used.
+    pub client_information: Option<ClientInformation>,
 }
 
 impl JoinOpts {
@@ -46,6 +53,9 @@ impl JoinOpts {
         if let Some(custom_socket_addr) = other.custom_socket_addr {
             self.custom_socket_addr = Some(custom_socket_addr);
         }
+        if let Some(client_information) = other.client_information.clone() {
+            self.client_information = Some(client_information);
+        }
     }
 
     /// Configure the SOCKS5 proxy used for connecting to the server and for
@@ -94,6 +104,19 @@ impl JoinOpts {
         self
     }
 
+    /// Set the [`ClientInformation`] that this bot will send to the server
+    /// when it joins, like its view distance and language.
+    ///
+    /// This can still be changed after joining with
+    /// [`Client::set_client_information`].
+    ///
+    /// [`Client::set_client_information`]: crate::Client::set_client_information
+    #[must_use]
+    pub fn client_information(mut self, client_information: ClientInformation) -> Self {
+        self.client_information = Some(client_information);
+        self
+    }
+
     #[doc(hidden)]
     #[deprecated = "renamed to `custom_server_addr`."]
     pub fn custom_address(self, server_addr: ServerAddr) -> Self {
@@ -6,6 +6,7 @@ pub mod accept_resource_packs;
 pub mod auto_reconnect;
 pub mod auto_respawn;
 pub mod auto_tool;
+pub mod block_actions;
 pub mod bot;
 mod builder;
 mod client_impl;
@@ -16,6 +17,7 @@ mod join_opts;
 pub mod nearest_entity;
 pub mod pathfinder;
 pub mod prelude;
+pub mod safe_walk;
 pub mod swarm;
 pub mod tick_broadcast;
 
@@ -55,7 +57,15 @@ pub use builder::ClientBuilder;
 use futures::future::BoxFuture;
 pub use join_opts::JoinOpts;
 
-pub use crate::{client_impl::Client, entity_ref::EntityRef, events::Event};
+pub use crate::{
+    client_impl::{
+        Client, ReconnectError,
+        debug::ClientDebugInfo,
+        entity_query::{ClientSnapshot, EntityInfo},
+    },
+    entity_ref::EntityRef,
+    events::Event,
+};
 
 pub type BoxHandleFn<S, R> = Box<dyn Fn(Client, Event, S) -> BoxFuture<'static, R> + Send>;
 pub type HandleFn<S, Fut> = fn(Client, Event, S) -> Fut;
@@ -26,6 +26,8 @@ where
     /// For Azalea's pathfinder, this is generally the estimated amount of time
     /// that it takes to complete the path, in ticks.
     pub cost: f32,
+    /// The number of nodes that were expanded while searching for the path.
+    pub nodes_expanded: usize,
 }
 
 // used for better results when timing out
@@ -91,6 +93,7 @@ where
                 movements: reconstruct_path(nodes, best_path, successors),
                 is_partial: false,
                 cost: g_score,
+                nodes_expanded: num_nodes,
             };
         }
 
@@ -180,6 +183,7 @@ where
         movements: reconstruct_path(nodes, best_paths[best_path_idx], successors),
         is_partial: true,
         cost: best_path_scores[best_path_idx],
+        nodes_expanded: num_nodes,
     }
 }
 
@@ -1,5 +1,6 @@
 use std::sync::LazyLock;
 
+use bevy_ecs::resource::Resource;
 use num_traits::Float;
 
 // based on https://github.com/cabaletta/baritone/blob/1.20.1/src/api/java/baritone/api/pathing/movement/ActionCosts.java
@@ -22,6 +23,53 @@ pub const COST_HEURISTIC: f32 = 3.563;
 // breaking blocks if it can be avoided
 pub const BLOCK_BREAK_ADDITIONAL_PENALTY: f32 = 2.;
 
+/// Multipliers for the costs of the different kinds of movements that the
+/// pathfinder can make.
+///
+/// This is a resource, so it applies to every client in the ECS world. All of
+/// the multipliers are 1 by default, and raising one makes the pathfinder
+/// prefer other kinds of movements when they're available.
+///
+/// Note that setting a multiplier below 1 can make the pathfinder return
+/// suboptimal paths, since the goal heuristics assume the default costs.
+#[derive(Clone, Copy, Debug, PartialEq, Resource)]
+pub struct PathfinderCosts {
+    /// The multiplier for walking and sprinting on land.
+    pub walk: f32,
+    /// The multiplier for entering water and swimming through it.
+    pub swim: f32,
+    /// The multiplier for jumping up a block or across a gap.
+    pub jump: f32,
+    /// The multiplier for falling down one or more blocks.
+    pub fall: f32,
+    /// The multiplier for breaking blocks that are in the way. This only
+    /// matters if [`PathfinderOpts::allow_mining`] is enabled.
+    ///
+    /// [`PathfinderOpts::allow_mining`]: crate::pathfinder::PathfinderOpts::allow_mining
+    pub mining: f32,
+}
+impl Default for PathfinderCosts {
+    fn default() -> Self {
+        Self {
+            walk: 1.,
+            swim: 1.,
+            jump: 1.,
+            fall: 1.,
+            mining: 1.,
+        }
+    }
+}
+impl PathfinderCosts {
+    /// Costs that make the pathfinder go around water whenever it can, at the
+    /// cost of potentially taking a much longer path.
+    pub fn avoid_water() -> Self {
+        Self {
+            swim: 10.,
+            ..Default::default()
+        }
+    }
+}
+
 pub static FALL_1_25_BLOCKS_COST: LazyLock<f32> = LazyLock::new(|| distance_to_ticks(1.25));
 pub static FALL_0_25_BLOCKS_COST: LazyLock<f32> = LazyLock::new(|| distance_to_ticks(0.25));
 pub static JUMP_ONE_BLOCK_COST: LazyLock<f32> =
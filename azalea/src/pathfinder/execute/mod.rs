@@ -28,6 +28,7 @@ use crate::{
     pathfinder::{
         ExecutingPath, GotoEvent, Pathfinder,
         astar::PathfinderTimeout,
+        costs::PathfinderCosts,
         custom_state::CustomPathfinderState,
         debug::debug_render_path_with_particles,
         execute::simulation::SimulatingPathState,
@@ -259,6 +260,7 @@ pub fn timeout_movement(
         Option<&SimulatingPathState>,
     )>,
     worlds: Res<Worlds>,
+    costs: Res<PathfinderCosts>,
 ) {
     for (
         entity,
@@ -309,6 +311,7 @@ pub fn timeout_movement(
                     position,
                     world_name,
                     custom_state,
+                    &costs,
                     inventory,
                 );
                 continue;
@@ -348,6 +351,7 @@ pub fn timeout_movement(
                 position,
                 world_name,
                 custom_state,
+                &costs,
                 inventory,
             );
         }
@@ -363,6 +367,7 @@ fn patch_path_from_timeout(
     position: &Position,
     world_name: &WorldName,
     custom_state: Option<&CustomPathfinderState>,
+    costs: &PathfinderCosts,
     inventory: &Inventory,
 ) {
     executing_path.queued_path = None;
@@ -392,6 +397,7 @@ fn patch_path_from_timeout(
         entity,
         world_lock,
         custom_state,
+        *costs,
         opts,
     );
     // reset last_node_reached_at so we don't immediately try to patch again
@@ -14,6 +14,7 @@ use crate::pathfinder::{
     CalculatePathCtx, ExecutingPath, Pathfinder, PathfinderOpts,
     astar::{self, PathfinderTimeout},
     calculate_path, call_successors_fn,
+    costs::PathfinderCosts,
     custom_state::CustomPathfinderState,
     goals::BlockPosGoal,
     mining::MiningCache,
@@ -33,6 +34,7 @@ pub fn check_for_path_obstruction(
         Option<&CustomPathfinderState>,
     )>,
     worlds: Res<Worlds>,
+    costs: Res<PathfinderCosts>,
 ) {
     for (entity, mut pathfinder, mut executing_path, world_name, inventory, custom_state) in
         &mut query
@@ -60,6 +62,7 @@ pub fn check_for_path_obstruction(
                 &cached_world,
                 &mining_cache,
                 &custom_state_ref,
+                &costs,
                 opts.successors_fn,
                 pos,
             )
@@ -112,6 +115,7 @@ pub fn check_for_path_obstruction(
             entity,
             world_lock,
             custom_state.clone(),
+            *costs,
             opts,
         );
     }
@@ -131,6 +135,7 @@ pub fn patch_path(
     entity: Entity,
     world_lock: Arc<RwLock<azalea_world::World>>,
     custom_state: CustomPathfinderState,
+    costs: PathfinderCosts,
     opts: PathfinderOpts,
 ) {
     let patch_start = if *patch_nodes.start() == 0 {
@@ -165,6 +170,7 @@ pub fn patch_path(
         goto_id_atomic,
         mining_cache,
         custom_state,
+        costs,
         opts: PathfinderOpts {
             min_timeout: PathfinderTimeout::Nodes(10_000),
             max_timeout: PathfinderTimeout::Nodes(10_000),
@@ -0,0 +1,144 @@
+//! Make the pathfinder keep our client near an entity that's moving.
+
+use azalea_core::position::BlockPos;
+use azalea_entity::{EntityUuid, Position, indexing::EntityUuidIndex};
+use azalea_world::WorldName;
+use bevy_ecs::prelude::*;
+use tracing::debug;
+use uuid::Uuid;
+
+use super::{GotoEvent, Pathfinder, PathfinderOpts, StopPathfindingEvent, goals::RadiusGoal};
+
+/// A component that makes the pathfinder keep our client within a distance of
+/// another entity.
+///
+/// Whenever the target gets further than `max_distance` away, we start
+/// pathfinding towards it again, and we keep updating the goal as it moves
+/// until we're back within range. We won't move away from the target if it
+/// gets closer than `min_distance`, but we also won't path any closer than
+/// that.
+///
+/// If the target leaves our render distance or goes to a different world,
+/// following is paused until it comes back.
+///
+/// This is usually added with [`PathfinderClientExt::follow_entity`], and
+/// removed with [`PathfinderClientExt::stop_following`].
+///
+/// [`PathfinderClientExt::follow_entity`]: super::PathfinderClientExt::follow_entity
+/// [`PathfinderClientExt::stop_following`]: super::PathfinderClientExt::stop_following
+#[derive(Clone, Component, Debug)]
+pub struct FollowEntity {
+    /// The ECS entity that we're following.
+    pub entity: Entity,
+    pub min_distance: f64,
+    pub max_distance: f64,
+    pub opts: PathfinderOpts,
+
+    /// The UUID of the target, which is used to find it again if it leaves and
+    /// re-enters our render distance (since it'll have a different ECS entity
+    /// then).
+    target_uuid: Option<Uuid>,
+    /// The block position that the target was at when we last started
+    /// pathfinding to it.
+    last_goal_pos: Option<BlockPos>,
+}
+impl FollowEntity {
+    pub fn new(entity: Entity, min_distance: f64, max_distance: f64) -> Self {
+        Self {
+            entity,
+            min_distance,
+            max_distance,
+            opts: PathfinderOpts::new(),
+            target_uuid: None,
+            last_goal_pos: None,
+        }
+    }
+
+    /// Set the options that will be used for pathfinding to the target.
+    pub fn opts(mut self, opts: PathfinderOpts) -> Self {
+        self.opts = opts;
+        self
+    }
+
+    /// The radius around the target that we'll try to get within when we start
+    /// pathfinding to it.
+    ///
+    /// This is between the min and max distances so we don't immediately start
+    /// pathfinding again if the target moves a little bit.
+    fn goal_radius(&self) -> f64 {
+        (self.min_distance + self.max_distance) / 2.
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn follow_entity(
+    mut query: Query<(
+        Entity,
+        &mut FollowEntity,
+        &Position,
+        &WorldName,
+        &Pathfinder,
+    )>,
+    targets: Query<(&Position, &WorldName, Option<&EntityUuid>)>,
+    entity_uuid_index: Option<Res<EntityUuidIndex>>,
+    mut goto_events: MessageWriter<GotoEvent>,
+    mut stop_pathfinding_events: MessageWriter<StopPathfindingEvent>,
+) {
+    for (entity, mut follow, position, world_name, pathfinder) in &mut query {
+        if targets.get(follow.entity).is_err() {
+            // the target might've left our render distance and come back as a new entity
+            let new_target = follow
+                .target_uuid
+                .and_then(|uuid| entity_uuid_index.as_ref()?.get(&uuid));
+            if let Some(new_target) = new_target {
+                follow.entity = new_target;
+            }
+        }
+
+        let target = targets
+            .get(follow.entity)
+            .ok()
+            .filter(|(_, target_world_name, _)| *target_world_name == world_name);
+        let Some((target_position, _, target_uuid)) = target else {
+            // the target is gone, so pause until it comes back
+            if follow.last_goal_pos.take().is_some() {
+                debug!("lost the entity that we were following, pausing");
+                stop_pathfinding_events.write(StopPathfindingEvent {
+                    entity,
+                    force: false,
+                });
+            }
+            continue;
+        };
+        if let Some(target_uuid) = target_uuid {
+            follow.target_uuid = Some(**target_uuid);
+        }
+
+        let target_block_pos = BlockPos::from(**target_position);
+        let is_pathfinding = pathfinder.goal.is_some() || pathfinder.is_calculating;
+        if !is_pathfinding {
+            follow.last_goal_pos = None;
+        }
+
+        let distance = position.distance_to(**target_position);
+        let should_goto = if distance > follow.max_distance {
+            !is_pathfinding || follow.last_goal_pos != Some(target_block_pos)
+        } else {
+            // we're already in range, but if we were on our way then keep updating the
+            // goal until we get to it
+            follow
+                .last_goal_pos
+                .is_some_and(|pos| pos != target_block_pos)
+        };
+        if !should_goto {
+            continue;
+        }
+
+        follow.last_goal_pos = Some(target_block_pos);
+        goto_events.write(GotoEvent::new(
+            entity,
+            RadiusGoal::new(**target_position, follow.goal_radius() as f32),
+            follow.opts.clone(),
+        ));
+    }
+}
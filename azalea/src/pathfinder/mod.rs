@@ -18,6 +18,7 @@ pub mod costs;
 pub mod custom_state;
 pub mod debug;
 pub mod execute;
+pub mod follow;
 pub mod goals;
 mod goto_event;
 pub mod mining;
@@ -58,7 +59,8 @@ use tokio::sync::broadcast::error::RecvError;
 use tracing::{debug, error, info, warn};
 
 use self::{
-    debug::debug_render_path_with_particles, goals::Goal, mining::MiningCache, moves::SuccessorsFn,
+    costs::PathfinderCosts, debug::debug_render_path_with_particles, goals::Goal,
+    mining::MiningCache, moves::SuccessorsFn,
 };
 use crate::{
     Client, WalkDirection,
@@ -81,12 +83,15 @@ impl Plugin for PathfinderPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<GotoEvent>()
             .add_message::<PathFoundEvent>()
+            .add_message::<PathNotFoundEvent>()
             .add_message::<StopPathfindingEvent>()
+            .init_resource::<PathfinderCosts>()
             .add_systems(GameTick, debug_render_path_with_particles)
             .add_systems(PreUpdate, add_default_pathfinder)
             .add_systems(
                 Update,
                 (
+                    follow::follow_entity,
                     goto_listener,
                     handle_tasks,
                     stop_pathfinding_on_world_change,
@@ -133,6 +138,24 @@ pub struct PathFoundEvent {
     pub is_partial: bool,
     pub successors_fn: SuccessorsFn,
     pub allow_mining: bool,
+    /// The number of nodes that A* expanded while calculating this path, which
+    /// can be useful for profiling.
+    pub nodes_expanded: usize,
+}
+
+/// An event that's sent when the pathfinder finishes calculating a path but
+/// wasn't able to find any way to get closer to the goal.
+///
+/// This usually means that the goal is unreachable. If
+/// [`PathfinderOpts::retry_on_no_path`] is enabled (which it is by default),
+/// the pathfinder will keep trying and this event may be sent again.
+#[derive(Clone, Debug, Message)]
+#[non_exhaustive]
+pub struct PathNotFoundEvent {
+    pub entity: Entity,
+    pub goal: Arc<dyn Goal>,
+    /// The number of nodes that A* expanded before giving up.
+    pub nodes_expanded: usize,
 }
 
 #[allow(clippy::type_complexity)]
@@ -205,6 +228,24 @@ pub trait PathfinderClientExt {
     /// Stop calculating a path and stop executing the current movement
     /// immediately.
     fn force_stop_pathfinding(&self);
+    /// Keep the bot within `max_distance` blocks of the given entity, even as
+    /// it moves.
+    ///
+    /// This continues until [`Self::stop_following`] is called. See
+    /// [`FollowEntity`] for more details.
+    ///
+    /// ```
+    /// # use azalea::prelude::*;
+    /// # fn example(bot: &Client, player: azalea::ecs::entity::Entity) {
+    /// bot.follow_entity(player, 2., 5.);
+    /// # }
+    /// ```
+    ///
+    /// [`FollowEntity`]: follow::FollowEntity
+    fn follow_entity(&self, entity: Entity, min_distance: f64, max_distance: f64);
+    /// Stop following the entity that we started following with
+    /// [`Self::follow_entity`], and stop pathfinding.
+    fn stop_following(&self);
     /// Waits forever until the bot no longer has a pathfinder goal.
     fn wait_until_goto_target_reached(&self) -> impl Future<Output = ()>;
     /// Returns true if the pathfinder has no active goal and isn't calculating
@@ -250,6 +291,24 @@ impl PathfinderClientExt for Client {
             force: true,
         });
     }
+    fn follow_entity(&self, entity: Entity, min_distance: f64, max_distance: f64) {
+        self.ecs
+            .write()
+            .entity_mut(self.entity)
+            .insert(follow::FollowEntity::new(
+                entity,
+                min_distance,
+                max_distance,
+            ));
+    }
+    fn stop_following(&self) {
+        let mut ecs = self.ecs.write();
+        ecs.entity_mut(self.entity).remove::<follow::FollowEntity>();
+        ecs.write_message(StopPathfindingEvent {
+            entity: self.entity,
+            force: false,
+        });
+    }
 
     async fn wait_until_goto_target_reached(&self) {
         // we do this to make sure the event got handled before we start checking
@@ -295,6 +354,7 @@ pub fn goto_listener(
         Option<&CustomPathfinderState>,
     )>,
     worlds: Res<Worlds>,
+    costs: Res<PathfinderCosts>,
 ) {
     let thread_pool = AsyncComputeTaskPool::get();
 
@@ -377,6 +437,7 @@ pub fn goto_listener(
         });
 
         let custom_state = custom_state.cloned().unwrap_or_default();
+        let costs = *costs;
         let opts = event.opts.clone();
         let task = thread_pool.spawn(async move {
             calculate_path(CalculatePathCtx {
@@ -387,6 +448,7 @@ pub fn goto_listener(
                 goto_id_atomic,
                 mining_cache,
                 custom_state,
+                costs,
                 opts,
             })
         });
@@ -414,6 +476,7 @@ pub struct CalculatePathCtx {
     pub goto_id_atomic: Arc<AtomicUsize>,
     pub mining_cache: MiningCache,
     pub custom_state: CustomPathfinderState,
+    pub costs: PathfinderCosts,
 
     pub opts: PathfinderOpts,
 }
@@ -438,6 +501,7 @@ pub fn calculate_path(ctx: CalculatePathCtx) -> Option<PathFoundEvent> {
             &cached_world,
             &ctx.mining_cache,
             &ctx.custom_state.0.read(),
+            &ctx.costs,
             ctx.opts.successors_fn,
             pos,
         )
@@ -449,6 +513,7 @@ pub fn calculate_path(ctx: CalculatePathCtx) -> Option<PathFoundEvent> {
         movements,
         is_partial,
         cost,
+        nodes_expanded,
     } = a_star(
         RelBlockPos::get_origin(origin),
         |n| ctx.goal.heuristic(n.apply(origin)),
@@ -524,6 +589,7 @@ pub fn calculate_path(ctx: CalculatePathCtx) -> Option<PathFoundEvent> {
         is_partial,
         successors_fn: ctx.opts.successors_fn,
         allow_mining: ctx.opts.allow_mining,
+        nodes_expanded,
     })
 }
 
@@ -557,7 +623,9 @@ pub fn path_found_listener(
         Option<&CustomPathfinderState>,
     )>,
     worlds: Res<Worlds>,
+    costs: Res<PathfinderCosts>,
     mut commands: Commands,
+    mut path_not_found_events: MessageWriter<PathNotFoundEvent>,
 ) {
     for event in events.read() {
         let Ok((mut pathfinder, executing_path, world_name, inventory, custom_state)) =
@@ -566,6 +634,18 @@ pub fn path_found_listener(
             debug!("got path found event for an entity that can't pathfind");
             continue;
         };
+
+        let found_nothing = event
+            .path
+            .as_ref()
+            .is_none_or(|path| path.is_empty() && event.is_partial);
+        if found_nothing && let Some(goal) = &pathfinder.goal {
+            path_not_found_events.write(PathNotFoundEvent {
+                entity: event.entity,
+                goal: goal.clone(),
+                nodes_expanded: event.nodes_expanded,
+            });
+        }
         if let Some(path) = &event.path {
             if let Some(mut executing_path) = executing_path {
                 let mut new_path = VecDeque::new();
@@ -591,6 +671,7 @@ pub fn path_found_listener(
                             &cached_world,
                             &mining_cache,
                             &custom_state_ref,
+                            &costs,
                             successors_fn,
                             pos,
                         )
@@ -726,6 +807,7 @@ pub fn call_successors_fn(
     cached_world: &CachedWorld,
     mining_cache: &MiningCache,
     custom_state: &CustomPathfinderStateRef,
+    costs: &PathfinderCosts,
     successors_fn: SuccessorsFn,
     pos: RelBlockPos,
 ) -> Vec<astar::Edge<RelBlockPos, moves::MoveData>> {
@@ -735,6 +817,7 @@ pub fn call_successors_fn(
         world: cached_world,
         mining_cache,
         custom_state,
+        costs,
     };
     successors_fn(&mut ctx, pos);
     edges
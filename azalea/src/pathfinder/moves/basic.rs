@@ -21,7 +21,7 @@ pub fn basic_move(ctx: &mut MovesCtx, node: RelBlockPos) {
 }
 
 fn forward_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
-    let mut base_cost = SPRINT_ONE_BLOCK_COST;
+    let mut base_cost = SPRINT_ONE_BLOCK_COST * ctx.costs.walk;
     // it's for us cheaper to have the water cost be applied when leaving the water
     // rather than when entering
     let currently_in_water = ctx.world.is_block_water(pos);
@@ -31,6 +31,7 @@ fn forward_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
         } else {
             base_cost = WALK_ONE_IN_WATER_COST;
         }
+        base_cost *= ctx.costs.swim;
     }
 
     for dir in CardinalDirection::iter() {
@@ -53,7 +54,7 @@ fn forward_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
             continue;
         }
 
-        let cost = base_cost + break_cost;
+        let cost = base_cost + break_cost * ctx.costs.mining;
 
         ctx.edges.push(Edge {
             movement: astar::Movement {
@@ -111,8 +112,9 @@ fn ascend_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
         return;
     }
 
-    let base_cost =
-        f32::max(WALK_ONE_BLOCK_COST, *JUMP_ONE_BLOCK_COST) + JUMP_PENALTY + break_cost_1;
+    let base_cost = (f32::max(WALK_ONE_BLOCK_COST, *JUMP_ONE_BLOCK_COST) + JUMP_PENALTY)
+        * ctx.costs.jump
+        + break_cost_1 * ctx.costs.mining;
 
     for dir in CardinalDirection::iter() {
         if let Some(stair_facing) = stair_facing {
@@ -129,7 +131,7 @@ fn ascend_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
             continue;
         }
 
-        let cost = base_cost + break_cost_2;
+        let cost = base_cost + break_cost_2 * ctx.costs.mining;
 
         ctx.edges.push(Edge {
             movement: astar::Movement {
@@ -274,7 +276,8 @@ fn descend_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
         let new_position = new_horizontal_position.down(fall_distance as i32);
 
         // only mine if we're descending 1 block
-        let mut break_cost_2;
+        let break_cost_2;
+        let mut enter_water_cost = 0.;
         if into_water {
             break_cost_2 = ctx
                 .world
@@ -282,7 +285,7 @@ fn descend_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
             if break_cost_2 == f32::INFINITY {
                 continue;
             }
-            break_cost_2 += ENTER_WATER_PENALTY;
+            enter_water_cost = ENTER_WATER_PENALTY * ctx.costs.swim;
         } else if fall_distance == 1 {
             break_cost_2 = ctx.world.cost_for_standing(new_position, ctx.mining_cache);
             if break_cost_2 == f32::INFINITY {
@@ -300,15 +303,15 @@ fn descend_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
             fall_distance += 1;
         }
 
-        let cost = WALK_OFF_BLOCK_COST
+        let cost = WALK_OFF_BLOCK_COST * ctx.costs.walk
             + f32::max(
                 *FALL_N_BLOCKS_COST
                     .get(fall_distance as usize)
                     .expect("already checked bounds on fall distance"),
                 CENTER_AFTER_FALL_COST,
-            )
-            + break_cost_1
-            + break_cost_2;
+            ) * ctx.costs.fall
+            + (break_cost_1 + break_cost_2) * ctx.costs.mining
+            + enter_water_cost;
 
         ctx.edges.push(Edge {
             movement: astar::Movement {
@@ -394,7 +397,7 @@ pub fn descend_is_reached(
 }
 
 fn diagonal_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
-    let mut base_cost = SPRINT_ONE_BLOCK_COST;
+    let mut base_cost = SPRINT_ONE_BLOCK_COST * ctx.costs.walk;
 
     let currently_in_water = ctx.world.is_block_water(pos);
     if currently_in_water {
@@ -403,6 +406,7 @@ fn diagonal_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
         } else {
             base_cost = WALK_ONE_IN_WATER_COST;
         }
+        base_cost *= ctx.costs.swim;
     }
 
     // add 0.001 as a tie-breaker to avoid unnecessarily going diagonal
@@ -453,9 +457,9 @@ fn diagonal_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
         if !left_passable || !right_passable {
             if !BARITONE_COMPAT {
                 // add a bit of cost because it'll probably be hugging a wall here
-                cost += WALK_ONE_BLOCK_COST / 2.;
+                cost += WALK_ONE_BLOCK_COST / 2. * ctx.costs.walk;
             } else {
-                cost = WALK_ONE_BLOCK_COST * (SQRT_2 - 0.001) * SQRT_2;
+                cost = WALK_ONE_BLOCK_COST * (SQRT_2 - 0.001) * SQRT_2 * ctx.costs.walk;
             }
         }
 
@@ -494,7 +498,7 @@ fn downward_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
         return;
     }
 
-    let cost = FALL_N_BLOCKS_COST[1] + break_cost;
+    let cost = FALL_N_BLOCKS_COST[1] * ctx.costs.fall + break_cost * ctx.costs.mining;
 
     ctx.edges.push(Edge {
         movement: astar::Movement {
@@ -22,6 +22,7 @@ use tracing::debug;
 
 use super::{
     astar,
+    costs::PathfinderCosts,
     custom_state::CustomPathfinderStateRef,
     mining::MiningCache,
     positions::RelBlockPos,
@@ -264,4 +265,5 @@ pub struct MovesCtx<'a> {
     pub world: &'a CachedWorld,
     pub mining_cache: &'a MiningCache,
     pub custom_state: &'a CustomPathfinderStateRef,
+    pub costs: &'a PathfinderCosts,
 }
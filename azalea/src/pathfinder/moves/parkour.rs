@@ -56,7 +56,7 @@ fn parkour_forward_1_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
         if !ctx.world.is_block_passable((pos + offset).up(2)) {
             continue;
         }
-        let cost = JUMP_PENALTY + WALK_ONE_BLOCK_COST * 2.;
+        let cost = JUMP_PENALTY * ctx.costs.jump + WALK_ONE_BLOCK_COST * 2. * ctx.costs.walk;
 
         ctx.edges.push(Edge {
             movement: astar::Movement {
@@ -84,7 +84,7 @@ fn parkour_forward_2_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
             continue;
         }
 
-        let mut cost = JUMP_PENALTY + WALK_ONE_BLOCK_COST * 3.;
+        let mut cost = JUMP_PENALTY * ctx.costs.jump + WALK_ONE_BLOCK_COST * 3. * ctx.costs.walk;
 
         let ascend: i32 = if ctx.world.is_standable(pos + offset.up(1)) {
             1
@@ -92,7 +92,7 @@ fn parkour_forward_2_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
             0
         } else if ctx.world.is_standable(pos + offset.down(1)) {
             // we mostly don't really wait for falling during parkour except here
-            cost += FALL_N_BLOCKS_COST[2] / 2.;
+            cost += FALL_N_BLOCKS_COST[2] / 2. * ctx.costs.fall;
             -1
         } else {
             continue;
@@ -166,7 +166,7 @@ fn parkour_forward_3_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
             continue;
         }
 
-        let cost = JUMP_PENALTY + SPRINT_ONE_BLOCK_COST * 4.;
+        let cost = JUMP_PENALTY * ctx.costs.jump + SPRINT_ONE_BLOCK_COST * 4. * ctx.costs.walk;
 
         ctx.edges.push(Edge {
             movement: astar::Movement {
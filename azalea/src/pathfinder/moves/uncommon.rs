@@ -46,8 +46,7 @@ pub fn descend_forward_1_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
             continue;
         }
 
-        let cost = WALK_OFF_BLOCK_COST
-            + WALK_ONE_BLOCK_COST
+        let cost = (WALK_OFF_BLOCK_COST + WALK_ONE_BLOCK_COST) * ctx.costs.walk
             + f32::max(
                 FALL_N_BLOCKS_COST
                     .get(fall_distance as usize)
@@ -56,7 +55,7 @@ pub fn descend_forward_1_move(ctx: &mut MovesCtx, pos: RelBlockPos) {
                     // probably not possible but just in case
                     .unwrap_or(f32::INFINITY),
                 CENTER_AFTER_FALL_COST,
-            );
+            ) * ctx.costs.fall;
 
         ctx.edges.push(Edge {
             movement: astar::Movement {
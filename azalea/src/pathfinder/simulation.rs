@@ -3,14 +3,14 @@
 use std::sync::Arc;
 
 use azalea_client::{
-    PhysicsState, interact::BlockStatePredictionHandler, local_player::LocalGameMode,
-    mining::MineBundle,
+    PhysicsState, WalkDirection, interact::BlockStatePredictionHandler,
+    local_player::LocalGameMode, mining::MineBundle,
 };
 use azalea_core::{
     entity_id::MinecraftEntityId, game_type::GameMode, position::Vec3, tick::GameTick,
 };
 use azalea_entity::{
-    Attributes, LookDirection, Physics, Position, dimensions::EntityDimensions,
+    Attributes, Jumping, LookDirection, Physics, Position, dimensions::EntityDimensions,
     inventory::Inventory,
 };
 use azalea_registry::builtin::EntityKind;
@@ -173,6 +173,83 @@ impl Simulation {
             .and_then(|c| *c)
             .is_some()
     }
+
+    /// Make the simulated player hold down the given inputs, starting from the
+    /// next tick.
+    pub fn set_input(&mut self, input: SimulationInput) {
+        let mut entity = self.app.world_mut().entity_mut(self.entity);
+        let mut physics_state = entity.get_mut::<PhysicsState>().unwrap();
+        physics_state.move_direction = input.walk_direction;
+        physics_state.trying_to_sprint = input.sprinting;
+        physics_state.trying_to_crouch = input.crouching;
+        **entity.get_mut::<Jumping>().unwrap() = input.jumping;
+        if let Some(look_direction) = input.look_direction {
+            *entity.get_mut::<LookDirection>().unwrap() = look_direction;
+        }
+    }
+}
+
+/// The inputs that a simulated player is holding down during a tick.
+///
+/// See [`simulate`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SimulationInput {
+    pub walk_direction: WalkDirection,
+    /// Whether we're trying to sprint. This only does anything if we're also
+    /// walking forwards.
+    pub sprinting: bool,
+    pub jumping: bool,
+    pub crouching: bool,
+    /// The direction to look in, or `None` to keep looking in the same
+    /// direction as the last tick.
+    pub look_direction: Option<LookDirection>,
+}
+
+/// Run the physics for a simulated player in the given world for a fixed
+/// number of ticks, and return the [`Simulation`] so the resulting position
+/// and velocity can be checked.
+///
+/// `inputs` are the inputs for each tick. If there are more ticks than inputs,
+/// the last input will keep being held down (or no inputs at all, if `inputs`
+/// is empty).
+///
+/// Simulations don't depend on anything outside of the given arguments, so
+/// running the same simulation twice will always give the same result. This
+/// makes it useful for testing the physics.
+///
+/// ```
+/// # use azalea::{
+/// #     BlockPos, Vec3,
+/// #     core::position::ChunkPos,
+/// #     pathfinder::simulation::{SimulatedPlayerBundle, simulate},
+/// #     registry::builtin::BlockKind,
+/// #     world::{Chunk, ChunkStorage, PartialChunkStorage},
+/// # };
+/// // the chunks are only kept loaded while the partial storage is alive
+/// let mut partial_chunks = PartialChunkStorage::default();
+/// let mut chunks = ChunkStorage::default();
+/// partial_chunks.set(&ChunkPos::new(0, 0), Some(Chunk::default()), &mut chunks);
+/// chunks.set_block_state(BlockPos::new(0, 69, 0), BlockKind::Stone.into());
+///
+/// let player = SimulatedPlayerBundle::new(Vec3::new(0.5, 72., 0.5));
+/// let simulation = simulate(chunks, player, &[], 20);
+/// assert_eq!(simulation.position().y, 70.);
+/// assert!(simulation.physics().on_ground());
+/// ```
+pub fn simulate(
+    chunks: ChunkStorage,
+    player: SimulatedPlayerBundle,
+    inputs: &[SimulationInput],
+    ticks: usize,
+) -> Simulation {
+    let mut simulation = Simulation::new(chunks, player);
+    for tick in 0..ticks {
+        if let Some(input) = inputs.get(tick) {
+            simulation.set_input(*input);
+        }
+        simulation.tick();
+    }
+    simulation
 }
 
 /// A set of simulations, useful for efficiently doing multiple simulations.
@@ -201,3 +278,75 @@ impl SimulationSet {
         **self.app.world().get::<Position>(entity).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use azalea_block::{blocks::StoneSlab, properties::Type};
+    use azalea_core::position::{BlockPos, ChunkPos};
+    use azalea_registry::builtin::BlockKind;
+    use azalea_world::{Chunk, PartialChunkStorage};
+
+    use super::*;
+
+    fn make_chunks(
+        partial_chunks: &mut PartialChunkStorage,
+        blocks: &[(BlockPos, azalea_block::BlockState)],
+    ) -> ChunkStorage {
+        let mut chunks = ChunkStorage::default();
+        partial_chunks.set(&ChunkPos::new(0, 0), Some(Chunk::default()), &mut chunks);
+        for (pos, block_state) in blocks {
+            chunks.set_block_state(*pos, *block_state);
+        }
+        chunks
+    }
+
+    #[test]
+    fn test_simulate_fall_onto_slab() {
+        let slab = StoneSlab {
+            kind: Type::Bottom,
+            waterlogged: false,
+        };
+        let mut partial_chunks = PartialChunkStorage::default();
+        let chunks = make_chunks(
+            &mut partial_chunks,
+            &[(BlockPos::new(0, 69, 0), slab.into())],
+        );
+
+        let player = SimulatedPlayerBundle::new(Vec3::new(0.5, 73., 0.5));
+        let simulation = simulate(chunks, player, &[], 30);
+        assert_eq!(simulation.position().y, 69.5);
+        assert!(simulation.physics().on_ground());
+    }
+
+    #[test]
+    fn test_simulate_is_deterministic() {
+        let blocks = (0..16)
+            .flat_map(|x| (0..16).map(move |z| (BlockPos::new(x, 69, z), BlockKind::Stone.into())))
+            .collect::<Vec<_>>();
+        let inputs = [
+            SimulationInput {
+                walk_direction: WalkDirection::Forward,
+                sprinting: true,
+                jumping: true,
+                look_direction: Some(LookDirection::new(0., 0.)),
+                ..Default::default()
+            },
+            SimulationInput {
+                walk_direction: WalkDirection::Forward,
+                ..Default::default()
+            },
+        ];
+
+        let run = || {
+            let mut partial_chunks = PartialChunkStorage::default();
+            let chunks = make_chunks(&mut partial_chunks, &blocks);
+            let player = SimulatedPlayerBundle::new(Vec3::new(8.5, 70., 0.5));
+            let simulation = simulate(chunks, player, &inputs, 15);
+            (simulation.position(), simulation.physics().velocity)
+        };
+        let (position, velocity) = run();
+        // we walked forwards (positive z when looking with a y_rot of 0)
+        assert!(position.z > 1.5, "{position:?}");
+        assert_eq!(run(), (position, velocity));
+    }
+}
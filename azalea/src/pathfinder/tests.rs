@@ -6,15 +6,21 @@ use std::{
 };
 
 use azalea_block::BlockState;
-use azalea_core::position::{BlockPos, ChunkPos};
+use azalea_client::test_utils::simulation::ReceivedMessages;
+use azalea_core::position::{BlockPos, ChunkPos, Vec3};
+use azalea_entity::Position;
 use azalea_registry::builtin::BlockKind;
-use azalea_world::{Chunk, ChunkStorage, PartialChunkStorage};
+use azalea_world::{Chunk, ChunkStorage, PartialChunkStorage, WorldName};
+use bevy_app::Update;
+use bevy_ecs::schedule::IntoScheduleConfigs;
 
 use super::{
-    GotoEvent,
+    GotoEvent, PathFoundEvent, PathNotFoundEvent,
     astar::PathfinderTimeout,
+    costs::PathfinderCosts,
+    follow::FollowEntity,
     goals::BlockPosGoal,
-    moves,
+    moves, path_found_listener,
     simulation::{SimulatedPlayerBundle, Simulation},
 };
 use crate::pathfinder::goto_event::PathfinderOpts;
@@ -298,3 +304,199 @@ fn test_mine_through_non_colliding_block() {
 
     assert_simulation_reaches(&mut simulation, 200, BlockPos::new(0, 70, 0));
 }
+
+#[test]
+fn test_follow_moving_entity() {
+    let mut partial_chunks = PartialChunkStorage::default();
+    let floor = (0..=24)
+        .map(|x| BlockPos::new(x, 70, 0))
+        .collect::<Vec<_>>();
+    let mut simulation =
+        setup_simulation_world(&mut partial_chunks, BlockPos::new(0, 71, 0), &floor, &[]);
+
+    let world_name = simulation.component::<WorldName>();
+    let target = simulation
+        .app
+        .world_mut()
+        .spawn((Position::new(Vec3::new(6.5, 71., 0.5)), world_name))
+        .id();
+    let set_target_x = |simulation: &mut Simulation, x: f64| {
+        *simulation
+            .app
+            .world_mut()
+            .get_mut::<Position>(target)
+            .unwrap() = Position::new(Vec3::new(x, 71., 0.5));
+    };
+    let distance_to_target = |simulation: &Simulation| {
+        let target_position = **simulation.app.world().get::<Position>(target).unwrap();
+        simulation.position().distance_to(target_position)
+    };
+
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(simulation.entity)
+        .insert(
+            FollowEntity::new(target, 1., 4.).opts(
+                PathfinderOpts::new()
+                    .allow_mining(false)
+                    .min_timeout(PathfinderTimeout::Nodes(1_000_000))
+                    .max_timeout(PathfinderTimeout::Nodes(5_000_000)),
+            ),
+        );
+    wait_until_bot_starts_moving(&mut simulation);
+
+    // walk the target along the floor, slower than the bot can walk
+    for x in 7..=18 {
+        set_target_x(&mut simulation, x as f64 + 0.5);
+        for _ in 0..10 {
+            simulation.tick();
+            thread::yield_now();
+        }
+    }
+    for _ in 0..60 {
+        simulation.tick();
+        thread::yield_now();
+    }
+    let distance = distance_to_target(&simulation);
+    assert!(
+        (1. ..=4.).contains(&distance),
+        "bot should be within range of the target, but it's {distance} blocks away"
+    );
+
+    // the target leaving our render distance should pause following instead of
+    // making the bot keep walking towards it
+    set_target_x(&mut simulation, 24.5);
+    simulation.tick();
+    simulation.app.world_mut().despawn(target);
+    for _ in 0..60 {
+        simulation.tick();
+        thread::yield_now();
+    }
+    assert!(simulation.get_component::<FollowEntity>().is_some());
+    assert!(simulation.position().x < 20.);
+}
+
+#[test]
+fn test_path_not_found_when_walled_off() {
+    let mut partial_chunks = PartialChunkStorage::default();
+    let mut solid_blocks = vec![BlockPos::new(0, 70, 0), BlockPos::new(5, 70, 0)];
+    // walls that are too tall to jump over on every side
+    for y in 71..=72 {
+        solid_blocks.extend([
+            BlockPos::new(1, y, 0),
+            BlockPos::new(-1, y, 0),
+            BlockPos::new(0, y, 1),
+            BlockPos::new(0, y, -1),
+        ]);
+    }
+    let mut simulation = setup_simulation_world(
+        &mut partial_chunks,
+        BlockPos::new(0, 71, 0),
+        &solid_blocks,
+        &[],
+    );
+
+    let not_found = ReceivedMessages::<PathNotFoundEvent>::default();
+    simulation
+        .app
+        .add_systems(Update, not_found.system().after(path_found_listener));
+
+    simulation.app.world_mut().write_message(GotoEvent {
+        entity: simulation.entity,
+        goal: Arc::new(BlockPosGoal(BlockPos::new(5, 71, 0))),
+        opts: PathfinderOpts::new()
+            .allow_mining(false)
+            .retry_on_no_path(false)
+            .min_timeout(PathfinderTimeout::Nodes(1_000_000))
+            .max_timeout(PathfinderTimeout::Nodes(5_000_000)),
+    });
+
+    let start_time = Instant::now();
+    while not_found.lock().is_empty() && start_time.elapsed() < Duration::from_millis(5000) {
+        simulation.tick();
+        thread::yield_now();
+    }
+    let not_found = not_found.lock();
+    assert_eq!(not_found.len(), 1);
+    assert_eq!(not_found[0].entity, simulation.entity);
+    assert_eq!(
+        BlockPos::from(simulation.position()),
+        BlockPos::new(0, 71, 0)
+    );
+}
+
+/// Find a path across a pool of water that has a long walkable detour around
+/// it, and return the positions of the nodes in the path.
+fn find_path_across_pool(costs: PathfinderCosts) -> Vec<BlockPos> {
+    let mut partial_chunks = PartialChunkStorage::default();
+    let mut solid_blocks = vec![
+        BlockPos::new(0, 70, 0),
+        BlockPos::new(1, 70, 0),
+        BlockPos::new(7, 70, 0),
+        BlockPos::new(8, 70, 0),
+    ];
+    let mut extra_blocks = Vec::new();
+    // the pool is one block lower than the floor so we can swim in it
+    for x in 2..=6 {
+        solid_blocks.push(BlockPos::new(x, 69, 0));
+        extra_blocks.push((BlockPos::new(x, 70, 0), BlockKind::Water.into()));
+    }
+    // and the detour goes in a big u-shape around the pool
+    for z in 1..=9 {
+        solid_blocks.extend([BlockPos::new(1, 70, z), BlockPos::new(7, 70, z)]);
+    }
+    for x in 2..=6 {
+        solid_blocks.push(BlockPos::new(x, 70, 9));
+    }
+    let mut simulation = setup_simulation_world(
+        &mut partial_chunks,
+        BlockPos::new(0, 71, 0),
+        &solid_blocks,
+        &extra_blocks,
+    );
+    simulation.app.insert_resource(costs);
+
+    let found_paths = ReceivedMessages::<PathFoundEvent>::default();
+    simulation
+        .app
+        .add_systems(Update, found_paths.system().after(path_found_listener));
+    let found_path = || {
+        found_paths
+            .lock()
+            .iter()
+            .find_map(|event| event.path.as_ref())
+            .map(|path| path.iter().map(|e| e.movement.target).collect::<Vec<_>>())
+    };
+
+    simulation.app.world_mut().write_message(GotoEvent {
+        entity: simulation.entity,
+        goal: Arc::new(BlockPosGoal(BlockPos::new(8, 71, 0))),
+        opts: PathfinderOpts::new()
+            .allow_mining(false)
+            .min_timeout(PathfinderTimeout::Nodes(1_000_000))
+            .max_timeout(PathfinderTimeout::Nodes(5_000_000)),
+    });
+
+    let start_time = Instant::now();
+    while found_path().is_none() && start_time.elapsed() < Duration::from_millis(5000) {
+        simulation.tick();
+        thread::yield_now();
+    }
+    found_path().expect("pathfinder should've found a path")
+}
+
+#[test]
+fn test_avoid_water_costs_route_around_pool() {
+    let is_in_pool = |pos: &BlockPos| pos.y == 70 && pos.z == 0 && (2..=6).contains(&pos.x);
+
+    // swimming straight through is cheaper than the detour by default
+    let path = find_path_across_pool(PathfinderCosts::default());
+    assert!(path.iter().any(is_in_pool), "{path:?}");
+    assert_eq!(path.last(), Some(&BlockPos::new(8, 71, 0)));
+
+    let path = find_path_across_pool(PathfinderCosts::avoid_water());
+    assert!(!path.iter().any(is_in_pool), "{path:?}");
+    assert!(path.contains(&BlockPos::new(4, 71, 9)), "{path:?}");
+    assert_eq!(path.last(), Some(&BlockPos::new(8, 71, 0)));
+}
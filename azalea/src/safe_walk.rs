@@ -0,0 +1,179 @@
+use azalea_block::{
+    BlockState,
+    fluid_state::{FluidKind, FluidState},
+};
+use azalea_core::{direction::CardinalDirection, position::BlockPos};
+use azalea_registry::builtin::BlockKind;
+use azalea_world::World;
+
+use crate::Client;
+
+/// The number of blocks that a player can fall without taking any damage.
+pub const SAFE_FALL_DISTANCE: i32 = 3;
+
+impl Client {
+    /// Returns whether walking one block in the given direction won't make us
+    /// fall more than [`SAFE_FALL_DISTANCE`] blocks or walk into something
+    /// dangerous like lava.
+    ///
+    /// See [`is_safe_to_step`] for more details.
+    ///
+    /// ```
+    /// # use azalea::core::direction::CardinalDirection;
+    /// # fn example(bot: &azalea::Client) {
+    /// if bot.is_safe_to_step(CardinalDirection::North) {
+    ///     bot.walk(azalea::WalkDirection::Forward);
+    /// }
+    /// # }
+    /// ```
+    pub fn is_safe_to_step(&self, direction: CardinalDirection) -> bool {
+        let position = BlockPos::from(self.position());
+        is_safe_to_step(&self.world().read(), position, direction)
+    }
+}
+
+/// Returns whether an entity with its feet at `position` could walk one block
+/// in the given direction without falling more than [`SAFE_FALL_DISTANCE`]
+/// blocks or touching a hazard.
+///
+/// Walking into a wall is considered safe, since we won't end up moving. Water
+/// is considered safe to land in no matter how far the fall is, and lava and
+/// other blocks that hurt us (see [`is_block_state_hazard`]) are never safe.
+///
+/// Unloaded blocks are considered unsafe.
+pub fn is_safe_to_step(world: &World, position: BlockPos, direction: CardinalDirection) -> bool {
+    let target = position.offset_with_direction(direction.into());
+
+    for pos in [target, target.up(1)] {
+        let Some(block_state) = world.get_block_state(pos) else {
+            return false;
+        };
+        if is_block_state_hazard(block_state) {
+            return false;
+        }
+    }
+    let Some(block_state) = world.get_block_state(target) else {
+        return false;
+    };
+    if !block_state.is_collision_shape_empty() {
+        // there's a wall in the way (or a block that we'd step up onto)
+        return true;
+    }
+
+    // look for the block that we'd land on. this stops at the bottom of the world
+    // since get_block_state returns None there
+    let mut fall_distance = 0;
+    while let Some(block_state) = world.get_block_state(target.down(fall_distance + 1)) {
+        if is_block_state_hazard(block_state) {
+            return false;
+        }
+        if FluidState::from(block_state).kind == FluidKind::Water {
+            // landing in water cancels fall damage
+            return true;
+        }
+        if !block_state.is_collision_shape_empty() {
+            return fall_distance <= SAFE_FALL_DISTANCE;
+        }
+        fall_distance += 1;
+    }
+
+    false
+}
+
+/// Returns whether touching or standing on this block hurts us, like lava,
+/// fire, or magma blocks.
+pub fn is_block_state_hazard(block_state: BlockState) -> bool {
+    if block_state.is_air() {
+        // fast path
+        return false;
+    }
+
+    if FluidState::from(block_state).kind == FluidKind::Lava {
+        return true;
+    }
+
+    matches!(
+        BlockKind::from(block_state),
+        BlockKind::Fire
+            | BlockKind::SoulFire
+            | BlockKind::MagmaBlock
+            | BlockKind::Campfire
+            | BlockKind::SoulCampfire
+            | BlockKind::Cactus
+            | BlockKind::SweetBerryBush
+            | BlockKind::WitherRose
+            | BlockKind::PowderSnow
+            | BlockKind::PointedDripstone
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use azalea_core::position::ChunkPos;
+    use azalea_world::{Chunk, ChunkStorage, PartialWorld};
+
+    use super::*;
+
+    fn make_world(blocks: &[(BlockPos, BlockKind)]) -> World {
+        let mut partial_world = PartialWorld::default();
+        let mut world = ChunkStorage::default();
+        partial_world
+            .chunks
+            .set(&ChunkPos { x: 0, z: 0 }, Some(Chunk::default()), &mut world);
+        for &(pos, block) in blocks {
+            partial_world
+                .chunks
+                .set_block_state(pos, block.into(), &world);
+        }
+        world.into()
+    }
+
+    #[test]
+    fn test_stepping_off_ledge_is_unsafe() {
+        // a pillar of stone that we're standing on top of
+        let world = make_world(&[
+            (BlockPos::new(1, 0, 1), BlockKind::Stone),
+            (BlockPos::new(1, 1, 1), BlockKind::Stone),
+            (BlockPos::new(1, 2, 1), BlockKind::Stone),
+            (BlockPos::new(1, 3, 1), BlockKind::Stone),
+            (BlockPos::new(1, 4, 1), BlockKind::Stone),
+            (BlockPos::new(1, 5, 1), BlockKind::Stone),
+            // the ground that we're standing on continues to the east
+            (BlockPos::new(2, 5, 1), BlockKind::Stone),
+        ]);
+        let position = BlockPos::new(1, 6, 1);
+
+        assert!(is_safe_to_step(&world, position, CardinalDirection::East));
+        assert!(!is_safe_to_step(&world, position, CardinalDirection::West));
+        assert!(!is_safe_to_step(&world, position, CardinalDirection::North));
+    }
+
+    #[test]
+    fn test_short_fall_is_safe() {
+        let world = make_world(&[
+            (BlockPos::new(1, 4, 1), BlockKind::Stone),
+            (BlockPos::new(2, 1, 1), BlockKind::Stone),
+            (BlockPos::new(0, 0, 1), BlockKind::Stone),
+        ]);
+        let position = BlockPos::new(1, 5, 1);
+
+        // falling 3 blocks doesn't hurt
+        assert!(is_safe_to_step(&world, position, CardinalDirection::East));
+        // but falling 4 blocks does
+        assert!(!is_safe_to_step(&world, position, CardinalDirection::West));
+    }
+
+    #[test]
+    fn test_lava_and_water() {
+        let world = make_world(&[
+            (BlockPos::new(1, 9, 1), BlockKind::Stone),
+            (BlockPos::new(0, 9, 1), BlockKind::Lava),
+            (BlockPos::new(2, 0, 1), BlockKind::Water),
+        ]);
+        let position = BlockPos::new(1, 10, 1);
+
+        assert!(!is_safe_to_step(&world, position, CardinalDirection::West));
+        // a long fall into water is fine
+        assert!(is_safe_to_step(&world, position, CardinalDirection::East));
+    }
+}
@@ -20,7 +20,7 @@ use tracing::{debug, error, warn};
 
 use crate::{
     BoxHandleFn, HandleFn, JoinOpts, NoState,
-    auto_reconnect::{AutoReconnectDelay, DEFAULT_RECONNECT_DELAY},
+    auto_reconnect::{AutoReconnectDelay, AutoReconnectPolicy, DEFAULT_RECONNECT_DELAY},
     bot::DefaultBotPlugins,
     swarm::{
         BoxSwarmHandleFn, DefaultSwarmPlugins, NoSwarmState, Swarm, SwarmEvent, SwarmHandleFn,
@@ -367,11 +367,23 @@ where
     /// If this function isn't called, then our clients will reconnect after
     /// [`DEFAULT_RECONNECT_DELAY`].
     #[must_use]
+    #[doc(alias = "reconnect_delay")]
     pub fn reconnect_after(mut self, delay: impl Into<Option<Duration>>) -> Self {
         self.reconnect_after = delay.into();
         self
     }
 
+    /// Set which disconnects our bots will reconnect after, how many times
+    /// they'll try, and how the delay increases after failed attempts.
+    ///
+    /// This sets the [`AutoReconnectPolicy`] resource, which can also be
+    /// inserted as a component to change it for a single bot.
+    #[must_use]
+    pub fn reconnect_policy(mut self, policy: AutoReconnectPolicy) -> Self {
+        self.app.insert_resource(policy);
+        self
+    }
+
     /// Build this `SwarmBuilder` into an actual [`Swarm`] and join the given
     /// server.
     ///
@@ -495,12 +507,16 @@ where
             task::spawn_local(async move {
                 if let Some(join_delay) = join_delay {
                     // if there's a join delay, then join one by one
-                    for ((account, bot_join_opts), state) in accounts.iter().zip(states) {
-                        let mut join_opts = join_opts.clone();
-                        join_opts.update(bot_join_opts);
-                        let _ = swarm_clone.add_with_opts(account, state, &join_opts).await;
-                        tokio::time::sleep(join_delay).await;
-                    }
+                    join_one_by_one(
+                        accounts.iter().zip(states),
+                        join_delay,
+                        async |((account, bot_join_opts), state)| {
+                            let mut join_opts = join_opts.clone();
+                            join_opts.update(bot_join_opts);
+                            let _ = swarm_clone.add_with_opts(account, state, &join_opts).await;
+                        },
+                    )
+                    .await;
                 } else {
                     // otherwise, join all at once
                     let swarm_borrow = &swarm_clone;
@@ -604,8 +620,42 @@ where
     }
 }
 
+/// Join every bot in order, waiting for each one to finish joining and then
+/// waiting for the `join_delay` before joining the next one.
+async fn join_one_by_one<T>(
+    bots: impl IntoIterator<Item = T>,
+    join_delay: Duration,
+    mut join: impl AsyncFnMut(T),
+) {
+    for bot in bots {
+        join(bot).await;
+        tokio::time::sleep(join_delay).await;
+    }
+}
+
 impl Default for SwarmBuilder<NoState, NoSwarmState, (), ()> {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tokio::time::Instant;
+
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_join_delay() {
+        let start = Instant::now();
+        let mut join_times = Vec::new();
+        join_one_by_one(["bot0", "bot1"], Duration::from_millis(100), async |_| {
+            join_times.push(start.elapsed());
+            // pretend that joining takes a while
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        })
+        .await;
+
+        assert_eq!(join_times, [Duration::ZERO, Duration::from_millis(110)]);
+    }
+}
@@ -6,25 +6,37 @@ mod builder;
 mod chat;
 mod events;
 pub mod prelude;
+mod shared_state;
 
-use std::sync::{
-    Arc,
-    atomic::{self, AtomicBool},
+use std::{
+    any::Any,
+    collections::HashMap,
+    fmt::{self, Debug},
+    sync::{
+        Arc,
+        atomic::{self, AtomicBool},
+    },
 };
 
-use azalea_client::{account::Account, chat::ChatPacket, join::ConnectOpts};
+use azalea_client::{
+    InGameState,
+    account::Account,
+    chat::{ChatPacket, SendChatEvent},
+    join::ConnectOpts,
+};
 use azalea_entity::LocalEntity;
 use azalea_protocol::address::ResolvedAddr;
-use azalea_world::Worlds;
+use azalea_world::{WorldName, Worlds};
 use bevy_app::{AppExit, PluginGroup, PluginGroupBuilder};
 use bevy_ecs::prelude::*;
 pub use builder::SwarmBuilder;
 use futures::future::BoxFuture;
 use parking_lot::RwLock;
+pub use shared_state::SharedState;
 use tokio::{sync::mpsc, task};
 use tracing::{debug, error, warn};
 
-use crate::{Client, JoinOpts, client_impl::StartClientOpts};
+use crate::{Client, JoinOpts, client_impl::StartClientOpts, events::LocalPlayerEvents};
 
 /// A swarm is a way to conveniently control many bots at once, while also
 /// being able to control bots at an individual level when desired.
@@ -177,6 +189,7 @@ impl Swarm {
         }
         let server_proxy = join_opts.server_proxy.clone();
         let sessionserver_proxy = join_opts.sessionserver_proxy.clone();
+        let client_information = join_opts.client_information.clone();
 
         let (tx, rx) = mpsc::unbounded_channel();
 
@@ -187,6 +200,8 @@ impl Swarm {
                 address,
                 server_proxy,
                 sessionserver_proxy,
+                rng_seed: None,
+                client_information,
             },
             event_sender: Some(tx),
         })
@@ -292,6 +307,121 @@ impl Swarm {
         query.iter(&ecs).collect::<Box<[Entity]>>()
     }
 
+    /// Get the ECS [`Entity`]s for all of our clients, grouped by the name of
+    /// the world that they're in.
+    ///
+    /// Use [`Worlds::world_names`] on [`Self::worlds`] if you only need the
+    /// names of the worlds.
+    pub fn clients_by_world(&self) -> HashMap<WorldName, Vec<Entity>> {
+        let mut ecs = self.ecs.write();
+        let mut query = ecs.query_filtered::<(Entity, &WorldName), With<LocalEntity>>();
+        let mut clients_by_world = HashMap::<WorldName, Vec<Entity>>::new();
+        for (entity, world_name) in query.iter(&ecs) {
+            clients_by_world
+                .entry(world_name.clone())
+                .or_default()
+                .push(entity);
+        }
+        clients_by_world
+    }
+
+    /// Send a message to every bot in the swarm, which they'll receive as an
+    /// [`Event::Broadcast`] in their handler.
+    ///
+    /// The message can be any type, and bots can get it back with
+    /// [`BroadcastMessage::downcast_ref`].
+    ///
+    /// Every bot that's currently in the swarm will receive the message exactly
+    /// once (including bots that are disconnected but waiting to reconnect),
+    /// and bots that are added later won't receive it. Each bot receives it in
+    /// order with its other events, but since handlers run concurrently there's
+    /// no guarantee about which bot handles it first.
+    ///
+    /// Returns the number of bots that the message was sent to.
+    ///
+    /// ```
+    /// # use azalea::{prelude::*, swarm::prelude::*};
+    /// struct GoTo(azalea::BlockPos);
+    ///
+    /// # fn example(swarm: Swarm) {
+    /// swarm.broadcast(GoTo(azalea::BlockPos::new(0, 70, 0)));
+    /// # }
+    ///
+    /// async fn handle(bot: Client, event: Event, _state: azalea::NoState) -> anyhow::Result<()> {
+    ///     if let Event::Broadcast(message) = event
+    ///         && let Some(GoTo(pos)) = message.downcast_ref::<GoTo>()
+    ///     {
+    ///         println!("{} is going to {pos}", bot.username());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Event::Broadcast`]: crate::Event::Broadcast
+    pub fn broadcast<T: Any + Send + Sync>(&self, message: T) -> usize {
+        let message = BroadcastMessage(Arc::new(message));
+
+        let mut ecs = self.ecs.write();
+        let mut query = ecs.query::<&LocalPlayerEvents>();
+        let mut sent_count = 0;
+        for local_player_events in query.iter(&ecs) {
+            if local_player_events
+                .send(crate::Event::Broadcast(message.clone()))
+                .is_ok()
+            {
+                sent_count += 1;
+            }
+        }
+        sent_count
+    }
+
+    /// Run a function for every bot in the swarm that's in the game state.
+    ///
+    /// Bots that are still connecting or in the configuration state are
+    /// skipped. The ECS is only locked once, so this is cheaper than iterating
+    /// over the swarm and calling methods on each [`Client`], but it also means
+    /// that the function can't call methods on [`Client`] or [`Swarm`] without
+    /// deadlocking.
+    ///
+    /// Returns the number of bots that the function was called for.
+    ///
+    /// ```
+    /// # use azalea::{entity::Jumping, prelude::*, swarm::prelude::*};
+    /// # fn example(swarm: Swarm) {
+    /// swarm.for_each(|ecs, bot| {
+    ///     if let Some(mut jumping) = ecs.get_mut::<Jumping>(bot) {
+    ///         **jumping = true;
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn for_each(&self, mut f: impl FnMut(&mut World, Entity)) -> usize {
+        let mut ecs = self.ecs.write();
+        let mut query = ecs.query_filtered::<Entity, (With<LocalEntity>, With<InGameState>)>();
+        let entities = query.iter(&ecs).collect::<Box<[Entity]>>();
+        for &entity in &entities {
+            f(&mut ecs, entity);
+        }
+        entities.len()
+    }
+
+    /// Send a chat message (or command, if it starts with a slash) from every
+    /// bot in the swarm that's in the game state.
+    ///
+    /// This works like calling [`Client::chat`] on every bot, but without
+    /// locking the ECS for each one. See [`Self::for_each`] for details about
+    /// which bots are included.
+    ///
+    /// Returns the number of bots that the message was sent from.
+    pub fn broadcast_chat(&self, message: &str) -> usize {
+        self.for_each(|ecs, entity| {
+            ecs.write_message(SendChatEvent {
+                entity,
+                content: message.to_owned(),
+            });
+        })
+    }
+
     /// End the entire swarm and return from [`SwarmBuilder::start`].
     ///
     /// You should typically avoid calling this if you intend on creating the
@@ -336,6 +466,27 @@ impl IntoIterator for Swarm {
     }
 }
 
+/// A message that was sent to every bot with [`Swarm::broadcast`].
+///
+/// Cloning this is cheap, since the message is behind an [`Arc`].
+#[derive(Clone)]
+pub struct BroadcastMessage(Arc<dyn Any + Send + Sync>);
+impl BroadcastMessage {
+    /// Get the message if it's of the given type.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+    /// Returns whether the message is of the given type.
+    pub fn is<T: Any>(&self) -> bool {
+        self.0.is::<T>()
+    }
+}
+impl Debug for BroadcastMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BroadcastMessage").finish_non_exhaustive()
+    }
+}
+
 /// This plugin group will add all the default plugins necessary for swarms to
 /// work.
 pub struct DefaultSwarmPlugins;
@@ -354,3 +505,135 @@ impl PluginGroup for DefaultSwarmPlugins {
 /// it for you.
 #[derive(Clone, Default, Resource)]
 pub struct NoSwarmState;
+
+#[cfg(test)]
+mod tests {
+    use azalea_client::{
+        InConfigState, chat::ChatPlugin, packet::game::SendGamePacketEvent,
+        test_utils::simulation::ReceivedMessages,
+    };
+    use azalea_protocol::{address::ServerAddr, packets::game::ServerboundGamePacket};
+    use bevy_app::{App, Update};
+
+    use super::*;
+
+    fn create_swarm(ecs: World, worlds: Worlds) -> Swarm {
+        let (bots_tx, _bots_rx) = mpsc::unbounded_channel();
+        let (swarm_tx, _swarm_rx) = mpsc::unbounded_channel();
+        Swarm {
+            ecs: Arc::new(RwLock::new(ecs)),
+            address: Arc::new(RwLock::new(ResolvedAddr {
+                server: ServerAddr {
+                    host: "localhost".to_owned(),
+                    port: 25565,
+                },
+                socket: "127.0.0.1:25565".parse().unwrap(),
+            })),
+            worlds: Arc::new(RwLock::new(worlds)),
+            bots_tx,
+            swarm_tx,
+        }
+    }
+
+    #[test]
+    fn test_broadcast() {
+        let mut ecs = World::new();
+        let (tx_a, mut rx_a) = mpsc::unbounded_channel();
+        let (tx_b, mut rx_b) = mpsc::unbounded_channel();
+        ecs.spawn(LocalPlayerEvents(tx_a));
+        ecs.spawn(LocalPlayerEvents(tx_b));
+
+        let swarm = create_swarm(ecs, Worlds::default());
+
+        #[derive(Debug, PartialEq)]
+        struct GoToSpawn;
+        assert_eq!(swarm.broadcast(GoToSpawn), 2);
+
+        for rx in [&mut rx_a, &mut rx_b] {
+            let Ok(crate::Event::Broadcast(message)) = rx.try_recv() else {
+                panic!("expected a broadcast event");
+            };
+            assert_eq!(message.downcast_ref::<GoToSpawn>(), Some(&GoToSpawn));
+            assert!(!message.is::<String>());
+            assert!(rx.try_recv().is_err());
+        }
+    }
+
+    #[test]
+    fn test_clients_by_world() {
+        let overworld = WorldName::new("minecraft:overworld");
+        let nether = WorldName::new("minecraft:the_nether");
+
+        let mut ecs = World::new();
+        let a = ecs.spawn((LocalEntity, overworld.clone())).id();
+        let b = ecs.spawn((LocalEntity, nether.clone())).id();
+        let c = ecs.spawn((LocalEntity, overworld.clone())).id();
+        // not one of our clients
+        ecs.spawn(overworld.clone());
+
+        let mut worlds = Worlds::default();
+        let _overworld_lock =
+            worlds.get_or_insert(overworld.clone(), 384, -64, &Default::default());
+        let _nether_lock = worlds.get_or_insert(nether.clone(), 256, 0, &Default::default());
+        // nobody is holding onto this world, so it shouldn't be included
+        let _ = worlds.get_or_insert(
+            WorldName::new("minecraft:the_end"),
+            256,
+            0,
+            &Default::default(),
+        );
+
+        let swarm = create_swarm(ecs, worlds);
+
+        let mut world_names = swarm
+            .worlds
+            .read()
+            .world_names()
+            .cloned()
+            .collect::<Vec<_>>();
+        world_names.sort_by_key(|name| name.to_string());
+        assert_eq!(world_names, [overworld.clone(), nether.clone()]);
+
+        let mut clients_by_world = swarm.clients_by_world();
+        assert_eq!(clients_by_world.len(), 2);
+        let mut overworld_clients = clients_by_world.remove(&overworld).unwrap();
+        overworld_clients.sort();
+        assert_eq!(overworld_clients, [a, c]);
+        assert_eq!(clients_by_world.remove(&nether).unwrap(), [b]);
+    }
+
+    #[test]
+    fn test_broadcast_chat() {
+        let mut app = App::new();
+        app.add_plugins(ChatPlugin);
+
+        let sent_packets = ReceivedMessages::default();
+        let sent_packets_clone = sent_packets.clone();
+        app.add_observer(move |send_game_packet: On<SendGamePacketEvent>| {
+            sent_packets_clone.push((send_game_packet.sent_by, send_game_packet.packet.clone()));
+        });
+
+        let ecs = app.world_mut();
+        let a = ecs.spawn((LocalEntity, InGameState)).id();
+        let b = ecs.spawn((LocalEntity, InGameState)).id();
+        // still in the configuration state, so it can't send chat messages yet
+        ecs.spawn((LocalEntity, InConfigState));
+
+        let swarm = create_swarm(std::mem::take(ecs), Worlds::default());
+        assert_eq!(swarm.broadcast_chat("hello"), 2);
+        swarm.ecs.write().run_schedule(Update);
+
+        let mut sent_packets = sent_packets.lock().clone();
+        sent_packets.sort_by_key(|(entity, _)| *entity);
+        let mut expected_senders = [a, b];
+        expected_senders.sort();
+        assert_eq!(sent_packets.len(), 2);
+        for ((sent_by, packet), expected_sender) in sent_packets.iter().zip(expected_senders) {
+            assert_eq!(*sent_by, expected_sender);
+            let ServerboundGamePacket::Chat(chat) = packet else {
+                panic!("expected a chat packet, got {packet:?}");
+            };
+            assert_eq!(chat.message, "hello");
+        }
+    }
+}
@@ -1,3 +1,3 @@
 //! A prelude that re-exports common swarm types in Azalea.
 
-pub use crate::swarm::{Swarm, SwarmBuilder, SwarmEvent};
+pub use crate::swarm::{SharedState, Swarm, SwarmBuilder, SwarmEvent};
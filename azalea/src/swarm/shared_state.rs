@@ -0,0 +1,115 @@
+//! Implements [`SharedState`].
+
+use std::sync::Arc;
+
+use bevy_ecs::resource::Resource;
+use parking_lot::{Mutex, MutexGuard};
+
+/// A value that's shared between every bot in a swarm and the swarm handler.
+///
+/// Cloning this is cheap and all the clones point to the same value, so it can
+/// be used as the swarm state in [`SwarmBuilder::set_swarm_state`] (or as a
+/// field in your own swarm state) to let your bots coordinate, for example to
+/// avoid attacking the same target.
+///
+/// The swarm state is inserted as a resource in the ECS, so bots can get it
+/// with [`Client::resource`].
+///
+/// ```
+/// # use azalea::{prelude::*, swarm::prelude::*};
+/// #[derive(Default)]
+/// struct Claims {
+///     targets: Vec<azalea::ecs::entity::Entity>,
+/// }
+///
+/// async fn handle(bot: Client, event: Event, _state: azalea::NoState) -> anyhow::Result<()> {
+///     let claims = bot.resource::<SharedState<Claims>>();
+///     if let Event::Tick = event {
+///         let target = bot.entity;
+///         let mut claims = claims.lock();
+///         if !claims.targets.contains(&target) {
+///             claims.targets.push(target);
+///         }
+///     }
+///     Ok(())
+/// }
+///
+/// async fn swarm_handle(
+///     _swarm: Swarm,
+///     _event: SwarmEvent,
+///     claims: SharedState<Claims>,
+/// ) -> anyhow::Result<()> {
+///     println!("{} targets claimed", claims.lock().targets.len());
+///     Ok(())
+/// }
+/// ```
+///
+/// # Locking
+///
+/// The lock should only be held for short amounts of time. In particular,
+/// don't hold it across an `.await`, and don't call methods on [`Client`] or
+/// [`Swarm`] while it's held, since those lock the ECS. If one of your systems
+/// also locks this while the ECS is locked, the two locks being taken in
+/// different orders can result in a deadlock.
+///
+/// [`SwarmBuilder::set_swarm_state`]: crate::swarm::SwarmBuilder::set_swarm_state
+/// [`Client::resource`]: crate::Client::resource
+/// [`Client`]: crate::Client
+/// [`Swarm`]: crate::swarm::Swarm
+#[derive(Debug, Default, Resource)]
+pub struct SharedState<T: Send + 'static>(Arc<Mutex<T>>);
+
+impl<T: Send + 'static> SharedState<T> {
+    pub fn new(value: T) -> Self {
+        Self(Arc::new(Mutex::new(value)))
+    }
+
+    /// Lock the state so it can be read or modified.
+    ///
+    /// See the [locking](Self#locking) section for how to avoid deadlocks.
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        self.0.lock()
+    }
+
+    /// Returns whether the two states point to the same value.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+// derive(Clone) would add a `T: Clone` bound
+impl<T: Send + 'static> Clone for SharedState<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::world::World;
+    use parking_lot::RwLock;
+
+    use super::*;
+    use crate::Client;
+
+    #[test]
+    fn test_shared_state_between_bots() {
+        let mut ecs = World::new();
+        let bot_a = ecs.spawn_empty().id();
+        let bot_b = ecs.spawn_empty().id();
+        ecs.insert_resource(SharedState::new(Vec::<String>::new()));
+        let ecs = Arc::new(RwLock::new(ecs));
+
+        let bot_a = Client::new(bot_a, ecs.clone());
+        let bot_b = Client::new(bot_b, ecs.clone());
+
+        bot_a
+            .resource::<SharedState<Vec<String>>>()
+            .lock()
+            .push("spawn".to_owned());
+
+        let state_b = bot_b.resource::<SharedState<Vec<String>>>();
+        assert_eq!(*state_b.lock(), vec!["spawn".to_owned()]);
+        assert!(state_b.ptr_eq(&bot_a.resource::<SharedState<Vec<String>>>()));
+    }
+}
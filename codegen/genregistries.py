@@ -14,6 +14,11 @@ def generate(version_id: str):
     lib.code.registry.generate_data_registries(data_registries)
     lib.code.inventory.update_menus(builtin_registries["minecraft:menu"]["entries"])
 
+    pumpkin_entities = lib.extract.get_pumpkin_data(version_id, "entities")
+    lib.code.registry.generate_mob_categories(
+        builtin_registries["minecraft:entity_type"], pumpkin_entities
+    )
+
     block_tags = lib.extract.get_registry_tags(version_id, "block")
     item_tags = lib.extract.get_registry_tags(version_id, "item")
     fluid_tags = lib.extract.get_registry_tags(version_id, "fluid")
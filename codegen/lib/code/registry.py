@@ -2,6 +2,7 @@ from lib.utils import get_dir_location, to_camel_case
 
 BUILTIN_REGISTRIES_DIR = get_dir_location("../azalea-registry/src/builtin.rs")
 DATA_REGISTRIES_DIR = get_dir_location("../azalea-registry/src/data.rs")
+MOB_CATEGORY_RS_DIR = get_dir_location("../azalea-registry/src/mob_category.rs")
 
 
 def generate_builtin_registries(registries: dict):
@@ -159,3 +160,41 @@ def registry_name_to_enum_name(registry_name: str) -> str:
         registry_name += "_kind"
 
     return to_camel_case(registry_name)
+
+
+def generate_mob_categories(entity_type_registry: dict, pumpkin_entities: dict):
+    # lines look like
+    # EntityKind::Zombie => MobCategory::Monster,
+    new_match_lines = []
+    entity_entries = sorted(
+        entity_type_registry["entries"].items(), key=lambda x: x[1]["protocol_id"]
+    )
+    for entity_id, _entity in entity_entries:
+        entity_name = entity_id.split(":")[-1]
+        variant_name = to_camel_case(entity_name)
+        # pumpkin uses the names of vanilla's MobCategory enum, like
+        # "UNDERGROUND_WATER_CREATURE"
+        category = pumpkin_entities[entity_name]["category"]
+        category_name = to_camel_case(category.lower())
+        new_match_lines.append(
+            f"            EntityKind::{variant_name} => MobCategory::{category_name},"
+        )
+
+    with open(MOB_CATEGORY_RS_DIR, "r") as f:
+        lines = f.read().split("\n")
+    new_lines = []
+
+    in_match = False
+    for i, line in enumerate(lines):
+        if not in_match:
+            new_lines.append(line)
+            if line.strip() == "match self {" and "category" in lines[i - 3]:
+                in_match = True
+        else:
+            if line == "        }":
+                new_lines.extend(new_match_lines)
+                new_lines.extend(lines[i:])
+                break
+
+    with open(MOB_CATEGORY_RS_DIR, "w") as f:
+        f.write("\n".join(new_lines))